@@ -0,0 +1,135 @@
+//! How `filter_words` scales with the word list and the number of guesses played
+//!
+//! Criterion measures every combination of 1k/5k/15k words and 1-6 guesses; a
+//! table of quick timings follows, so changes to the filter can be compared at a
+//! glance. The word lists are synthetic, drawn with English letter frequencies from
+//! a fixed seed, so the numbers don't depend on which lists are installed.
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box};
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
+use wordle::{ConstraintFilter, DefaultConstraintFilter, DefaultFeedbackGenerator};
+use wordle::{FeedbackGenerator, Guess, Word};
+
+const LIST_SIZES: [usize; 3] = [1_000, 5_000, 15_000];
+const MAX_GUESSES: usize = 6;
+
+/// Relative frequency of each letter in English text, a to z
+const LETTER_WEIGHTS: [u32; 26] = [
+    82, 15, 28, 43, 127, 22, 20, 61, 70, 2, 8, 40, 24, 67, 75, 19, 1, 60, 63, 91, 28, 10, 24, 2,
+    20, 1,
+];
+
+/// Time spent on each cell of the scaling table
+const TABLE_BUDGET: Duration = Duration::from_millis(200);
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// `count` different five-letter words, the same ones for the same count
+fn synthetic_words(count: usize) -> Vec<Word> {
+    let total: u32 = LETTER_WEIGHTS.iter().sum();
+    let mut state = 0x5EED;
+    let mut letter = || {
+        let mut pick = (splitmix64(&mut state) % total as u64) as u32;
+        for (i, weight) in LETTER_WEIGHTS.iter().enumerate() {
+            if pick < *weight {
+                return (b'a' + i as u8) as char;
+            }
+            pick -= weight;
+        }
+        'e'
+    };
+    let mut words = BTreeSet::new();
+    while words.len() < count {
+        let text: String = (0..Word::LENGTH).map(|_| letter()).collect();
+        words.insert(Word::from_str(&text).unwrap());
+    }
+    words.into_iter().collect()
+}
+
+/// Guesses spread over `words`, each scored against one target from the list
+fn history(words: &[Word], guesses: usize) -> Vec<Guess> {
+    let feedback = DefaultFeedbackGenerator::new();
+    let target = &words[words.len() / 2];
+    let step = words.len() / (guesses + 1);
+    (1..=guesses)
+        .map(|i| {
+            let word = words[(i * step + 7) % words.len()].clone();
+            let pattern = feedback.generate_feedback(&word, target);
+            Guess::new(word, pattern)
+        })
+        .collect()
+}
+
+fn bench_filter_scaling(c: &mut Criterion) {
+    let filter = DefaultConstraintFilter::new();
+    for size in LIST_SIZES {
+        let words = synthetic_words(size);
+        let mut group = c.benchmark_group(format!("filter_words/{}", size));
+        group.throughput(Throughput::Elements(size as u64));
+        for guesses in 1..=MAX_GUESSES {
+            let constraints = history(&words, guesses);
+            group.bench_with_input(
+                BenchmarkId::new("guesses", guesses),
+                &constraints,
+                |b, constraints| {
+                    b.iter(|| filter.filter_words(black_box(&words), black_box(constraints)))
+                },
+            );
+        }
+        group.finish();
+    }
+}
+
+/// Mean time of one `filter_words` call, run for about [`TABLE_BUDGET`]
+fn quick_timing(
+    filter: &DefaultConstraintFilter,
+    words: &[Word],
+    constraints: &[Guess],
+) -> Duration {
+    let started = Instant::now();
+    let mut runs = 0u32;
+    while started.elapsed() < TABLE_BUDGET {
+        black_box(filter.filter_words(black_box(words), black_box(constraints)));
+        runs += 1;
+    }
+    started.elapsed() / runs
+}
+
+/// One row per list size, one column per guess count: µs per call, and in brackets
+/// ns per word
+fn print_scaling_table() {
+    let filter = DefaultConstraintFilter::new();
+    println!();
+    println!("filter_words scaling: µs per call (ns per word)");
+    print!("{:>7}", "words");
+    for guesses in 1..=MAX_GUESSES {
+        let label = if guesses == 1 { "guess" } else { "guesses" };
+        print!("{:>17}", format!("{} {}", guesses, label));
+    }
+    println!();
+    for size in LIST_SIZES {
+        let words = synthetic_words(size);
+        print!("{:>7}", size);
+        for guesses in 1..=MAX_GUESSES {
+            let time = quick_timing(&filter, &words, &history(&words, guesses));
+            let per_word = time.as_nanos() as f64 / size as f64;
+            let cell = format!("{:.1} ({:.1})", time.as_secs_f64() * 1e6, per_word);
+            print!("{:>17}", cell);
+        }
+        println!();
+    }
+}
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_filter_scaling(&mut criterion);
+    criterion.final_summary();
+    print_scaling_table();
+}
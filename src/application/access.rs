@@ -0,0 +1,253 @@
+use crate::core::error::{InterfaceError, Result};
+use crate::infrastructure::{RateLimitConfig, ServerConfig};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// API key authentication
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeyAuthenticator {
+    keys: Vec<String>,
+}
+
+impl ApiKeyAuthenticator {
+    pub fn new(keys: Vec<String>) -> Self {
+        Self { keys }
+    }
+
+    /// Whether authentication is enforced
+    pub fn is_enabled(&self) -> bool {
+        !self.keys.is_empty()
+    }
+
+    /// Check a presented key; always succeeds when authentication is disabled
+    pub fn authenticate(&self, presented: Option<&str>) -> Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        let presented = presented.ok_or_else(|| {
+            InterfaceError::Unauthorized("Missing API key".to_string())
+        })?;
+        if self
+            .keys
+            .iter()
+            .any(|key| constant_time_eq(key.as_bytes(), presented.as_bytes()))
+        {
+            Ok(())
+        } else {
+            Err(InterfaceError::Unauthorized("Invalid API key".to_string()).into())
+        }
+    }
+}
+
+/// Compare without early exit so timing doesn't reveal matching prefixes
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Buckets kept before the ones refilled to capacity are dropped
+const SWEEP_ABOVE: usize = 1024;
+/// Buckets kept at most; the least recently used goes first
+const MAX_BUCKETS: usize = 65_536;
+
+/// Token-bucket rate limiter keyed by client (API key or address)
+///
+/// A bucket left alone until it's full again is no different from a new one, so
+/// those are dropped once many clients have been seen.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        Self {
+            capacity: (config.requests_per_minute + config.burst).max(1) as f64,
+            refill_per_sec: config.requests_per_minute as f64 / 60.0,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take one token for `key`, failing with the time until the next token is available
+    pub fn check(&self, key: &str) -> Result<()> {
+        self.check_at(key, Instant::now())
+    }
+
+    fn check_at(&self, key: &str, now: Instant) -> Result<()> {
+        self.take_at(key, now, true)
+    }
+
+    /// Fail like [`check`](Self::check) when `key` is out of tokens, without taking one
+    pub fn ensure_available(&self, key: &str) -> Result<()> {
+        self.take_at(key, Instant::now(), false)
+    }
+
+    fn take_at(&self, key: &str, now: Instant, take: bool) -> Result<()> {
+        let mut buckets = self
+            .buckets
+            .lock()
+            .map_err(|_| InterfaceError::EventError("Rate limiter poisoned".to_string()))?;
+        if !buckets.contains_key(key) && buckets.len() >= SWEEP_ABOVE {
+            self.evict(&mut buckets, now);
+        }
+        let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: self.capacity,
+            last_refill: now,
+        });
+
+        let elapsed = now.saturating_duration_since(bucket.last_refill);
+        bucket.tokens =
+            (bucket.tokens + elapsed.as_secs_f64() * self.refill_per_sec).min(self.capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            if take {
+                bucket.tokens -= 1.0;
+            }
+            Ok(())
+        } else {
+            let retry_after = if self.refill_per_sec > 0.0 {
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.refill_per_sec)
+            } else {
+                Duration::MAX
+            };
+            Err(InterfaceError::RateLimited { retry_after }.into())
+        }
+    }
+
+    /// Drop buckets that have refilled to capacity, then the least recently used
+    /// ones while there are still too many
+    fn evict(&self, buckets: &mut HashMap<String, Bucket>, now: Instant) {
+        buckets.retain(|_, bucket| {
+            let elapsed = now.saturating_duration_since(bucket.last_refill);
+            bucket.tokens + elapsed.as_secs_f64() * self.refill_per_sec < self.capacity
+        });
+        while buckets.len() >= MAX_BUCKETS {
+            let Some(oldest) = buckets
+                .iter()
+                .min_by_key(|(_, bucket)| bucket.last_refill)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            buckets.remove(&oldest);
+        }
+    }
+}
+
+/// Combined auth + rate limiting check applied to every incoming request
+#[derive(Debug)]
+pub struct AccessGuard {
+    authenticator: ApiKeyAuthenticator,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl AccessGuard {
+    pub fn from_config(config: &ServerConfig) -> Self {
+        Self {
+            authenticator: ApiKeyAuthenticator::new(config.api_keys.clone()),
+            rate_limiter: config.rate_limit.as_ref().map(RateLimiter::new),
+        }
+    }
+
+    /// Authorize a request. `client` identifies anonymous callers (e.g. the peer
+    /// address) for rate limiting when no API key is used.
+    ///
+    /// A key is only trusted once it checks out, so `client` must have tokens left
+    /// before one is tried, and each rejected key costs `client` a token: guessing
+    /// keys is throttled like any other request.
+    pub fn check(&self, api_key: Option<&str>, client: &str) -> Result<()> {
+        let Some(limiter) = &self.rate_limiter else {
+            return self.authenticator.authenticate(api_key);
+        };
+        limiter.ensure_available(client)?;
+        if let Err(e) = self.authenticator.authenticate(api_key) {
+            limiter.check(client)?;
+            return Err(e);
+        }
+        limiter.check(api_key.unwrap_or(client))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::error::WordleError;
+
+    #[test]
+    fn test_authentication() {
+        let open = ApiKeyAuthenticator::default();
+        assert!(open.authenticate(None).is_ok());
+
+        let auth = ApiKeyAuthenticator::new(vec!["secret".to_string()]);
+        assert!(auth.authenticate(Some("secret")).is_ok());
+        assert!(auth.authenticate(Some("secreT")).is_err());
+        assert!(auth.authenticate(None).is_err());
+    }
+
+    #[test]
+    fn test_rate_limiter_refills() {
+        let limiter = RateLimiter::new(&RateLimitConfig {
+            requests_per_minute: 60,
+            burst: 1,
+        });
+        let start = Instant::now();
+        // Capacity is rpm + burst
+        for _ in 0..61 {
+            assert!(limiter.check_at("k", start).is_ok());
+        }
+        match limiter.check_at("k", start) {
+            Err(WordleError::Interface(InterfaceError::RateLimited { retry_after })) => {
+                assert!(retry_after <= Duration::from_secs(1));
+            }
+            other => panic!("expected rate limit error, got {:?}", other),
+        }
+        // One token per second at 60 rpm
+        assert!(limiter.check_at("k", start + Duration::from_secs(1)).is_ok());
+        // Other keys have their own bucket
+        assert!(limiter.check_at("other", start).is_ok());
+
+        // Clients whose bucket has refilled are forgotten once there are many
+        for client in 0..SWEEP_ABOVE {
+            limiter.check_at(&client.to_string(), start).unwrap();
+        }
+        let later = start + Duration::from_secs(120);
+        assert!(limiter.check_at("new", later).is_ok());
+        assert_eq!(limiter.buckets.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_failed_keys_are_rate_limited() {
+        let guard = AccessGuard::from_config(&ServerConfig {
+            api_keys: vec!["secret".to_string()],
+            rate_limit: Some(RateLimitConfig {
+                requests_per_minute: 2,
+                burst: 0,
+            }),
+            ..ServerConfig::default()
+        });
+        for guess in ["a", "b"] {
+            assert!(matches!(
+                guard.check(Some(guess), "10.0.0.1"),
+                Err(WordleError::Interface(InterfaceError::Unauthorized(_)))
+            ));
+        }
+        // Out of tokens: even the right key is refused without being checked
+        assert!(matches!(
+            guard.check(Some("secret"), "10.0.0.1"),
+            Err(WordleError::Interface(InterfaceError::RateLimited { .. }))
+        ));
+        assert!(guard.check(Some("secret"), "10.0.0.2").is_ok());
+    }
+}
@@ -0,0 +1,562 @@
+//! Simulated games against known answers
+//!
+//! [`BenchmarkRunner`] plays the solver against each target from a source of
+//! answers, scoring guesses with a feedback generator, and hands every finished
+//! game to a [`BenchmarkReporter`]. The `benchmark` command and answer analysis
+//! both run on it instead of driving a game engine themselves.
+//!
+//! [`BenchmarkRunner::run_concurrent`] plays several games at once on the rayon
+//! pool; games then reach the reporter in the order they finish.
+
+use crate::{
+    core::{
+        error::{Result, SolverError, WordleError},
+        traits::{FeedbackGenerator, WordleSolver},
+        types::{Guess, Word},
+    },
+    domain::{DEFAULT_MAX_ATTEMPTS, DefaultFeedbackGenerator},
+};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::mpsc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// One simulated game
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedGame {
+    pub target: Word,
+    pub guesses: Vec<Guess>,
+    pub solved: bool,
+}
+
+impl SimulatedGame {
+    /// Guesses played, including the winning one
+    pub fn guess_count(&self) -> usize {
+        self.guesses.len()
+    }
+}
+
+/// Totals over the games a runner has played
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BenchmarkSummary {
+    pub games: usize,
+    pub wins: usize,
+    /// Guesses over all games, lost ones included
+    pub total_guesses: usize,
+    /// Guesses over the won games only
+    pub winning_guesses: usize,
+    /// Most guesses any game took, lost ones included
+    pub worst_guesses: usize,
+    pub elapsed: Duration,
+}
+
+impl BenchmarkSummary {
+    fn record(&mut self, game: &SimulatedGame) {
+        self.games += 1;
+        self.total_guesses += game.guess_count();
+        self.worst_guesses = self.worst_guesses.max(game.guess_count());
+        if game.solved {
+            self.wins += 1;
+            self.winning_guesses += game.guess_count();
+        }
+    }
+
+    /// Share of games won, 0.0 before any game
+    pub fn win_rate(&self) -> f64 {
+        ratio(self.wins, self.games)
+    }
+
+    /// Mean guesses per game, lost games counting every guess they used
+    pub fn average_guesses(&self) -> f64 {
+        ratio(self.total_guesses, self.games)
+    }
+
+    /// Mean guesses per won game
+    pub fn average_winning_guesses(&self) -> f64 {
+        ratio(self.winning_guesses, self.wins)
+    }
+
+    pub fn games_per_second(&self) -> f64 {
+        match self.elapsed.as_secs_f64() {
+            secs if secs > 0.0 => self.games as f64 / secs,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Headline figures of a benchmark, saved as JSON to compare later runs against
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkReport {
+    pub games: usize,
+    pub win_rate: f64,
+    pub average_guesses: f64,
+    pub worst_guesses: usize,
+    /// Wall-clock milliseconds per game; games run concurrently, so this depends on
+    /// the machine's cores as well as the solver
+    pub ms_per_game: f64,
+}
+
+impl From<&BenchmarkSummary> for BenchmarkReport {
+    fn from(summary: &BenchmarkSummary) -> Self {
+        let ms_per_game = match summary.games {
+            0 => 0.0,
+            games => summary.elapsed.as_secs_f64() * 1000.0 / games as f64,
+        };
+        Self {
+            games: summary.games,
+            win_rate: summary.win_rate(),
+            average_guesses: summary.average_guesses(),
+            worst_guesses: summary.worst_guesses,
+            ms_per_game,
+        }
+    }
+}
+
+/// How one metric moved from the baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricChange {
+    pub metric: &'static str,
+    pub baseline: f64,
+    pub current: f64,
+    /// How much worse, as a fraction of the baseline
+    pub change: f64,
+}
+
+impl BenchmarkReport {
+    /// The compared metrics with whether a higher value is worse
+    fn metrics(&self) -> [(&'static str, f64, bool); 3] {
+        [
+            ("average guesses", self.average_guesses, true),
+            ("win rate", self.win_rate, false),
+            ("ms per game", self.ms_per_game, true),
+        ]
+    }
+
+    /// Each compared metric against `baseline`: name, baseline, current value and
+    /// the change for the worse as a fraction of the baseline (negative when better)
+    pub fn compare(&self, baseline: &Self) -> Vec<MetricChange> {
+        baseline
+            .metrics()
+            .into_iter()
+            .zip(self.metrics())
+            .filter(|((_, before, _), _)| *before != 0.0)
+            .map(|((metric, before, higher_is_worse), (_, now, _))| {
+                let worse_by = if higher_is_worse {
+                    now - before
+                } else {
+                    before - now
+                };
+                MetricChange {
+                    metric,
+                    baseline: before,
+                    current: now,
+                    change: worse_by / before,
+                }
+            })
+            .collect()
+    }
+
+    /// Metrics worse than in `baseline` by more than `threshold` (0.05 = 5%)
+    pub fn regressions(&self, baseline: &Self, threshold: f64) -> Vec<MetricChange> {
+        self.compare(baseline)
+            .into_iter()
+            .filter(|r| r.change > threshold)
+            .collect()
+    }
+}
+
+fn ratio(part: usize, whole: usize) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64
+    }
+}
+
+/// Receives each game as a benchmark progresses
+pub trait BenchmarkReporter {
+    /// Called after every game with the totals so far
+    fn game_played(&mut self, game: &SimulatedGame, summary: &BenchmarkSummary);
+}
+
+/// Reports nothing
+impl BenchmarkReporter for () {
+    fn game_played(&mut self, _game: &SimulatedGame, _summary: &BenchmarkSummary) {}
+}
+
+impl<F: FnMut(&SimulatedGame, &BenchmarkSummary)> BenchmarkReporter for F {
+    fn game_played(&mut self, game: &SimulatedGame, summary: &BenchmarkSummary) {
+        self(game, summary)
+    }
+}
+
+/// Plays a solver against known answers without touching its own game
+pub struct BenchmarkRunner<'a> {
+    solver: &'a dyn WordleSolver,
+    feedback: Box<dyn FeedbackGenerator>,
+    max_guesses: usize,
+}
+
+impl fmt::Debug for BenchmarkRunner<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BenchmarkRunner")
+            .field("solver", &"&dyn WordleSolver")
+            .field("feedback", &self.feedback)
+            .field("max_guesses", &self.max_guesses)
+            .finish()
+    }
+}
+
+impl<'a> BenchmarkRunner<'a> {
+    pub fn new(solver: &'a dyn WordleSolver) -> Self {
+        Self {
+            solver,
+            feedback: Box::new(DefaultFeedbackGenerator::new()),
+            max_guesses: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+
+    pub fn with_feedback_generator(mut self, feedback: Box<dyn FeedbackGenerator>) -> Self {
+        self.feedback = feedback;
+        self
+    }
+
+    pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = max_guesses.max(1);
+        self
+    }
+
+    /// Play one game against `target`
+    ///
+    /// A target the solver can't reach (one outside its answer list) ends the game
+    /// unsolved rather than failing.
+    pub fn play(&self, target: &Word) -> Result<SimulatedGame> {
+        self.play_from(None, target)
+    }
+
+    /// Play one game against `target` opening with `opener` instead of the solver's
+    /// first guess; the solver picks every later guess
+    pub fn play_opening(&self, opener: &Word, target: &Word) -> Result<SimulatedGame> {
+        self.play_from(Some(opener), target)
+    }
+
+    fn play_from(&self, opener: Option<&Word>, target: &Word) -> Result<SimulatedGame> {
+        let mut guesses: Vec<Guess> = Vec::with_capacity(self.max_guesses);
+        let mut solved = false;
+        while !solved && guesses.len() < self.max_guesses {
+            let next = match opener {
+                Some(opener) if guesses.is_empty() => Ok(opener.clone()),
+                _ => self.solver.best_guess_after(&guesses),
+            };
+            let word = match next {
+                Ok(word) => word,
+                Err(WordleError::Solver(SolverError::NoPossibleWords)) => break,
+                Err(e) => return Err(e),
+            };
+            let feedback = self.feedback.generate_feedback(&word, target);
+            solved = feedback.is_win();
+            guesses.push(Guess::new(word, feedback));
+        }
+        Ok(SimulatedGame {
+            target: target.clone(),
+            guesses,
+            solved,
+        })
+    }
+
+    /// Play every target in turn, reporting each game, and return the totals
+    pub fn run(
+        &self,
+        targets: impl IntoIterator<Item = Word>,
+        reporter: &mut dyn BenchmarkReporter,
+    ) -> Result<BenchmarkSummary> {
+        let started = Instant::now();
+        let mut summary = BenchmarkSummary::default();
+        for target in targets {
+            let game = self.play(&target)?;
+            summary.record(&game);
+            summary.elapsed = started.elapsed();
+            reporter.game_played(&game, &summary);
+        }
+        summary.elapsed = started.elapsed();
+        Ok(summary)
+    }
+
+    /// Like [`run`](Self::run), but playing games in parallel
+    ///
+    /// The reporter is called on the calling thread, once per game as it finishes.
+    /// The first error stops the games not yet started.
+    pub fn run_concurrent<I>(
+        &self,
+        targets: I,
+        reporter: &mut dyn BenchmarkReporter,
+    ) -> Result<BenchmarkSummary>
+    where
+        I: IntoIterator<Item = Word>,
+        I::IntoIter: Send,
+    {
+        let started = Instant::now();
+        let mut summary = BenchmarkSummary::default();
+        let (sender, games) = mpsc::channel();
+        let targets = targets.into_iter();
+        std::thread::scope(|scope| {
+            let worker = scope.spawn(move || {
+                targets
+                    .par_bridge()
+                    .try_for_each_with(sender, |sender, target| {
+                        // The receiver outlives the worker, so sending can't fail
+                        let _ = sender.send(self.play(&target)?);
+                        Result::Ok(())
+                    })
+            });
+            for game in games {
+                summary.record(&game);
+                summary.elapsed = started.elapsed();
+                reporter.game_played(&game, &summary);
+            }
+            worker.join().expect("benchmark worker panicked")
+        })?;
+        summary.elapsed = started.elapsed();
+        Ok(summary)
+    }
+}
+
+/// How long a benchmark runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BenchmarkGoal {
+    /// A fixed number of games
+    Games(usize),
+    /// As many games as fit in the time
+    Duration(Duration),
+}
+
+/// Games kept by [`BenchmarkProgress`] as the worst so far
+pub const WORST_GAMES_KEPT: usize = 5;
+
+/// Running totals of a benchmark, for progress displays
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkProgress {
+    pub goal: BenchmarkGoal,
+    pub summary: BenchmarkSummary,
+    /// Lost games first, then those that took the most guesses
+    pub worst: Vec<SimulatedGame>,
+}
+
+impl BenchmarkProgress {
+    pub fn new(goal: BenchmarkGoal) -> Self {
+        Self {
+            goal,
+            summary: BenchmarkSummary::default(),
+            worst: Vec::with_capacity(WORST_GAMES_KEPT + 1),
+        }
+    }
+
+    /// Share of the goal reached, from 0.0 to 1.0
+    pub fn fraction(&self) -> f64 {
+        let fraction = match self.goal {
+            BenchmarkGoal::Games(games) => ratio(self.summary.games, games),
+            BenchmarkGoal::Duration(budget) if !budget.is_zero() => {
+                self.summary.elapsed.as_secs_f64() / budget.as_secs_f64()
+            }
+            BenchmarkGoal::Duration(_) => 1.0,
+        };
+        fraction.clamp(0.0, 1.0)
+    }
+
+    /// Time left at the current pace; `None` before the first game
+    pub fn eta(&self) -> Option<Duration> {
+        match self.goal {
+            BenchmarkGoal::Games(games) => {
+                let rate = self.summary.games_per_second();
+                (rate > 0.0).then(|| {
+                    let left = games.saturating_sub(self.summary.games);
+                    Duration::from_secs_f64(left as f64 / rate)
+                })
+            }
+            BenchmarkGoal::Duration(budget) => {
+                (self.summary.games > 0).then(|| budget.saturating_sub(self.summary.elapsed))
+            }
+        }
+    }
+}
+
+impl BenchmarkReporter for BenchmarkProgress {
+    fn game_played(&mut self, game: &SimulatedGame, summary: &BenchmarkSummary) {
+        self.summary = *summary;
+        let badness = |game: &SimulatedGame| (!game.solved, game.guess_count());
+        let at = self
+            .worst
+            .partition_point(|kept| badness(kept) >= badness(game));
+        if at < WORST_GAMES_KEPT {
+            self.worst.insert(at, game.clone());
+            self.worst.truncate(WORST_GAMES_KEPT);
+        }
+    }
+}
+
+/// Endless stream of answers picked at random, repeats allowed
+#[derive(Debug, Clone)]
+pub struct RandomTargets {
+    answers: Vec<Word>,
+    state: u64,
+}
+
+impl RandomTargets {
+    /// Seeded from the clock, so each run draws different targets
+    pub fn new(answers: Vec<Word>) -> Self {
+        Self::with_seed(answers, clock_seed())
+    }
+
+    /// The same seed always draws the same targets
+    pub fn with_seed(answers: Vec<Word>, seed: u64) -> Self {
+        Self {
+            answers,
+            state: seed,
+        }
+    }
+
+    /// Stop drawing once `budget` has passed since this call
+    pub fn for_duration(self, budget: Duration) -> impl Iterator<Item = Word> {
+        let started = Instant::now();
+        self.take_while(move |_| started.elapsed() < budget)
+    }
+}
+
+impl Iterator for RandomTargets {
+    type Item = Word;
+
+    fn next(&mut self) -> Option<Word> {
+        if self.answers.is_empty() {
+            return None;
+        }
+        let z = splitmix64(&mut self.state);
+        Some(self.answers[(z % self.answers.len() as u64) as usize].clone())
+    }
+}
+
+/// A seed that differs from run to run
+pub(crate) fn clock_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+/// Next value of the splitmix64 sequence at `state`
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::testing::words;
+    use crate::domain::solver_engine::testing::fixed_solver;
+
+    const ANSWERS: [&str; 6] = ["crane", "crate", "trace", "slate", "plate", "grate"];
+
+    #[tokio::test]
+    async fn test_runner_solves_every_answer() {
+        let solver = fixed_solver(&ANSWERS).await;
+        let runner = BenchmarkRunner::new(&solver);
+
+        let mut played = Vec::new();
+        let mut reporter = |game: &SimulatedGame, summary: &BenchmarkSummary| {
+            played.push((game.target.clone(), summary.games));
+        };
+        let summary = runner.run(words(&ANSWERS), &mut reporter).unwrap();
+
+        assert_eq!(summary.games, ANSWERS.len());
+        assert_eq!(summary.wins, ANSWERS.len());
+        assert_eq!(summary.win_rate(), 1.0);
+        assert_eq!(summary.total_guesses, summary.winning_guesses);
+        assert!(summary.average_guesses() >= 1.0);
+        assert_eq!(played.len(), ANSWERS.len());
+        assert_eq!(played[2], (Word::from_str("trace").unwrap(), 3));
+
+        // The solver's own game is untouched
+        assert!(solver.get_guess_history().is_empty());
+    }
+
+    #[test]
+    fn test_regressions_past_the_threshold() {
+        let report = |average_guesses, win_rate, ms_per_game| BenchmarkReport {
+            games: 100,
+            win_rate,
+            average_guesses,
+            worst_guesses: 6,
+            ms_per_game,
+        };
+        let baseline = report(3.6, 1.0, 10.0);
+        // 2.8% more guesses, 10% fewer wins, twice as fast
+        let current = report(3.7, 0.9, 5.0);
+
+        let changes = current.compare(&baseline);
+        assert!((changes[0].change - 0.1 / 3.6).abs() < 1e-9);
+        assert!((changes[2].change + 0.5).abs() < 1e-9);
+        let regressed: Vec<_> = current
+            .regressions(&baseline, 0.05)
+            .into_iter()
+            .map(|r| r.metric)
+            .collect();
+        assert_eq!(regressed, vec!["win rate"]);
+        assert_eq!(current.regressions(&baseline, 0.01).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_runner_gives_up_on_unknown_targets() {
+        let solver = fixed_solver(&ANSWERS).await;
+        let runner = BenchmarkRunner::new(&solver).with_max_guesses(2);
+
+        let game = runner.play(&Word::from_str("zesty").unwrap()).unwrap();
+        assert!(!game.solved);
+        assert!(game.guess_count() <= 2);
+
+        let summary = runner.run(words(&["zesty"]), &mut ()).unwrap();
+        assert_eq!((summary.games, summary.wins), (1, 0));
+        assert_eq!(summary.average_winning_guesses(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_run_matches_sequential() {
+        let solver = fixed_solver(&ANSWERS).await;
+        let runner = BenchmarkRunner::new(&solver);
+        let targets = words(&["crane", "zesty", "trace", "slate", "plate", "grate"]);
+
+        let sequential = runner.run(targets.clone(), &mut ()).unwrap();
+        let mut progress = BenchmarkProgress::new(BenchmarkGoal::Games(targets.len()));
+        let concurrent = runner.run_concurrent(targets, &mut progress).unwrap();
+
+        assert_eq!(concurrent.games, sequential.games);
+        assert_eq!(concurrent.wins, sequential.wins);
+        assert_eq!(concurrent.total_guesses, sequential.total_guesses);
+        assert_eq!(progress.summary.games, 6);
+        assert_eq!(progress.fraction(), 1.0);
+        assert_eq!(progress.eta(), Some(Duration::ZERO));
+        // ZESTY isn't an answer, so it's the worst game however the others went
+        assert_eq!(progress.worst.len(), WORST_GAMES_KEPT);
+        assert_eq!(progress.worst[0].target.as_str(), "zesty");
+        let counts: Vec<usize> = progress.worst[1..]
+            .iter()
+            .map(|g| g.guess_count())
+            .collect();
+        assert!(counts.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+
+    #[test]
+    fn test_random_targets_repeat_for_a_seed() {
+        let draw = |seed| {
+            RandomTargets::with_seed(words(&ANSWERS), seed)
+                .take(20)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(draw(7), draw(7));
+        assert!(draw(7).iter().all(|w| ANSWERS.contains(&w.as_str())));
+        assert_eq!(RandomTargets::with_seed(Vec::new(), 7).next(), None);
+    }
+}
@@ -1,6 +1,9 @@
-use crate::core::{
-    error::Result,
-    types::{FeedbackPattern, Word},
+use crate::{
+    core::{
+        error::{GameError, Result, SolverError},
+        types::{FeedbackPattern, ManualConstraint, Word},
+    },
+    domain::{WordOrder, WordPage},
 };
 
 /// Commands that can be executed in the application
@@ -15,6 +18,14 @@ pub enum Command {
         word: Word,
         feedback: FeedbackPattern,
     },
+    /// Add several guess results in order, e.g. a pasted game transcript
+    AddGuessBatch {
+        guesses: Vec<(Word, FeedbackPattern)>,
+    },
+    /// Pin a letter to a position or ban a letter, from knowledge outside the game
+    AddManualConstraint { constraint: ManualConstraint },
+    /// Drop every pinned and banned letter, keeping the guesses
+    ClearManualConstraints,
     /// Get the best next guess
     GetBestGuess,
     /// Get the best first guess
@@ -25,6 +36,95 @@ pub enum Command {
     GetStatistics,
     /// Get top candidate guesses
     GetTopCandidates { limit: usize },
+    /// Measure how a guess would split the remaining words
+    EvaluateGuess { word: Word },
+    /// Take back the latest guess result
+    UndoLastGuess,
+    /// Get the best-information, best-answer-chance and balanced suggestions
+    GetSuggestionSet,
+    /// Find a guess that separates near-identical remaining words
+    FindDisambiguator,
+    /// Get up to `limit` possible words from `offset` on, in `sort` order
+    GetPossibleWordsPage {
+        offset: usize,
+        limit: usize,
+        sort: WordOrder,
+    },
+}
+
+impl Command {
+    /// Stable snake_case name of the command (used for metrics and logs)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::StartGame { .. } => "start_game",
+            Command::MakeGuess { .. } => "make_guess",
+            Command::AddGuessResult { .. } => "add_guess_result",
+            Command::AddGuessBatch { .. } => "add_guess_batch",
+            Command::AddManualConstraint { .. } => "add_manual_constraint",
+            Command::ClearManualConstraints => "clear_manual_constraints",
+            Command::GetBestGuess => "get_best_guess",
+            Command::GetBestFirstGuess => "get_best_first_guess",
+            Command::Reset => "reset",
+            Command::GetStatistics => "get_statistics",
+            Command::GetTopCandidates { .. } => "get_top_candidates",
+            Command::EvaluateGuess { .. } => "evaluate_guess",
+            Command::UndoLastGuess => "undo_last_guess",
+            Command::GetSuggestionSet => "get_suggestion_set",
+            Command::FindDisambiguator => "find_disambiguator",
+            Command::GetPossibleWordsPage { .. } => "get_possible_words_page",
+        }
+    }
+
+    /// Short name for the latency shown to players, e.g. `suggest`
+    pub fn label(&self) -> &'static str {
+        match self {
+            Command::StartGame { .. } => "start",
+            Command::MakeGuess { .. } => "guess",
+            Command::AddGuessResult { .. } => "feedback",
+            Command::AddGuessBatch { .. } => "batch",
+            Command::AddManualConstraint { .. } => "constrain",
+            Command::ClearManualConstraints => "unconstrain",
+            Command::GetBestGuess => "suggest",
+            Command::GetBestFirstGuess => "opener",
+            Command::Reset => "reset",
+            Command::GetStatistics => "stats",
+            Command::GetTopCandidates { .. } => "rank",
+            Command::EvaluateGuess { .. } => "evaluate",
+            Command::UndoLastGuess => "undo",
+            Command::GetSuggestionSet => "suggestions",
+            Command::FindDisambiguator => "disambiguate",
+            Command::GetPossibleWordsPage { .. } => "page",
+        }
+    }
+
+    /// Whether the command runs the solver over the possible words: it filters
+    /// them, scores them or both, rather than looking something up
+    ///
+    /// These are the commands whose time is kept as the
+    /// [last operation](crate::core::metrics::MetricsRegistry::last_operation).
+    pub fn is_solver_operation(&self) -> bool {
+        matches!(
+            self,
+            Command::StartGame { .. }
+                | Command::MakeGuess { .. }
+                | Command::AddGuessResult { .. }
+                | Command::AddGuessBatch { .. }
+                | Command::AddManualConstraint { .. }
+                | Command::ClearManualConstraints
+                | Command::GetBestGuess
+                | Command::GetBestFirstGuess
+                | Command::Reset
+                | Command::UndoLastGuess
+        )
+    }
+
+    /// Whether the command computes solver suggestions
+    pub fn is_suggestion(&self) -> bool {
+        matches!(
+            self,
+            Command::GetBestGuess | Command::GetTopCandidates { .. } | Command::GetSuggestionSet
+        )
+    }
 }
 
 /// Result of executing a command
@@ -39,6 +139,10 @@ pub enum CommandResult {
     },
     /// Guess result was added
     GuessResultAdded { remaining_words: usize },
+    /// Batch of guess results was added; remaining word counts after each entry
+    GuessBatchAdded { remaining_words: Vec<usize> },
+    /// Manual constraints changed; words left under the guesses and every constraint
+    ManualConstraintsUpdated { remaining_words: usize },
     /// Best guess determined
     BestGuess { word: Word, confidence: f64 },
     /// Best first guess determined
@@ -51,10 +155,90 @@ pub enum CommandResult {
     },
     /// Top candidates retrieved
     TopCandidates { candidates: Vec<(Word, f64)> },
+    /// Guess evaluated against the remaining words
+    GuessEvaluated {
+        word: Word,
+        quality: crate::core::types::SuggestionQuality,
+    },
+    /// Latest guess taken back; `None` if there was nothing to undo
+    GuessUndone {
+        guess: Option<crate::core::types::Guess>,
+        remaining_words: usize,
+    },
+    /// Suggestions for each risk level; `None` if no words remain
+    SuggestionSet {
+        set: Option<crate::core::types::SuggestionSet>,
+    },
+    /// Disambiguating guess; `None` unless the remaining words are near-identical
+    Disambiguator {
+        found: Option<crate::core::types::Disambiguator>,
+    },
+    /// Page of the possible words, with how many there are in all
+    PossibleWordsPage { page: WordPage },
     /// Command failed
     Error { message: String },
 }
 
+/// Parse a single guess entry: `crane=01020`, `crane:01020` or `crane 01020`
+///
+/// The feedback may also use colour initials and spaces, e.g. `crane g g y b b`; see
+/// [`FeedbackPattern::parse`].
+pub fn parse_guess_entry(entry: &str) -> Result<(Word, FeedbackPattern)> {
+    split_guess_entry(entry).map_err(|msg| SolverError::InvalidFeedback(msg).into())
+}
+
+fn split_guess_entry(entry: &str) -> std::result::Result<(Word, FeedbackPattern), String> {
+    let entry = entry.trim();
+    let parts: Vec<&str> = entry
+        .split(|c: char| c.is_whitespace() || matches!(c, '=' | ':'))
+        .filter(|t| !t.is_empty())
+        .collect();
+    // Everything after the word is feedback, which may be spaced out ("g g y b b")
+    let Some((word, feedback)) = parts.split_first().filter(|(_, rest)| !rest.is_empty()) else {
+        return Err(format!("'{}' should be WORD=FEEDBACK, e.g. crane=01020", entry));
+    };
+    let feedback = feedback.concat();
+
+    let word = Word::parse_guess(word).map_err(|e| match e {
+        GameError::InvalidWordLength { .. } => format!("word '{}': {}", word, e),
+        e => e.to_string(),
+    })?;
+    let feedback = FeedbackPattern::parse(&feedback).map_err(|e| {
+        format!(
+            "feedback '{}' for {}: {} (use five of 0/b=absent, 1/y=present, 2/g=correct)",
+            feedback, word, e
+        )
+    })?;
+    Ok((word, feedback))
+}
+
+/// Parse a list of guess entries, e.g. `"crane:01020,sloth:20010"` or one
+/// `crane 01020` per line
+///
+/// Entries are separated by newlines, commas or semicolons; see
+/// [`parse_guess_entry`] for the entry format. Errors name the 1-based entry that failed.
+pub fn parse_guess_batch(text: &str) -> Result<Vec<(Word, FeedbackPattern)>> {
+    let entries: Vec<&str> = text
+        .split(['\n', ',', ';'])
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .collect();
+
+    if entries.is_empty() {
+        return Err(SolverError::InvalidFeedback("No guesses given".to_string()).into());
+    }
+
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            split_guess_entry(entry).map_err(|msg| {
+                SolverError::InvalidFeedback(format!("entry {}: {}", i + 1, msg)).into()
+            })
+        })
+        .collect()
+}
+
 /// Trait for command execution
 pub trait CommandExecutor {
     /// Execute a command and return the result
@@ -62,7 +246,7 @@ pub trait CommandExecutor {
 }
 
 /// Command validation
-pub trait CommandValidator: std::fmt::Debug {
+pub trait CommandValidator: Send + Sync + std::fmt::Debug {
     /// Validate a command before execution
     fn validate(&self, command: &Command) -> Result<()>;
 }
@@ -86,24 +270,26 @@ impl CommandValidator for DefaultCommandValidator {
                 }
                 Ok(())
             }
-            Command::AddGuessResult { word, feedback } => {
-                // Validate word and feedback consistency
-                if word.as_str().len() != 5 {
-                    return Err(crate::core::error::GameError::InvalidWordLength {
-                        expected: 5,
-                        actual: word.as_str().len(),
-                    }
-                    .into());
+            Command::AddGuessResult { word, feedback } => validate_guess_result(word, feedback),
+            Command::AddGuessBatch { guesses } => {
+                if guesses.is_empty() {
+                    return Err(SolverError::InvalidFeedback("No guesses given".to_string()).into());
                 }
-                if feedback.as_slice().len() != 5 {
-                    return Err(crate::core::error::SolverError::InvalidFeedback(
-                        "Feedback must have exactly 5 elements".to_string(),
-                    )
-                    .into());
+                for (i, (word, feedback)) in guesses.iter().enumerate() {
+                    validate_guess_result(word, feedback)?;
+                    // A solved row must end the game
+                    if feedback.is_win() && i + 1 < guesses.len() {
+                        return Err(SolverError::InvalidFeedback(format!(
+                            "entry {} ('{}') is already solved but more guesses follow",
+                            i + 1,
+                            word
+                        ))
+                        .into());
+                    }
                 }
                 Ok(())
             }
-            Command::GetTopCandidates { limit } => {
+            Command::GetTopCandidates { limit } | Command::GetPossibleWordsPage { limit, .. } => {
                 if *limit == 0 {
                     return Err(crate::core::error::SolverError::InvalidFeedback(
                         "Limit must be greater than 0".to_string(),
@@ -118,6 +304,24 @@ impl CommandValidator for DefaultCommandValidator {
     }
 }
 
+/// Validate word and feedback consistency
+fn validate_guess_result(word: &Word, feedback: &FeedbackPattern) -> Result<()> {
+    if word.as_str().len() != 5 {
+        return Err(crate::core::error::GameError::InvalidWordLength {
+            expected: 5,
+            actual: word.as_str().len(),
+        }
+        .into());
+    }
+    if feedback.as_slice().len() != 5 {
+        return Err(crate::core::error::SolverError::InvalidFeedback(
+            "Feedback must have exactly 5 elements".to_string(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,4 +339,56 @@ mod tests {
         let command = Command::GetTopCandidates { limit: 0 };
         assert!(validator.validate(&command).is_err());
     }
+
+    #[test]
+    fn test_parse_guess_batch() {
+        let guesses = parse_guess_batch("crane 01020\nsloth 20010\n").unwrap();
+        assert_eq!(guesses.len(), 2);
+        assert_eq!(guesses[1].0.as_str(), "sloth");
+        assert_eq!(guesses[1].1.to_code_string(), "20010");
+
+        assert_eq!(parse_guess_batch("crane 01020; sloth 20010").unwrap().len(), 2);
+        assert_eq!(parse_guess_batch("crane:01020,sloth=20010").unwrap().len(), 2);
+
+        let err = parse_guess_batch("crane 01020\nsloth 2001").unwrap_err();
+        assert!(err.to_string().contains("entry 2"));
+        // An unpaired trailing token is an error, not silently dropped
+        assert!(parse_guess_batch("crane 01020 sloth").is_err());
+        assert!(parse_guess_batch("crane:01020,sloth").is_err());
+        assert!(parse_guess_batch("  ").is_err());
+    }
+
+    #[test]
+    fn test_parse_guess_entry() {
+        let (word, feedback) = parse_guess_entry("crane=01020").unwrap();
+        assert_eq!(word.as_str(), "crane");
+        assert_eq!(feedback.to_code_string(), "01020");
+
+        let err = parse_guess_entry("crane").unwrap_err().to_string();
+        assert!(err.contains("WORD=FEEDBACK"), "{}", err);
+        assert!(parse_guess_entry("crane=0102").is_err());
+        assert!(parse_guess_entry("cran=01020").is_err());
+        assert!(parse_guess_entry("crane=01020=1").is_err());
+
+        // Colour initials and spaced codes, in any case
+        for entry in [
+            "crane g g y b b",
+            "CRANE=GgYbB",
+            "crane: 2 2 1 0 0",
+            "crane ggy 00",
+        ] {
+            let (_, feedback) = parse_guess_entry(entry).unwrap();
+            assert_eq!(feedback.to_code_string(), "22100", "{}", entry);
+        }
+        assert!(parse_guess_entry("crane g g y b").is_err());
+        assert!(parse_guess_entry("crane g g y b r").is_err());
+    }
+
+    #[test]
+    fn test_batch_rejects_guesses_after_win() {
+        let validator = DefaultCommandValidator;
+        let guesses = parse_guess_batch("crane 22222\nsloth 00000").unwrap();
+        let command = Command::AddGuessBatch { guesses };
+        assert!(validator.validate(&command).is_err());
+    }
 }
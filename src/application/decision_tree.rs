@@ -0,0 +1,265 @@
+//! Published decision trees, and how far the solver strays from them
+//!
+//! Optimal Wordle trees (e.g. the 3.42-average SALET tree) are published one line
+//! per answer, listing each guess of that game followed by its feedback and turn:
+//!
+//! ```text
+//! salet BBBYB1 courd BYBBB2 thing GGGGG3
+//! ```
+//!
+//! Feedback is `B`/`Y`/`G` (or `0`/`1`/`2`); the turn number is optional. Lines
+//! sharing a history make up one node of the tree, and [`DecisionTree::compare`]
+//! asks the solver for its guess at every node.
+
+use crate::{
+    core::{
+        error::{DataError, Result},
+        traits::{FeedbackGenerator, WordleSolver},
+        types::{Feedback, FeedbackPattern, Guess, Word},
+    },
+    domain::DefaultFeedbackGenerator,
+};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// One answer's game in a tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreePath {
+    pub answer: Word,
+    /// Every guess, the winning one last
+    pub guesses: Vec<Guess>,
+}
+
+/// A guess the tree makes after some history
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeNode {
+    pub history: Vec<Guess>,
+    pub guess: Word,
+    /// Answers still possible at this node
+    pub answers: usize,
+}
+
+/// A node where the solver picked another guess
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeDivergence {
+    pub history: Vec<Guess>,
+    pub tree_guess: Word,
+    /// `None` when the solver had no suggestion
+    pub solver_guess: Option<Word>,
+    pub answers: usize,
+}
+
+/// Agreement between the solver and a tree, node by node
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TreeComparison {
+    pub nodes: usize,
+    pub agreed: usize,
+    /// (nodes, agreed) by turn, from the opener
+    pub by_turn: Vec<(usize, usize)>,
+    /// Diverging nodes, those deciding the most answers first
+    pub divergences: Vec<NodeDivergence>,
+}
+
+impl TreeComparison {
+    /// Share of nodes where the solver picked the tree's guess
+    pub fn agreement(&self) -> f64 {
+        if self.nodes == 0 {
+            0.0
+        } else {
+            self.agreed as f64 / self.nodes as f64
+        }
+    }
+}
+
+/// A decision tree read from its published path list
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DecisionTree {
+    pub paths: Vec<TreePath>,
+}
+
+/// Feedback written as `BYG` letters or `012` digits, with an optional turn number
+fn parse_feedback(token: &str) -> Option<FeedbackPattern> {
+    let squares: String = token.chars().take(Word::LENGTH).collect();
+    let turn = &token[squares.len()..];
+    if !turn.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let feedback: Vec<Feedback> = squares
+        .chars()
+        .map(|c| match c.to_ascii_uppercase() {
+            'G' | '2' => Some(Feedback::Correct),
+            'Y' | '1' => Some(Feedback::Present),
+            'B' | 'X' | '0' => Some(Feedback::Absent),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    Some(FeedbackPattern::from_array(feedback.try_into().ok()?))
+}
+
+impl DecisionTree {
+    /// Parse a path list; every feedback must be the real feedback for the line's
+    /// answer, and equal histories must lead to the same guess
+    pub fn parse(text: &str) -> Result<Self> {
+        let generator = DefaultFeedbackGenerator::new();
+        let mut paths = Vec::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |reason: String| {
+                DataError::InvalidFormat(format!("line {}: {}", number + 1, reason))
+            };
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if !tokens.len().is_multiple_of(2) {
+                return Err(invalid("expected guess and feedback pairs".to_string()).into());
+            }
+            let mut guesses = Vec::with_capacity(tokens.len() / 2);
+            for pair in tokens.chunks(2) {
+                let word = Word::from_str(pair[0]).map_err(invalid)?;
+                let feedback = parse_feedback(pair[1])
+                    .ok_or_else(|| invalid(format!("bad feedback '{}'", pair[1])))?;
+                guesses.push(Guess::new(word, feedback));
+            }
+            let Some(last) = guesses.last().filter(|guess| guess.feedback.is_win()) else {
+                return Err(invalid("the last guess isn't solved".to_string()).into());
+            };
+            let answer = last.word.clone();
+            if let Some(guess) = guesses
+                .iter()
+                .find(|guess| generator.generate_feedback(&guess.word, &answer) != guess.feedback)
+            {
+                return Err(invalid(format!(
+                    "{} doesn't give {} against {}",
+                    guess.word, guess.feedback, answer
+                ))
+                .into());
+            }
+            paths.push(TreePath { answer, guesses });
+        }
+        if paths.is_empty() {
+            return Err(DataError::InvalidFormat("the tree has no paths".to_string()).into());
+        }
+        let tree = Self { paths };
+        tree.nodes()?;
+        Ok(tree)
+    }
+
+    /// Read a tree file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(DataError::from)?;
+        Self::parse(&text)
+    }
+
+    /// Answers the tree covers
+    pub fn answers(&self) -> Vec<Word> {
+        self.paths.iter().map(|path| path.answer.clone()).collect()
+    }
+
+    /// Mean guesses per answer
+    pub fn average_guesses(&self) -> f64 {
+        let total: usize = self.paths.iter().map(|path| path.guesses.len()).sum();
+        total as f64 / self.paths.len().max(1) as f64
+    }
+
+    /// Every node, in history order; fails where two paths disagree on a guess
+    pub fn nodes(&self) -> Result<Vec<TreeNode>> {
+        let mut nodes: BTreeMap<Vec<(Word, String)>, TreeNode> = BTreeMap::new();
+        for path in &self.paths {
+            for turn in 0..path.guesses.len() {
+                let history = &path.guesses[..turn];
+                let key = history
+                    .iter()
+                    .map(|guess| (guess.word.clone(), guess.feedback.to_code_string()))
+                    .collect();
+                let guess = &path.guesses[turn].word;
+                let node = nodes.entry(key).or_insert_with(|| TreeNode {
+                    history: history.to_vec(),
+                    guess: guess.clone(),
+                    answers: 0,
+                });
+                if node.guess != *guess {
+                    return Err(DataError::InvalidFormat(format!(
+                        "{} and {} both follow the same guesses (answer {})",
+                        node.guess, guess, path.answer
+                    ))
+                    .into());
+                }
+                node.answers += 1;
+            }
+        }
+        Ok(nodes.into_values().collect())
+    }
+
+    /// Ask `solver` for its guess at every node of the tree
+    pub fn compare(&self, solver: &dyn WordleSolver) -> Result<TreeComparison> {
+        let mut comparison = TreeComparison::default();
+        for node in self.nodes()? {
+            let turn = node.history.len();
+            if comparison.by_turn.len() <= turn {
+                comparison.by_turn.resize(turn + 1, (0, 0));
+            }
+            let solver_guess = solver.best_guess_after(&node.history).ok();
+            comparison.nodes += 1;
+            comparison.by_turn[turn].0 += 1;
+            if solver_guess.as_ref() == Some(&node.guess) {
+                comparison.agreed += 1;
+                comparison.by_turn[turn].1 += 1;
+            } else {
+                comparison.divergences.push(NodeDivergence {
+                    history: node.history,
+                    tree_guess: node.guess,
+                    solver_guess,
+                    answers: node.answers,
+                });
+            }
+        }
+        comparison.divergences.sort_by(|a, b| {
+            b.answers
+                .cmp(&a.answers)
+                .then(a.history.len().cmp(&b.history.len()))
+        });
+        Ok(comparison)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::solver_engine::testing::fixed_solver;
+
+    const TREE: &str = "\
+# Three answers under a CRANE opener
+crane GGGGG1
+crane GGGBG1 crate GGGGG2
+crane GGGBG1 crate GGGBG2 craze GGGGG3
+";
+
+    #[test]
+    fn test_tree_rejects_wrong_feedback_and_conflicting_guesses() {
+        assert!(DecisionTree::parse("crane GGGGG1\ncrane gggbg crate ggggg").is_ok());
+        assert!(DecisionTree::parse("crane GGGYG crate GGGGG").is_err());
+        assert!(DecisionTree::parse("crane 22202 crate 22222\ncrane 22202 craze 22222").is_err());
+        assert!(DecisionTree::parse("crane BBBBB").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_compare_counts_agreement_per_turn() {
+        let tree = DecisionTree::parse(TREE).unwrap();
+        assert_eq!(tree.answers().len(), 3);
+        assert!((tree.average_guesses() - 2.0).abs() < 1e-9);
+        assert_eq!(tree.nodes().unwrap().len(), 3);
+
+        let solver = fixed_solver(&["crane", "crate", "craze"]).await;
+        let comparison = tree.compare(&solver).unwrap();
+        assert_eq!(comparison.nodes, 3);
+        assert_eq!(comparison.by_turn.len(), 3);
+        assert_eq!(
+            comparison.nodes - comparison.agreed,
+            comparison.divergences.len()
+        );
+        // The widest disagreement comes first
+        let answers: Vec<usize> = comparison.divergences.iter().map(|d| d.answers).collect();
+        assert!(answers.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+}
@@ -0,0 +1,133 @@
+//! Checking that a strategy's guess doesn't depend on thread scheduling
+//!
+//! The strategies score candidates in parallel, so a reduction that isn't a total
+//! order (two words tying on score, say) can pick a different word depending on
+//! how rayon splits the work. [`check_determinism`] asks a strategy for its guess
+//! in the same position repeatedly, on thread pools of several sizes, and records
+//! every answer so any disagreement shows up.
+
+use crate::core::{
+    error::{Result, SolverError},
+    traits::SolvingStrategy,
+    types::Word,
+};
+use std::collections::BTreeMap;
+
+/// Runs per thread count when none is given
+pub const DEFAULT_DETERMINISM_RUNS: usize = 5;
+
+/// One call to the strategy
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeterminismRun {
+    /// Size of the thread pool the call ran on
+    pub threads: usize,
+    /// The guess, or the error message
+    pub guess: std::result::Result<Word, String>,
+}
+
+/// Every call made in one position
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeterminismCheck {
+    pub runs: Vec<DeterminismRun>,
+}
+
+impl DeterminismCheck {
+    /// How often each distinct result came back; errors read `error: <message>`
+    pub fn outcomes(&self) -> BTreeMap<String, usize> {
+        let mut outcomes = BTreeMap::new();
+        for run in &self.runs {
+            let outcome = match &run.guess {
+                Ok(word) => word.to_string(),
+                Err(message) => format!("error: {}", message),
+            };
+            *outcomes.entry(outcome).or_insert(0) += 1;
+        }
+        outcomes
+    }
+
+    /// Whether every call returned the same result
+    pub fn is_deterministic(&self) -> bool {
+        self.runs
+            .windows(2)
+            .all(|pair| pair[0].guess == pair[1].guess)
+    }
+}
+
+/// One thread, two, and every core, without repeats
+pub fn default_thread_counts() -> Vec<usize> {
+    let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let mut counts = vec![1, 2, cores];
+    counts.sort_unstable();
+    counts.dedup();
+    counts
+}
+
+/// Ask `strategy` for its guess `runs` times on a pool of each of `thread_counts`
+pub fn check_determinism(
+    strategy: &dyn SolvingStrategy,
+    possible_words: &[Word],
+    candidates: &[Word],
+    runs: usize,
+    thread_counts: &[usize],
+) -> Result<DeterminismCheck> {
+    let mut check = DeterminismCheck::default();
+    for &threads in thread_counts {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .map_err(|e| {
+                SolverError::AlgorithmFailure(format!("can't start {} threads: {}", threads, e))
+            })?;
+        for _ in 0..runs {
+            let guess = pool
+                .install(|| strategy.get_best_guess(possible_words, candidates))
+                .map_err(|e| e.to_string());
+            check.runs.push(DeterminismRun { threads, guess });
+        }
+    }
+    Ok(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::testing::words;
+    use crate::infrastructure::{CachedEntropyCalculator, EntropyBasedStrategy};
+
+    #[test]
+    fn test_tied_scores_give_one_guess_on_any_pool() {
+        // Anagrams split the answers identically, so their scores tie exactly
+        let answers = words(&["crate", "trace", "react", "cater", "caret", "slate"]);
+        // Enough candidates for the strategy to score them in parallel
+        let mut candidates = answers.clone();
+        for first in b'a'..=b'z' {
+            for last in b'a'..=b'l' {
+                let letters = format!("{}rat{}", first as char, last as char);
+                candidates.push(Word::from_str(&letters).unwrap());
+            }
+        }
+        let strategy = EntropyBasedStrategy::new(CachedEntropyCalculator::new()).unwrap();
+
+        let check = check_determinism(&strategy, &answers, &candidates, 3, &[1, 2, 4]).unwrap();
+        assert_eq!(check.runs.len(), 9);
+        assert!(check.is_deterministic(), "{:?}", check.outcomes());
+        assert_eq!(check.outcomes().len(), 1);
+    }
+
+    #[test]
+    fn test_differing_runs_are_reported() {
+        let run = |threads, guess: &str| DeterminismRun {
+            threads,
+            guess: Ok(Word::from_str(guess).unwrap()),
+        };
+        let check = DeterminismCheck {
+            runs: vec![run(1, "crate"), run(2, "trace"), run(2, "crate")],
+        };
+        assert!(!check.is_deterministic());
+        assert_eq!(
+            check.outcomes(),
+            BTreeMap::from([("crate".to_string(), 2), ("trace".to_string(), 1)])
+        );
+        assert!(default_thread_counts().contains(&1));
+    }
+}
@@ -0,0 +1,198 @@
+//! Cross-checking the constraint filter against brute force
+//!
+//! The solver trusts [`ConstraintFilter`] to keep exactly the words that could
+//! still be the answer. [`audit_filter`] replays simulated games turn by turn and
+//! compares the filter's survivors with a brute-force re-simulation: a word
+//! survives iff scoring every recorded guess against it reproduces the recorded
+//! feedback. The reference scorer here is deliberately naive and shares no code
+//! with the optimized one, so a bug in either shows up as a divergence.
+
+use crate::{
+    application::SimulatedGame,
+    core::{
+        traits::ConstraintFilter,
+        types::{Feedback, FeedbackPattern, Guess, Word},
+    },
+};
+use std::collections::BTreeSet;
+
+/// Feedback for `guess` against `target`, worked out letter by letter
+///
+/// Greens first; then each other guessed letter is yellow while the target has
+/// an unmatched copy of it left, reading left to right.
+pub fn reference_feedback(guess: &Word, target: &Word) -> FeedbackPattern {
+    let guess: Vec<char> = guess.as_str().chars().collect();
+    let mut unmatched: Vec<Option<char>> = target.as_str().chars().map(Some).collect();
+    let mut feedback = [Feedback::Absent; Word::LENGTH];
+    for (i, letter) in guess.iter().enumerate() {
+        if unmatched[i] == Some(*letter) {
+            feedback[i] = Feedback::Correct;
+            unmatched[i] = None;
+        }
+    }
+    for (i, letter) in guess.iter().enumerate() {
+        if feedback[i] == Feedback::Correct {
+            continue;
+        }
+        if let Some(slot) = unmatched.iter_mut().find(|slot| **slot == Some(*letter)) {
+            feedback[i] = Feedback::Present;
+            *slot = None;
+        }
+    }
+    FeedbackPattern::from_array(feedback)
+}
+
+/// Words that reproduce every guess's feedback, by brute force
+pub fn brute_force_survivors(words: &[Word], guesses: &[Guess]) -> Vec<Word> {
+    words
+        .iter()
+        .filter(|word| {
+            guesses
+                .iter()
+                .all(|guess| reference_feedback(&guess.word, word) == guess.feedback)
+        })
+        .cloned()
+        .collect()
+}
+
+/// A turn where the filter and brute force disagreed
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterDivergence {
+    /// Answer of the simulated game
+    pub target: Word,
+    /// Guesses up to and including the turn checked
+    pub guesses: Vec<Guess>,
+    /// Kept by the filter although they contradict a guess
+    pub kept: Vec<Word>,
+    /// Dropped by the filter although they fit every guess
+    pub dropped: Vec<Word>,
+}
+
+/// Result of auditing the filter over a set of games
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FilterAudit {
+    pub games: usize,
+    /// Turns whose survivors were compared
+    pub turns: usize,
+    /// Words compared, over all turns
+    pub words_checked: usize,
+    pub divergences: Vec<FilterDivergence>,
+}
+
+impl FilterAudit {
+    /// Whether the filter agreed with brute force on every turn
+    pub fn passed(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+/// Compare `filter` with brute force over `words` after every turn of `games`
+pub fn audit_filter(
+    filter: &dyn ConstraintFilter,
+    words: &[Word],
+    games: &[SimulatedGame],
+) -> FilterAudit {
+    let mut audit = FilterAudit {
+        games: games.len(),
+        ..FilterAudit::default()
+    };
+    for game in games {
+        for turn in 1..=game.guesses.len() {
+            let guesses = &game.guesses[..turn];
+            let filtered: BTreeSet<Word> =
+                filter.filter_words(words, guesses).into_iter().collect();
+            let expected: BTreeSet<Word> =
+                brute_force_survivors(words, guesses).into_iter().collect();
+            audit.turns += 1;
+            audit.words_checked += words.len();
+            if filtered != expected {
+                audit.divergences.push(FilterDivergence {
+                    target: game.target.clone(),
+                    guesses: guesses.to_vec(),
+                    kept: filtered.difference(&expected).cloned().collect(),
+                    dropped: expected.difference(&filtered).cloned().collect(),
+                });
+            }
+        }
+    }
+    audit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::traits::FeedbackGenerator;
+    use crate::domain::{DefaultConstraintFilter, DefaultFeedbackGenerator};
+
+    fn word(text: &str) -> Word {
+        Word::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_reference_feedback_matches_generator() {
+        let generator = DefaultFeedbackGenerator::new();
+        let words = [
+            "speed", "abide", "erase", "steal", "crepe", "eerie", "geese", "llama",
+        ];
+        for guess in words.map(word) {
+            for target in words.map(word) {
+                assert_eq!(
+                    reference_feedback(&guess, &target),
+                    generator.generate_feedback(&guess, &target),
+                    "{} against {}",
+                    guess,
+                    target
+                );
+            }
+        }
+        // SPEED has one E left after the green one, so only the first other E is yellow
+        assert_eq!(
+            reference_feedback(&word("geese"), &word("speed")).to_code_string(),
+            "01210"
+        );
+    }
+
+    /// Keeps every word, whatever the guesses say
+    #[derive(Debug)]
+    struct KeepAll;
+
+    impl ConstraintFilter for KeepAll {
+        fn filter_words(&self, words: &[Word], _constraints: &[Guess]) -> Vec<Word> {
+            words.to_vec()
+        }
+
+        fn satisfies_constraints(&self, _word: &Word, _constraints: &[Guess]) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_audit_reports_divergent_turns() {
+        let words: Vec<Word> = ["crane", "crate", "trace", "slate"].map(word).to_vec();
+        let target = word("crate");
+        let guesses = ["slate", "crane", "crate"]
+            .map(|guess| {
+                let guess = word(guess);
+                let feedback = reference_feedback(&guess, &target);
+                Guess::new(guess, feedback)
+            })
+            .to_vec();
+        let games = [SimulatedGame {
+            target,
+            guesses,
+            solved: true,
+        }];
+
+        let audit = audit_filter(&DefaultConstraintFilter::new(), &words, &games);
+        assert!(audit.passed());
+        assert_eq!((audit.turns, audit.words_checked), (3, 12));
+
+        let audit = audit_filter(&KeepAll, &words, &games);
+        assert_eq!(audit.divergences.len(), 3);
+        assert_eq!(
+            audit.divergences[0].kept,
+            ["crane", "slate", "trace"].map(word)
+        );
+        assert!(audit.divergences[0].dropped.is_empty());
+    }
+}
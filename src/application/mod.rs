@@ -1,8 +1,32 @@
 /// Application layer orchestrating business logic
+pub mod access;
+pub mod benchmark;
+pub mod decision_tree;
+pub mod determinism;
+pub mod filter_audit;
+pub mod opener_search;
+pub mod pipe;
 pub mod state;
+pub mod state_observer;
 pub mod service;
 pub mod commands;
+pub mod session;
+pub mod script;
+pub mod puzzle_pack;
+pub mod tuning;
 
+pub use access::*;
+pub use benchmark::*;
+pub use decision_tree::*;
+pub use determinism::*;
+pub use filter_audit::*;
+pub use opener_search::*;
+pub use pipe::*;
 pub use state::*;
+pub use state_observer::*;
 pub use service::*;
 pub use commands::*;
+pub use session::*;
+pub use script::*;
+pub use puzzle_pack::*;
+pub use tuning::*;
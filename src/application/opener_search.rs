@@ -0,0 +1,352 @@
+//! Experimental opener search by simulated annealing
+//!
+//! [`rank_single_probes`] ranks openers by the answers they leave, which says
+//! nothing about the guesses that follow, and playing full games from each of the
+//! ~13k guesses takes far too long. [`anneal_openers`] walks the guesses instead,
+//! starting from the solver's own opener: each step plays a neighbour (a guess one
+//! letter away, now and then any guess) through full games on a fixed sample of
+//! answers and moves to it when it does better, or, while the temperature is still
+//! high, sometimes when it does worse. The best openers met on the way are replayed
+//! on a second, larger sample and reported with 95% confidence intervals.
+//!
+//! [`rank_single_probes`]: crate::domain::rank_single_probes
+
+use crate::application::benchmark::{BenchmarkRunner, SimulatedGame, clock_seed, splitmix64};
+use crate::core::{error::Result, types::Word};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Chance of a step jumping to any guess instead of a neighbour
+const JUMP_CHANCE: f64 = 0.1;
+
+/// z-score of a two-sided 95% confidence interval
+const Z_95: f64 = 1.96;
+
+/// How an opener search runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpenerSearchConfig {
+    /// Moves tried; an opener tried again is not replayed
+    pub steps: usize,
+    /// Answers each opener is played against while searching
+    pub sample: usize,
+    /// Answers the best openers are replayed against for the report
+    pub validation: usize,
+    /// Openers reported
+    pub keep: usize,
+    /// Temperature at the first step, in guesses per game
+    pub start_temperature: f64,
+    /// Temperature at the last step
+    pub end_temperature: f64,
+    /// Seed for reproducible samples and moves (from the clock by default)
+    pub seed: Option<u64>,
+}
+
+impl Default for OpenerSearchConfig {
+    fn default() -> Self {
+        Self {
+            steps: 200,
+            sample: 60,
+            validation: 300,
+            keep: 5,
+            start_temperature: 0.2,
+            end_temperature: 0.005,
+            seed: None,
+        }
+    }
+}
+
+impl OpenerSearchConfig {
+    /// Temperature at `step`, falling geometrically from the start to the end one
+    fn temperature(&self, step: usize) -> f64 {
+        let end = self.end_temperature.max(1e-9);
+        let start = self.start_temperature.max(end);
+        let progress = step as f64 / self.steps.max(1) as f64;
+        start * (end / start).powf(progress)
+    }
+}
+
+/// Guesses a game cost: those played, and one more for a lost game
+pub fn game_cost(game: &SimulatedGame) -> f64 {
+    (game.guess_count() + usize::from(!game.solved)) as f64
+}
+
+/// An opener's mean cost over a sample of answers, with a 95% confidence interval
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenerEstimate {
+    pub opener: Word,
+    pub games: usize,
+    pub wins: usize,
+    /// Mean [`game_cost`]
+    pub mean_guesses: f64,
+    /// Half the width of the confidence interval; infinite below two games
+    pub margin: f64,
+}
+
+impl OpenerEstimate {
+    pub fn from_games(opener: Word, games: &[SimulatedGame]) -> Self {
+        let costs: Vec<f64> = games.iter().map(game_cost).collect();
+        let n = costs.len() as f64;
+        let mean_guesses = if costs.is_empty() {
+            0.0
+        } else {
+            costs.iter().sum::<f64>() / n
+        };
+        let margin = if costs.len() < 2 {
+            f64::INFINITY
+        } else {
+            let variance = costs
+                .iter()
+                .map(|c| (c - mean_guesses).powi(2))
+                .sum::<f64>()
+                / (n - 1.0);
+            Z_95 * (variance / n).sqrt()
+        };
+        Self {
+            opener,
+            games: games.len(),
+            wins: games.iter().filter(|g| g.solved).count(),
+            mean_guesses,
+            margin,
+        }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games as f64
+        }
+    }
+
+    /// Lower and upper ends of the confidence interval
+    pub fn interval(&self) -> (f64, f64) {
+        (
+            self.mean_guesses - self.margin,
+            self.mean_guesses + self.margin,
+        )
+    }
+}
+
+/// What an opener search found
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenerSearchReport {
+    /// Seed the search ran with, to repeat it
+    pub seed: u64,
+    /// The solver's own opener, where the walk started, on the validation answers
+    pub start: OpenerEstimate,
+    /// The openers that did best while searching, on the validation answers, best first
+    pub best: Vec<OpenerEstimate>,
+    pub steps: usize,
+    /// Steps that moved the walk to the opener tried
+    pub accepted: usize,
+    /// Different openers played on the search sample
+    pub openers_played: usize,
+    /// Games played in all, validation included
+    pub games_played: usize,
+}
+
+/// Search `guesses` for openers that play better than `start` against `answers`
+///
+/// Every opener plays the same sample of answers, so scores met on the way compare
+/// fairly; the report replays the best on a separate sample, as the search favours
+/// openers that got lucky on its own.
+pub fn anneal_openers(
+    runner: &BenchmarkRunner<'_>,
+    start: Word,
+    guesses: &[Word],
+    answers: &[Word],
+    config: &OpenerSearchConfig,
+) -> Result<OpenerSearchReport> {
+    let seed = config.seed.unwrap_or_else(clock_seed);
+    let mut rng = seed;
+    let sample = draw_sample(answers, config.sample, &mut rng);
+    let validation = draw_sample(answers, config.validation, &mut rng);
+
+    let mut scores: HashMap<Word, f64> = HashMap::new();
+    let mut current_score = mean_cost(&play_all(runner, &start, &sample)?);
+    scores.insert(start.clone(), current_score);
+    let mut current = start.clone();
+    let mut accepted = 0;
+    for step in 0..config.steps {
+        let candidate = neighbour(&current, guesses, &mut rng);
+        let candidate_score = match scores.get(&candidate) {
+            Some(&score) => score,
+            None => {
+                let score = mean_cost(&play_all(runner, &candidate, &sample)?);
+                scores.insert(candidate.clone(), score);
+                score
+            }
+        };
+        let worse_by = candidate_score - current_score;
+        let chance = (-worse_by / config.temperature(step)).exp();
+        if worse_by <= 0.0 || unit(&mut rng) < chance {
+            current = candidate;
+            current_score = candidate_score;
+            accepted += 1;
+        }
+    }
+
+    let mut ranked: Vec<(Word, f64)> = scores.iter().map(|(w, s)| (w.clone(), *s)).collect();
+    ranked.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    let validate = |opener: Word| -> Result<OpenerEstimate> {
+        let games = play_all(runner, &opener, &validation)?;
+        Ok(OpenerEstimate::from_games(opener, &games))
+    };
+    let mut best = ranked
+        .iter()
+        .take(config.keep)
+        .map(|(opener, _)| validate(opener.clone()))
+        .collect::<Result<Vec<_>>>()?;
+    best.sort_by(|a, b| a.mean_guesses.total_cmp(&b.mean_guesses));
+    let start = match best.iter().find(|e| e.opener == start) {
+        Some(estimate) => estimate.clone(),
+        None => validate(start)?,
+    };
+    let validated = best.len() + usize::from(!best.contains(&start));
+
+    Ok(OpenerSearchReport {
+        seed,
+        start,
+        best,
+        steps: config.steps,
+        accepted,
+        openers_played: scores.len(),
+        games_played: scores.len() * sample.len() + validated * validation.len(),
+    })
+}
+
+/// `opener` played against every target, in parallel
+fn play_all(
+    runner: &BenchmarkRunner<'_>,
+    opener: &Word,
+    targets: &[Word],
+) -> Result<Vec<SimulatedGame>> {
+    targets
+        .par_iter()
+        .map(|target| runner.play_opening(opener, target))
+        .collect()
+}
+
+fn mean_cost(games: &[SimulatedGame]) -> f64 {
+    games.iter().map(game_cost).sum::<f64>() / games.len().max(1) as f64
+}
+
+/// A guess one letter away from `word`, or now and then any guess
+fn neighbour(word: &Word, guesses: &[Word], rng: &mut u64) -> Word {
+    if guesses.is_empty() {
+        return word.clone();
+    }
+    let close: Vec<&Word> = if unit(rng) < JUMP_CHANCE {
+        Vec::new()
+    } else {
+        guesses
+            .iter()
+            .filter(|g| one_letter_apart(word, g))
+            .collect()
+    };
+    match close.len() {
+        0 => guesses[pick(guesses.len(), rng)].clone(),
+        n => close[pick(n, rng)].clone(),
+    }
+}
+
+fn one_letter_apart(a: &Word, b: &Word) -> bool {
+    let differing = a
+        .as_str()
+        .bytes()
+        .zip(b.as_str().bytes())
+        .filter(|(x, y)| x != y)
+        .count();
+    differing == 1
+}
+
+/// `count` different answers drawn at random, or all of them if there are fewer
+fn draw_sample(answers: &[Word], count: usize, rng: &mut u64) -> Vec<Word> {
+    let mut pool = answers.to_vec();
+    let count = count.min(pool.len());
+    for i in 0..count {
+        let j = i + pick(pool.len() - i, rng);
+        pool.swap(i, j);
+    }
+    pool.truncate(count);
+    pool
+}
+
+fn pick(len: usize, rng: &mut u64) -> usize {
+    (splitmix64(rng) % len as u64) as usize
+}
+
+/// Uniform in `[0, 1)`
+fn unit(rng: &mut u64) -> f64 {
+    (splitmix64(rng) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{FeedbackPattern, Guess};
+    use crate::domain::solver_engine::testing::fixed_solver;
+
+    const WORDS: [&str; 8] = [
+        "crane", "crate", "trace", "slate", "plate", "grate", "brace", "grace",
+    ];
+
+    #[tokio::test]
+    async fn test_annealing_is_reproducible_and_validates_its_finds() {
+        let solver = fixed_solver(&WORDS).await;
+        let runner = BenchmarkRunner::new(&solver);
+        let words: Vec<Word> = WORDS.iter().map(|w| Word::from_str(w).unwrap()).collect();
+        let config = OpenerSearchConfig {
+            steps: 30,
+            sample: 5,
+            keep: 3,
+            seed: Some(7),
+            ..OpenerSearchConfig::default()
+        };
+
+        let start = words[0].clone();
+        let report = anneal_openers(&runner, start.clone(), &words, &words, &config).unwrap();
+        let again = anneal_openers(&runner, start.clone(), &words, &words, &config).unwrap();
+        assert_eq!(report, again);
+
+        assert_eq!(report.start.opener, start);
+        assert_eq!(report.best.len(), 3);
+        for estimate in &report.best {
+            // Validation plays every answer, as there are fewer than asked for
+            assert_eq!(estimate.games, WORDS.len());
+            let (low, high) = estimate.interval();
+            assert!(low <= estimate.mean_guesses && estimate.mean_guesses <= high);
+        }
+        assert!(
+            report
+                .best
+                .windows(2)
+                .all(|w| w[0].mean_guesses <= w[1].mean_guesses)
+        );
+        assert!(report.openers_played <= WORDS.len());
+    }
+
+    #[test]
+    fn test_estimate_margin_shrinks_with_more_games() {
+        let word = Word::from_str("crane").unwrap();
+        let win = FeedbackPattern::from_code_string("22222").unwrap();
+        let game = |guesses: usize| SimulatedGame {
+            target: word.clone(),
+            guesses: vec![Guess::new(word.clone(), win); guesses],
+            solved: true,
+        };
+        let few: Vec<_> = [3, 4].into_iter().map(game).collect();
+        let many: Vec<_> = [3, 4].into_iter().cycle().take(40).map(game).collect();
+        let few = OpenerEstimate::from_games(word.clone(), &few);
+        let many = OpenerEstimate::from_games(word.clone(), &many);
+        assert_eq!(few.mean_guesses, 3.5);
+        assert_eq!(many.mean_guesses, 3.5);
+        assert!(many.margin < few.margin);
+        let single = [game(3)];
+        assert!(
+            OpenerEstimate::from_games(word, &single)
+                .margin
+                .is_infinite()
+        );
+    }
+}
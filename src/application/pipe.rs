@@ -0,0 +1,542 @@
+//! Line protocol for driving the solver without a terminal
+//!
+//! When `interactive` runs with stdin or stdout redirected (a pipe, CI), the TUI
+//! can't start, so it reads one request per line instead and answers each with
+//! one line: `ok <summary>` or `error <message>`, the summary being the same
+//! [`describe_result`] text command scripts record. Blank lines and `#` comments
+//! get no reply.
+//!
+//! ```text
+//! > crane 01020
+//! ok 60 remaining
+//! > best
+//! ok sloth
+//! ```
+//!
+//! Several clients sharing the pipe can each play their own game: `session new`
+//! answers with a token, and requests prefixed with `@TOKEN` go to that game
+//! instead of the default one. Sessions idle for longer than their TTL expire.
+//!
+//! ```text
+//! > session new
+//! ok 9f2c...
+//! > @9f2c... crane 01020
+//! ok 60 remaining
+//! ```
+//!
+//! With a `server` section in the settings, requests are checked against its API
+//! keys and rate limits: `auth KEY` presents a key for the requests after it.
+
+use crate::{
+    application::{
+        AccessGuard, AppState, Command, CommandExecutor, SessionSnapshot, SessionStore,
+        SessionToken, describe_result, parse_guess_batch,
+    },
+    core::{
+        error::{DataError, Result},
+        traits::StateManager,
+        types::{FeedbackPattern, ManualConstraint, Word},
+    },
+    domain::WordOrder,
+};
+use std::io::{BufRead, Write};
+use std::time::Duration;
+
+/// Candidates listed by `top` without a count
+pub const PIPE_TOP_DEFAULT: usize = 5;
+
+/// Possible words listed by `words` without a count
+pub const PIPE_PAGE_DEFAULT: usize = 50;
+
+/// Requests understood by [`run_pipe`], for `help`
+pub const PIPE_HELP: &str = "WORD FEEDBACK (e.g. crane 01020 or crane:gybbb, several \
+    separated by commas) | best | first | top [N] | eval WORD | suggest | undo | reset | \
+    stats | words [OFFSET [N [alphabetical|typical]]] | pin a3|-x | unpin | \
+    session new | session end TOKEN | @TOKEN REQUEST | auth KEY | help | quit";
+
+/// Rate limiting key for requests sent without an API key; the pipe has one peer
+const PIPE_CLIENT: &str = "pipe";
+
+/// One line of the protocol
+#[derive(Debug, Clone)]
+pub enum PipeRequest {
+    Execute(Command),
+    /// Run a command in the game of a session opened with `session new`
+    InSession(SessionToken, Command),
+    NewSession,
+    EndSession(SessionToken),
+    /// Present an API key for the requests that follow
+    Auth(String),
+    Help,
+    Quit,
+}
+
+/// Games opened with `session new`, independent of the default game and of each other
+pub struct PipeSessions<T> {
+    store: SessionStore<T>,
+    open: Box<dyn FnMut() -> Result<T>>,
+}
+
+impl<T: CommandExecutor + StateManager<State = AppState>> PipeSessions<T> {
+    /// Sessions expire after `ttl` idle; `open` starts the game of a new one
+    pub fn new(ttl: Duration, open: impl FnMut() -> Result<T> + 'static) -> Self {
+        Self {
+            store: SessionStore::new(ttl),
+            open: Box::new(open),
+        }
+    }
+
+    /// Number of open sessions
+    pub fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+
+    /// Every session's guesses, for saving with a [`SessionFile`](crate::application::SessionFile)
+    pub fn snapshot(&self) -> Vec<SessionSnapshot> {
+        self.store.snapshot()
+    }
+
+    /// Reopen saved sessions under their old tokens, replaying each one's guesses
+    /// into a new game
+    pub fn restore(&mut self, snapshots: Vec<SessionSnapshot>) -> Result<()> {
+        let open = &mut self.open;
+        self.store.restore(snapshots, |snapshot| {
+            let mut game = open()?;
+            for guess in &snapshot.guesses {
+                let invalid = |e: String| {
+                    DataError::InvalidFormat(format!("session {}: {}", snapshot.token, e))
+                };
+                game.execute(Command::AddGuessResult {
+                    word: Word::from_str(&guess.word).map_err(invalid)?,
+                    feedback: FeedbackPattern::from_code_string(&guess.feedback)
+                        .map_err(invalid)?,
+                })?;
+            }
+            Ok(game)
+        })
+    }
+}
+
+/// Read a request line; `None` for blank lines and comments
+pub fn parse_pipe_request(line: &str) -> Result<Option<PipeRequest>> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+    if let Some(addressed) = line.strip_prefix('@') {
+        let (token, request) = addressed
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| DataError::InvalidFormat("@TOKEN needs a request".into()))?;
+        return match parse_pipe_request(request)? {
+            Some(PipeRequest::Execute(command)) => Ok(Some(PipeRequest::InSession(
+                SessionToken::from(token),
+                command,
+            ))),
+            _ => Err(DataError::InvalidFormat(format!(
+                "'{}' can't be sent to a session",
+                request.trim()
+            ))
+            .into()),
+        };
+    }
+    let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let rest = rest.trim();
+    let word = |text: &str| {
+        Word::from_str(text).map_err(|e| DataError::InvalidFormat(format!("'{}': {}", text, e)))
+    };
+    let count = |text: &str| {
+        text.parse()
+            .map_err(|_| DataError::InvalidFormat(format!("'{}' isn't a count", text)))
+    };
+    let command = match (keyword.to_ascii_lowercase().as_str(), rest) {
+        ("help" | "?", "") => return Ok(Some(PipeRequest::Help)),
+        ("quit" | "exit", "") => return Ok(Some(PipeRequest::Quit)),
+        ("auth", key) if !key.is_empty() => return Ok(Some(PipeRequest::Auth(key.to_string()))),
+        ("session", "new") => return Ok(Some(PipeRequest::NewSession)),
+        ("session", args) if args.starts_with("end ") => {
+            let token = args["end ".len()..].trim();
+            return Ok(Some(PipeRequest::EndSession(SessionToken::from(token))));
+        }
+        ("best", "") => Command::GetBestGuess,
+        ("first", "") => Command::GetBestFirstGuess,
+        ("top", "") => Command::GetTopCandidates {
+            limit: PIPE_TOP_DEFAULT,
+        },
+        ("top", text) => Command::GetTopCandidates {
+            limit: count(text)?,
+        },
+        ("words", args) => {
+            let args: Vec<&str> = args.split_whitespace().collect();
+            if args.len() > 3 {
+                return Err(DataError::InvalidFormat("words takes at most 3 values".into()).into());
+            }
+            let offset = match args.first() {
+                Some(text) => count(text)?,
+                None => 0,
+            };
+            let limit = match args.get(1) {
+                Some(text) => count(text)?,
+                None => PIPE_PAGE_DEFAULT,
+            };
+            let sort = match args.get(2) {
+                Some(label) => WordOrder::from_label(label).ok_or_else(|| {
+                    DataError::InvalidFormat(format!("'{}' isn't alphabetical or typical", label))
+                })?,
+                None => WordOrder::default(),
+            };
+            Command::GetPossibleWordsPage {
+                offset,
+                limit,
+                sort,
+            }
+        }
+        ("eval", text) if !text.is_empty() => Command::EvaluateGuess { word: word(text)? },
+        ("suggest", "") => Command::GetSuggestionSet,
+        ("undo", "") => Command::UndoLastGuess,
+        ("reset", "") => Command::Reset,
+        ("stats", "") => Command::GetStatistics,
+        ("pin", text) if !text.is_empty() => Command::AddManualConstraint {
+            constraint: ManualConstraint::parse(text)?,
+        },
+        ("unpin", "") => Command::ClearManualConstraints,
+        _ => {
+            let mut guesses = parse_guess_batch(line)?;
+            if guesses.len() == 1 {
+                let (word, feedback) = guesses.remove(0);
+                Command::AddGuessResult { word, feedback }
+            } else {
+                Command::AddGuessBatch { guesses }
+            }
+        }
+    };
+    Ok(Some(PipeRequest::Execute(command)))
+}
+
+/// `ok <summary>` or `error <message>` for running `command`
+fn reply_to(executor: &mut dyn CommandExecutor, command: Command) -> String {
+    match describe_result(&executor.execute(command)) {
+        summary if summary.starts_with("error: ") => {
+            format!("error {}", &summary["error: ".len()..])
+        }
+        summary => format!("ok {}", summary),
+    }
+}
+
+/// Refuse `request` unless `guard` lets it through; `help` and `quit` always pass
+fn admit(guard: &AccessGuard, api_key: Option<&str>, request: &PipeRequest) -> Result<()> {
+    match request {
+        PipeRequest::Help | PipeRequest::Quit => Ok(()),
+        PipeRequest::Auth(key) => guard.check(Some(key), PIPE_CLIENT),
+        _ => guard.check(api_key, PIPE_CLIENT),
+    }
+}
+
+/// Answer requests from `input` on `output` until `quit` or the end of the input
+///
+/// Requests without an `@TOKEN` go to `executor`. With a `guard`, every request
+/// must pass its API key and rate limit checks first. Each reply is flushed
+/// straight away, so a program on the other end of the pipe can wait for it
+/// before sending the next request.
+pub fn run_pipe<T: CommandExecutor + StateManager<State = AppState>>(
+    executor: &mut dyn CommandExecutor,
+    sessions: &mut PipeSessions<T>,
+    guard: Option<&AccessGuard>,
+    input: impl BufRead,
+    mut output: impl Write,
+) -> Result<()> {
+    let mut api_key = None;
+    for line in input.lines() {
+        let line = line.map_err(DataError::from)?;
+        let request = parse_pipe_request(&line).and_then(|request| {
+            if let (Some(guard), Some(request)) = (guard, &request) {
+                admit(guard, api_key.as_deref(), request)?;
+            }
+            Ok(request)
+        });
+        let reply = match request {
+            Ok(None) => continue,
+            Ok(Some(PipeRequest::Quit)) => break,
+            Ok(Some(PipeRequest::Help)) => format!("ok {}", PIPE_HELP),
+            Ok(Some(PipeRequest::Auth(key))) => {
+                api_key = Some(key);
+                "ok authenticated".to_string()
+            }
+            Ok(Some(PipeRequest::Execute(command))) => reply_to(executor, command),
+            Ok(Some(PipeRequest::InSession(token, command))) => {
+                match sessions.store.get_mut(&token) {
+                    Some(game) => reply_to(game, command),
+                    None => format!("error no session {} (it may have expired)", token),
+                }
+            }
+            Ok(Some(PipeRequest::NewSession)) => {
+                sessions.store.evict_expired();
+                match (sessions.open)() {
+                    Ok(game) => format!("ok {}", sessions.store.insert(game)),
+                    Err(e) => format!("error {}", e),
+                }
+            }
+            Ok(Some(PipeRequest::EndSession(token))) => match sessions.store.remove(&token) {
+                Some(_) => "ok ended".to_string(),
+                None => format!("error no session {}", token),
+            },
+            Err(e) => format!("error {}", e),
+        };
+        writeln!(output, "{}", reply).map_err(DataError::from)?;
+        output.flush().map_err(DataError::from)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::{AppStateManager, CommandResult, DEFAULT_SESSION_TTL},
+        core::{error::GameError, traits::StateUpdater, types::Guess},
+        infrastructure::{RateLimitConfig, ServerConfig},
+    };
+
+    #[test]
+    fn test_requests_parse_to_commands() {
+        let parse = |line| parse_pipe_request(line).unwrap();
+        assert!(parse("  # comment").is_none());
+        assert!(matches!(parse("QUIT"), Some(PipeRequest::Quit)));
+        assert!(matches!(
+            parse("top 3"),
+            Some(PipeRequest::Execute(Command::GetTopCandidates { limit: 3 }))
+        ));
+        let Some(PipeRequest::Execute(Command::AddGuessResult { word, feedback })) =
+            parse("crane gybbb")
+        else {
+            panic!("expected a guess result");
+        };
+        assert_eq!(word.as_str(), "crane");
+        assert_eq!(
+            feedback,
+            FeedbackPattern::from_code_string("21000").unwrap()
+        );
+        assert!(matches!(
+            parse("crane:01020,sloth:20010"),
+            Some(PipeRequest::Execute(Command::AddGuessBatch { guesses })) if guesses.len() == 2
+        ));
+        assert!(matches!(
+            parse("words 100 20 typical"),
+            Some(PipeRequest::Execute(Command::GetPossibleWordsPage {
+                offset: 100,
+                limit: 20,
+                sort: WordOrder::Typical
+            }))
+        ));
+        assert!(matches!(parse("auth s3cret"), Some(PipeRequest::Auth(key)) if key == "s3cret"));
+        assert!(parse_pipe_request("top many").is_err());
+        assert!(parse_pipe_request("best guess").is_err());
+    }
+
+    /// Suggests CRANE and refuses every guess result
+    struct Canned;
+
+    impl CommandExecutor for Canned {
+        fn execute(&mut self, command: Command) -> Result<CommandResult> {
+            match command {
+                Command::GetBestGuess => Ok(CommandResult::BestGuess {
+                    word: Word::from_str("crane").unwrap(),
+                    confidence: 1.0,
+                }),
+                Command::AddGuessResult { word, .. } => {
+                    Err(GameError::WordNotFound(word.to_string()).into())
+                }
+                _ => Ok(CommandResult::Reset),
+            }
+        }
+    }
+
+    /// Suggests the last word guessed in it, CRANE before any
+    #[derive(Debug, Default)]
+    struct Echo {
+        state: AppStateManager,
+        last: Option<Word>,
+    }
+
+    impl StateManager for Echo {
+        type State = AppState;
+
+        fn get_state(&self) -> &AppState {
+            self.state.get_state()
+        }
+
+        fn reset_state(&mut self) {
+            self.last = None;
+        }
+    }
+
+    impl CommandExecutor for Echo {
+        fn execute(&mut self, command: Command) -> Result<CommandResult> {
+            match command {
+                Command::AddGuessResult { word, feedback } => {
+                    let guess = Guess::new(word.clone(), feedback);
+                    self.state.update_state(|state| {
+                        state.guess_history.push(guess);
+                        Ok(())
+                    })?;
+                    self.last = Some(word);
+                    Ok(CommandResult::Reset)
+                }
+                _ => Ok(CommandResult::BestGuess {
+                    word: self
+                        .last
+                        .clone()
+                        .unwrap_or(Word::from_str("crane").unwrap()),
+                    confidence: 1.0,
+                }),
+            }
+        }
+    }
+
+    fn run(executor: &mut dyn CommandExecutor, input: &str) -> Vec<String> {
+        let mut sessions = PipeSessions::new(DEFAULT_SESSION_TTL, || Ok(Echo::default()));
+        let mut output = Vec::new();
+        run_pipe(executor, &mut sessions, None, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_pipe_replies_once_per_request() {
+        let replies = run(
+            &mut Canned,
+            "best\n\n# skipped\nzzzzz 00000\nbogus\nreset\nquit\nbest\n",
+        );
+        assert_eq!(replies.len(), 4);
+        assert_eq!(replies[0], "ok crane");
+        assert!(replies[1].starts_with("error ") && replies[1].contains("zzzzz"));
+        assert!(replies[2].starts_with("error "));
+        assert_eq!(replies[3], "ok reset");
+    }
+
+    #[test]
+    fn test_sessions_play_independent_games() {
+        let mut default = Echo::default();
+        let mut sessions = PipeSessions::new(DEFAULT_SESSION_TTL, || Ok(Echo::default()));
+        let mut output = Vec::new();
+        run_pipe(
+            &mut default,
+            &mut sessions,
+            None,
+            "session new\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        let token = String::from_utf8(output).unwrap()["ok ".len()..]
+            .trim()
+            .to_string();
+        assert_eq!(token.len(), 32);
+        assert_eq!(sessions.len(), 1);
+
+        let input = format!(
+            "@{t} slate 00000\nbest\n@{t} best\n@{t} quit\nsession end {t}\n@{t} best\n",
+            t = token
+        );
+        let mut output = Vec::new();
+        run_pipe(
+            &mut default,
+            &mut sessions,
+            None,
+            input.as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let replies: Vec<&str> = output.lines().collect();
+        assert_eq!(replies[1], "ok crane");
+        assert_eq!(replies[2], "ok slate");
+        assert!(replies[3].starts_with("error "));
+        assert_eq!(replies[4], "ok ended");
+        assert!(replies[5].starts_with("error no session"));
+        assert!(sessions.is_empty());
+    }
+
+    #[test]
+    fn test_sessions_survive_a_restart() {
+        let mut default = Echo::default();
+        let mut sessions = PipeSessions::new(DEFAULT_SESSION_TTL, || Ok(Echo::default()));
+        let mut output = Vec::new();
+        run_pipe(
+            &mut default,
+            &mut sessions,
+            None,
+            "session new\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        let token = String::from_utf8(output).unwrap()["ok ".len()..]
+            .trim()
+            .to_string();
+        let input = format!("@{} slate 00000\n", token);
+        run_pipe(
+            &mut default,
+            &mut sessions,
+            None,
+            input.as_bytes(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+
+        let mut restarted = PipeSessions::new(DEFAULT_SESSION_TTL, || Ok(Echo::default()));
+        restarted.restore(sessions.snapshot()).unwrap();
+        let replies = {
+            let mut output = Vec::new();
+            let input = format!("@{} best\n", token);
+            run_pipe(
+                &mut default,
+                &mut restarted,
+                None,
+                input.as_bytes(),
+                &mut output,
+            )
+            .unwrap();
+            String::from_utf8(output).unwrap()
+        };
+        assert_eq!(replies.trim(), "ok slate");
+    }
+
+    #[test]
+    fn test_guard_checks_keys_and_rate_limits() {
+        let guard = AccessGuard::from_config(&ServerConfig {
+            api_keys: vec!["secret".to_string()],
+            rate_limit: Some(RateLimitConfig {
+                requests_per_minute: 3,
+                burst: 0,
+            }),
+            ..ServerConfig::default()
+        });
+        let mut sessions = PipeSessions::new(DEFAULT_SESSION_TTL, || Ok(Echo::default()));
+        let mut output = Vec::new();
+        let input = "best\nsession new\nhelp\nauth secret\nbest\nbest\nbest\n";
+        run_pipe(
+            &mut Canned,
+            &mut sessions,
+            Some(&guard),
+            input.as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let replies: Vec<&str> = output.lines().collect();
+        assert_eq!(replies.len(), 7);
+        // Each request without a key costs the pipe one of its 3 tokens
+        assert!(replies[0].starts_with("error ") && replies[0].contains("Missing API key"));
+        assert!(replies[1].starts_with("error "));
+        assert!(sessions.is_empty());
+        assert!(replies[2].starts_with("ok "));
+        assert_eq!(replies[3], "ok authenticated");
+        assert_eq!(&replies[4..6], ["ok crane", "ok crane"]);
+        assert!(replies[6].starts_with("error ") && replies[6].contains("Rate limited"));
+    }
+}
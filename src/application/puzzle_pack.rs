@@ -0,0 +1,376 @@
+//! Puzzle packs for custom games
+//!
+//! A pack is a JSON file with an ordered list of target words, written by the
+//! `generate` command for teachers and game nights. The targets are drawn at
+//! random from the answer list, subject to [`PackConstraints`]. `pack play` plays
+//! them one after the other in the TUI, under the pack's [`PackRules`], and the
+//! results are kept per pack in the statistics store.
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "name": "Game night",
+//!   "description": "Three hard ones",
+//!   "rules": { "hard_mode": true, "max_guesses": 5 },
+//!   "targets": ["knoll", "mummy", "epoxy"]
+//! }
+//! ```
+
+use crate::{
+    application::{RandomTargets, WordleApplicationService},
+    core::{
+        error::{DataError, Result},
+        types::Word,
+    },
+    domain::Difficulty,
+    infrastructure::{ArchiveResult, UserSettings},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Version written to new packs; loading refuses newer ones
+pub const PACK_VERSION: u32 = 1;
+
+/// A named, ordered set of puzzles
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PuzzlePack {
+    pub version: u32,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Unix timestamp (seconds) when the pack was made
+    #[serde(default)]
+    pub created_at: u64,
+    #[serde(default, skip_serializing_if = "PackRules::is_default")]
+    pub rules: PackRules,
+    /// Answers in the order they are played
+    pub targets: Vec<String>,
+}
+
+/// Settings a pack imposes while it is played; unset ones keep the player's own
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PackRules {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hard_mode: Option<bool>,
+    /// Guesses per puzzle, within [`UserSettings::GUESS_BUDGETS`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_guesses: Option<usize>,
+}
+
+impl PackRules {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// `settings` with these rules in force
+    pub fn apply(&self, settings: &UserSettings) -> UserSettings {
+        UserSettings {
+            hard_mode: self.hard_mode.unwrap_or(settings.hard_mode),
+            max_guesses: self.max_guesses.unwrap_or(settings.max_guesses),
+            ..settings.clone()
+        }
+    }
+}
+
+impl PuzzlePack {
+    pub fn new(name: impl Into<String>, targets: &[Word]) -> Self {
+        Self {
+            version: PACK_VERSION,
+            name: name.into(),
+            description: None,
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            rules: PackRules::default(),
+            targets: targets.iter().map(Word::to_string).collect(),
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(DataError::from)?;
+        let pack: Self = serde_json::from_str(&text).map_err(DataError::from)?;
+        if pack.version > PACK_VERSION {
+            return Err(DataError::InvalidFormat(format!(
+                "pack version {} is newer than this build reads ({})",
+                pack.version, PACK_VERSION
+            ))
+            .into());
+        }
+        if pack.targets.is_empty() {
+            return Err(DataError::InvalidFormat("the pack has no targets".to_string()).into());
+        }
+        if let Some(budget) = pack.rules.max_guesses {
+            if !UserSettings::GUESS_BUDGETS.contains(&budget) {
+                return Err(DataError::InvalidFormat(format!(
+                    "max_guesses {} is outside {:?}",
+                    budget,
+                    UserSettings::GUESS_BUDGETS
+                ))
+                .into());
+            }
+        }
+        pack.target_words()?;
+        Ok(pack)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let text = serde_json::to_string_pretty(self).map_err(DataError::from)?;
+        std::fs::write(path, text).map_err(DataError::from)?;
+        Ok(())
+    }
+
+    /// The targets as words, failing on the first one that isn't a valid word
+    pub fn target_words(&self) -> Result<Vec<Word>> {
+        self.targets
+            .iter()
+            .map(|target| {
+                Word::from_str(target).map_err(|e| {
+                    DataError::InvalidFormat(format!("pack target '{}': {}", target, e)).into()
+                })
+            })
+            .collect()
+    }
+}
+
+/// A player's way through a pack: the best result on each puzzle played so far
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackProgress {
+    pub name: String,
+    pub total: usize,
+    /// Position of the puzzle being played, from 0
+    pub current: usize,
+    pub results: BTreeMap<usize, ArchiveResult>,
+}
+
+impl PackProgress {
+    /// Progress through `pack` given the stored `results`, positioned on the first
+    /// puzzle without one (the first puzzle once all are played)
+    pub fn new(pack: &PuzzlePack, results: BTreeMap<usize, ArchiveResult>) -> Self {
+        let total = pack.targets.len();
+        let current = (0..total).find(|i| !results.contains_key(i)).unwrap_or(0);
+        Self {
+            name: pack.name.clone(),
+            total,
+            current,
+            results,
+        }
+    }
+
+    /// Position after the current puzzle, if the pack goes on
+    pub fn next(&self) -> Option<usize> {
+        (self.current + 1 < self.total).then_some(self.current + 1)
+    }
+
+    pub fn solved(&self) -> usize {
+        self.results
+            .values()
+            .filter(|r| matches!(r, ArchiveResult::Solved(_)))
+            .count()
+    }
+
+    /// Average guesses over the solved puzzles
+    pub fn average_guesses(&self) -> Option<f64> {
+        let guesses: Vec<usize> = self
+            .results
+            .values()
+            .filter_map(|r| match r {
+                ArchiveResult::Solved(n) => Some(*n),
+                ArchiveResult::Failed => None,
+            })
+            .collect();
+        (!guesses.is_empty()).then(|| guesses.iter().sum::<usize>() as f64 / guesses.len() as f64)
+    }
+
+    /// e.g. "Game night 3/10, 2 solved"
+    pub fn label(&self) -> String {
+        format!(
+            "{} {}/{}, {} solved",
+            self.name,
+            self.current + 1,
+            self.total,
+            self.solved()
+        )
+    }
+}
+
+/// What the targets of a generated pack must satisfy
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PackConstraints {
+    /// Targets to pick
+    pub count: usize,
+    /// Lowest and highest forecast difficulty accepted; any when unset
+    pub difficulty: Option<(Difficulty, Difficulty)>,
+    /// Letters every target must contain
+    pub must_contain: Vec<u8>,
+    /// Words never to pick, e.g. recent daily answers
+    pub exclude: BTreeSet<Word>,
+}
+
+impl PackConstraints {
+    /// Whether `word` passes the checks that don't need the solver
+    pub fn admits(&self, word: &Word) -> bool {
+        !self.exclude.contains(word) && self.must_contain.iter().all(|b| word.bytes().contains(b))
+    }
+
+    /// Whether `difficulty` lies in the band
+    pub fn admits_difficulty(&self, difficulty: Difficulty) -> bool {
+        self.difficulty
+            .is_none_or(|(lowest, highest)| (lowest..=highest).contains(&difficulty))
+    }
+
+    /// The constraints in a few words, used as the pack's description
+    pub fn describe(&self) -> String {
+        let plural = |n: usize| if n == 1 { "" } else { "s" };
+        let mut parts = vec![format!("{} puzzle{}", self.count, plural(self.count))];
+        match self.difficulty {
+            Some((lowest, highest)) if lowest == highest => {
+                parts.push(lowest.label().to_string());
+            }
+            Some((lowest, highest)) => {
+                parts.push(format!("{} to {}", lowest.label(), highest.label()));
+            }
+            None => {}
+        }
+        if !self.must_contain.is_empty() {
+            let letters = String::from_utf8_lossy(&self.must_contain).to_uppercase();
+            parts.push(format!("with {}", letters));
+        }
+        if !self.exclude.is_empty() {
+            let n = self.exclude.len();
+            parts.push(format!("avoiding {} recent answer{}", n, plural(n)));
+        }
+        parts.join(", ")
+    }
+}
+
+/// A target picked for a pack, with its forecast when a difficulty band was asked for
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedTarget {
+    pub word: Word,
+    pub difficulty: Option<Difficulty>,
+}
+
+/// Answers passing the solver-free constraints, in a random order fixed by `seed`
+pub fn shuffled_candidates(
+    answers: &[Word],
+    constraints: &PackConstraints,
+    seed: u64,
+) -> Vec<Word> {
+    let pool: Vec<Word> = answers
+        .iter()
+        .filter(|word| constraints.admits(word))
+        .cloned()
+        .collect();
+    let total = pool.len();
+    let mut draws = RandomTargets::with_seed(pool, seed);
+    let mut seen = BTreeSet::new();
+    let mut order = Vec::with_capacity(total);
+    while order.len() < total {
+        let Some(word) = draws.next() else { break };
+        if seen.insert(word.clone()) {
+            order.push(word);
+        }
+    }
+    order
+}
+
+/// Pick up to `constraints.count` targets from the service's answer list
+///
+/// With a difficulty band each candidate is forecast by playing it with the solver
+/// (see [`WordleApplicationService::analyze_answer`]), which resets the current game.
+/// Fewer targets come back when too few answers qualify.
+pub fn generate_targets(
+    service: &mut WordleApplicationService,
+    constraints: &PackConstraints,
+    seed: u64,
+) -> Result<Vec<GeneratedTarget>> {
+    service.reset_game()?;
+    let answers = service.possible_words(None);
+    let mut targets = Vec::with_capacity(constraints.count);
+    for word in shuffled_candidates(&answers, constraints, seed) {
+        if targets.len() >= constraints.count {
+            break;
+        }
+        let difficulty = match constraints.difficulty {
+            Some(_) => Some(service.analyze_answer(&word)?.forecast()),
+            None => None,
+        };
+        if difficulty.is_none_or(|d| constraints.admits_difficulty(d)) {
+            targets.push(GeneratedTarget { word, difficulty });
+        }
+    }
+    Ok(targets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::testing::words;
+
+    #[test]
+    fn test_candidates_follow_letters_exclusions_and_seed() {
+        let answers = words(&["crane", "slate", "train", "rebut", "cigar", "pudgy"]);
+        let constraints = PackConstraints {
+            count: 3,
+            must_contain: b"r".to_vec(),
+            exclude: words(&["cigar"]).into_iter().collect(),
+            ..PackConstraints::default()
+        };
+        let order = shuffled_candidates(&answers, &constraints, 7);
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, words(&["crane", "rebut", "train"]));
+        assert_eq!(shuffled_candidates(&answers, &constraints, 7), order);
+        assert_eq!(constraints.describe(), "3 puzzles, with R, avoiding 1 recent answer");
+
+        let band = PackConstraints {
+            difficulty: Some((Difficulty::Moderate, Difficulty::Hard)),
+            ..constraints
+        };
+        assert!(band.admits_difficulty(Difficulty::Hard));
+        assert!(!band.admits_difficulty(Difficulty::Easy));
+    }
+
+    #[test]
+    fn test_pack_round_trip() {
+        let path = std::env::temp_dir().join(format!("wordle_pack_{}.json", std::process::id()));
+        let pack = PuzzlePack::new("Game night", &words(&["crane", "slate"]))
+            .with_description("2 puzzles");
+        pack.save(&path).unwrap();
+        let loaded = PuzzlePack::load(&path).unwrap();
+        assert_eq!(loaded, pack);
+        assert_eq!(loaded.target_words().unwrap(), words(&["crane", "slate"]));
+
+        std::fs::write(&path, r#"{"version": 1, "name": "bad", "targets": ["crane", "xx"]}"#)
+            .unwrap();
+        assert!(PuzzlePack::load(&path).is_err());
+        let rules = r#"{"version": 1, "name": "x", "rules": {"max_guesses": 50}, "targets": []}"#;
+        std::fs::write(&path, rules.replace("[]", r#"["crane"]"#)).unwrap();
+        assert!(PuzzlePack::load(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_progress_resumes_at_first_unplayed_puzzle() {
+        let mut pack = PuzzlePack::new("night", &words(&["crane", "slate", "pudgy"]));
+        pack.rules.max_guesses = Some(4);
+        let settings = pack.rules.apply(&UserSettings::default());
+        assert_eq!((settings.max_guesses, settings.hard_mode), (4, false));
+
+        let results = BTreeMap::from([(0, ArchiveResult::Solved(3)), (1, ArchiveResult::Failed)]);
+        let progress = PackProgress::new(&pack, results);
+        assert_eq!((progress.current, progress.next()), (2, None));
+        assert_eq!(progress.average_guesses(), Some(3.0));
+        assert_eq!(progress.label(), "night 3/3, 1 solved");
+        assert_eq!(PackProgress::new(&pack, BTreeMap::new()).next(), Some(1));
+    }
+}
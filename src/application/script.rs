@@ -0,0 +1,440 @@
+//! Recorded command scripts
+//!
+//! With recording on, every [`Command`] the application service executes is
+//! appended to a JSON script together with a short summary of its result.
+//! Replaying the script against a fresh service runs the same commands and flags
+//! every step whose result differs, which makes a bug report reproducible and a
+//! script usable as an acceptance test.
+
+use crate::{
+    application::{Command, CommandExecutor, CommandResult, SessionGuess},
+    core::{
+        error::{DataError, Result},
+        types::{FeedbackPattern, ManualConstraint, Word},
+    },
+    domain::WordOrder,
+};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a file to record executed commands into
+pub const RECORD_SCRIPT_ENV: &str = "WORDLE_RECORD_SCRIPT";
+
+/// Version written to new scripts; replay refuses newer ones
+pub const SCRIPT_VERSION: u32 = 1;
+
+/// Script file named by `$WORDLE_RECORD_SCRIPT`, if set
+pub fn record_script_path() -> Option<PathBuf> {
+    std::env::var(RECORD_SCRIPT_ENV)
+        .ok()
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
+
+/// A [`Command`] with words and feedback as plain strings, as stored in a script
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum RecordedCommand {
+    StartGame {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target: Option<String>,
+    },
+    MakeGuess { word: String },
+    AddGuessResult { word: String, feedback: String },
+    AddGuessBatch { guesses: Vec<SessionGuess> },
+    /// Constraint in the `a3` / `-x` form read by [`ManualConstraint::parse`]
+    AddManualConstraint { constraint: String },
+    ClearManualConstraints,
+    GetBestGuess,
+    GetBestFirstGuess,
+    Reset,
+    GetStatistics,
+    GetTopCandidates { limit: usize },
+    EvaluateGuess { word: String },
+    UndoLastGuess,
+    GetSuggestionSet,
+    FindDisambiguator,
+    GetPossibleWordsPage {
+        offset: usize,
+        limit: usize,
+        #[serde(default)]
+        sort: WordOrder,
+    },
+}
+
+impl From<&Command> for RecordedCommand {
+    fn from(command: &Command) -> Self {
+        match command {
+            Command::StartGame { target_word } => Self::StartGame {
+                target: target_word.as_ref().map(Word::to_string),
+            },
+            Command::MakeGuess { word } => Self::MakeGuess {
+                word: word.to_string(),
+            },
+            Command::AddGuessResult { word, feedback } => Self::AddGuessResult {
+                word: word.to_string(),
+                feedback: feedback.to_code_string(),
+            },
+            Command::AddGuessBatch { guesses } => Self::AddGuessBatch {
+                guesses: guesses
+                    .iter()
+                    .map(|(word, feedback)| SessionGuess {
+                        word: word.to_string(),
+                        feedback: feedback.to_code_string(),
+                    })
+                    .collect(),
+            },
+            Command::AddManualConstraint { constraint } => Self::AddManualConstraint {
+                constraint: constraint.to_string(),
+            },
+            Command::ClearManualConstraints => Self::ClearManualConstraints,
+            Command::GetBestGuess => Self::GetBestGuess,
+            Command::GetBestFirstGuess => Self::GetBestFirstGuess,
+            Command::Reset => Self::Reset,
+            Command::GetStatistics => Self::GetStatistics,
+            Command::GetTopCandidates { limit } => Self::GetTopCandidates { limit: *limit },
+            Command::EvaluateGuess { word } => Self::EvaluateGuess {
+                word: word.to_string(),
+            },
+            Command::UndoLastGuess => Self::UndoLastGuess,
+            Command::GetSuggestionSet => Self::GetSuggestionSet,
+            Command::FindDisambiguator => Self::FindDisambiguator,
+            Command::GetPossibleWordsPage {
+                offset,
+                limit,
+                sort,
+            } => Self::GetPossibleWordsPage {
+                offset: *offset,
+                limit: *limit,
+                sort: *sort,
+            },
+        }
+    }
+}
+
+impl RecordedCommand {
+    /// The command to execute; fails on words or feedback a hand-edited script got wrong
+    pub fn to_command(&self) -> Result<Command> {
+        let word = |text: &str| {
+            Word::from_str(text).map_err(|e| DataError::InvalidFormat(format!("'{}': {}", text, e)))
+        };
+        let feedback = |text: &str| {
+            FeedbackPattern::from_code_string(text)
+                .map_err(|e| DataError::InvalidFormat(format!("feedback '{}': {}", text, e)))
+        };
+        Ok(match self {
+            Self::StartGame { target } => Command::StartGame {
+                target_word: target.as_deref().map(word).transpose()?,
+            },
+            Self::MakeGuess { word: text } => Command::MakeGuess { word: word(text)? },
+            Self::AddGuessResult {
+                word: text,
+                feedback: code,
+            } => Command::AddGuessResult {
+                word: word(text)?,
+                feedback: feedback(code)?,
+            },
+            Self::AddGuessBatch { guesses } => Command::AddGuessBatch {
+                guesses: guesses
+                    .iter()
+                    .map(|g| Ok((word(&g.word)?, feedback(&g.feedback)?)))
+                    .collect::<Result<_>>()?,
+            },
+            Self::AddManualConstraint { constraint } => Command::AddManualConstraint {
+                constraint: ManualConstraint::parse(constraint)?,
+            },
+            Self::ClearManualConstraints => Command::ClearManualConstraints,
+            Self::GetBestGuess => Command::GetBestGuess,
+            Self::GetBestFirstGuess => Command::GetBestFirstGuess,
+            Self::Reset => Command::Reset,
+            Self::GetStatistics => Command::GetStatistics,
+            Self::GetTopCandidates { limit } => Command::GetTopCandidates { limit: *limit },
+            Self::EvaluateGuess { word: text } => Command::EvaluateGuess { word: word(text)? },
+            Self::UndoLastGuess => Command::UndoLastGuess,
+            Self::GetSuggestionSet => Command::GetSuggestionSet,
+            Self::FindDisambiguator => Command::FindDisambiguator,
+            Self::GetPossibleWordsPage {
+                offset,
+                limit,
+                sort,
+            } => Command::GetPossibleWordsPage {
+                offset: *offset,
+                limit: *limit,
+                sort: *sort,
+            },
+        })
+    }
+}
+
+/// Short, deterministic description of a command's result, compared on replay
+///
+/// Scores and probabilities are left out so that harmless floating-point drift
+/// doesn't fail a replay; words, feedback and word counts are kept.
+pub fn describe_result(result: &Result<CommandResult>) -> String {
+    let words = |words: &mut dyn Iterator<Item = &Word>| {
+        words.map(Word::to_string).collect::<Vec<_>>().join(",")
+    };
+    match result {
+        Err(e) => format!("error: {}", e),
+        Ok(result) => match result {
+            CommandResult::GameStarted { target_set } => {
+                format!("started{}", if *target_set { " with target" } else { "" })
+            }
+            CommandResult::GuessMade {
+                feedback,
+                game_finished,
+            } => format!(
+                "{}{}",
+                feedback.to_code_string(),
+                if *game_finished { " finished" } else { "" }
+            ),
+            CommandResult::GuessResultAdded { remaining_words }
+            | CommandResult::ManualConstraintsUpdated { remaining_words } => {
+                format!("{} remaining", remaining_words)
+            }
+            CommandResult::GuessBatchAdded { remaining_words } => format!(
+                "{} remaining",
+                remaining_words.last().copied().unwrap_or_default()
+            ),
+            CommandResult::BestGuess { word, .. } | CommandResult::BestFirstGuess { word } => {
+                word.to_string()
+            }
+            CommandResult::Reset => "reset".to_string(),
+            CommandResult::Statistics { stats } => {
+                format!("{} guesses, {} remaining", stats.total_guesses, stats.remaining_words)
+            }
+            CommandResult::TopCandidates { candidates } => {
+                words(&mut candidates.iter().map(|(word, _)| word))
+            }
+            CommandResult::GuessEvaluated { word, quality } => format!(
+                "{}: {} buckets, worst {}",
+                word, quality.bucket_count, quality.worst_case_remaining
+            ),
+            CommandResult::GuessUndone {
+                guess,
+                remaining_words,
+            } => match guess {
+                Some(guess) => format!("undid {}, {} remaining", guess.word, remaining_words),
+                None => format!("nothing to undo, {} remaining", remaining_words),
+            },
+            CommandResult::SuggestionSet { set } => match set {
+                Some(set) => words(&mut set.iter().map(|(_, suggestion)| &suggestion.word)),
+                None => "no suggestions".to_string(),
+            },
+            CommandResult::Disambiguator { found } => match found {
+                Some(found) => found.word.to_string(),
+                None => "none".to_string(),
+            },
+            CommandResult::PossibleWordsPage { page } => format!(
+                "{} remaining, from {}: {}",
+                page.total,
+                page.offset,
+                words(&mut page.words.iter())
+            ),
+            CommandResult::Error { message } => format!("error: {}", message),
+        },
+    }
+}
+
+/// One command in a script and the result it had when recorded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptStep {
+    #[serde(flatten)]
+    pub command: RecordedCommand,
+    /// [`describe_result`] of the recorded run; steps without one aren't checked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expect: Option<String>,
+}
+
+/// A sequence of commands to replay against the application service
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandScript {
+    pub version: u32,
+    pub steps: Vec<ScriptStep>,
+}
+
+impl Default for CommandScript {
+    fn default() -> Self {
+        Self {
+            version: SCRIPT_VERSION,
+            steps: Vec::new(),
+        }
+    }
+}
+
+impl CommandScript {
+    /// Append `command` with the result it produced
+    pub fn record(&mut self, command: &Command, result: &Result<CommandResult>) {
+        self.steps.push(ScriptStep {
+            command: command.into(),
+            expect: Some(describe_result(result)),
+        });
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(DataError::from)?;
+        let script: Self = serde_json::from_str(&text).map_err(DataError::from)?;
+        if script.version > SCRIPT_VERSION {
+            return Err(DataError::InvalidFormat(format!(
+                "script version {} is newer than this build reads ({})",
+                script.version, SCRIPT_VERSION
+            ))
+            .into());
+        }
+        Ok(script)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let text = serde_json::to_string_pretty(self).map_err(DataError::from)?;
+        std::fs::write(path, text).map_err(DataError::from)?;
+        Ok(())
+    }
+
+    /// Execute every step in order and compare each result with the recorded one
+    ///
+    /// A failing command is an outcome like any other and doesn't stop the replay;
+    /// only a step that can't be turned back into a command does.
+    pub fn replay(&self, executor: &mut dyn CommandExecutor) -> Result<ScriptReport> {
+        let mut steps = Vec::with_capacity(self.steps.len());
+        for step in &self.steps {
+            let command = step.command.to_command()?;
+            let name = command.name();
+            let actual = describe_result(&executor.execute(command));
+            steps.push(StepOutcome {
+                name,
+                expected: step.expect.clone(),
+                actual,
+            });
+        }
+        Ok(ScriptReport { steps })
+    }
+}
+
+/// What one replayed step did
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepOutcome {
+    /// [`Command::name`] of the step
+    pub name: &'static str,
+    pub expected: Option<String>,
+    pub actual: String,
+}
+
+impl StepOutcome {
+    /// Whether the result differs from the recorded one
+    pub fn diverged(&self) -> bool {
+        self.expected.as_ref().is_some_and(|expected| *expected != self.actual)
+    }
+}
+
+/// Results of a replay, in script order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptReport {
+    pub steps: Vec<StepOutcome>,
+}
+
+impl ScriptReport {
+    /// 1-based step numbers and outcomes of the steps that diverged
+    pub fn divergences(&self) -> impl Iterator<Item = (usize, &StepOutcome)> {
+        self.steps
+            .iter()
+            .enumerate()
+            .filter(|(_, step)| step.diverged())
+            .map(|(i, step)| (i + 1, step))
+    }
+
+    pub fn passed(&self) -> bool {
+        self.divergences().next().is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::error::GameError;
+
+    /// Answers every guess with the same feedback and counts the commands
+    struct Scripted {
+        executed: Vec<&'static str>,
+    }
+
+    impl CommandExecutor for Scripted {
+        fn execute(&mut self, command: Command) -> Result<CommandResult> {
+            self.executed.push(command.name());
+            match command {
+                Command::MakeGuess { word } if word.as_str() == "zzzzz" => {
+                    Err(GameError::WordNotFound(word.to_string()).into())
+                }
+                Command::MakeGuess { .. } => Ok(CommandResult::GuessMade {
+                    feedback: FeedbackPattern::from_code_string("00202").unwrap(),
+                    game_finished: false,
+                }),
+                _ => Ok(CommandResult::Reset),
+            }
+        }
+    }
+
+    fn word(text: &str) -> Word {
+        Word::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_recorded_commands_round_trip_through_json() {
+        let commands = vec![
+            Command::StartGame {
+                target_word: Some(word("crane")),
+            },
+            Command::AddGuessBatch {
+                guesses: vec![(word("slate"), FeedbackPattern::from_code_string("00202").unwrap())],
+            },
+            Command::AddManualConstraint {
+                constraint: ManualConstraint::parse("c1").unwrap(),
+            },
+            Command::GetTopCandidates { limit: 3 },
+        ];
+        let mut script = CommandScript::default();
+        for command in &commands {
+            script.record(command, &Ok(CommandResult::Reset));
+        }
+
+        let json = serde_json::to_string(&script).unwrap();
+        assert!(json.contains(r#""command":"start_game","target":"crane""#), "{}", json);
+        let read: CommandScript = serde_json::from_str(&json).unwrap();
+        assert_eq!(read, script);
+        for (step, command) in read.steps.iter().zip(&commands) {
+            assert_eq!(step.command.to_command().unwrap().name(), command.name());
+            assert_eq!(RecordedCommand::from(&step.command.to_command().unwrap()), step.command);
+        }
+
+        let bad = RecordedCommand::MakeGuess {
+            word: "cran".to_string(),
+        };
+        assert!(bad.to_command().is_err());
+    }
+
+    #[test]
+    fn test_replay_flags_diverging_steps() {
+        let script: CommandScript = serde_json::from_str(
+            r#"{"version": 1, "steps": [
+                {"command": "reset"},
+                {"command": "make_guess", "word": "slate", "expect": "00202"},
+                {"command": "make_guess", "word": "crane", "expect": "22222 finished"},
+                {"command": "make_guess", "word": "zzzzz", "expect": "error: nope"}
+            ]}"#,
+        )
+        .unwrap();
+        let mut executor = Scripted {
+            executed: Vec::new(),
+        };
+        let report = script.replay(&mut executor).unwrap();
+
+        // Failures are outcomes too; every step runs
+        assert_eq!(executor.executed.len(), 4);
+        assert_eq!(report.steps[0].expected, None);
+        assert!(!report.steps[1].diverged());
+        let diverged: Vec<usize> = report.divergences().map(|(n, _)| n).collect();
+        assert_eq!(diverged, vec![3, 4]);
+        assert!(report.steps[3].actual.starts_with("error: "));
+        assert!(!report.passed());
+    }
+}
@@ -1,31 +1,57 @@
 use crate::{
     application::{
-        AppEvent, AppState, AppStateManager, Command, CommandExecutor, CommandResult,
-        CommandValidator, DefaultCommandValidator, EventHandler,
+        AppEvent, AppState, AppStateManager, BenchmarkRunner, Command, CommandExecutor,
+        CommandResult, CommandScript, CommandValidator, DecisionTree, DefaultCommandValidator,
+        EventHandler, OpenerSearchConfig, OpenerSearchReport, StateObserver, SubscriptionId,
+        TreeComparison, anneal_openers,
     },
     core::{
-        error::Result,
+        error::{Result, SolverError},
+        metrics::OperationTiming,
+        morphology::AnswerPrior,
         traits::{GameEngine, StateManager, StateUpdater, WordleSolver},
-        types::{FeedbackPattern, Word},
+        types::{
+            Disambiguator, FeedbackPattern, Guess, ManualConstraint, PrefixScore, RankedCandidate,
+            SuggestionQuality, SuggestionSet, TrapCluster, Word, WordCluster,
+        },
     },
+    domain::{
+        AnswerReport, CandidateDiff, GameEvent, GameEventBus, PAIR_SHORTLIST, PrefixIndex,
+        ProbePair, WordGraphAnalysis, WordGraphReport, WordOrder, WordPage, cluster_words,
+        common_family, families_of, letter_rarity, played_in_hard_mode, rank_probe_pairs,
+        rank_single_probes,
+    },
+    infrastructure::letter_contributions,
 };
 use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio_util::sync::CancellationToken;
 
 /// Main application service orchestrating all components
 pub struct WordleApplicationService {
     game_engine: Box<dyn GameEngine>,
-    solver: Box<dyn WordleSolver>,
+    /// Shared with a suggestion running on the rayon pool
+    solver: Arc<dyn WordleSolver>,
     state_manager: AppStateManager,
     command_validator: Box<dyn CommandValidator>,
+    events: GameEventBus,
+    /// When the current game started, for the finished event
+    started_at: Instant,
+    /// Script file and the commands recorded into it so far
+    recording: Option<(PathBuf, CommandScript)>,
 }
 
 impl fmt::Debug for WordleApplicationService {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("WordleApplicationService")
             .field("game_engine", &"Box<dyn GameEngine>")
-            .field("solver", &"Box<dyn WordleSolver>")
+            .field("solver", &"Arc<dyn WordleSolver>")
             .field("state_manager", &self.state_manager)
             .field("command_validator", &"Box<dyn CommandValidator>")
+            .field("events", &self.events)
+            .field("recording", &self.recording.as_ref().map(|(path, _)| path))
             .finish()
     }
 }
@@ -41,7 +67,7 @@ impl WordleApplicationService {
             entropy_calculator,
         )?);
         let constraint_filter = Box::new(crate::domain::DefaultConstraintFilter::new());
-        let solver = Box::new(
+        let solver = Arc::new(
             crate::domain::DefaultWordleSolver::new(
                 word_list_provider,
                 strategy,
@@ -58,6 +84,9 @@ impl WordleApplicationService {
             solver,
             state_manager,
             command_validator,
+            events: GameEventBus::new(),
+            started_at: Instant::now(),
+            recording: None,
         })
     }
 
@@ -71,9 +100,12 @@ impl WordleApplicationService {
 
         Ok(Self {
             game_engine,
-            solver,
+            solver: Arc::from(solver),
             state_manager,
             command_validator,
+            events: GameEventBus::new(),
+            started_at: Instant::now(),
+            recording: None,
         })
     }
 
@@ -85,17 +117,65 @@ impl WordleApplicationService {
     ) -> Self {
         Self {
             game_engine,
-            solver,
+            solver: Arc::from(solver),
             state_manager,
             command_validator,
+            events: GameEventBus::new(),
+            started_at: Instant::now(),
+            recording: None,
         }
     }
 
+    /// Autosave the in-progress game to `path` after every guess
+    pub fn enable_autosave(&mut self, path: impl Into<std::path::PathBuf>) {
+        self.state_manager.set_autosave_path(Some(path.into()));
+    }
+
+    /// Stop autosaving, e.g. for demo games that shouldn't be offered for resume
+    pub fn disable_autosave(&mut self) {
+        self.state_manager.set_autosave_path(None);
+    }
+
+    /// Record every command executed from now on into a script at `path`
+    ///
+    /// The file is rewritten after each command, so it is complete even if the
+    /// program is killed. Replay it with [`CommandScript::replay`].
+    pub fn enable_recording(&mut self, path: impl Into<PathBuf>) {
+        self.recording = Some((path.into(), CommandScript::default()));
+    }
+
+    /// Notify `observer` of every application state change, with what changed
+    pub fn subscribe_state(&mut self, observer: impl StateObserver + 'static) -> SubscriptionId {
+        self.state_manager.subscribe(observer)
+    }
+
+    /// Stop notifying a state subscriber; false if it was already gone
+    pub fn unsubscribe_state(&mut self, id: SubscriptionId) -> bool {
+        self.state_manager.unsubscribe(id)
+    }
+
+    /// Follow the game as it is played: guesses, feedback and the result
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<GameEvent> {
+        self.events.subscribe()
+    }
+
     /// Get current application state
     pub fn get_state(&self) -> &AppState {
         self.state_manager.get_state()
     }
 
+    /// The solver, to update
+    ///
+    /// A suggestion whose caller gave up on it keeps the solver until its search
+    /// notices the cancellation; updates fail with [`SolverError::SearchRunning`]
+    /// until then rather than block the thread.
+    fn solver_mut(&mut self) -> Result<&mut dyn WordleSolver> {
+        match Arc::get_mut(&mut self.solver) {
+            Some(solver) => Ok(solver),
+            None => Err(SolverError::SearchRunning.into()),
+        }
+    }
+
     /// Update application state with solver information
     fn update_state_with_solver_info(&mut self) -> Result<()> {
         let stats = self.solver.get_statistics();
@@ -117,16 +197,26 @@ impl WordleApplicationService {
 
     /// Process a user guess
     pub fn process_guess(&mut self, word: &Word) -> Result<FeedbackPattern> {
+        // Reject before the game engine counts it
+        self.solver.validate_guess(word)?;
+
         // Make guess in game engine
+        let attempt = self.game_engine.attempts_count() + 1;
         let feedback = self.game_engine.make_guess(word)?;
+        self.events.publish(GameEvent::GuessMade {
+            word: word.clone(),
+            attempt,
+        });
 
         // Update solver with the result
-        self.solver.add_guess_result(word, &feedback)?;
+        let previous = self.solver.get_possible_words(None);
+        self.solver_mut()?.add_guess_result(word, &feedback)?;
 
         // Update application state
         self.state_manager.update_state(|state| {
-            let guess = crate::core::types::Guess::new(word.clone(), feedback.clone());
+            let guess = crate::core::types::Guess::new(word.clone(), feedback);
             state.guess_history.push(guess);
+            state.previous_candidates = previous;
             state.game_result = self.game_engine.get_result();
             Ok(())
         })?;
@@ -134,9 +224,50 @@ impl WordleApplicationService {
         // Update solver information
         self.update_state_with_solver_info()?;
 
+        self.publish_feedback(Guess::new(word.clone(), feedback));
+        if self.game_engine.is_finished() {
+            self.publish_finished(
+                self.game_engine.get_result().is_won(),
+                self.game_engine.target().cloned(),
+            );
+        }
         Ok(feedback)
     }
 
+    /// Publish the feedback for `guess` with the words it leaves
+    fn publish_feedback(&self, guess: Guess) {
+        self.events.publish(GameEvent::FeedbackIssued {
+            guess,
+            remaining: self.solver.remaining_words_count(),
+        });
+    }
+
+    /// Publish the end of the game with every guess played
+    fn publish_finished(&self, won: bool, target: Option<Word>) {
+        self.events.publish(GameEvent::GameFinished {
+            won,
+            target,
+            guesses: self.get_state().guess_history.clone(),
+            duration: self.started_at.elapsed(),
+        });
+    }
+
+    /// Publish feedback entered for `guess`, and the end of the game when it wins
+    /// or uses the last guess
+    fn publish_entered(&self, guess: Guess) {
+        let played = self.get_state().guess_history.len();
+        self.events.publish(GameEvent::GuessMade {
+            word: guess.word.clone(),
+            attempt: played,
+        });
+        let won = guess.is_winning();
+        let target = won.then(|| guess.word.clone());
+        self.publish_feedback(guess);
+        if won || played >= self.game_engine.max_attempts() {
+            self.publish_finished(won, target);
+        }
+    }
+
     /// Start a new game
     pub fn start_game(&mut self, target_word: Option<&Word>) -> Result<()> {
         if let Some(word) = target_word {
@@ -144,39 +275,121 @@ impl WordleApplicationService {
         }
 
         // Reset state
+        self.solver_mut()?.reset();
         self.state_manager.reset_state();
-        self.solver.reset();
 
         // Update initial suggestions
         self.update_state_with_solver_info()?;
 
+        self.started_at = Instant::now();
+        self.events.publish(GameEvent::GameStarted {
+            target_set: target_word.is_some(),
+        });
         Ok(())
     }
 
     /// Reset the current game
     pub fn reset_game(&mut self) -> Result<()> {
+        self.clear_guesses()?;
+        self.started_at = Instant::now();
+        self.events.publish(GameEvent::GameStarted { target_set: false });
+        Ok(())
+    }
+
+    /// Forget the guesses without announcing a new game
+    fn clear_guesses(&mut self) -> Result<()> {
+        self.solver_mut()?.reset();
         self.state_manager.reset_state();
-        self.solver.reset();
         self.update_state_with_solver_info()
     }
 
+    /// Switch hard mode mid-game; the guesses so far stay and suggestions are recomputed
+    pub fn set_hard_mode(&mut self, hard_mode: bool) -> Result<()> {
+        self.solver_mut()?.set_hard_mode(hard_mode);
+        self.update_state_with_solver_info()
+    }
+
+    /// Refresh the solver's word lists in place, keeping the game; the guesses so far
+    /// narrow the new answers and the suggestions are recomputed from them
+    pub async fn refresh_word_lists(&mut self, force: bool) -> Result<(usize, usize)> {
+        let counts = self.solver_mut()?.refresh_word_lists(force).await?;
+        self.update_state_with_solver_info()?;
+        Ok(counts)
+    }
+
+    /// Keep `words` (e.g. from [`StatisticsStore::used_words`]) out of the suggestions
+    ///
+    /// [`StatisticsStore::used_words`]: crate::infrastructure::StatisticsStore::used_words
+    pub fn set_excluded_words(&mut self, words: Vec<Word>) -> Result<()> {
+        self.solver_mut()?.set_excluded_words(words);
+        self.update_state_with_solver_info()
+    }
+
+    /// Whether the guesses so far look like hard-mode play (every hint reused)
+    pub fn played_in_hard_mode(&self) -> bool {
+        played_in_hard_mode(self.solver.get_guess_history())
+    }
+
+    /// Parse typed text as a guess and check it may be played now
+    ///
+    /// Errors say why not: wrong length, characters other than a-z, not in the
+    /// guess list, or ignoring a hint in hard mode.
+    pub fn check_guess(&self, text: &str) -> Result<Word> {
+        let word = Word::parse_guess(text)?;
+        self.solver.validate_guess(&word)?;
+        Ok(word)
+    }
+
     /// Get the best first guess
     pub fn get_best_first_guess(&self) -> Result<Word> {
         self.solver.get_best_first_guess()
     }
 
+    /// Whether the game is down to its last guesses, so only possible answers are
+    /// suggested
+    pub fn is_urgent(&self) -> bool {
+        self.solver.is_urgent()
+    }
+
     /// Get the best next guess
     pub fn get_best_next_guess(&mut self) -> Result<Word> {
         self.solver.get_best_guess()
     }
 
+    /// Get the best next guess, giving up with [`SolverError::Cancelled`] once `token`
+    /// is cancelled
+    ///
+    /// For applications embedding the solver (a GUI, a server) that want to drop a
+    /// suggestion nobody is waiting for any more. The candidates are scored on the
+    /// rayon pool, checking the token before each one, while this task waits, so the
+    /// runtime stays free to cancel: a UI event, a request timeout. Dropping the
+    /// future cancels the search too.
+    pub async fn suggest_with_cancel(&self, token: CancellationToken) -> Result<Word> {
+        // Cancel the search, not the caller's token, when this future is dropped
+        let search = token.child_token();
+        let _stop_on_drop = search.clone().drop_guard();
+        let solver = Arc::clone(&self.solver);
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        rayon::spawn(move || {
+            let best = solver.get_best_guess_cancellable(&search);
+            // Let go of the solver before answering, so updates can take it back
+            drop(solver);
+            let _ = sender.send(best);
+        });
+        receiver
+            .await
+            .map_err(|_| SolverError::AlgorithmFailure("suggestion search stopped".to_string()))?
+    }
+
     /// Add a previous guess result (for importing game state)
     pub fn add_guess_result(&mut self, word: &Word, feedback: &FeedbackPattern) -> Result<()> {
-        self.solver.add_guess_result(word, feedback)?;
+        let previous = self.solver.get_possible_words(None);
+        self.solver_mut()?.add_guess_result(word, feedback)?;
 
         self.state_manager.update_state(|state| {
-            let guess = crate::core::types::Guess::new(word.clone(), feedback.clone());
+            let guess = crate::core::types::Guess::new(word.clone(), *feedback);
             state.guess_history.push(guess);
+            state.previous_candidates = previous;
             Ok(())
         })?;
 
@@ -184,12 +397,330 @@ impl WordleApplicationService {
 
         // TUIでの通常プレイでは、クリア表示はTUI終了後に行うためここでは出力しない
 
+        self.publish_entered(Guess::new(word.clone(), *feedback));
         Ok(())
     }
+
+    /// Narrow the possible words with a pinned or banned letter the game didn't reveal
+    pub fn add_manual_constraint(&mut self, constraint: ManualConstraint) -> Result<()> {
+        self.solver_mut()?.add_manual_constraint(constraint)?;
+        self.update_state_with_solver_info()
+    }
+
+    /// Forget every pinned and banned letter
+    pub fn clear_manual_constraints(&mut self) -> Result<()> {
+        self.solver_mut()?.clear_manual_constraints();
+        self.update_state_with_solver_info()
+    }
+
+    /// Pinned and banned letters in force, in the order they were added
+    pub fn manual_constraints(&self) -> &[ManualConstraint] {
+        self.solver.manual_constraints()
+    }
+
+    /// Remaining possible answers, all of them when `limit` is None
+    pub fn possible_words(&self, limit: Option<usize>) -> Vec<Word> {
+        self.solver.get_possible_words(limit)
+    }
+
+    /// Up to `limit` possible words from `offset` on in `order`, with their total
+    pub fn possible_words_page(&self, offset: usize, limit: usize, order: WordOrder) -> WordPage {
+        WordPage::of(&self.solver.get_possible_words(None), offset, limit, order)
+    }
+
+    /// Letters the guesses so far confirmed in place, e.g. to lock them in the input
+    pub fn confirmed_positions(&self) -> [Option<char>; Word::LENGTH] {
+        self.solver.confirmed_positions()
+    }
+
+    /// A pseudo-random word from the remaining answers, e.g. as a hidden target
+    pub fn random_answer(&self) -> Option<Word> {
+        let words = self.solver.get_possible_words(None);
+        if words.is_empty() {
+            return None;
+        }
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos() as usize ^ d.as_secs() as usize)
+            .unwrap_or(0);
+        Some(words[seed % words.len()].clone())
+    }
+
+    /// Simulated games with this service's solver and guess limit; the current game is
+    /// left alone
+    pub fn benchmark_runner(&self) -> BenchmarkRunner<'_> {
+        BenchmarkRunner::new(self.solver.as_ref())
+            .with_max_guesses(self.game_engine.max_attempts())
+    }
+
+    /// The solver's guess at every node of a published decision tree, against the tree's
+    pub fn compare_with_tree(&self, tree: &DecisionTree) -> Result<TreeComparison> {
+        tree.compare(self.solver.as_ref())
+    }
+
+    /// Play the solver's best guess against the target set with [`Command::StartGame`]
+    ///
+    /// The first guess is the stored opener, as in [`BenchmarkRunner`] games, so a
+    /// played-out game matches benchmark play. Returns the guess with its generated
+    /// feedback and whether the game is over.
+    ///
+    /// [`BenchmarkRunner`]: crate::application::BenchmarkRunner
+    pub fn play_best_guess(&mut self) -> Result<(Guess, bool)> {
+        let word = if self.get_state().guess_history.is_empty() {
+            self.get_best_first_guess()?
+        } else {
+            self.get_best_next_guess()?
+        };
+        let feedback = self.process_guess(&word)?;
+        Ok((Guess::new(word, feedback), self.game_engine.is_finished()))
+    }
+
+    /// Worst case, bucket count and answer probability of `word` for the remaining words
+    pub fn suggestion_quality(&self, word: &Word) -> SuggestionQuality {
+        let possible_words = self.solver.get_possible_words(None);
+        SuggestionQuality::evaluate(word, &possible_words)
+    }
+
+    /// Index of every valid guess for completing typed letters
+    pub fn prefix_index(&self) -> PrefixIndex {
+        PrefixIndex::new(self.solver.guess_words())
+    }
+
+    /// How many remaining words start with the letters typed so far, and the best of them
+    pub fn score_prefix(&self, prefix: &str) -> PrefixScore {
+        PrefixScore::evaluate(prefix, &self.solver.get_possible_words(None))
+    }
+
+    /// The solver's top candidates with their scores and chance of being the answer,
+    /// tagged word forms counting for less (see [`AnswerPrior::from_env`])
+    pub fn ranked_candidates(&self) -> Vec<RankedCandidate> {
+        let prior = AnswerPrior::from_env();
+        RankedCandidate::rank_weighted(
+            &self.get_state().top_candidates,
+            &self.solver.get_possible_words(None),
+            |word| prior.weight(self.solver.morphology(word)),
+        )
+    }
+
+    /// One suggestion per risk level: most information, best answer chance and balanced
+    pub fn suggestion_set(&self) -> Option<SuggestionSet> {
+        self.solver.get_suggestion_set()
+    }
+
+    /// Bits of expected information each letter of `word` contributes
+    pub fn letter_heat(&self, word: &Word) -> [f64; Word::LENGTH] {
+        letter_contributions(word, &self.solver.get_possible_words(None))
+    }
+
+    /// Families of remaining words sharing four letters in place, largest first
+    pub fn word_clusters(&self, min_size: usize) -> Vec<WordCluster> {
+        cluster_words(&self.solver.get_possible_words(None), min_size)
+    }
+
+    /// How hard `word` would be as an answer: solver guesses, trap families and letter rarity
+    ///
+    /// The solver plays `word` from its best opener, so this resets the current game.
+    pub fn analyze_answer(&mut self, word: &Word) -> Result<AnswerReport> {
+        self.reset_game()?;
+        let answers = self.solver.get_possible_words(None);
+        let in_answer_list = answers.contains(word);
+
+        let game = self.benchmark_runner().play(word)?;
+        let solver_guesses = game.solved.then(|| game.guess_count());
+
+        Ok(AnswerReport {
+            word: word.clone(),
+            in_answer_list,
+            solver_guesses,
+            families: families_of(word, &answers, 2),
+            letter_rarity: letter_rarity(word, &answers),
+        })
+    }
+
+    /// Families of near-identical answers in the full answer list, with the guesses that split them
+    pub fn trap_clusters(&self, min_size: usize, guesses_per_trap: usize) -> Vec<TrapCluster> {
+        self.solver.get_trap_clusters(min_size, guesses_per_trap)
+    }
+
+    /// Up to `limit` guesses leaving the fewest possible words on average, best first
+    pub fn single_probes(&self, limit: usize) -> Vec<(Word, f64)> {
+        let possible_words = self.solver.get_possible_words(None);
+        let calculator = crate::infrastructure::SimpleEntropyCalculator::new();
+        rank_single_probes(
+            &calculator,
+            &possible_words,
+            &self.solver.guess_words(),
+            limit,
+        )
+    }
+
+    /// Up to `limit` fixed pairs of next two guesses leaving the fewest possible words
+    /// on average, best first; the first of each is one of the [`PAIR_SHORTLIST`] best
+    /// single guesses
+    pub fn probe_pairs(&self, limit: usize) -> Vec<ProbePair> {
+        let possible_words = self.solver.get_possible_words(None);
+        let guesses = self.solver.guess_words();
+        let calculator = crate::infrastructure::SimpleEntropyCalculator::new();
+        rank_probe_pairs(
+            &calculator,
+            &possible_words,
+            &guesses,
+            PAIR_SHORTLIST,
+            limit,
+        )
+    }
+
+    /// Experimental: openers that play better than the solver's own, found by simulated
+    /// annealing over full games against the remaining words
+    pub fn search_openers(&self, config: &OpenerSearchConfig) -> Result<OpenerSearchReport> {
+        let start = self.solver.best_guess_after(&[])?;
+        let answers = self.solver.get_possible_words(None);
+        let guesses = self.solver.guess_words();
+        anneal_openers(&self.benchmark_runner(), start, &guesses, &answers, config)
+    }
+
+    /// How the remaining words link up by one-letter changes, with the guess that best
+    /// splits each of the `max_breakers` largest groups
+    pub fn word_graph(&self, max_breakers: usize) -> WordGraphReport {
+        self.solver.get_word_graph(max_breakers)
+    }
+
+    /// What the latest guess ruled out: the possible words before it against those left,
+    /// with up to `limit` notable words marked as eliminated or surviving
+    pub fn candidate_diff(&self, limit: usize) -> Option<CandidateDiff> {
+        let state = self.get_state();
+        let guess = state.last_guess()?.clone();
+        Some(CandidateDiff::between(
+            guess,
+            &state.previous_candidates,
+            &self.solver.get_possible_words(None),
+            limit,
+        ))
+    }
+
+    /// The family the remaining words collapsed into, once three or more differ in one letter
+    pub fn active_trap(&self) -> Option<WordCluster> {
+        let remaining = self.solver.get_possible_words(None);
+        if remaining.len() < 3 {
+            return None;
+        }
+        common_family(&remaining)
+    }
+
+    /// A guess packed with the letters that separate near-identical remaining words
+    pub fn disambiguator(&self) -> Option<Disambiguator> {
+        self.solver.get_disambiguator()
+    }
+
+    /// Add several guess results in order, returning the remaining word count after each
+    ///
+    /// Suggestions are recomputed once at the end rather than after every entry.
+    pub fn add_guess_batch(&mut self, guesses: &[(Word, FeedbackPattern)]) -> Result<Vec<usize>> {
+        self.enter_guesses(guesses, true)
+    }
+
+    /// Enter `guesses`, publishing events for each when `publish` (not for replays),
+    /// and return the words left after each
+    fn enter_guesses(
+        &mut self,
+        guesses: &[(Word, FeedbackPattern)],
+        publish: bool,
+    ) -> Result<Vec<usize>> {
+        let mut remaining = Vec::with_capacity(guesses.len());
+        for (word, feedback) in guesses {
+            let previous = self.solver.get_possible_words(None);
+            self.solver_mut()?.add_guess_result(word, feedback)?;
+            self.state_manager.update_state(|state| {
+                let guess = crate::core::types::Guess::new(word.clone(), *feedback);
+                state.guess_history.push(guess);
+                state.previous_candidates = previous;
+                Ok(())
+            })?;
+            remaining.push(self.solver.remaining_words_count());
+            if publish {
+                self.publish_entered(Guess::new(word.clone(), *feedback));
+            }
+        }
+
+        self.update_state_with_solver_info()?;
+        Ok(remaining)
+    }
+
+    /// Take back the latest guess result by replaying the ones before it
+    ///
+    /// Only meant for entered feedback; a game engine target isn't rewound.
+    pub fn undo_last_guess(&mut self) -> Result<Option<Guess>> {
+        let mut history = self.get_state().guess_history.clone();
+        let Some(undone) = history.pop() else {
+            return Ok(None);
+        };
+        let replay: Vec<(Word, FeedbackPattern)> =
+            history.into_iter().map(|g| (g.word, g.feedback)).collect();
+
+        // The replay starts from a reset solver; the player's constraints still hold
+        let manual = self.manual_constraints().to_vec();
+        self.clear_guesses()?;
+        for constraint in manual {
+            self.solver_mut()?.add_manual_constraint(constraint)?;
+        }
+        self.enter_guesses(&replay, false)?;
+        self.events.publish(GameEvent::GuessUndone {
+            guess: undone.clone(),
+        });
+        Ok(Some(undone))
+    }
+}
+
+impl StateManager for WordleApplicationService {
+    type State = AppState;
+
+    fn get_state(&self) -> &Self::State {
+        self.state_manager.get_state()
+    }
+
+    fn reset_state(&mut self) {
+        if let Err(e) = self.reset_game() {
+            log::warn!("Failed to refresh solver info after reset: {}", e);
+        }
+    }
 }
 
 impl CommandExecutor for WordleApplicationService {
     fn execute(&mut self, command: Command) -> Result<CommandResult> {
+        let metrics = crate::core::metrics::global();
+        let name = command.name();
+        let is_suggestion = command.is_suggestion();
+        let timed = command.is_solver_operation().then(|| command.label());
+        let started = Instant::now();
+
+        metrics.record_command(name);
+        let recorded = self.recording.is_some().then(|| command.clone());
+        let result = self.execute_command(command);
+        if result.is_err() {
+            metrics.record_command_error(name);
+        } else if is_suggestion {
+            metrics.observe_suggestion_latency(started.elapsed());
+        }
+        if let Some(operation) = timed.filter(|_| result.is_ok()) {
+            metrics.record_operation(OperationTiming {
+                operation,
+                elapsed: started.elapsed(),
+                candidates: self.solver.remaining_words_count(),
+            });
+        }
+
+        if let (Some(command), Some((path, script))) = (recorded, self.recording.as_mut()) {
+            script.record(&command, &result);
+            if let Err(e) = script.save(&*path) {
+                log::warn!("Failed to record command script to {}: {}", path.display(), e);
+            }
+        }
+        result
+    }
+}
+
+impl WordleApplicationService {
+    fn execute_command(&mut self, command: Command) -> Result<CommandResult> {
         // Validate command first
         self.command_validator.validate(&command)?;
 
@@ -213,6 +744,20 @@ impl CommandExecutor for WordleApplicationService {
                 let remaining_words = self.solver.remaining_words_count();
                 Ok(CommandResult::GuessResultAdded { remaining_words })
             }
+            Command::AddGuessBatch { guesses } => {
+                let remaining_words = self.add_guess_batch(&guesses)?;
+                Ok(CommandResult::GuessBatchAdded { remaining_words })
+            }
+            Command::AddManualConstraint { constraint } => {
+                self.add_manual_constraint(constraint)?;
+                let remaining_words = self.solver.remaining_words_count();
+                Ok(CommandResult::ManualConstraintsUpdated { remaining_words })
+            }
+            Command::ClearManualConstraints => {
+                self.clear_manual_constraints()?;
+                let remaining_words = self.solver.remaining_words_count();
+                Ok(CommandResult::ManualConstraintsUpdated { remaining_words })
+            }
             Command::GetBestGuess => {
                 let word = self.get_best_next_guess()?;
                 // Calculate confidence based on remaining words
@@ -240,6 +785,31 @@ impl CommandExecutor for WordleApplicationService {
                 let candidates = self.solver.get_top_candidates(limit);
                 Ok(CommandResult::TopCandidates { candidates })
             }
+            Command::EvaluateGuess { word } => {
+                let quality = self.suggestion_quality(&word);
+                Ok(CommandResult::GuessEvaluated { word, quality })
+            }
+            Command::GetSuggestionSet => Ok(CommandResult::SuggestionSet {
+                set: self.suggestion_set(),
+            }),
+            Command::FindDisambiguator => Ok(CommandResult::Disambiguator {
+                found: self.disambiguator(),
+            }),
+            Command::GetPossibleWordsPage {
+                offset,
+                limit,
+                sort,
+            } => Ok(CommandResult::PossibleWordsPage {
+                page: self.possible_words_page(offset, limit, sort),
+            }),
+            Command::UndoLastGuess => {
+                let guess = self.undo_last_guess()?;
+                let remaining_words = self.solver.remaining_words_count();
+                Ok(CommandResult::GuessUndone {
+                    guess,
+                    remaining_words,
+                })
+            }
         }
     }
 }
@@ -312,6 +882,8 @@ impl EventHandler for WordleApplicationService {
                 // Handle error display
                 Ok(false)
             }
+            // The front end rebuilds the service with the new configuration
+            AppEvent::ConfigChanged(_) => Ok(false),
         }
     }
 }
@@ -319,6 +891,147 @@ impl EventHandler for WordleApplicationService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::error::WordleError;
+    use crate::core::traits::SolvingStrategy;
+    use crate::domain::solver_engine::testing::FixedWords;
+    use std::time::Duration;
+
+    /// Strategy that only stops searching once cancelled
+    #[derive(Debug)]
+    struct UntilCancelled;
+
+    impl SolvingStrategy for UntilCancelled {
+        fn get_best_guess(&self, _possible: &[Word], candidates: &[Word]) -> Result<Word> {
+            Ok(candidates[0].clone())
+        }
+
+        fn get_best_guess_cancellable(
+            &self,
+            _possible: &[Word],
+            _candidates: &[Word],
+            cancel: &CancellationToken,
+        ) -> Result<Word> {
+            while !cancel.is_cancelled() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            Err(SolverError::Cancelled.into())
+        }
+
+        fn get_best_first_guess(&self) -> Result<Word> {
+            Err(SolverError::NoCandidates.into())
+        }
+
+        fn get_top_candidates(&self, _: &[Word], _: &[Word], _: usize) -> Vec<(Word, f64)> {
+            Vec::new()
+        }
+
+        fn clear_cache(&mut self) {}
+    }
+
+    async fn endless_service() -> WordleApplicationService {
+        let words = ["crane", "slate", "plate"].map(|w| Word::from_str(w).unwrap());
+        let solver = crate::domain::DefaultWordleSolver::new(
+            Box::new(FixedWords(words.to_vec())),
+            Box::new(UntilCancelled),
+            Box::new(crate::domain::DefaultConstraintFilter::new()),
+        )
+        .await
+        .unwrap();
+        let engine = crate::domain::DefaultGameEngine::with_feedback_generator(Box::new(
+            crate::domain::DefaultFeedbackGenerator::new(),
+        ));
+        WordleApplicationService::with_dependencies(Box::new(engine), Box::new(solver))
+            .await
+            .unwrap()
+    }
+
+    // A current-thread runtime, so the cancelling task only runs if the search
+    // leaves the executor free
+    #[tokio::test]
+    async fn test_suggest_with_cancel_stops_when_cancelled() {
+        let mut service = endless_service().await;
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            canceller.cancel();
+        });
+        let result = service.suggest_with_cancel(token).await;
+        assert!(matches!(
+            result,
+            Err(WordleError::Solver(SolverError::Cancelled))
+        ));
+
+        // Dropping the future stops the search, and the solver can be updated again
+        let token = CancellationToken::new();
+        let abandoned = tokio::time::timeout(
+            Duration::from_millis(20),
+            service.suggest_with_cancel(token.clone()),
+        );
+        assert!(abandoned.await.is_err());
+        assert!(!token.is_cancelled());
+        // Updates are refused, not blocked on, until the search sees the cancellation
+        let mut updated = service.set_hard_mode(true);
+        for _ in 0..1000 {
+            if !matches!(
+                updated,
+                Err(WordleError::Solver(SolverError::SearchRunning))
+            ) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            updated = service.set_hard_mode(true);
+        }
+        updated.unwrap();
+    }
+
+    /// Strategy that opens with `0` and otherwise picks the first candidate
+    #[derive(Debug)]
+    struct OpensWith(&'static str);
+
+    impl SolvingStrategy for OpensWith {
+        fn get_best_guess(&self, _possible: &[Word], candidates: &[Word]) -> Result<Word> {
+            Ok(candidates[0].clone())
+        }
+
+        fn get_best_first_guess(&self) -> Result<Word> {
+            Ok(Word::from_str(self.0).unwrap())
+        }
+
+        fn get_top_candidates(&self, _: &[Word], _: &[Word], _: usize) -> Vec<(Word, f64)> {
+            Vec::new()
+        }
+
+        fn clear_cache(&mut self) {}
+    }
+
+    #[tokio::test]
+    async fn test_played_game_opens_with_the_stored_opener() {
+        let words = ["crane", "slate", "plate"].map(|w| Word::from_str(w).unwrap());
+        let solver = crate::domain::DefaultWordleSolver::new(
+            Box::new(FixedWords(words.to_vec())),
+            Box::new(OpensWith("slate")),
+            Box::new(crate::domain::DefaultConstraintFilter::new()),
+        )
+        .await
+        .unwrap();
+        let engine = crate::domain::DefaultGameEngine::with_feedback_generator(Box::new(
+            crate::domain::DefaultFeedbackGenerator::new(),
+        ));
+        let mut service =
+            WordleApplicationService::with_dependencies(Box::new(engine), Box::new(solver))
+                .await
+                .unwrap();
+        service
+            .execute(Command::StartGame {
+                target_word: Some(words[2].clone()),
+            })
+            .unwrap();
+
+        let (first, _) = service.play_best_guess().unwrap();
+        assert_eq!(first.word, service.get_best_first_guess().unwrap());
+        assert_eq!(first.word.as_str(), "slate");
+    }
 
     #[tokio::test]
     async fn test_application_service_creation() {
@@ -0,0 +1,331 @@
+use crate::{
+    application::AppState,
+    core::{
+        error::{DataError, Result},
+        traits::{DataPersistence, StateManager},
+    },
+    infrastructure::write_atomic,
+};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Default idle time after which a session is evicted
+pub const DEFAULT_SESSION_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// Opaque token identifying a client session
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SessionToken(String);
+
+impl SessionToken {
+    /// Generate a new token from 128 bits of OS randomness (hex encoded)
+    ///
+    /// # Panics
+    /// When the OS random number generator is unavailable.
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 16];
+        getrandom::getrandom(&mut bytes).expect("OS random number generator unavailable");
+        Self(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for SessionToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for SessionToken {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// A guess recorded in a session snapshot (word and 0/1/2 code string)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionGuess {
+    pub word: String,
+    pub feedback: String,
+}
+
+/// Serializable snapshot of a session used for optional persistence
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    pub token: SessionToken,
+    pub guesses: Vec<SessionGuess>,
+}
+
+struct SessionEntry<T> {
+    value: T,
+    last_access: Instant,
+}
+
+/// In-memory session store with TTL eviction
+///
+/// Each session owns its own state (typically a `WordleApplicationService`), so
+/// multiple clients can play independent games concurrently. Wrap the store in a
+/// `Mutex` to share it between request handlers.
+pub struct SessionStore<T> {
+    sessions: HashMap<SessionToken, SessionEntry<T>>,
+    ttl: Duration,
+    /// Sessions this store has added to the active sessions gauge
+    published: usize,
+}
+
+impl<T> fmt::Debug for SessionStore<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionStore")
+            .field("sessions", &self.sessions.len())
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
+impl<T: StateManager<State = AppState>> SessionStore<T> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            sessions: HashMap::new(),
+            ttl,
+            published: 0,
+        }
+    }
+
+    /// Register a new session and return its token
+    pub fn insert(&mut self, value: T) -> SessionToken {
+        let token = SessionToken::generate();
+        self.insert_with_token(token.clone(), value);
+        token
+    }
+
+    /// Register a session under an existing token (e.g. when restoring)
+    pub fn insert_with_token(&mut self, token: SessionToken, value: T) {
+        self.sessions.insert(
+            token,
+            SessionEntry {
+                value,
+                last_access: Instant::now(),
+            },
+        );
+        self.publish_gauge();
+    }
+
+    /// Get a session, refreshing its idle timer. Expired sessions are treated as missing.
+    pub fn get_mut(&mut self, token: &SessionToken) -> Option<&mut T> {
+        let ttl = self.ttl;
+        let expired = self
+            .sessions
+            .get(token)
+            .map(|entry| entry.last_access.elapsed() > ttl)?;
+        if expired {
+            self.remove(token);
+            return None;
+        }
+        self.sessions.get_mut(token).map(|entry| {
+            entry.last_access = Instant::now();
+            &mut entry.value
+        })
+    }
+
+    /// Get a session's state without refreshing its idle timer
+    pub fn state(&self, token: &SessionToken) -> Option<&AppState> {
+        self.sessions
+            .get(token)
+            .filter(|entry| entry.last_access.elapsed() <= self.ttl)
+            .map(|entry| entry.value.get_state())
+    }
+
+    /// Remove a session explicitly
+    pub fn remove(&mut self, token: &SessionToken) -> Option<T> {
+        let removed = self.sessions.remove(token).map(|entry| entry.value);
+        self.publish_gauge();
+        removed
+    }
+
+    /// Drop all sessions idle for longer than the TTL, returning how many were evicted
+    pub fn evict_expired(&mut self) -> usize {
+        let before = self.sessions.len();
+        let ttl = self.ttl;
+        self.sessions
+            .retain(|_, entry| entry.last_access.elapsed() <= ttl);
+        self.publish_gauge();
+        before - self.sessions.len()
+    }
+
+    /// Number of live sessions (including not yet evicted expired ones)
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Snapshot every session's guess history for persistence
+    pub fn snapshot(&self) -> Vec<SessionSnapshot> {
+        let mut snapshots: Vec<_> = self
+            .sessions
+            .iter()
+            .map(|(token, entry)| SessionSnapshot {
+                token: token.clone(),
+                guesses: entry
+                    .value
+                    .get_state()
+                    .guess_history
+                    .iter()
+                    .map(|g| SessionGuess {
+                        word: g.word.to_string(),
+                        feedback: g.feedback.to_code_string(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        snapshots.sort_by(|a, b| a.token.0.cmp(&b.token.0));
+        snapshots
+    }
+
+    /// Rebuild sessions from snapshots; `factory` creates a session value with the
+    /// snapshot's guesses replayed
+    pub fn restore<F>(&mut self, snapshots: Vec<SessionSnapshot>, mut factory: F) -> Result<()>
+    where
+        F: FnMut(&SessionSnapshot) -> Result<T>,
+    {
+        for snapshot in snapshots {
+            let value = factory(&snapshot)?;
+            self.insert_with_token(snapshot.token, value);
+        }
+        Ok(())
+    }
+
+    fn publish_gauge(&mut self) {
+        let count = self.sessions.len();
+        crate::core::metrics::global().add_active_sessions(count as i64 - self.published as i64);
+        self.published = count;
+    }
+}
+
+impl<T> Drop for SessionStore<T> {
+    fn drop(&mut self) {
+        crate::core::metrics::global().add_active_sessions(-(self.published as i64));
+    }
+}
+
+impl<T: StateManager<State = AppState>> Default for SessionStore<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_SESSION_TTL)
+    }
+}
+
+/// JSON file persistence for session snapshots
+#[derive(Debug, Clone)]
+pub struct SessionFile {
+    path: PathBuf,
+}
+
+impl SessionFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl DataPersistence for SessionFile {
+    type Data = Vec<SessionSnapshot>;
+
+    async fn load(&mut self) -> Result<Self::Data> {
+        let text = tokio::fs::read_to_string(&self.path)
+            .await
+            .map_err(DataError::from)?;
+        Ok(serde_json::from_str(&text).map_err(DataError::from)?)
+    }
+
+    async fn save(&mut self, data: &Self::Data) -> Result<()> {
+        let text = serde_json::to_string_pretty(data).map_err(DataError::from)?;
+        write_atomic(&self.path, text).await
+    }
+
+    async fn exists(&self) -> bool {
+        tokio::fs::metadata(&self.path).await.is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::AppStateManager;
+    use crate::core::traits::StateUpdater;
+    use crate::core::types::{FeedbackPattern, Guess, Word};
+
+    #[test]
+    fn test_sessions_are_independent() {
+        let mut store = SessionStore::new(DEFAULT_SESSION_TTL);
+        let a = store.insert(AppStateManager::new());
+        let b = store.insert(AppStateManager::new());
+        assert_ne!(a, b);
+        assert_eq!(a.as_str().len(), 32);
+        assert!(a.as_str().bytes().all(|b| b.is_ascii_hexdigit()));
+
+        store
+            .get_mut(&a)
+            .unwrap()
+            .update_state(|state| {
+                state.remaining_words_count = 42;
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(store.state(&a).unwrap().remaining_words_count, 42);
+        assert_eq!(store.state(&b).unwrap().remaining_words_count, 0);
+        assert_eq!(store.len(), 2);
+    }
+
+    #[test]
+    fn test_ttl_eviction() {
+        let mut store = SessionStore::new(Duration::ZERO);
+        let token = store.insert(AppStateManager::new());
+        std::thread::sleep(Duration::from_millis(2));
+
+        assert!(store.get_mut(&token).is_none());
+        store.insert(AppStateManager::new());
+        std::thread::sleep(Duration::from_millis(2));
+        assert_eq!(store.evict_expired(), 1);
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let mut store = SessionStore::new(DEFAULT_SESSION_TTL);
+        let token = store.insert(AppStateManager::new());
+        store
+            .get_mut(&token)
+            .unwrap()
+            .update_state(|state| {
+                state.guess_history.push(Guess::new(
+                    Word::from_str("crane").unwrap(),
+                    FeedbackPattern::from_code_string("01020").unwrap(),
+                ));
+                Ok(())
+            })
+            .unwrap();
+
+        let snapshots = store.snapshot();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].guesses[0].feedback, "01020");
+
+        let mut restored = SessionStore::new(DEFAULT_SESSION_TTL);
+        restored
+            .restore(snapshots, |_| Ok(AppStateManager::new()))
+            .unwrap();
+        assert!(restored.state(&token).is_some());
+    }
+}
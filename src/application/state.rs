@@ -1,8 +1,16 @@
-use crate::core::{
-    error::Result,
-    traits::{StateManager, StateUpdater},
-    types::{GameResult, Guess, SolverStatistics, Word},
+use crate::{
+    application::{SessionGuess, StateDiff, StateObserver, SubscriptionId},
+    core::{
+        error::{DataError, Result},
+        traits::{StateManager, StateUpdater},
+        types::{GameResult, Guess, SolverStatistics, Word},
+    },
+    infrastructure::{ConfigFile, write_atomic_blocking},
 };
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Application state
 #[derive(Debug, Clone)]
@@ -23,6 +31,8 @@ pub struct AppState {
     pub solver_stats: SolverStatistics,
     /// Whether the solver is actively running
     pub solver_active: bool,
+    /// Possible words before the latest guess, for showing what it ruled out
+    pub previous_candidates: Vec<Word>,
 }
 
 impl AppState {
@@ -36,6 +46,7 @@ impl AppState {
             top_candidates: Vec::new(),
             solver_stats: SolverStatistics::new(),
             solver_active: false,
+            previous_candidates: Vec::new(),
         }
     }
 
@@ -48,6 +59,7 @@ impl AppState {
         self.top_candidates.clear();
         self.solver_stats = SolverStatistics::new();
         self.solver_active = false;
+        self.previous_candidates.clear();
     }
 
     pub fn is_game_finished(&self) -> bool {
@@ -75,16 +87,160 @@ impl Default for AppState {
     }
 }
 
+/// An unfinished game written to disk so it can be resumed later
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedGame {
+    /// Unix timestamp (seconds) of the last autosave
+    pub saved_at: u64,
+    pub guesses: Vec<SessionGuess>,
+}
+
+impl SavedGame {
+    fn from_history(history: &[Guess]) -> Self {
+        Self {
+            saved_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            guesses: history
+                .iter()
+                .map(|g| SessionGuess {
+                    word: g.word.to_string(),
+                    feedback: g.feedback.to_code_string(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Load a saved game; a missing file means there is nothing to resume
+    pub fn load(path: impl AsRef<Path>) -> Result<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Ok(Some(serde_json::from_str(&text).map_err(DataError::from)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(DataError::from(e).into()),
+        }
+    }
+
+    /// Write the saved game to `path`, replacing it in one step so a crash mid-write
+    /// leaves the previous save intact
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let text = serde_json::to_string_pretty(self).map_err(DataError::from)?;
+        write_atomic_blocking(path, text)
+    }
+
+    /// Delete a saved game, ignoring a missing file
+    pub fn remove(path: impl AsRef<Path>) -> Result<()> {
+        match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(DataError::from(e).into()),
+            _ => Ok(()),
+        }
+    }
+}
+
 /// State manager for the application
-#[derive(Debug)]
+///
+/// With autosave enabled, the guess history is written to a [`SavedGame`] file
+/// whenever it changes, and the file is removed once the game is won or reset.
+/// Subscribed observers get a [`StateDiff`] after each update or reset that changed
+/// something.
 pub struct AppStateManager {
     state: AppState,
+    autosave_path: Option<PathBuf>,
+    saved_guess_count: usize,
+    observers: Vec<(SubscriptionId, Box<dyn StateObserver>)>,
+    next_subscription: u64,
+}
+
+impl fmt::Debug for AppStateManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppStateManager")
+            .field("state", &self.state)
+            .field("autosave_path", &self.autosave_path)
+            .field("saved_guess_count", &self.saved_guess_count)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
 }
 
 impl AppStateManager {
     pub fn new() -> Self {
         Self {
             state: AppState::new(),
+            autosave_path: None,
+            saved_guess_count: 0,
+            observers: Vec::new(),
+            next_subscription: 0,
+        }
+    }
+
+    /// Notify `observer` of every state change from now on
+    pub fn subscribe(&mut self, observer: impl StateObserver + 'static) -> SubscriptionId {
+        let id = SubscriptionId(self.next_subscription);
+        self.next_subscription += 1;
+        self.observers.push((id, Box::new(observer)));
+        id
+    }
+
+    /// Stop notifying a subscriber; false if it was already gone
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let before = self.observers.len();
+        self.observers.retain(|(observer, _)| *observer != id);
+        self.observers.len() != before
+    }
+
+    /// Copy of the state to diff against, only taken when someone is listening
+    fn snapshot(&self) -> Option<AppState> {
+        (!self.observers.is_empty()).then(|| self.state.clone())
+    }
+
+    fn notify(&mut self, before: Option<AppState>) {
+        let Some(before) = before else {
+            return;
+        };
+        let diff = StateDiff::between(&before, &self.state);
+        if diff.is_empty() {
+            return;
+        }
+        for (_, observer) in &mut self.observers {
+            observer.state_changed(&diff, &self.state);
+        }
+    }
+
+    /// Enable autosaving the in-progress game to `path`
+    pub fn with_autosave(path: impl Into<PathBuf>) -> Self {
+        let mut manager = Self::new();
+        manager.set_autosave_path(Some(path.into()));
+        manager
+    }
+
+    pub fn set_autosave_path(&mut self, path: Option<PathBuf>) {
+        self.autosave_path = path;
+        self.saved_guess_count = self.state.guess_history.len();
+    }
+
+    pub fn autosave_path(&self) -> Option<&Path> {
+        self.autosave_path.as_deref()
+    }
+
+    /// Persist or clear the saved game after the guess history changed
+    fn autosave(&mut self) {
+        let Some(path) = &self.autosave_path else {
+            return;
+        };
+        let history = &self.state.guess_history;
+        if history.len() == self.saved_guess_count {
+            return;
+        }
+        self.saved_guess_count = history.len();
+
+        let finished = history.is_empty() || history.last().is_some_and(Guess::is_winning);
+        let result = if finished {
+            SavedGame::remove(path)
+        } else {
+            SavedGame::from_history(history).save(path)
+        };
+        if let Err(e) = result {
+            log::warn!("Failed to autosave game to {}: {}", path.display(), e);
         }
     }
 }
@@ -103,7 +259,10 @@ impl StateManager for AppStateManager {
     }
 
     fn reset_state(&mut self) {
+        let before = self.snapshot();
         self.state.reset();
+        self.autosave();
+        self.notify(before);
     }
 }
 
@@ -112,7 +271,11 @@ impl StateUpdater<AppState> for AppStateManager {
     where
         F: FnOnce(&mut AppState) -> Result<()>,
     {
-        update_fn(&mut self.state)
+        let before = self.snapshot();
+        let result = update_fn(&mut self.state);
+        self.autosave();
+        self.notify(before);
+        result
     }
 }
 
@@ -135,6 +298,8 @@ pub enum AppEvent {
     SolverStatsUpdated(SolverStatistics),
     /// Error occurred
     Error(String),
+    /// A configuration file was edited while the app was running
+    ConfigChanged(ConfigFile),
 }
 
 /// Application event handler
@@ -176,4 +341,77 @@ mod tests {
         manager.reset_state();
         assert_eq!(manager.get_state().remaining_words_count, 0);
     }
+
+    #[test]
+    fn test_observers_get_diffs_until_unsubscribed() {
+        use crate::application::StateChange;
+        use std::sync::{Arc, Mutex};
+
+        let mut manager = AppStateManager::new();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+        let id = manager.subscribe(move |diff: &StateDiff, _: &AppState| {
+            log.lock().unwrap().push(diff.clone());
+        });
+
+        manager
+            .update_state(|state| {
+                state.remaining_words_count = 40;
+                Ok(())
+            })
+            .unwrap();
+        // Nothing changed, nothing sent
+        manager.update_state(|_| Ok(())).unwrap();
+        manager.reset_state();
+        assert_eq!(seen.lock().unwrap().len(), 2);
+        assert_eq!(
+            seen.lock().unwrap()[1].changes,
+            vec![StateChange::RemainingWordsChanged { from: 40, to: 0 }]
+        );
+
+        assert!(manager.unsubscribe(id));
+        assert!(!manager.unsubscribe(id));
+        manager
+            .update_state(|state| {
+                state.remaining_words_count = 7;
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(seen.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_autosave_and_clear_on_win() {
+        use crate::core::types::FeedbackPattern;
+
+        let path = std::env::temp_dir().join(format!(
+            "wordle_autosave_test_{}.json",
+            std::process::id()
+        ));
+        let mut manager = AppStateManager::with_autosave(&path);
+        let guess = |word: &str, code: &str| {
+            Guess::new(
+                Word::from_str(word).unwrap(),
+                FeedbackPattern::from_code_string(code).unwrap(),
+            )
+        };
+
+        manager
+            .update_state(|state| {
+                state.guess_history.push(guess("adieu", "01020"));
+                Ok(())
+            })
+            .unwrap();
+        let saved = SavedGame::load(&path).unwrap().unwrap();
+        assert_eq!(saved.guesses.len(), 1);
+        assert_eq!(saved.guesses[0].feedback, "01020");
+
+        manager
+            .update_state(|state| {
+                state.guess_history.push(guess("crane", "22222"));
+                Ok(())
+            })
+            .unwrap();
+        assert!(SavedGame::load(&path).unwrap().is_none());
+    }
 }
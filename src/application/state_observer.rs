@@ -0,0 +1,125 @@
+//! Notifications of application state changes
+//!
+//! Components that show or forward the state (TUI panels, server sessions,
+//! webhooks) subscribe an observer to the
+//! [`AppStateManager`](crate::application::AppStateManager) and get a [`StateDiff`]
+//! after every update that changed something, instead of re-reading the whole
+//! state after each command.
+
+use crate::{
+    application::AppState,
+    core::types::{GameResult, Guess, Word},
+};
+
+/// Handle returned by `subscribe`, used to unsubscribe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubscriptionId(pub(crate) u64);
+
+/// One part of the state that changed
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateChange {
+    /// Guesses appended to the history, oldest first
+    GuessesAdded(Vec<Guess>),
+    /// Guesses dropped from the end of the history (undo or reset), oldest first
+    GuessesRemoved(Vec<Guess>),
+    SuggestionChanged(Option<Word>),
+    RemainingWordsChanged { from: usize, to: usize },
+    TopCandidatesChanged(Vec<(Word, f64)>),
+    GameResultChanged(GameResult),
+}
+
+/// What an update changed, in a fixed order: history, suggestion, counts, result
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateDiff {
+    pub changes: Vec<StateChange>,
+}
+
+impl StateDiff {
+    /// Changes from `before` to `after`
+    pub fn between(before: &AppState, after: &AppState) -> Self {
+        let mut changes = Vec::new();
+
+        let kept = before
+            .guess_history
+            .iter()
+            .zip(&after.guess_history)
+            .take_while(|(a, b)| a == b)
+            .count();
+        if kept < before.guess_history.len() {
+            changes.push(StateChange::GuessesRemoved(before.guess_history[kept..].to_vec()));
+        }
+        if kept < after.guess_history.len() {
+            changes.push(StateChange::GuessesAdded(after.guess_history[kept..].to_vec()));
+        }
+
+        if before.current_suggestion != after.current_suggestion {
+            changes.push(StateChange::SuggestionChanged(after.current_suggestion.clone()));
+        }
+        if before.remaining_words_count != after.remaining_words_count {
+            changes.push(StateChange::RemainingWordsChanged {
+                from: before.remaining_words_count,
+                to: after.remaining_words_count,
+            });
+        }
+        if before.top_candidates != after.top_candidates {
+            changes.push(StateChange::TopCandidatesChanged(after.top_candidates.clone()));
+        }
+        if before.game_result != after.game_result {
+            changes.push(StateChange::GameResultChanged(after.game_result.clone()));
+        }
+
+        Self { changes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Receives state changes; closures taking `(&StateDiff, &AppState)` work too
+pub trait StateObserver: Send + Sync {
+    /// Called after an update that changed something, with the new state
+    fn state_changed(&mut self, diff: &StateDiff, state: &AppState);
+}
+
+impl<F: FnMut(&StateDiff, &AppState) + Send + Sync> StateObserver for F {
+    fn state_changed(&mut self, diff: &StateDiff, state: &AppState) {
+        self(diff, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::FeedbackPattern;
+
+    fn guess(word: &str, code: &str) -> Guess {
+        Guess::new(
+            Word::from_str(word).unwrap(),
+            FeedbackPattern::from_code_string(code).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_diff_reports_only_what_changed() {
+        let mut before = AppState::new();
+        before.guess_history = vec![guess("crane", "00100"), guess("sloth", "01000")];
+        before.remaining_words_count = 12;
+
+        let mut after = before.clone();
+        assert!(StateDiff::between(&before, &after).is_empty());
+
+        // Undo the last guess and play another
+        after.guess_history[1] = guess("pious", "00200");
+        after.remaining_words_count = 3;
+        let diff = StateDiff::between(&before, &after);
+        assert_eq!(
+            diff.changes,
+            vec![
+                StateChange::GuessesRemoved(vec![guess("sloth", "01000")]),
+                StateChange::GuessesAdded(vec![guess("pious", "00200")]),
+                StateChange::RemainingWordsChanged { from: 12, to: 3 },
+            ]
+        );
+    }
+}
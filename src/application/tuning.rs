@@ -0,0 +1,135 @@
+//! Picking strategy parameters from benchmark results
+//!
+//! The `tune` command benchmarks a strategy once per [`StrategyTuning`] of its
+//! grid on the same answers and keeps each result as a [`TuningTrial`]. A
+//! [`TuningMetric`] then says which trial did best; the metrics can disagree, so
+//! each is reported and one of them picks the tuning that gets saved.
+
+use crate::application::BenchmarkSummary;
+use crate::core::container::StrategyTuning;
+use std::cmp::Ordering;
+
+/// One tuning benchmarked over the tuning answers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TuningTrial {
+    pub tuning: StrategyTuning,
+    pub summary: BenchmarkSummary,
+}
+
+/// What makes one trial better than another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TuningMetric {
+    /// Most games won, then fewest guesses
+    #[default]
+    WinRate,
+    /// Fewest guesses per game, lost games counting every guess, then most wins
+    AverageGuesses,
+    /// Fewest losses, then the fewest guesses the longest game took
+    WorstCase,
+}
+
+impl TuningMetric {
+    pub const ALL: [TuningMetric; 3] = [Self::WinRate, Self::AverageGuesses, Self::WorstCase];
+
+    /// The metric with this [`label`](Self::label), ignoring case
+    pub fn from_label(label: &str) -> Option<Self> {
+        let label = label.trim();
+        Self::ALL
+            .into_iter()
+            .find(|metric| metric.label().eq_ignore_ascii_case(label))
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::WinRate => "win-rate",
+            Self::AverageGuesses => "average",
+            Self::WorstCase => "worst",
+        }
+    }
+
+    /// `Less` when `a` did better than `b`
+    fn compare(self, a: &BenchmarkSummary, b: &BenchmarkSummary) -> Ordering {
+        let losses = |s: &BenchmarkSummary| s.games - s.wins;
+        match self {
+            Self::WinRate => b
+                .win_rate()
+                .total_cmp(&a.win_rate())
+                .then(a.average_guesses().total_cmp(&b.average_guesses())),
+            Self::AverageGuesses => a
+                .average_guesses()
+                .total_cmp(&b.average_guesses())
+                .then(b.win_rate().total_cmp(&a.win_rate())),
+            Self::WorstCase => losses(a)
+                .cmp(&losses(b))
+                .then(a.worst_guesses.cmp(&b.worst_guesses))
+                .then(a.average_guesses().total_cmp(&b.average_guesses())),
+        }
+    }
+
+    /// The best of `trials`; on a tie the earliest, so the default tuning (first in
+    /// a [`grid`](StrategyTuning::grid)) wins unless another one beats it
+    pub fn best(self, trials: &[TuningTrial]) -> Option<&TuningTrial> {
+        trials.iter().reduce(
+            |best, trial| match self.compare(&trial.summary, &best.summary) {
+                Ordering::Less => trial,
+                _ => best,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::container::StrategyType;
+
+    fn trial(endgame_threshold: usize, wins: usize, total: usize, worst: usize) -> TuningTrial {
+        TuningTrial {
+            tuning: StrategyTuning {
+                endgame_threshold,
+                ..StrategyTuning::default()
+            },
+            summary: BenchmarkSummary {
+                games: 10,
+                wins,
+                total_guesses: total,
+                worst_guesses: worst,
+                ..BenchmarkSummary::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_each_metric_picks_its_best_trial() {
+        let trials = [
+            trial(3, 9, 38, 6),
+            trial(2, 10, 42, 6),
+            trial(5, 9, 36, 7),
+            trial(8, 10, 42, 5),
+        ];
+        let best = |metric: TuningMetric| metric.best(&trials).unwrap().tuning.endgame_threshold;
+
+        // The first of the two perfect runs wins the tie
+        assert_eq!(best(TuningMetric::WinRate), 2);
+        assert_eq!(best(TuningMetric::AverageGuesses), 5);
+        assert_eq!(best(TuningMetric::WorstCase), 8);
+        assert!(TuningMetric::WinRate.best(&[]).is_none());
+        assert_eq!(
+            TuningMetric::from_label("Worst"),
+            Some(TuningMetric::WorstCase)
+        );
+    }
+
+    #[test]
+    fn test_grid_varies_only_what_the_strategy_reads() {
+        let entropy = StrategyTuning::grid(StrategyType::Entropy);
+        assert_eq!(entropy[0], StrategyTuning::default());
+        assert!(entropy.iter().skip(1).all(|t| *t != entropy[0]));
+        assert!(entropy.iter().all(|t| t.entropy_percent == 80));
+        assert!(entropy.iter().any(|t| t.prefilter_above.is_some()));
+
+        let hybrid = StrategyTuning::grid(StrategyType::Hybrid);
+        assert!(hybrid.iter().all(|t| t.prefilter_above.is_none()));
+        assert_eq!(StrategyTuning::grid(StrategyType::Frequency).len(), 1);
+    }
+}
@@ -0,0 +1,238 @@
+//! Dependency-free Wordle algorithms
+//!
+//! Feedback computation, pattern encoding and entropy over word tables. This module
+//! only uses `core`: no allocation and no `std` float intrinsics (it carries its own
+//! `log2`), so it can be copied into a `#![no_std]` crate for embedded or WASM
+//! targets unchanged. The entropy calculators and feedback generator build on it.
+
+#![deny(clippy::std_instead_of_core, clippy::std_instead_of_alloc)]
+
+use core::f64::consts::{LOG2_E, SQRT_2};
+
+/// Letters per word
+pub const WORD_LEN: usize = 5;
+
+/// Number of distinct feedback patterns (3^5)
+pub const PATTERN_COUNT: usize = 243;
+
+/// Feedback digit: letter not in the target
+pub const ABSENT: u8 = 0;
+/// Feedback digit: letter in the target at another position
+pub const PRESENT: u8 = 1;
+/// Feedback digit: letter at the right position
+pub const CORRECT: u8 = 2;
+
+/// Pattern index of an all-correct guess
+pub const SOLVED_PATTERN: u16 = (PATTERN_COUNT - 1) as u16;
+
+/// Feedback digits for `guess` against `target`, with Wordle's duplicate-letter rules:
+/// greens first, then yellows left to right, each target letter used at most once
+#[inline]
+pub fn feedback_digits(guess: &[u8; WORD_LEN], target: &[u8; WORD_LEN]) -> [u8; WORD_LEN] {
+    let mut used = [false; WORD_LEN];
+    let mut digits = [ABSENT; WORD_LEN];
+
+    // Greens
+    for i in 0..WORD_LEN {
+        if guess[i] == target[i] {
+            digits[i] = CORRECT;
+            used[i] = true;
+        }
+    }
+    // Yellows
+    for i in 0..WORD_LEN {
+        if digits[i] == ABSENT {
+            let g = guess[i];
+            for j in 0..WORD_LEN {
+                if !used[j] && g == target[j] {
+                    digits[i] = PRESENT;
+                    used[j] = true;
+                    break;
+                }
+            }
+        }
+    }
+    digits
+}
+
+/// Encode feedback digits as a base-3 little-endian index in `0..243`
+#[inline]
+pub fn encode_pattern(digits: &[u8; WORD_LEN]) -> u16 {
+    digits
+        .iter()
+        .rev()
+        .fold(0u16, |acc, &digit| acc * 3 + digit as u16)
+}
+
+/// Decode a pattern index back into feedback digits
+#[inline]
+pub fn decode_pattern(mut index: u16) -> [u8; WORD_LEN] {
+    let mut digits = [ABSENT; WORD_LEN];
+    for digit in digits.iter_mut() {
+        *digit = (index % 3) as u8;
+        index /= 3;
+    }
+    digits
+}
+
+/// Pattern index for `guess` against `target`
+#[inline]
+pub fn feedback_index(guess: &[u8; WORD_LEN], target: &[u8; WORD_LEN]) -> usize {
+    encode_pattern(&feedback_digits(guess, target)) as usize
+}
+
+/// Count how `targets` partition by feedback for `guess`, returning the number counted
+pub fn count_patterns<'a, I>(
+    guess: &[u8; WORD_LEN],
+    targets: I,
+    counts: &mut [u32; PATTERN_COUNT],
+) -> u32
+where
+    I: IntoIterator<Item = &'a [u8; WORD_LEN]>,
+{
+    let mut total = 0;
+    for target in targets {
+        counts[feedback_index(guess, target)] += 1;
+        total += 1;
+    }
+    total
+}
+
+/// Shannon entropy (bits) of a feedback partition
+pub fn entropy_from_counts(counts: &[u32; PATTERN_COUNT], total: u32) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    let total = total as f64;
+    counts
+        .iter()
+        .filter(|&&c| c > 0)
+        .map(|&c| {
+            let p = c as f64 / total;
+            -p * log2(p)
+        })
+        .sum()
+}
+
+/// Expected reduction in log2(candidate count) from a feedback partition
+pub fn information_gain_from_counts(counts: &[u32; PATTERN_COUNT], total: u32) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    let total_f = total as f64;
+    let expected_log_size: f64 = counts
+        .iter()
+        .filter(|&&c| c > 0)
+        .map(|&c| c as f64 / total_f * log2(c as f64))
+        .sum();
+    log2(total_f) - expected_log_size
+}
+
+/// Entropy of `guess` over the subset of `table` selected by `indices`
+pub fn entropy_over_indices(
+    guess: &[u8; WORD_LEN],
+    table: &[[u8; WORD_LEN]],
+    indices: &[u32],
+) -> f64 {
+    let mut counts = [0u32; PATTERN_COUNT];
+    let total = count_patterns(guess, indices.iter().map(|&i| &table[i as usize]), &mut counts);
+    entropy_from_counts(&counts, total)
+}
+
+/// Base-2 logarithm without `std`
+///
+/// Splits `x` into exponent and mantissa, then evaluates ln(m) with the
+/// `2·atanh((m-1)/(m+1))` series; accurate to within a few ulps.
+pub fn log2(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return f64::INFINITY;
+    }
+
+    const MANTISSA_MASK: u64 = (1 << 52) - 1;
+    let bits = x.to_bits();
+    let mut exponent = ((bits >> 52) & 0x7ff) as i64;
+    let mut mantissa = bits & MANTISSA_MASK;
+    if exponent == 0 {
+        // Subnormal: shift the leading one into the implicit bit position
+        let shift = mantissa.leading_zeros() as i64 - 11;
+        mantissa = (mantissa << shift) & MANTISSA_MASK;
+        exponent = 1 - shift;
+    }
+
+    // m in [1, 2), folded into [sqrt(1/2), sqrt(2)) so the series converges quickly
+    let mut m = f64::from_bits(mantissa | (1023 << 52));
+    let mut e = exponent - 1023;
+    if m > SQRT_2 {
+        m /= 2.0;
+        e += 1;
+    }
+
+    let t = (m - 1.0) / (m + 1.0);
+    let t2 = t * t;
+    let mut term = t;
+    let mut series = 0.0;
+    let mut k = 1.0;
+    // |t| <= 0.172, so 12 terms reach f64 precision
+    for _ in 0..12 {
+        series += term / k;
+        term *= t2;
+        k += 2.0;
+    }
+    e as f64 + 2.0 * series * LOG2_E
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feedback_duplicate_letters() {
+        // Only one 'l' in "hello" can be matched by the two in "llama"
+        assert_eq!(feedback_digits(b"llama", b"hello"), [1, 1, 0, 0, 0]);
+        assert_eq!(feedback_digits(b"speed", b"abide"), [0, 0, 1, 0, 1]);
+        assert_eq!(feedback_index(b"crane", b"crane"), SOLVED_PATTERN as usize);
+    }
+
+    #[test]
+    fn test_pattern_round_trip() {
+        for index in 0..PATTERN_COUNT as u16 {
+            assert_eq!(encode_pattern(&decode_pattern(index)), index);
+        }
+        assert_eq!(encode_pattern(&[2, 0, 0, 0, 0]), 2);
+        assert_eq!(encode_pattern(&[0, 0, 0, 0, 1]), 81);
+    }
+
+    #[test]
+    fn test_log2_matches_std() {
+        let mut x: f64 = 1e-310;
+        while x < 1e300 {
+            let expected = x.log2();
+            assert!(
+                (log2(x) - expected).abs() <= 1e-12 * expected.abs().max(1.0),
+                "log2({}) = {}, expected {}",
+                x,
+                log2(x),
+                expected
+            );
+            x *= 1.37;
+        }
+        assert_eq!(log2(8.0), 3.0);
+        assert_eq!(log2(0.0), f64::NEG_INFINITY);
+        assert!(log2(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_entropy_over_indices() {
+        let table = [*b"apple", *b"about", *b"bread", *b"crane"];
+        // Two distinct, equally likely patterns: one bit
+        let entropy = entropy_over_indices(b"apple", &table, &[0, 3]);
+        assert!((entropy - 1.0).abs() < 1e-12);
+        assert_eq!(entropy_over_indices(b"apple", &table, &[]), 0.0);
+    }
+}
@@ -0,0 +1,121 @@
+//! Letters words are spelled with
+//!
+//! Per-letter tables (letter frequencies, positional counts, bigrams, letter
+//! budgets in feedback checks) are indexed through an [`Alphabet`] instead of
+//! `b - b'a'` arithmetic, so another alphabet only has to change here. Letters
+//! are single bytes, matching [`Word`](crate::core::types::Word)'s storage.
+
+/// Marks bytes outside the alphabet in the lookup table
+const NOT_A_LETTER: u8 = u8::MAX;
+
+/// Letters a word may contain, each with an index into per-letter tables
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Alphabet {
+    letters: &'static [u8],
+    lookup: [u8; 256],
+}
+
+impl Alphabet {
+    /// The 26 lowercase letters a-z
+    pub const ENGLISH: Alphabet = Alphabet::new(b"abcdefghijklmnopqrstuvwxyz");
+
+    /// Alphabet of `letters`, indexed in the order given
+    ///
+    /// Panics (at compile time for constants) on repeated letters or more than
+    /// 255 of them.
+    pub const fn new(letters: &'static [u8]) -> Self {
+        assert!(letters.len() < NOT_A_LETTER as usize, "too many letters");
+        let mut lookup = [NOT_A_LETTER; 256];
+        let mut i = 0;
+        while i < letters.len() {
+            assert!(
+                lookup[letters[i] as usize] == NOT_A_LETTER,
+                "repeated letter"
+            );
+            lookup[letters[i] as usize] = i as u8;
+            i += 1;
+        }
+        Self { letters, lookup }
+    }
+
+    /// Number of letters
+    pub const fn size(&self) -> usize {
+        self.letters.len()
+    }
+
+    /// Table index of `letter`, or None if it isn't in the alphabet
+    #[inline]
+    pub fn index(&self, letter: u8) -> Option<usize> {
+        match self.lookup[letter as usize] {
+            NOT_A_LETTER => None,
+            index => Some(index as usize),
+        }
+    }
+
+    /// Table index of a letter already known to be in the alphabet, e.g. from a
+    /// validated word
+    ///
+    /// Panics if it isn't.
+    #[inline]
+    pub fn position(&self, letter: u8) -> usize {
+        self.index(letter)
+            .unwrap_or_else(|| panic!("{:?} is not in the alphabet", letter as char))
+    }
+
+    /// The letter at table index `index`
+    pub fn letter(&self, index: usize) -> Option<char> {
+        self.letters.get(index).map(|&b| b as char)
+    }
+
+    /// Whether `c` is one of the letters
+    pub fn contains(&self, c: char) -> bool {
+        u8::try_from(c).is_ok_and(|b| self.index(b).is_some())
+    }
+
+    /// Whether every character of `text` is one of the letters
+    pub fn spells(&self, text: &str) -> bool {
+        text.chars().all(|c| self.contains(c))
+    }
+
+    /// The letters in index order
+    pub fn letters(&self) -> impl Iterator<Item = char> + '_ {
+        self.letters.iter().map(|&b| b as char)
+    }
+}
+
+impl std::fmt::Debug for Alphabet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Alphabet")
+            .field(&String::from_utf8_lossy(self.letters))
+            .finish()
+    }
+}
+
+/// Letters in the alphabet words use, the length of per-letter tables
+pub const LETTER_COUNT: usize = Alphabet::ENGLISH.size();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_indexing() {
+        let alphabet = Alphabet::ENGLISH;
+        assert_eq!(alphabet.size(), 26);
+        assert_eq!(alphabet.index(b'a'), Some(0));
+        assert_eq!(alphabet.position(b'z'), 25);
+        assert_eq!(alphabet.index(b'A'), None);
+        assert_eq!(alphabet.letter(2), Some('c'));
+        assert!(alphabet.spells("crane"));
+        assert!(!alphabet.spells("crané"));
+        assert_eq!(alphabet.letters().count(), LETTER_COUNT);
+    }
+
+    #[test]
+    fn test_custom_alphabet() {
+        let vowels = Alphabet::new(b"aeiou");
+        assert_eq!(vowels.size(), 5);
+        assert_eq!(vowels.index(b'i'), Some(2));
+        assert!(!vowels.contains('b'));
+    }
+}
@@ -0,0 +1,104 @@
+//! Injectable time source
+//!
+//! Cache freshness, statistics timestamps and turn timers ask a [`Clock`] instead
+//! of calling `SystemTime::now` or `Instant::now` themselves, so tests can swap in
+//! a [`ManualClock`] and move time forward to make a cache stale or a turn long.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Source of the current time
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// Wall-clock time, for timestamps and ages of files
+    fn now(&self) -> SystemTime;
+
+    /// Monotonic time, for measuring how long something took
+    fn instant(&self) -> Instant;
+
+    /// Seconds since the Unix epoch (0 for a clock set before it)
+    fn unix_secs(&self) -> u64 {
+        self.now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+
+    /// Time since `earlier`, a reading of [`Clock::instant`]
+    fn elapsed(&self, earlier: Instant) -> Duration {
+        self.instant().saturating_duration_since(earlier)
+    }
+}
+
+/// The operating system's clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// The system clock, shared
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// Clock that only moves when told to, for tests
+#[derive(Debug)]
+pub struct ManualClock {
+    wall: SystemTime,
+    base: Instant,
+    offset: Mutex<Duration>,
+}
+
+impl ManualClock {
+    /// A clock reading `unix_secs` seconds after the epoch
+    pub fn at(unix_secs: u64) -> Self {
+        Self {
+            wall: UNIX_EPOCH + Duration::from_secs(unix_secs),
+            base: Instant::now(),
+            offset: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Move both readings forward by `by`
+    pub fn advance(&self, by: Duration) {
+        *self.offset.lock().unwrap_or_else(|e| e.into_inner()) += by;
+    }
+
+    fn offset(&self) -> Duration {
+        *self.offset.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        self.wall + self.offset()
+    }
+
+    fn instant(&self) -> Instant {
+        self.base + self.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock_moves_only_when_advanced() {
+        let clock = ManualClock::at(1_000);
+        let started = clock.instant();
+        assert_eq!(clock.unix_secs(), 1_000);
+        assert_eq!(clock.elapsed(started), Duration::ZERO);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(clock.unix_secs(), 1_090);
+        assert_eq!(clock.elapsed(started), Duration::from_secs(90));
+    }
+}
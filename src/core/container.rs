@@ -6,6 +6,8 @@ use crate::core::{
         WordListProvider, WordleSolver,
     },
 };
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 /// Configuration for dependency injection
@@ -13,36 +15,148 @@ use std::sync::Arc;
 pub struct DependencyConfig {
     /// Strategy to use for solving
     pub strategy_type: StrategyType,
+    /// Parameters of the entropy and hybrid strategies
+    pub tuning: StrategyTuning,
     /// Whether to use cached entropy calculator
     pub use_cached_entropy: bool,
     /// Word list source configuration
     pub word_list_config: WordListConfig,
+    /// Only suggest guesses that reuse every revealed hint
+    pub hard_mode: bool,
+    /// Guesses allowed per game
+    pub max_guesses: usize,
+    /// Rules the game engine and solver hold guesses to
+    pub rules: crate::domain::GameRules,
+    /// Record the commands each application service executes into this script file
+    /// (`$WORDLE_RECORD_SCRIPT` by default)
+    pub record_script: Option<PathBuf>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum StrategyType {
     Entropy,
     Frequency,
     Hybrid,
 }
 
+impl StrategyType {
+    pub const ALL: [StrategyType; 3] = [Self::Entropy, Self::Frequency, Self::Hybrid];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Entropy => "entropy",
+            Self::Frequency => "frequency",
+            Self::Hybrid => "hybrid",
+        }
+    }
+}
+
+/// Parameters of the entropy and hybrid strategies, as searched by `tune`
+///
+/// Saved with the user settings; missing fields take the built-in values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StrategyTuning {
+    /// Hybrid: percent of the score taken from entropy while many words remain, the
+    /// rest coming from letter frequency
+    pub entropy_percent: u8,
+    /// Hybrid: words remaining above which `entropy_percent` applies
+    pub entropy_above: usize,
+    /// Words remaining at or below which the endgame starts: the entropy strategy
+    /// prefers guesses that could be the answer, the hybrid one leans on frequency
+    pub endgame_threshold: usize,
+    /// Entropy: while more than this many words remain, only score guesses with five
+    /// different letters (`WORDLE_FAST_PREFILTER=1` turns on 200 when unset)
+    pub prefilter_above: Option<usize>,
+}
+
+impl Default for StrategyTuning {
+    fn default() -> Self {
+        Self {
+            entropy_percent: 80,
+            entropy_above: 50,
+            endgame_threshold: 3,
+            prefilter_above: None,
+        }
+    }
+}
+
+impl StrategyTuning {
+    /// Every combination `tune` tries for `strategy`, the default first
+    ///
+    /// Only the parameters `strategy` reads are varied, so the frequency strategy
+    /// has nothing to tune.
+    pub fn grid(strategy: StrategyType) -> Vec<Self> {
+        let default = Self::default();
+        let mut grid = vec![default];
+        match strategy {
+            StrategyType::Entropy => {
+                for endgame_threshold in [2, 3, 5, 8] {
+                    for prefilter_above in [None, Some(200), Some(500)] {
+                        grid.push(Self {
+                            endgame_threshold,
+                            prefilter_above,
+                            ..default
+                        });
+                    }
+                }
+            }
+            StrategyType::Hybrid => {
+                for entropy_percent in [60, 70, 80, 90] {
+                    for entropy_above in [25, 50, 100] {
+                        for endgame_threshold in [2, 3, 5] {
+                            grid.push(Self {
+                                entropy_percent,
+                                entropy_above,
+                                endgame_threshold,
+                                ..default
+                            });
+                        }
+                    }
+                }
+            }
+            StrategyType::Frequency => {}
+        }
+        // The default turns up again inside the loops
+        let mut seen = Vec::with_capacity(grid.len());
+        grid.retain(|tuning| {
+            let new = !seen.contains(tuning);
+            seen.push(*tuning);
+            new
+        });
+        grid
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WordListConfig {
     /// Path to word list file
     pub file_path: Option<String>,
     /// Whether to load extended guess words
     pub include_extended_guesses: bool,
+    /// Where word lists are downloaded from
+    pub source_preset: crate::infrastructure::WordSourcePreset,
+    /// Frozen snapshot to load instead of the cache (see `wordlist freeze`)
+    pub snapshot_tag: Option<String>,
 }
 
 impl Default for DependencyConfig {
     fn default() -> Self {
         Self {
             strategy_type: StrategyType::Entropy,
+            tuning: StrategyTuning::default(),
             use_cached_entropy: true,
             word_list_config: WordListConfig {
                 file_path: None,
                 include_extended_guesses: true,
+                source_preset: Default::default(),
+                snapshot_tag: None,
             },
+            hard_mode: false,
+            max_guesses: crate::domain::DEFAULT_MAX_ATTEMPTS,
+            rules: crate::domain::GameRules::default(),
+            record_script: crate::application::record_script_path(),
         }
     }
 }
@@ -65,46 +179,75 @@ impl Container {
         Self { config }
     }
 
+    pub fn config(&self) -> &DependencyConfig {
+        &self.config
+    }
+
     /// Create word list provider
     pub fn create_word_list_provider(&self) -> Result<Box<dyn WordListProvider>> {
-        let provider = if let Some(file_path) = &self.config.word_list_config.file_path {
+        Ok(Box::new(self.create_file_word_list_provider()))
+    }
+
+    /// The concrete file-backed provider, for snapshot and cache management
+    pub fn create_file_word_list_provider(&self) -> crate::infrastructure::FileWordListProvider {
+        let word_list_config = &self.config.word_list_config;
+        let mut provider = if let Some(file_path) = &word_list_config.file_path {
             crate::infrastructure::FileWordListProvider::with_path(file_path.clone())
         } else {
             crate::infrastructure::FileWordListProvider::new()
         };
-
-        Ok(Box::new(provider))
+        if let Some(sources) = word_list_config.source_preset.sources() {
+            provider = provider.with_sources(sources);
+        }
+        if let Some(tag) = &word_list_config.snapshot_tag {
+            provider = provider.with_snapshot(tag.clone());
+        }
+        provider
     }
 
     /// Create entropy calculator
     pub fn create_entropy_calculator(&self) -> impl EntropyCalculator {
-        if self.config.use_cached_entropy {
-            crate::infrastructure::CachedEntropyCalculator::new()
-        } else {
-            crate::infrastructure::CachedEntropyCalculator::new() // Use cached for both for now
-        }
+        // Use cached for both for now; `use_cached_entropy` is kept for config compatibility
+        crate::infrastructure::CachedEntropyCalculator::new()
     }
 
     /// Create solving strategy
+    ///
+    /// A first guess stored in the word-list cache for this strategy (see `wordlist
+    /// warm-cache`) replaces the built-in opener.
     pub async fn create_strategy(&self) -> Result<Box<dyn SolvingStrategy>> {
+        let mut word_provider = self.create_file_word_list_provider();
+        let loaded = word_provider.load_words().await;
+        let opener = word_provider.first_guess(self.config.strategy_type.name());
         let strategy: Box<dyn SolvingStrategy> = match self.config.strategy_type {
             StrategyType::Entropy => {
                 let entropy_calc = crate::infrastructure::CachedEntropyCalculator::new();
-                Box::new(crate::infrastructure::EntropyBasedStrategy::new(
-                    entropy_calc,
-                )?)
+                let strategy = crate::infrastructure::EntropyBasedStrategy::new(entropy_calc)?
+                    .with_tuning(self.config.tuning);
+                match opener {
+                    Some(word) => Box::new(strategy.with_opener(word)),
+                    None => Box::new(strategy),
+                }
             }
             StrategyType::Frequency => {
-                // Need to load words first for frequency analysis
-                let mut word_provider = self.create_word_list_provider()?;
-                word_provider.load_words().await?;
+                // Frequency analysis needs the words loaded
+                loaded?;
                 let words = word_provider.get_answer_words();
-                Box::new(crate::infrastructure::FrequencyBasedStrategy::new(words)?)
+                let strategy = crate::infrastructure::FrequencyBasedStrategy::new(words)?;
+                match opener {
+                    Some(word) => Box::new(strategy.with_opener(word)),
+                    None => Box::new(strategy),
+                }
             }
             StrategyType::Hybrid => {
                 // Create hybrid strategy combining entropy and frequency
                 let entropy_calc = crate::infrastructure::CachedEntropyCalculator::new();
-                Box::new(crate::infrastructure::HybridStrategy::new(entropy_calc)?)
+                let strategy = crate::infrastructure::HybridStrategy::new(entropy_calc)?
+                    .with_tuning(self.config.tuning);
+                match opener {
+                    Some(word) => Box::new(strategy.with_opener(word)),
+                    None => Box::new(strategy),
+                }
             }
         };
 
@@ -126,7 +269,9 @@ impl Container {
         let feedback_generator = self.create_feedback_generator();
         let game_engine =
             crate::domain::DefaultGameEngine::with_feedback_generator_async(feedback_generator)
-                .await?;
+                .await?
+                .with_max_attempts(self.config.max_guesses)
+                .with_rules(self.config.rules);
         Ok(Box::new(game_engine))
     }
 
@@ -141,7 +286,10 @@ impl Container {
             strategy,
             constraint_filter,
         )
-        .await?;
+        .await?
+        .with_hard_mode(self.config.hard_mode)
+        .with_rules(self.config.rules)
+        .with_max_guesses(self.config.max_guesses);
 
         Ok(Box::new(solver))
     }
@@ -153,7 +301,13 @@ impl Container {
         let game_engine = self.create_game_engine().await?;
         let solver = self.create_solver().await?;
 
-        crate::application::WordleApplicationService::with_dependencies(game_engine, solver).await
+        let mut service =
+            crate::application::WordleApplicationService::with_dependencies(game_engine, solver)
+                .await?;
+        if let Some(path) = &self.config.record_script {
+            service.enable_recording(path.clone());
+        }
+        Ok(service)
     }
 }
 
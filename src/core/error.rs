@@ -19,6 +19,12 @@ pub enum GameError {
     InvalidWordLength { expected: usize, actual: usize },
     /// Word not in dictionary
     WordNotFound(String),
+    /// Guess containing something other than the letters a-z
+    InvalidCharacters(String),
+    /// Guess ignoring a hint revealed earlier while hard mode is on
+    HardModeViolation { word: String, reason: String },
+    /// Word guessed earlier in the same game, while the rules refuse repeats
+    RepeatedGuess(String),
     /// No target word set
     NoTargetWord,
     /// Game already finished
@@ -35,6 +41,12 @@ pub enum SolverError {
     AlgorithmFailure(String),
     /// No candidates available
     NoCandidates,
+    /// Manual constraint that can't be read or contradicts another one
+    InvalidConstraint(String),
+    /// The caller cancelled the computation before it finished
+    Cancelled,
+    /// A cancelled suggestion search still holds the solver; retry once it stops
+    SearchRunning,
 }
 
 #[derive(Debug)]
@@ -47,6 +59,8 @@ pub enum DataError {
     InvalidFormat(String),
     /// Missing required data
     MissingData(String),
+    /// A file is locked by another process
+    Locked(String),
 }
 
 #[derive(Debug)]
@@ -57,6 +71,10 @@ pub enum InterfaceError {
     EventError(String),
     /// Rendering error
     RenderError(String),
+    /// Missing or invalid credentials
+    Unauthorized(String),
+    /// Too many requests; retry after the given duration
+    RateLimited { retry_after: std::time::Duration },
 }
 
 impl fmt::Display for WordleError {
@@ -76,7 +94,16 @@ impl fmt::Display for GameError {
             GameError::InvalidWordLength { expected, actual } => {
                 write!(f, "Invalid word length: expected {}, got {}", expected, actual)
             }
-            GameError::WordNotFound(word) => write!(f, "Word not found: {}", word),
+            GameError::WordNotFound(word) => write!(f, "'{}' is not in the guess list", word),
+            GameError::InvalidCharacters(word) => {
+                write!(f, "'{}' may only contain the letters A-Z", word)
+            }
+            GameError::HardModeViolation { word, reason } => {
+                write!(f, "'{}' breaks hard mode: {}", word, reason)
+            }
+            GameError::RepeatedGuess(word) => {
+                write!(f, "'{}' was already guessed this game", word)
+            }
             GameError::NoTargetWord => write!(f, "No target word set"),
             GameError::GameFinished => write!(f, "Game already finished"),
         }
@@ -90,6 +117,11 @@ impl fmt::Display for SolverError {
             SolverError::InvalidFeedback(msg) => write!(f, "Invalid feedback: {}", msg),
             SolverError::AlgorithmFailure(msg) => write!(f, "Algorithm failure: {}", msg),
             SolverError::NoCandidates => write!(f, "No candidates available"),
+            SolverError::InvalidConstraint(msg) => write!(f, "Invalid constraint: {}", msg),
+            SolverError::Cancelled => write!(f, "Cancelled"),
+            SolverError::SearchRunning => {
+                write!(f, "A cancelled suggestion search is still stopping")
+            }
         }
     }
 }
@@ -101,6 +133,7 @@ impl fmt::Display for DataError {
             DataError::JsonError(e) => write!(f, "JSON error: {}", e),
             DataError::InvalidFormat(msg) => write!(f, "Invalid format: {}", msg),
             DataError::MissingData(field) => write!(f, "Missing required data: {}", field),
+            DataError::Locked(what) => write!(f, "{} is locked by another process", what),
         }
     }
 }
@@ -111,6 +144,10 @@ impl fmt::Display for InterfaceError {
             InterfaceError::TerminalError(msg) => write!(f, "Terminal error: {}", msg),
             InterfaceError::EventError(msg) => write!(f, "Event error: {}", msg),
             InterfaceError::RenderError(msg) => write!(f, "Render error: {}", msg),
+            InterfaceError::Unauthorized(msg) => write!(f, "Unauthorized: {}", msg),
+            InterfaceError::RateLimited { retry_after } => {
+                write!(f, "Rate limited: retry after {:.1}s", retry_after.as_secs_f64())
+            }
         }
     }
 }
@@ -0,0 +1,281 @@
+//! Runtime metrics with Prometheus text exposition
+//!
+//! A single process-wide registry collects command counts, suggestion latency,
+//! word list cache hit rates and active session counts. The CLI's `--metrics-file`
+//! writes it out via [`MetricsRegistry::render_prometheus`] when a run ends.
+//! The latest solver operation is kept too, for front ends that show what the last
+//! step cost (see [`MetricsRegistry::last_operation`]).
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// Upper bounds (seconds) of the suggestion latency histogram buckets
+pub const LATENCY_BUCKETS: [f64; 12] = [
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Cumulative latency histogram with fixed buckets
+#[derive(Debug, Default)]
+pub struct LatencyHistogram {
+    buckets: [AtomicU64; LATENCY_BUCKETS.len()],
+    count: AtomicU64,
+    sum_micros: AtomicU64,
+}
+
+impl LatencyHistogram {
+    /// Record one observation
+    pub fn observe(&self, elapsed: Duration) {
+        let secs = elapsed.as_secs_f64();
+        for (bound, bucket) in LATENCY_BUCKETS.iter().zip(&self.buckets) {
+            if secs <= *bound {
+                bucket.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_micros
+            .fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Number of observations
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Sum of all observations in seconds
+    pub fn sum_seconds(&self) -> f64 {
+        self.sum_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0
+    }
+}
+
+/// How long one solver operation took and how many possible words it left
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperationTiming {
+    /// Short name of the operation, e.g. `suggest`
+    pub operation: &'static str,
+    pub elapsed: Duration,
+    /// Possible words once the operation finished
+    pub candidates: usize,
+}
+
+/// Process-wide metrics registry
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    commands: Mutex<BTreeMap<&'static str, u64>>,
+    command_errors: Mutex<BTreeMap<&'static str, u64>>,
+    suggestion_latency: LatencyHistogram,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    active_sessions: AtomicI64,
+    last_operation: Mutex<Option<OperationTiming>>,
+}
+
+impl MetricsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count an executed command by name
+    pub fn record_command(&self, name: &'static str) {
+        if let Ok(mut commands) = self.commands.lock() {
+            *commands.entry(name).or_insert(0) += 1;
+        }
+    }
+
+    /// Count a failed command by name
+    pub fn record_command_error(&self, name: &'static str) {
+        if let Ok(mut errors) = self.command_errors.lock() {
+            *errors.entry(name).or_insert(0) += 1;
+        }
+    }
+
+    /// Record the time taken to compute a suggestion
+    pub fn observe_suggestion_latency(&self, elapsed: Duration) {
+        self.suggestion_latency.observe(elapsed);
+    }
+
+    /// Remember `timing` as the latest solver operation
+    pub fn record_operation(&self, timing: OperationTiming) {
+        if let Ok(mut last) = self.last_operation.lock() {
+            *last = Some(timing);
+        }
+    }
+
+    /// The latest solver operation recorded, if any
+    pub fn last_operation(&self) -> Option<OperationTiming> {
+        self.last_operation.lock().ok().and_then(|last| *last)
+    }
+
+    /// Record a word list cache lookup
+    pub fn record_cache_lookup(&self, hit: bool) {
+        if hit {
+            self.cache_hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.cache_misses.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Adjust the number of currently active sessions by `delta`
+    ///
+    /// Each session store reports its own changes, so the gauge is the sum over all
+    /// stores in the process.
+    pub fn add_active_sessions(&self, delta: i64) {
+        self.active_sessions.fetch_add(delta, Ordering::Relaxed);
+    }
+
+    /// Number of times a command has been executed
+    pub fn command_count(&self, name: &str) -> u64 {
+        self.commands
+            .lock()
+            .map(|c| c.get(name).copied().unwrap_or(0))
+            .unwrap_or(0)
+    }
+
+    /// Suggestion latency histogram
+    pub fn suggestion_latency(&self) -> &LatencyHistogram {
+        &self.suggestion_latency
+    }
+
+    /// Cache hit rate in [0, 1], or None when no lookups were recorded
+    pub fn cache_hit_rate(&self) -> Option<f64> {
+        let hits = self.cache_hits.load(Ordering::Relaxed);
+        let total = hits + self.cache_misses.load(Ordering::Relaxed);
+        if total == 0 {
+            None
+        } else {
+            Some(hits as f64 / total as f64)
+        }
+    }
+
+    /// Render all metrics in the Prometheus text exposition format (version 0.0.4)
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP wordle_commands_total Application commands executed.\n");
+        out.push_str("# TYPE wordle_commands_total counter\n");
+        if let Ok(commands) = self.commands.lock() {
+            for (name, count) in commands.iter() {
+                let _ = writeln!(out, "wordle_commands_total{{command=\"{}\"}} {}", name, count);
+            }
+        }
+
+        out.push_str("# HELP wordle_command_errors_total Application commands that failed.\n");
+        out.push_str("# TYPE wordle_command_errors_total counter\n");
+        if let Ok(errors) = self.command_errors.lock() {
+            for (name, count) in errors.iter() {
+                let _ = writeln!(
+                    out,
+                    "wordle_command_errors_total{{command=\"{}\"}} {}",
+                    name, count
+                );
+            }
+        }
+
+        let histogram = &self.suggestion_latency;
+        out.push_str("# HELP wordle_suggestion_duration_seconds Time spent computing suggestions.\n");
+        out.push_str("# TYPE wordle_suggestion_duration_seconds histogram\n");
+        for (bound, bucket) in LATENCY_BUCKETS.iter().zip(&histogram.buckets) {
+            let _ = writeln!(
+                out,
+                "wordle_suggestion_duration_seconds_bucket{{le=\"{}\"}} {}",
+                bound,
+                bucket.load(Ordering::Relaxed)
+            );
+        }
+        let _ = writeln!(
+            out,
+            "wordle_suggestion_duration_seconds_bucket{{le=\"+Inf\"}} {}",
+            histogram.count()
+        );
+        let _ = writeln!(
+            out,
+            "wordle_suggestion_duration_seconds_sum {}",
+            histogram.sum_seconds()
+        );
+        let _ = writeln!(out, "wordle_suggestion_duration_seconds_count {}", histogram.count());
+
+        out.push_str("# HELP wordle_word_list_cache_lookups_total Word list cache lookups.\n");
+        out.push_str("# TYPE wordle_word_list_cache_lookups_total counter\n");
+        let _ = writeln!(
+            out,
+            "wordle_word_list_cache_lookups_total{{result=\"hit\"}} {}",
+            self.cache_hits.load(Ordering::Relaxed)
+        );
+        let _ = writeln!(
+            out,
+            "wordle_word_list_cache_lookups_total{{result=\"miss\"}} {}",
+            self.cache_misses.load(Ordering::Relaxed)
+        );
+
+        out.push_str("# HELP wordle_active_sessions Currently active game sessions.\n");
+        out.push_str("# TYPE wordle_active_sessions gauge\n");
+        let _ = writeln!(
+            out,
+            "wordle_active_sessions {}",
+            self.active_sessions.load(Ordering::Relaxed)
+        );
+
+        out
+    }
+}
+
+/// Get the process-wide metrics registry
+pub fn global() -> &'static MetricsRegistry {
+    static REGISTRY: OnceLock<MetricsRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(MetricsRegistry::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let histogram = LatencyHistogram::default();
+        histogram.observe(Duration::from_millis(3));
+        histogram.observe(Duration::from_millis(200));
+
+        assert_eq!(histogram.count(), 2);
+        assert_eq!(histogram.buckets[0].load(Ordering::Relaxed), 0); // <= 1ms
+        assert_eq!(histogram.buckets[1].load(Ordering::Relaxed), 1); // <= 5ms
+        assert_eq!(histogram.buckets[6].load(Ordering::Relaxed), 2); // <= 250ms
+        assert!((histogram.sum_seconds() - 0.203).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_prometheus_rendering() {
+        let registry = MetricsRegistry::new();
+        registry.record_command("get_best_guess");
+        registry.record_command("get_best_guess");
+        registry.record_command_error("add_guess_result");
+        registry.record_cache_lookup(true);
+        registry.record_cache_lookup(false);
+        registry.add_active_sessions(5);
+        registry.add_active_sessions(-2);
+
+        let text = registry.render_prometheus();
+        assert!(text.contains("wordle_commands_total{command=\"get_best_guess\"} 2"));
+        assert!(text.contains("wordle_command_errors_total{command=\"add_guess_result\"} 1"));
+        assert!(text.contains("wordle_word_list_cache_lookups_total{result=\"hit\"} 1"));
+        assert!(text.contains("wordle_active_sessions 3"));
+        assert!(text.contains("wordle_suggestion_duration_seconds_bucket{le=\"+Inf\"} 0"));
+        assert_eq!(registry.cache_hit_rate(), Some(0.5));
+    }
+
+    #[test]
+    fn test_last_operation_keeps_the_latest() {
+        let registry = MetricsRegistry::new();
+        assert_eq!(registry.last_operation(), None);
+        for (operation, candidates) in [("feedback", 120), ("suggest", 120)] {
+            registry.record_operation(OperationTiming {
+                operation,
+                elapsed: Duration::from_millis(15),
+                candidates,
+            });
+        }
+        let last = registry.last_operation().unwrap();
+        assert_eq!((last.operation, last.candidates), ("suggest", 120));
+    }
+}
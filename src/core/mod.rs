@@ -1,10 +1,18 @@
+pub mod algorithms;
+pub mod alphabet;
+pub mod clock;
 pub mod container;
 /// Core domain types and traits for the Wordle solver
 pub mod error;
+pub mod metrics;
+pub mod morphology;
+pub mod ranking;
 pub mod traits;
 pub mod types;
 
-pub use container::*;
+pub use alphabet::*;
+pub use clock::*;
 pub use error::*;
+pub use morphology::*;
 pub use traits::*;
 pub use types::*;
@@ -0,0 +1,214 @@
+//! Word-form tags for the answer prior
+//!
+//! Official answers are almost never plurals, past tenses, gerunds or names, while
+//! a dictionary-sized answer list is full of them. [`MorphologyTags`] flags such
+//! words from their spelling alone; the tags are computed when a word list cache is
+//! built, stored in the WLF file, and read back by [`AnswerPrior`] to make flagged
+//! words less likely answers without ruling them out.
+
+use serde::{Deserialize, Serialize};
+use std::ops::BitOr;
+
+/// Environment variable setting [`AnswerPrior::flagged_weight`]
+pub const FLAGGED_WEIGHT_ENV: &str = "WORDLE_FLAGGED_WEIGHT";
+
+/// Five-letter names and places that a plain dictionary lists as ordinary words
+///
+/// Words that double as common nouns or verbs ("grace", "frank", "china") are left out.
+pub const COMMON_PROPER_NOUNS: &[&str] = &[
+    "aaron", "adams", "alice", "allen", "annie", "betty", "brian", "carol", "chile", "chris",
+    "craig", "david", "diana", "dubai", "egypt", "ellen", "emily", "evans", "gabon", "ghana",
+    "haiti", "harry", "helen", "india", "iraqi", "italy", "jacob", "james", "japan", "jason",
+    "jesus", "jones", "kenya", "kevin", "laura", "libya", "linda", "lucas", "maria", "milan",
+    "nancy", "nepal", "nigel", "oscar", "paris", "qatar", "ralph", "sarah", "seoul", "simon",
+    "spain", "sudan", "susan", "syria", "texas", "tokyo", "wales", "yemen",
+];
+
+/// Set of word forms a word likely is, guessed from its spelling
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MorphologyTags(u8);
+
+impl MorphologyTags {
+    pub const NONE: Self = Self(0);
+    /// Ends in a plural "s" (but not "ss", "us" or "is", as in "abyss")
+    pub const PLURAL: Self = Self(1);
+    /// Ends in "ed" (but not "eed", as in "greed")
+    pub const PAST_TENSE: Self = Self(1 << 1);
+    /// Ends in "ing" after a stem with a vowel ("doing", but not "thing")
+    pub const GERUND: Self = Self(1 << 2);
+    /// One of [`COMMON_PROPER_NOUNS`]
+    pub const PROPER_NOUN: Self = Self(1 << 3);
+
+    pub const ALL: [MorphologyTags; 4] = [
+        Self::PLURAL,
+        Self::PAST_TENSE,
+        Self::GERUND,
+        Self::PROPER_NOUN,
+    ];
+
+    /// Tags of a lowercase word
+    pub fn of(word: &str) -> Self {
+        let mut tags = Self::NONE;
+        if word.ends_with('s')
+            && !(word.ends_with("ss") || word.ends_with("us") || word.ends_with("is"))
+        {
+            tags = tags | Self::PLURAL;
+        }
+        if word.ends_with("ed") && !word.ends_with("eed") {
+            tags = tags | Self::PAST_TENSE;
+        }
+        if let Some(stem) = word.strip_suffix("ing") {
+            if stem.bytes().any(|b| b"aeiouy".contains(&b)) {
+                tags = tags | Self::GERUND;
+            }
+        }
+        if COMMON_PROPER_NOUNS.binary_search(&word).is_ok() {
+            tags = tags | Self::PROPER_NOUN;
+        }
+        tags
+    }
+
+    /// Tags from their stored bits; unknown bits are dropped
+    pub fn from_bits(bits: u8) -> Self {
+        let known = Self::ALL.iter().fold(0, |all, tag| all | tag.0);
+        Self(bits & known)
+    }
+
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Name of a single tag, for display
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::PLURAL => "plural",
+            Self::PAST_TENSE => "past tense",
+            Self::GERUND => "gerund",
+            Self::PROPER_NOUN => "name",
+            _ => "",
+        }
+    }
+
+    /// One-letter mark of a single tag, for compact lists
+    pub fn symbol(self) -> char {
+        match self {
+            Self::PLURAL => 's',
+            Self::PAST_TENSE => 'd',
+            Self::GERUND => 'g',
+            Self::PROPER_NOUN => 'n',
+            _ => ' ',
+        }
+    }
+
+    /// The single tags set, in [`Self::ALL`] order
+    pub fn iter(self) -> impl Iterator<Item = MorphologyTags> {
+        Self::ALL.into_iter().filter(move |tag| self.contains(*tag))
+    }
+
+    /// Marks of the tags set, e.g. "sd"
+    pub fn symbols(self) -> String {
+        self.iter().map(Self::symbol).collect()
+    }
+}
+
+impl BitOr for MorphologyTags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// How likely each remaining word is to be the answer, before any feedback
+///
+/// Untagged words all weigh 1; a word with any [`MorphologyTags`] weighs
+/// `flagged_weight`, so it stays possible but counts for less.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnswerPrior {
+    /// Weight of a tagged word (0.0-1.0); 1.0 treats every word alike
+    pub flagged_weight: f64,
+}
+
+impl Default for AnswerPrior {
+    fn default() -> Self {
+        Self {
+            flagged_weight: 0.2,
+        }
+    }
+}
+
+impl AnswerPrior {
+    /// Every word equally likely
+    pub fn uniform() -> Self {
+        Self {
+            flagged_weight: 1.0,
+        }
+    }
+
+    /// The prior with `WORDLE_FLAGGED_WEIGHT` as the flagged weight, or the default
+    pub fn from_env() -> Self {
+        let Ok(value) = std::env::var(FLAGGED_WEIGHT_ENV) else {
+            return Self::default();
+        };
+        match value.trim().parse::<f64>() {
+            Ok(weight) if (0.0..=1.0).contains(&weight) => Self {
+                flagged_weight: weight,
+            },
+            _ => {
+                log::warn!(
+                    "Ignoring {} '{}': expected 0.0-1.0",
+                    FLAGGED_WEIGHT_ENV,
+                    value
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Weight of a word tagged `tags`
+    pub fn weight(&self, tags: MorphologyTags) -> f64 {
+        if tags.is_empty() {
+            1.0
+        } else {
+            self.flagged_weight
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_follow_the_spelling() {
+        assert_eq!(MorphologyTags::of("crane"), MorphologyTags::NONE);
+        assert_eq!(MorphologyTags::of("cares"), MorphologyTags::PLURAL);
+        assert!(MorphologyTags::of("abyss").is_empty());
+        assert_eq!(MorphologyTags::of("fried"), MorphologyTags::PAST_TENSE);
+        assert!(MorphologyTags::of("greed").is_empty());
+        assert_eq!(MorphologyTags::of("doing"), MorphologyTags::GERUND);
+        assert!(MorphologyTags::of("thing").is_empty());
+        assert_eq!(MorphologyTags::of("paris"), MorphologyTags::PROPER_NOUN);
+        let tags = MorphologyTags::of("james");
+        assert_eq!(tags, MorphologyTags::PLURAL | MorphologyTags::PROPER_NOUN);
+        assert_eq!(tags.symbols(), "sn");
+        assert_eq!(MorphologyTags::from_bits(tags.bits() | 0x80), tags);
+    }
+
+    #[test]
+    fn test_prior_weighs_tagged_words_down() {
+        let prior = AnswerPrior::default();
+        assert_eq!(prior.weight(MorphologyTags::NONE), 1.0);
+        assert!(prior.weight(MorphologyTags::PLURAL) < 1.0);
+        assert_eq!(AnswerPrior::uniform().weight(MorphologyTags::GERUND), 1.0);
+    }
+}
@@ -0,0 +1,107 @@
+//! Deterministic candidate ranking
+//!
+//! Every ranking API (best guess, top candidates) orders words the same way:
+//!
+//! 1. score, descending (NaN scores last)
+//! 2. possible answers before guess-only words
+//! 3. lexicographic by word
+//!
+//! This is a total order, so results don't depend on hash or thread scheduling
+//! order when scores tie.
+
+use crate::core::types::Word;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// Compare two scores so that higher scores come first and NaN comes last
+#[inline]
+pub fn score_desc(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (false, false) => b.total_cmp(&a),
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+    }
+}
+
+/// Orders scored candidates using the crate-wide ranking rules
+#[derive(Debug)]
+pub struct CandidateRanker<'a> {
+    answers: HashSet<&'a Word>,
+}
+
+impl<'a> CandidateRanker<'a> {
+    /// `possible_words` are the remaining answers, preferred on score ties
+    pub fn new(possible_words: &'a [Word]) -> Self {
+        Self {
+            answers: possible_words.iter().collect(),
+        }
+    }
+
+    #[inline]
+    pub fn is_answer(&self, word: &Word) -> bool {
+        self.answers.contains(word)
+    }
+
+    /// `Less` means `a` ranks ahead of `b`
+    pub fn compare(&self, a: (&Word, f64), b: (&Word, f64)) -> Ordering {
+        score_desc(a.1, b.1)
+            .then_with(|| self.is_answer(b.0).cmp(&self.is_answer(a.0)))
+            .then_with(|| a.0.cmp(b.0))
+    }
+
+    /// Sort scored candidates best-first
+    pub fn sort(&self, scored: &mut [(Word, f64)]) {
+        scored.sort_by(|a, b| self.compare((&a.0, a.1), (&b.0, b.1)));
+    }
+
+    /// The best-ranked candidate
+    pub fn best<'w, I>(&self, scored: I) -> Option<&'w Word>
+    where
+        I: IntoIterator<Item = (&'w Word, f64)>,
+    {
+        scored
+            .into_iter()
+            .min_by(|a, b| self.compare(*a, *b))
+            .map(|(word, _)| word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn w(s: &str) -> Word {
+        Word::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_ties_prefer_answers_then_lexicographic() {
+        let answers = vec![w("crane"), w("trace")];
+        let ranker = CandidateRanker::new(&answers);
+
+        let mut scored = vec![
+            (w("zesty"), 2.0),
+            (w("trace"), 2.0),
+            (w("adieu"), 2.0),
+            (w("crane"), 2.0),
+            (w("slate"), 3.0),
+            (w("nymph"), f64::NAN),
+        ];
+        ranker.sort(&mut scored);
+        let order: Vec<_> = scored.iter().map(|(word, _)| word.as_str()).collect();
+        assert_eq!(order, ["slate", "crane", "trace", "adieu", "zesty", "nymph"]);
+    }
+
+    #[test]
+    fn test_best_is_independent_of_input_order() {
+        let answers = vec![w("crane")];
+        let ranker = CandidateRanker::new(&answers);
+        let words = [w("adieu"), w("crane"), w("zesty")];
+
+        let forward = ranker.best(words.iter().map(|word| (word, 1.0)));
+        let backward = ranker.best(words.iter().rev().map(|word| (word, 1.0)));
+        assert_eq!(forward, Some(&words[1]));
+        assert_eq!(forward, backward);
+    }
+}
@@ -1,8 +1,15 @@
 use crate::core::{
-    error::Result,
-    types::{FeedbackPattern, GameResult, Guess, SolverStatistics, Word},
+    algorithms::PATTERN_COUNT,
+    error::{Result, SolverError},
+    morphology::MorphologyTags,
+    types::{
+        Disambiguator, Feedback, FeedbackPattern, GameResult, Guess, ManualConstraint,
+        SolverStatistics, SuggestionSet, TrapCluster, Word,
+    },
 };
 use async_trait::async_trait;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
 
 /// Trait for word list providers
 #[async_trait]
@@ -22,6 +29,23 @@ pub trait WordListProvider: Send + Sync + std::fmt::Debug {
     /// Check if a word is a possible answer
     fn is_possible_answer(&self, word: &Word) -> bool;
 
+    /// Counter bumped whenever different lists are swapped in (e.g. by a refresh)
+    ///
+    /// Anything computed from the lists records the generation it was built from and
+    /// rebuilds when the provider's has moved on. Providers whose lists never change
+    /// can keep the default.
+    fn generation(&self) -> u64 {
+        0
+    }
+
+    /// Word-form tags of `word`, read by the answer prior
+    ///
+    /// The default tags it from its spelling; providers that store tags with their
+    /// lists return those.
+    fn morphology(&self, word: &Word) -> MorphologyTags {
+        MorphologyTags::of(word.as_str())
+    }
+
     /// Refresh the underlying word lists (optionally forcing a remote fetch)
     async fn refresh(&mut self, _force: bool) -> Result<(usize, usize)> {
         // Default implementation falls back to load_words when implementations
@@ -34,16 +58,41 @@ pub trait WordListProvider: Send + Sync + std::fmt::Debug {
 }
 
 /// Trait for solving strategies
+///
+/// Scoring only reads the strategy, so one instance can score for several threads
+/// at once; clearing caches is the only update.
 pub trait SolvingStrategy: Send + Sync + std::fmt::Debug {
     /// Get the best next guess given current constraints
-    fn get_best_guess(&mut self, possible_words: &[Word], candidates: &[Word]) -> Result<Word>;
+    fn get_best_guess(&self, possible_words: &[Word], candidates: &[Word]) -> Result<Word>;
+
+    /// [`get_best_guess`](Self::get_best_guess), giving up with
+    /// [`SolverError::Cancelled`] once `cancel` fires
+    ///
+    /// The default only checks before and after scoring; strategies with a long
+    /// scoring loop check inside it too.
+    fn get_best_guess_cancellable(
+        &self,
+        possible_words: &[Word],
+        candidates: &[Word],
+        cancel: &CancellationToken,
+    ) -> Result<Word> {
+        if cancel.is_cancelled() {
+            return Err(SolverError::Cancelled.into());
+        }
+        let best = self.get_best_guess(possible_words, candidates)?;
+        if cancel.is_cancelled() {
+            return Err(SolverError::Cancelled.into());
+        }
+        Ok(best)
+    }
 
     /// Get the best first guess
     fn get_best_first_guess(&self) -> Result<Word>;
 
-    /// Get top N candidates with their scores
+    /// Get top N candidates with their scores, ordered as described in
+    /// [`crate::core::ranking`] (score desc, then answers first, then lexicographic)
     fn get_top_candidates(
-        &mut self,
+        &self,
         possible_words: &[Word],
         candidates: &[Word],
         limit: usize,
@@ -51,6 +100,10 @@ pub trait SolvingStrategy: Send + Sync + std::fmt::Debug {
 
     /// Clear any internal caches
     fn clear_cache(&mut self);
+
+    /// The word lists were swapped for `answers`; rebuild anything derived from the
+    /// old ones
+    fn word_lists_changed(&mut self, _answers: &[Word]) {}
 }
 
 /// Trait for entropy calculation
@@ -63,6 +116,13 @@ pub trait EntropyCalculator: Send + Sync + std::fmt::Debug {
 
     /// Find the guess with maximum entropy
     fn find_max_entropy_guess(&self, candidates: &[Word], possible_words: &[Word]) -> Option<Word>;
+
+    /// How many of `possible_words` fall in each feedback bucket for `guess`, indexed
+    /// by [`FeedbackPattern::index`]; the histogram behind entropy, worst cases and
+    /// bucket charts
+    fn partition_counts(&self, guess: &Word, possible_words: &[Word]) -> [usize; PATTERN_COUNT] {
+        FeedbackPattern::partition(guess, possible_words)
+    }
 }
 
 /// Trait for feedback generation
@@ -99,6 +159,16 @@ pub trait GameEngine: Send + Sync + std::fmt::Debug {
 
     /// Get the guess history
     fn get_history(&self) -> &[Guess];
+
+    /// The word being guessed, once set
+    fn target(&self) -> Option<&Word> {
+        None
+    }
+
+    /// Guesses allowed per game
+    fn max_attempts(&self) -> usize {
+        crate::domain::DEFAULT_MAX_ATTEMPTS
+    }
 }
 
 /// Trait for Wordle solver
@@ -109,24 +179,79 @@ pub trait WordleSolver: Send + Sync + std::fmt::Debug {
     where
         Self: Sized;
 
+    /// Turn hard mode on or off for the rest of the game, keeping the guesses so far
+    fn set_hard_mode(&mut self, hard_mode: bool);
+
+    /// Never suggest these words as openers or probes; they may still be named as the answer
+    fn set_excluded_words(&mut self, words: Vec<Word>);
+
+    /// Check that `word` may be played now: in the guess list and, in hard mode,
+    /// reusing every revealed hint
+    fn validate_guess(&self, word: &Word) -> Result<()>;
+
     /// Add a guess result and update internal state
     fn add_guess_result(&mut self, word: &Word, feedback: &FeedbackPattern) -> Result<()>;
 
+    /// Narrow the possible words with knowledge from outside the game; fails if it
+    /// contradicts a constraint added earlier
+    fn add_manual_constraint(&mut self, constraint: ManualConstraint) -> Result<()>;
+
+    /// Drop every manual constraint, keeping the guesses
+    fn clear_manual_constraints(&mut self);
+
+    /// Manual constraints in the order they were added
+    fn manual_constraints(&self) -> &[ManualConstraint];
+
     /// Get the best next guess
-    fn get_best_guess(&mut self) -> Result<Word>;
+    fn get_best_guess(&self) -> Result<Word>;
+
+    /// [`get_best_guess`](Self::get_best_guess), giving up with
+    /// [`SolverError::Cancelled`] once `cancel` fires
+    fn get_best_guess_cancellable(&self, cancel: &CancellationToken) -> Result<Word> {
+        if cancel.is_cancelled() {
+            return Err(SolverError::Cancelled.into());
+        }
+        self.get_best_guess()
+    }
 
     /// Get the best first guess
     fn get_best_first_guess(&self) -> Result<Word>;
 
+    /// Best guess after `history` without touching this solver's own game
+    fn best_guess_after(&self, history: &[Guess]) -> Result<Word>;
+
+    /// Letters the guesses so far confirmed in place, as the constraint filter reads them
+    fn confirmed_positions(&self) -> [Option<char>; Word::LENGTH];
+
     /// Get remaining possible words count
     fn remaining_words_count(&self) -> usize;
 
     /// Get current possible words (with optional limit)
     fn get_possible_words(&self, limit: Option<usize>) -> Vec<Word>;
 
+    /// Every word accepted as a guess (answers included), sorted
+    fn guess_words(&self) -> Arc<Vec<Word>>;
+
     /// Reset solver to initial state
     fn reset(&mut self);
 
+    /// Refresh the word lists (see [`WordListProvider::refresh`]) and bring everything
+    /// built from them up to date, keeping the game; returns the answer and guess counts
+    async fn refresh_word_lists(&mut self, force: bool) -> Result<(usize, usize)>;
+
+    /// [`WordListProvider::generation`] of the lists the solver is working from
+    fn word_list_generation(&self) -> u64;
+
+    /// [`WordListProvider::morphology`] of `word` in the solver's lists
+    fn morphology(&self, word: &Word) -> MorphologyTags {
+        MorphologyTags::of(word.as_str())
+    }
+
+    /// Whether so few guesses are left that only possible answers are suggested
+    fn is_urgent(&self) -> bool {
+        false
+    }
+
     /// Check if puzzle is solved
     fn is_solved(&self) -> bool;
 
@@ -137,7 +262,17 @@ pub trait WordleSolver: Send + Sync + std::fmt::Debug {
     fn get_statistics(&self) -> SolverStatistics;
 
     /// Get top candidate guesses
-    fn get_top_candidates(&mut self, limit: usize) -> Vec<(Word, f64)>;
+    fn get_top_candidates(&self, limit: usize) -> Vec<(Word, f64)>;
+
+    /// Best-information, best-answer-chance and balanced suggestions for this turn
+    fn get_suggestion_set(&self) -> Option<SuggestionSet>;
+
+    /// A guess separating remaining words that differ in only a position or two
+    fn get_disambiguator(&self) -> Option<Disambiguator>;
+
+    /// Families of at least `min_size` in the full answer list, each with its best
+    /// `guesses_per_trap` disambiguating guesses
+    fn get_trap_clusters(&self, min_size: usize, guesses_per_trap: usize) -> Vec<TrapCluster>;
 }
 
 /// Trait for constraint filtering
@@ -147,6 +282,32 @@ pub trait ConstraintFilter: Send + Sync + std::fmt::Debug {
 
     /// Check if a single word satisfies all constraints
     fn satisfies_constraints(&self, word: &Word, constraints: &[Guess]) -> bool;
+
+    /// Filter words by the guesses and by constraints the player added by hand
+    fn filter_words_with_manual(
+        &self,
+        words: &[Word],
+        constraints: &[Guess],
+        manual: &[ManualConstraint],
+    ) -> Vec<Word> {
+        let mut words = self.filter_words(words, constraints);
+        words.retain(|word| manual.iter().all(|c| c.allows(word)));
+        words
+    }
+
+    /// Letter each position is confirmed to hold, i.e. was green in one of `constraints`
+    fn confirmed_positions(&self, constraints: &[Guess]) -> [Option<char>; Word::LENGTH] {
+        let mut confirmed = [None; Word::LENGTH];
+        for guess in constraints {
+            let letters = guess.word.bytes();
+            for (pos, feedback) in guess.feedback.as_slice().iter().enumerate() {
+                if *feedback == Feedback::Correct {
+                    confirmed[pos] = Some(letters[pos] as char);
+                }
+            }
+        }
+        confirmed
+    }
 }
 
 /// Trait for application state management (simplified for dyn compatibility)
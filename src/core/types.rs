@@ -1,3 +1,6 @@
+use crate::core::algorithms::{self, PATTERN_COUNT};
+use crate::core::alphabet::Alphabet;
+use crate::core::error::{GameError, SolverError};
 use std::cmp::Ordering;
 use std::fmt;
 use std::str::FromStr;
@@ -24,6 +27,26 @@ impl Feedback {
         }
     }
 
+    /// Feedback for a typed code: the digits 0-2 or the colour initials b (black/gray),
+    /// y (yellow) and g (green), in either case
+    pub fn from_shorthand(code: char) -> Option<Self> {
+        match code.to_ascii_lowercase() {
+            'g' => Some(Feedback::Correct),
+            'y' => Some(Feedback::Present),
+            'b' => Some(Feedback::Absent),
+            code => Self::from_code(code),
+        }
+    }
+
+    /// Colour initial: G (green), Y (yellow) or B (black/gray)
+    pub fn to_initial(self) -> char {
+        match self {
+            Feedback::Correct => 'G',
+            Feedback::Present => 'Y',
+            Feedback::Absent => 'B',
+        }
+    }
+
     /// Convert Feedback to result code character
     pub fn to_code(self) -> char {
         match self {
@@ -33,6 +56,37 @@ impl Feedback {
         }
     }
 
+    /// Feedback for a square of a shared grid: green, yellow, white or black, and
+    /// the orange and blue of the high-contrast theme
+    pub fn from_emoji(square: char) -> Option<Self> {
+        match square {
+            '🟩' | '🟧' => Some(Feedback::Correct),
+            '🟨' | '🟦' => Some(Feedback::Present),
+            '⬜' | '⬛' => Some(Feedback::Absent),
+            _ => None,
+        }
+    }
+
+    /// Feedback for an [`algorithms`] digit, None for
+    /// anything but 0, 1 or 2
+    pub fn from_digit(digit: u8) -> Option<Self> {
+        match digit {
+            algorithms::CORRECT => Some(Feedback::Correct),
+            algorithms::PRESENT => Some(Feedback::Present),
+            algorithms::ABSENT => Some(Feedback::Absent),
+            _ => None,
+        }
+    }
+
+    /// The [`algorithms`] digit for this feedback
+    pub fn digit(self) -> u8 {
+        match self {
+            Feedback::Correct => algorithms::CORRECT,
+            Feedback::Present => algorithms::PRESENT,
+            Feedback::Absent => algorithms::ABSENT,
+        }
+    }
+
     /// Get the priority score for this feedback (higher = better)
     pub fn priority_score(self) -> u8 {
         match self {
@@ -68,6 +122,9 @@ impl Word {
     /// Fixed Wordle word length
     pub const LENGTH: usize = 5;
 
+    /// Letters words are spelled with
+    pub const ALPHABET: Alphabet = Alphabet::ENGLISH;
+
     /// Create a new Word with validation
     pub fn new(word: String) -> Result<Self, String> {
         if word.len() != Self::LENGTH {
@@ -77,27 +134,47 @@ impl Word {
             ));
         }
 
-        if !word.chars().all(|c| c.is_ascii_lowercase()) {
+        if !Self::ALPHABET.spells(&word) {
             return Err("Word must contain only lowercase ASCII letters".to_string());
         }
 
-        // Safe: validated ASCII-lowercase and len == 5
+        // Safe: validated single-byte letters and len == 5
         let mut arr = [0u8; Self::LENGTH];
         arr.copy_from_slice(word.as_bytes());
         Ok(Word { s: word, b: arr })
     }
 
     /// Create a Word from a string slice (convenience method)
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(word: &str) -> Result<Self, String> {
         Self::new(word.to_lowercase())
     }
 
+    /// Parse a guess as typed by a player, saying what's wrong with it
+    ///
+    /// Surrounding whitespace and case are ignored. Anything other than the
+    /// letters a-z is [`GameError::InvalidCharacters`]; otherwise a count other
+    /// than five is [`GameError::InvalidWordLength`].
+    pub fn parse_guess(text: &str) -> Result<Self, GameError> {
+        let text = text.trim();
+        if !Self::ALPHABET.spells(&text.to_ascii_lowercase()) {
+            return Err(GameError::InvalidCharacters(text.to_string()));
+        }
+        if text.len() != Self::LENGTH {
+            return Err(GameError::InvalidWordLength {
+                expected: Self::LENGTH,
+                actual: text.len(),
+            });
+        }
+        Self::new(text.to_ascii_lowercase()).map_err(|_| GameError::InvalidCharacters(text.into()))
+    }
+
     /// Get the underlying string
     pub fn as_str(&self) -> &str {
         &self.s
     }
 
-    /// Get the underlying 5 bytes (ASCII lowercase)
+    /// Get the underlying 5 bytes (letters of [`Word::ALPHABET`])
     #[inline]
     pub fn bytes(&self) -> &[u8; 5] {
         &self.b
@@ -189,6 +266,35 @@ impl FeedbackPattern {
         Ok(FeedbackPattern(arr))
     }
 
+    /// Parse feedback the way people type it: digits or `g`/`y`/`b` initials, in any
+    /// case, spaced or not (`"01020"`, `"g g y b b"`, `"GgYbB"`, `"2 2 1 0 0"`)
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let squares: Vec<Feedback> = text
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| {
+                Feedback::from_shorthand(c).ok_or_else(|| format!("Invalid feedback code: {}", c))
+            })
+            .collect::<Result<_, _>>()?;
+        let squares: [Feedback; 5] = squares
+            .try_into()
+            .map_err(|codes: Vec<Feedback>| format!("Got {} feedback codes, not 5", codes.len()))?;
+        Ok(FeedbackPattern(squares))
+    }
+
+    /// Create from one row of a shared emoji grid (e.g. "🟩⬜🟨⬜⬜")
+    pub fn from_emoji_row(row: &str) -> Result<Self, String> {
+        let squares: Vec<Feedback> = row
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| Feedback::from_emoji(c).ok_or_else(|| format!("Not a feedback square: {}", c)))
+            .collect::<Result<_, _>>()?;
+        let squares: [Feedback; 5] = squares
+            .try_into()
+            .map_err(|row: Vec<Feedback>| format!("Row has {} squares, not 5", row.len()))?;
+        Ok(FeedbackPattern(squares))
+    }
+
     /// Get the underlying feedback vector
     #[inline]
     pub fn as_slice(&self) -> &[Feedback] {
@@ -200,6 +306,11 @@ impl FeedbackPattern {
         self.0.iter().map(|f| f.to_code()).collect()
     }
 
+    /// Convert to colour initials, e.g. `BYBBG`
+    pub fn to_initial_string(&self) -> String {
+        self.0.iter().map(|f| f.to_initial()).collect()
+    }
+
     /// Check if this pattern indicates a win (all correct)
     #[inline]
     pub fn is_win(&self) -> bool {
@@ -226,6 +337,74 @@ impl FeedbackPattern {
     pub fn from_array(arr: [Feedback; 5]) -> Self {
         FeedbackPattern(arr)
     }
+
+    /// Pattern for [`algorithms`] feedback digits; digits above 2 read as absent
+    pub fn from_digits(digits: [u8; 5]) -> Self {
+        FeedbackPattern(digits.map(|d| Feedback::from_digit(d).unwrap_or(Feedback::Absent)))
+    }
+
+    /// The [`algorithms`] feedback digits
+    pub fn digits(&self) -> [u8; 5] {
+        self.0.map(Feedback::digit)
+    }
+
+    /// Pattern at `index` in the entropy calculators' base-3 encoding (first
+    /// letter least significant), or None past the 243 patterns
+    pub fn from_index(index: u16) -> Option<Self> {
+        ((index as usize) < PATTERN_COUNT)
+            .then(|| Self::from_digits(algorithms::decode_pattern(index)))
+    }
+
+    /// This pattern's index in the entropy calculators' base-3 encoding
+    pub fn index(&self) -> u16 {
+        algorithms::encode_pattern(&self.digits())
+    }
+
+    /// [`index`](Self::index) of the feedback `guess` gets when `answer` is the answer,
+    /// without building the pattern
+    pub fn index_for(guess: &Word, answer: &Word) -> u16 {
+        algorithms::feedback_index(guess.bytes(), answer.bytes()) as u16
+    }
+
+    /// How `possible_words` split by the feedback `guess` would get, counted per
+    /// pattern [`index`](Self::index)
+    pub fn partition(guess: &Word, possible_words: &[Word]) -> [usize; PATTERN_COUNT] {
+        let mut counts = [0usize; PATTERN_COUNT];
+        for word in possible_words {
+            counts[Self::index_for(guess, word) as usize] += 1;
+        }
+        counts
+    }
+
+    /// All 243 patterns in index order, from all absent to all correct
+    pub fn all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        (0..PATTERN_COUNT as u16).map(|index| Self::from_digits(algorithms::decode_pattern(index)))
+    }
+
+    /// Table of every pattern `label` returns Some for, in index order and
+    /// `columns` entries per line, each entry the index, the tiles and the label
+    ///
+    /// For example the second guesses or the target counts after an opener.
+    pub fn table<F>(columns: usize, mut label: F) -> String
+    where
+        F: FnMut(FeedbackPattern) -> Option<String>,
+    {
+        let entries: Vec<(Self, String)> =
+            Self::all().filter_map(|p| label(p).map(|text| (p, text))).collect();
+        let width = entries.iter().map(|(_, text)| text.chars().count()).max().unwrap_or(0);
+        let mut table = String::new();
+        for row in entries.chunks(columns.max(1)) {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|(pattern, text)| {
+                    format!("{:>3} {} {:<width$}", pattern.index(), pattern, text)
+                })
+                .collect();
+            table.push_str(cells.join("  ").trim_end());
+            table.push('\n');
+        }
+        table
+    }
 }
 
 impl fmt::Display for FeedbackPattern {
@@ -255,6 +434,101 @@ impl Guess {
     }
 }
 
+/// Knowledge about the answer from outside the game, e.g. a hint from a friend
+///
+/// Applied on top of the feedback from the guesses. Positions are 0-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ManualConstraint {
+    /// `letter` is at `position`
+    Pinned { letter: char, position: usize },
+    /// `letter` isn't in the answer at all
+    Banned { letter: char },
+}
+
+impl ManualConstraint {
+    /// Read one constraint: `a3` (or `3a`) pins A to the third position, `-x` (or `!x`)
+    /// bans X
+    pub fn parse(text: &str) -> Result<Self, SolverError> {
+        let text = text.trim().to_ascii_lowercase();
+        let invalid = || {
+            SolverError::InvalidConstraint(format!(
+                "'{}' should be a letter and a position (a3) or a banned letter (-x)",
+                text
+            ))
+        };
+        let chars: Vec<char> = text.chars().collect();
+        let letter = |c: char| Word::ALPHABET.contains(c).then_some(c);
+        let position = |c: char| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .filter(|d| (1..=Word::LENGTH).contains(d))
+                .map(|d| d - 1)
+        };
+        match chars[..] {
+            ['-' | '!', c] => letter(c).map(|letter| Self::Banned { letter }),
+            [a, b] if a.is_ascii_digit() => letter(b)
+                .zip(position(a))
+                .map(|(letter, position)| Self::Pinned { letter, position }),
+            [a, b] => letter(a)
+                .zip(position(b))
+                .map(|(letter, position)| Self::Pinned { letter, position }),
+            _ => None,
+        }
+        .ok_or_else(invalid)
+    }
+
+    /// Read several constraints separated by commas or spaces, e.g. `a3, -x -z`
+    pub fn parse_list(text: &str) -> Result<Vec<Self>, SolverError> {
+        text.split([',', ' '])
+            .filter(|t| !t.trim().is_empty())
+            .map(Self::parse)
+            .collect()
+    }
+
+    pub fn letter(&self) -> char {
+        match *self {
+            Self::Pinned { letter, .. } | Self::Banned { letter } => letter,
+        }
+    }
+
+    /// Whether `word` could be the answer under this constraint
+    pub fn allows(&self, word: &Word) -> bool {
+        match *self {
+            Self::Pinned { letter, position } => word.bytes()[position] == letter as u8,
+            Self::Banned { letter } => !word.bytes().contains(&(letter as u8)),
+        }
+    }
+
+    /// Why `self` can't hold together with `other`, if it can't
+    pub fn conflict(&self, other: &Self) -> Option<String> {
+        match (*self, *other) {
+            (Self::Pinned { letter: a, position: i }, Self::Pinned { letter: b, position: j })
+                if i == j && a != b =>
+            {
+                Some(format!("position {} is already pinned to {}", i + 1, other))
+            }
+            (Self::Pinned { letter: a, .. }, Self::Banned { letter: b })
+            | (Self::Banned { letter: b }, Self::Pinned { letter: a, .. })
+                if a == b =>
+            {
+                Some(format!("{} conflicts with {}", self, other))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ManualConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Pinned { letter, position } => {
+                write!(f, "{}{}", letter.to_ascii_uppercase(), position + 1)
+            }
+            Self::Banned { letter } => write!(f, "-{}", letter.to_ascii_uppercase()),
+        }
+    }
+}
+
 /// Game result enumeration with more detailed information
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GameResult {
@@ -283,7 +557,8 @@ pub struct SolverStatistics {
     pub remaining_words: usize,
     pub is_solved: bool,
     pub possible_words_sample: Vec<Word>,
-    pub entropy_scores: Vec<(Word, f64)>,
+    /// Information measured for each turn played, oldest first
+    pub entropy_scores: Vec<TurnEntropy>,
 }
 
 impl SolverStatistics {
@@ -296,6 +571,57 @@ impl SolverStatistics {
             entropy_scores: Vec::new(),
         }
     }
+
+    /// Bits the feedback has given over all turns so far
+    pub fn bits_gained(&self) -> f64 {
+        self.entropy_scores.iter().map(|turn| turn.bits_gained).sum()
+    }
+}
+
+/// Information one turn's guess was expected to give and what its feedback gave
+#[derive(Debug, Clone, PartialEq)]
+pub struct TurnEntropy {
+    /// The word played
+    pub played: Word,
+    /// Expected bits from the played word over the words possible before it
+    pub played_entropy: f64,
+    /// The solver's suggestion for the turn with its expected bits, None if the
+    /// solver wasn't asked before the guess (e.g. for replayed guesses)
+    pub suggested: Option<(Word, f64)>,
+    /// Bits the feedback actually gave: log2 of the words before over the words after
+    pub bits_gained: f64,
+}
+
+impl TurnEntropy {
+    /// Measure a turn that played `played` when `before` were possible and left
+    /// `after` of them
+    pub fn measure(played: Word, suggested: Option<Word>, before: &[Word], after: usize) -> Self {
+        // abs: a word with a single pattern sums to -0.0
+        let entropy = |word: &Word| SuggestionQuality::evaluate(word, before).entropy.abs();
+        // No words left means the feedback contradicts itself; count it as no information
+        let bits_gained = if after == 0 {
+            0.0
+        } else {
+            (before.len().max(1) as f64 / after as f64).log2()
+        };
+        Self {
+            played_entropy: entropy(&played),
+            suggested: suggested.map(|word| {
+                let bits = entropy(&word);
+                (word, bits)
+            }),
+            played,
+            bits_gained,
+        }
+    }
+
+    /// Expected bits the played word fell short of the suggestion by, 0 when it
+    /// matched or beat it
+    pub fn shortfall(&self) -> f64 {
+        self.suggested
+            .as_ref()
+            .map_or(0.0, |(_, bits)| (bits - self.played_entropy).max(0.0))
+    }
 }
 
 impl Default for SolverStatistics {
@@ -303,3 +629,521 @@ impl Default for SolverStatistics {
         Self::new()
     }
 }
+
+/// Risk profile of a guess against the remaining possible answers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuggestionQuality {
+    /// Expected information from the feedback, in bits
+    pub entropy: f64,
+    /// Number of distinct feedback patterns the guess can produce
+    pub bucket_count: usize,
+    /// Words left after the least informative feedback (0 if the guess can only win)
+    pub worst_case_remaining: usize,
+    /// Chance the guess is itself the answer, assuming all remaining words are equally likely
+    pub answer_probability: f64,
+}
+
+impl SuggestionQuality {
+    /// Partition `possible_words` by the feedback `guess` would receive
+    pub fn evaluate(guess: &Word, possible_words: &[Word]) -> Self {
+        Self::from_partition(&FeedbackPattern::partition(guess, possible_words))
+    }
+
+    /// Quality of a guess from its partition, as counted by
+    /// `EntropyCalculator::partition_counts`
+    pub fn from_partition(counts: &[usize; PATTERN_COUNT]) -> Self {
+        use crate::core::algorithms::SOLVED_PATTERN;
+
+        let solved = SOLVED_PATTERN as usize;
+        let total: usize = counts.iter().sum();
+        let entropy = counts
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f64 / total as f64;
+                -p * algorithms::log2(p)
+            })
+            .sum();
+        let worst_case_remaining = counts
+            .iter()
+            .enumerate()
+            .filter(|&(pattern, _)| pattern != solved)
+            .map(|(_, &count)| count)
+            .max()
+            .unwrap_or(0);
+        let answer_probability = if total == 0 {
+            0.0
+        } else {
+            counts[solved] as f64 / total as f64
+        };
+
+        Self {
+            entropy,
+            bucket_count: counts.iter().filter(|&&count| count > 0).count(),
+            worst_case_remaining,
+            answer_probability,
+        }
+    }
+
+    /// Partition `possible_words` by the feedback of playing `first` and then `second`
+    ///
+    /// Buckets are the combined pairs of patterns; the worst case leaves out the
+    /// pairs where either guess wins, and the answer probability is the chance
+    /// that one of the two is the answer.
+    pub fn evaluate_pair(first: &Word, second: &Word, possible_words: &[Word]) -> Self {
+        use crate::core::algorithms::SOLVED_PATTERN;
+
+        let solved = SOLVED_PATTERN as usize;
+        let mut counts = vec![0u32; PATTERN_COUNT * PATTERN_COUNT];
+        let mut wins = 0usize;
+        for target in possible_words {
+            let a = algorithms::feedback_index(first.bytes(), target.bytes());
+            let b = algorithms::feedback_index(second.bytes(), target.bytes());
+            counts[a * PATTERN_COUNT + b] += 1;
+            if a == solved || b == solved {
+                wins += 1;
+            }
+        }
+
+        let total = possible_words.len() as f64;
+        let entropy = counts
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * algorithms::log2(p)
+            })
+            .sum();
+        let worst_case_remaining = counts
+            .iter()
+            .enumerate()
+            .filter(|&(pair, _)| pair / PATTERN_COUNT != solved && pair % PATTERN_COUNT != solved)
+            .map(|(_, &count)| count as usize)
+            .max()
+            .unwrap_or(0);
+
+        Self {
+            entropy,
+            bucket_count: counts.iter().filter(|&&count| count > 0).count(),
+            worst_case_remaining,
+            answer_probability: if wins == 0 { 0.0 } else { wins as f64 / total },
+        }
+    }
+}
+
+/// Live score of a partly typed guess against the remaining possible answers
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrefixScore {
+    /// Letters typed so far, lowercase
+    pub prefix: String,
+    /// Remaining answers that start with the prefix
+    pub consistent: usize,
+    /// The most informative of those answers, with its expected bits
+    pub best_completion: Option<(Word, f64)>,
+}
+
+impl PrefixScore {
+    /// Score `prefix` against `possible_words`; ties go to the crate-wide ranking order
+    pub fn evaluate(prefix: &str, possible_words: &[Word]) -> Self {
+        use crate::core::ranking::CandidateRanker;
+
+        let prefix = prefix.to_ascii_lowercase();
+        let scored: Vec<(&Word, f64)> = possible_words
+            .iter()
+            .filter(|word| word.as_str().starts_with(&prefix))
+            .map(|word| {
+                let bits = SuggestionQuality::evaluate(word, possible_words).entropy;
+                (word, bits)
+            })
+            .collect();
+        let ranker = CandidateRanker::new(possible_words);
+        let best_completion = scored
+            .iter()
+            .min_by(|a, b| ranker.compare(**a, **b))
+            .map(|&(word, bits)| (word.clone(), bits));
+        Self {
+            prefix,
+            consistent: scored.len(),
+            best_completion,
+        }
+    }
+}
+
+/// Trade-off a suggestion in a [`SuggestionSet`] is picked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuggestionKind {
+    /// Most expected information, answer or not
+    Information,
+    /// Best-splitting word that could itself be the answer
+    AnswerChance,
+    /// Information plus a bonus for winning outright
+    Balanced,
+}
+
+impl SuggestionKind {
+    pub const ALL: [SuggestionKind; 3] = [Self::Information, Self::AnswerChance, Self::Balanced];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Information => "Best info",
+            Self::AnswerChance => "Best chance",
+            Self::Balanced => "Balanced",
+        }
+    }
+}
+
+/// A suggested guess with its risk profile
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub word: Word,
+    pub quality: SuggestionQuality,
+}
+
+/// A top-ranked guess with its strategy score and its chance of being the answer
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedCandidate {
+    pub word: Word,
+    /// The strategy's score, in bits for the entropy strategy
+    pub score: f64,
+    /// The guess's share of the remaining words' prior weight, 0 if it can't be the answer
+    pub answer_probability: f64,
+}
+
+impl RankedCandidate {
+    /// Attach answer chances to `(word, score)` rankings, every possible word being equally likely
+    pub fn rank(top: &[(Word, f64)], possible_words: &[Word]) -> Vec<Self> {
+        Self::rank_weighted(top, possible_words, |_| 1.0)
+    }
+
+    /// Attach answer chances to `(word, score)` rankings, each possible word being as
+    /// likely as its `weight` (see [`AnswerPrior`](crate::core::morphology::AnswerPrior))
+    pub fn rank_weighted(
+        top: &[(Word, f64)],
+        possible_words: &[Word],
+        weight: impl Fn(&Word) -> f64,
+    ) -> Vec<Self> {
+        let total: f64 = possible_words.iter().map(&weight).sum();
+        top.iter()
+            .map(|(word, score)| Self {
+                word: word.clone(),
+                score: *score,
+                answer_probability: if total > 0.0 && possible_words.contains(word) {
+                    weight(word) / total
+                } else {
+                    0.0
+                },
+            })
+            .collect()
+    }
+}
+
+/// One suggestion per [`SuggestionKind`] for the same turn
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuggestionSet {
+    pub information: Suggestion,
+    pub answer_chance: Suggestion,
+    pub balanced: Suggestion,
+}
+
+impl SuggestionSet {
+    /// Pick a suggestion of each kind from already evaluated guesses
+    ///
+    /// The balanced score is the expected information plus, when the guess
+    /// wins outright, the bits that would otherwise still be missing. Ties
+    /// follow [`CandidateRanker`](crate::core::ranking::CandidateRanker).
+    /// Returns `None` if `evaluated` is empty.
+    pub fn select(evaluated: &[Suggestion], possible_words: &[Word]) -> Option<Self> {
+        let ranker = crate::core::ranking::CandidateRanker::new(possible_words);
+        let remaining_bits = (possible_words.len().max(1) as f64).log2();
+        let best = |score: &dyn Fn(&SuggestionQuality) -> f64, answers_only: bool| {
+            let scored = evaluated
+                .iter()
+                .filter(|s| !answers_only || s.quality.answer_probability > 0.0)
+                .map(|s| (&s.word, score(&s.quality)));
+            let word = ranker.best(scored)?;
+            evaluated.iter().find(|s| &s.word == word).cloned()
+        };
+        let entropy = |q: &SuggestionQuality| q.entropy;
+
+        let information = best(&entropy, false)?;
+        let answer_chance = best(&entropy, true).unwrap_or_else(|| information.clone());
+        let balanced = best(
+            &|q: &SuggestionQuality| q.entropy + q.answer_probability * remaining_bits,
+            false,
+        )?;
+        Some(Self {
+            information,
+            answer_chance,
+            balanced,
+        })
+    }
+
+    pub fn get(&self, kind: SuggestionKind) -> &Suggestion {
+        match kind {
+            SuggestionKind::Information => &self.information,
+            SuggestionKind::AnswerChance => &self.answer_chance,
+            SuggestionKind::Balanced => &self.balanced,
+        }
+    }
+
+    /// The suggestions in [`SuggestionKind::ALL`] order
+    pub fn iter(&self) -> impl Iterator<Item = (SuggestionKind, &Suggestion)> {
+        SuggestionKind::ALL.into_iter().map(|kind| (kind, self.get(kind)))
+    }
+}
+
+/// A guess that tells apart remaining words differing in only a position or two
+///
+/// For shale/shame/shake/share the distinguishing letters are l, m, k and r,
+/// and a guess like MACHE checks most of them at once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disambiguator {
+    pub word: Word,
+    /// Letters that separate the remaining words
+    pub distinguishing: Vec<char>,
+    /// Distinguishing letters contained in `word`
+    pub covered: Vec<char>,
+    pub quality: SuggestionQuality,
+}
+
+/// Words sharing every letter but one position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordCluster {
+    /// The shared letters, `_` marking the position that varies (e.g. "_ATCH")
+    pub pattern: String,
+    pub words: Vec<Word>,
+}
+
+impl WordCluster {
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+/// A family of answers sharing four letters, with the guesses that split it best
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrapCluster {
+    pub family: WordCluster,
+    /// Best disambiguating guesses for the family, best first
+    pub guesses: Vec<Disambiguator>,
+}
+
+/// Fixtures for tests elsewhere in the crate
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::Word;
+
+    /// `list` parsed as words
+    pub(crate) fn words(list: &[&str]) -> Vec<Word> {
+        list.iter().map(|w| Word::from_str(w).unwrap()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_guess_explains_rejections() {
+        assert_eq!(Word::parse_guess(" Crane ").unwrap().as_str(), "crane");
+        assert!(matches!(
+            Word::parse_guess("cran"),
+            Err(GameError::InvalidWordLength {
+                expected: 5,
+                actual: 4
+            })
+        ));
+        assert!(matches!(Word::parse_guess("cr4ne"), Err(GameError::InvalidCharacters(_))));
+        assert!(matches!(Word::parse_guess("crané"), Err(GameError::InvalidCharacters(_))));
+    }
+
+    #[test]
+    fn test_pattern_enumeration_matches_encoding() {
+        assert_eq!(FeedbackPattern::all().len(), PATTERN_COUNT);
+        for (index, pattern) in FeedbackPattern::all().enumerate() {
+            assert_eq!(pattern.index() as usize, index);
+            assert_eq!(FeedbackPattern::from_index(index as u16), Some(pattern));
+        }
+        assert_eq!(FeedbackPattern::from_index(PATTERN_COUNT as u16), None);
+        assert!(FeedbackPattern::all().last().unwrap().is_win());
+
+        // First letter is the least significant digit
+        let pattern = FeedbackPattern::from_code_string("21000").unwrap();
+        assert_eq!(pattern.index(), 2 + 3);
+        let target = Word::from_str("crane").unwrap();
+        let guess = Word::from_str("caddy").unwrap();
+        let index = algorithms::feedback_index(guess.bytes(), target.bytes());
+        assert_eq!(FeedbackPattern::from_index(index as u16), Some(pattern));
+    }
+
+    #[test]
+    fn test_turn_entropy() {
+        let before: Vec<Word> = ["crane", "crate", "trace", "slate"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+        let turn = TurnEntropy::measure(
+            Word::from_str("fuzzy").unwrap(),
+            Some(Word::from_str("crate").unwrap()),
+            &before,
+            1,
+        );
+        // fuzzy can't split anything; the feedback still left one of four
+        assert_eq!(turn.played_entropy, 0.0);
+        assert!((turn.bits_gained - 2.0).abs() < 1e-9);
+        let (_, suggested_bits) = turn.suggested.clone().unwrap();
+        assert!(suggested_bits > 1.0);
+        assert_eq!(turn.shortfall(), suggested_bits);
+
+        let mut stats = SolverStatistics::new();
+        stats.entropy_scores = vec![turn.clone(), turn];
+        assert!((stats.bits_gained() - 4.0).abs() < 1e-9);
+        assert_eq!(TurnEntropy::measure(before[0].clone(), None, &before, 0).bits_gained, 0.0);
+    }
+
+    #[test]
+    fn test_pattern_table() {
+        let table = FeedbackPattern::table(2, |p| {
+            (p.index() < 3 || p.is_win()).then(|| p.to_code_string())
+        });
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "  0 ⬜⬜⬜⬜⬜ 00000    1 🟨⬜⬜⬜⬜ 10000");
+        assert_eq!(lines[1], "  2 🟩⬜⬜⬜⬜ 20000  242 🟩🟩🟩🟩🟩 22222");
+        assert_eq!(FeedbackPattern::table(4, |_| None), "");
+    }
+
+    #[test]
+    fn test_manual_constraints() {
+        let pinned = ManualConstraint::parse("A3").unwrap();
+        assert_eq!(pinned, ManualConstraint::Pinned { letter: 'a', position: 2 });
+        assert_eq!(ManualConstraint::parse("3a").unwrap(), pinned);
+        assert_eq!(pinned.to_string(), "A3");
+        let banned = ManualConstraint::parse("!x").unwrap();
+        assert_eq!(banned, ManualConstraint::Banned { letter: 'x' });
+        assert_eq!(ManualConstraint::parse_list("a3, -x").unwrap(), [pinned, banned]);
+        for bad in ["a6", "a0", "ax", "-3", "abc", ""] {
+            assert!(ManualConstraint::parse(bad).is_err(), "{}", bad);
+        }
+
+        assert!(pinned.allows(&Word::from_str("plane").unwrap()));
+        assert!(!pinned.allows(&Word::from_str("cried").unwrap()));
+        assert!(!banned.allows(&Word::from_str("boxer").unwrap()));
+
+        let other_letter = ManualConstraint::parse("e3").unwrap();
+        assert!(pinned.conflict(&other_letter).is_some());
+        assert!(pinned.conflict(&ManualConstraint::parse("-a").unwrap()).is_some());
+        assert!(pinned.conflict(&ManualConstraint::parse("a4").unwrap()).is_none());
+        assert!(pinned.conflict(&banned).is_none());
+    }
+
+    #[test]
+    fn test_ranked_candidates_get_uniform_answer_chance() {
+        let words = |list: &[&str]| -> Vec<Word> {
+            list.iter().map(|w| Word::from_str(w).unwrap()).collect()
+        };
+        let possible = words(&["fight", "light", "might", "sight"]);
+        let top: Vec<(Word, f64)> =
+            words(&["flams", "light"]).into_iter().zip([2.0, 1.5]).collect();
+
+        let ranked = RankedCandidate::rank(&top, &possible);
+        assert_eq!(ranked[0].answer_probability, 0.0);
+        assert_eq!(ranked[1].answer_probability, 0.25);
+        assert_eq!(ranked[1].score, 1.5);
+
+        // "light" weighs twice the others: 2 of 5
+        let light = &top[1].0;
+        let weighted =
+            RankedCandidate::rank_weighted(&top, &possible, |w| if w == light { 2.0 } else { 1.0 });
+        assert_eq!(weighted[1].answer_probability, 0.4);
+    }
+
+    #[test]
+    fn test_suggestion_set_picks_by_kind() {
+        let words: Vec<Word> = ["fight", "light", "might", "night", "sight"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+        let evaluated: Vec<Suggestion> = ["flams", "night", "fling"]
+            .iter()
+            .map(|w| {
+                let word = Word::from_str(w).unwrap();
+                let quality = SuggestionQuality::evaluate(&word, &words);
+                Suggestion { word, quality }
+            })
+            .collect();
+
+        let set = SuggestionSet::select(&evaluated, &words).unwrap();
+        // "flams" splits all five but can't win; "night" is an answer but only splits two ways
+        assert_eq!(set.information.word.as_str(), "flams");
+        assert_eq!(set.answer_chance.word.as_str(), "night");
+        assert!(set.answer_chance.quality.answer_probability > 0.0);
+        assert_eq!(set.get(SuggestionKind::Balanced), &set.balanced);
+        assert!(SuggestionSet::select(&[], &words).is_none());
+    }
+
+    #[test]
+    fn test_suggestion_quality() {
+        let words: Vec<Word> = ["crane", "crate", "trace", "slate"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+
+        let quality = SuggestionQuality::evaluate(&words[0], &words);
+        assert_eq!(quality.bucket_count, 4);
+        assert_eq!(quality.worst_case_remaining, 1);
+        assert!((quality.answer_probability - 0.25).abs() < 1e-12);
+        assert!((quality.entropy - 2.0).abs() < 1e-12);
+
+        let outsider = Word::from_str("dumpy").unwrap();
+        let quality = SuggestionQuality::evaluate(&outsider, &words);
+        assert_eq!(quality.bucket_count, 1);
+        assert_eq!(quality.worst_case_remaining, 4);
+        assert_eq!(quality.answer_probability, 0.0);
+    }
+
+    #[test]
+    fn test_pair_quality_combines_both_guesses() {
+        let words: Vec<Word> = ["fight", "light", "might", "night", "sight", "tight"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+        let word = |w: &str| Word::from_str(w).unwrap();
+
+        // "flams" and "nitty" each split off a few; together they tell all six apart
+        let single = SuggestionQuality::evaluate(&word("flams"), &words);
+        let pair = SuggestionQuality::evaluate_pair(&word("flams"), &word("nitty"), &words);
+        assert!(single.bucket_count < 6);
+        assert_eq!(pair.bucket_count, 6);
+        assert_eq!(pair.worst_case_remaining, 1);
+        assert!((pair.entropy - (6.0f64).log2()).abs() < 1e-9);
+        assert_eq!(pair.answer_probability, 0.0);
+
+        let pair = SuggestionQuality::evaluate_pair(&word("fight"), &word("light"), &words);
+        assert!((pair.answer_probability - 2.0 / 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_prefix_score_counts_completions() {
+        let words: Vec<Word> = ["slate", "slant", "sling", "crane", "trace"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+
+        let score = PrefixScore::evaluate("SL", &words);
+        assert_eq!((score.prefix.as_str(), score.consistent), ("sl", 3));
+        let (best, bits) = score.best_completion.unwrap();
+        assert!(best.as_str().starts_with("sl"));
+        for word in &words[..3] {
+            assert!(SuggestionQuality::evaluate(word, &words).entropy <= bits);
+        }
+
+        let none = PrefixScore::evaluate("sx", &words);
+        assert_eq!(none.consistent, 0);
+        assert_eq!(none.best_completion, None);
+    }
+}
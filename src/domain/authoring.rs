@@ -0,0 +1,162 @@
+//! Difficulty of proposed answers, for puzzle authors
+//!
+//! A word is hard when the solver needs many guesses for it, when it sits in a
+//! family of near-identical words (the "_ATCH" trap) or when its letters are
+//! rare among the answers.
+
+use crate::core::{
+    alphabet::LETTER_COUNT,
+    types::{Word, WordCluster},
+};
+use std::cmp::Ordering;
+
+/// How hard a proposed answer is
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnswerReport {
+    pub word: Word,
+    /// Whether the word is in the loaded answer list; the solver can't find words outside it
+    pub in_answer_list: bool,
+    /// Guesses the solver needed from its best opener; None if it ran out of guesses
+    pub solver_guesses: Option<usize>,
+    /// Families of answers sharing four letters in place with the word, largest first
+    pub families: Vec<WordCluster>,
+    /// 0 when every answer has the word's letters, 1 when none do
+    pub letter_rarity: f64,
+}
+
+impl AnswerReport {
+    /// Size of the largest family the word belongs to, 0 when it has none
+    pub fn trap_size(&self) -> usize {
+        self.families.first().map_or(0, WordCluster::len)
+    }
+
+    /// Ordering with the harder answer first: unsolved, more guesses, bigger trap, rarer letters
+    pub fn compare_difficulty(&self, other: &Self) -> Ordering {
+        let guesses = |r: &Self| r.solver_guesses.unwrap_or(usize::MAX);
+        guesses(other)
+            .cmp(&guesses(self))
+            .then_with(|| other.trap_size().cmp(&self.trap_size()))
+            .then_with(|| other.letter_rarity.total_cmp(&self.letter_rarity))
+            .then_with(|| self.word.cmp(&other.word))
+    }
+
+    /// Forecast for a day with this answer: the solver's guess count sets the level
+    /// (3 or fewer easy, 4 moderate, 5 hard, more or failed brutal), and a large trap
+    /// family or rare letters each add one
+    pub fn forecast(&self) -> Difficulty {
+        let mut level = match self.solver_guesses {
+            Some(0..=3) => 0,
+            Some(4) => 1,
+            Some(5) => 2,
+            _ => 3,
+        };
+        level += usize::from(self.trap_size() >= TRAP_FORECAST_SIZE);
+        level += usize::from(self.letter_rarity >= RARE_LETTERS_FORECAST);
+        match level {
+            0 => Difficulty::Easy,
+            1 => Difficulty::Moderate,
+            2 => Difficulty::Hard,
+            _ => Difficulty::Brutal,
+        }
+    }
+}
+
+/// Overall difficulty of an answer, from an [`AnswerReport`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    Easy,
+    Moderate,
+    Hard,
+    Brutal,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [Self::Easy, Self::Moderate, Self::Hard, Self::Brutal];
+
+    /// The difficulty with this [`label`](Self::label), ignoring case
+    pub fn from_label(label: &str) -> Option<Self> {
+        let label = label.trim();
+        Self::ALL
+            .into_iter()
+            .find(|d| d.label().eq_ignore_ascii_case(label))
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Easy => "easy",
+            Self::Moderate => "moderate",
+            Self::Hard => "hard",
+            Self::Brutal => "brutal",
+        }
+    }
+}
+
+/// Family size from which a trap makes an answer a level harder
+pub const TRAP_FORECAST_SIZE: usize = 6;
+
+/// Letter rarity from which an answer is a level harder
+pub const RARE_LETTERS_FORECAST: f64 = 0.8;
+
+/// Mean share of `answers` missing each letter of `word`; 0.0 for an empty list
+pub fn letter_rarity(word: &Word, answers: &[Word]) -> f64 {
+    if answers.is_empty() {
+        return 0.0;
+    }
+    let alphabet = Word::ALPHABET;
+    let mut containing = [0usize; LETTER_COUNT];
+    for answer in answers {
+        let mut seen = [false; LETTER_COUNT];
+        for &b in answer.bytes() {
+            seen[alphabet.position(b)] = true;
+        }
+        for (count, seen) in containing.iter_mut().zip(seen) {
+            *count += seen as usize;
+        }
+    }
+    let missing: f64 = word
+        .bytes()
+        .iter()
+        .map(|&b| 1.0 - containing[alphabet.position(b)] as f64 / answers.len() as f64)
+        .sum();
+    missing / Word::LENGTH as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::testing::words;
+
+    #[test]
+    fn test_letter_rarity() {
+        let answers = words(&["crane", "slate", "fuzzy"]);
+        let common = letter_rarity(&Word::from_str("trace").unwrap(), &answers);
+        let rare = letter_rarity(&Word::from_str("jumpy").unwrap(), &answers);
+        assert!(common < rare);
+        assert_eq!(letter_rarity(&Word::from_str("fuzzy").unwrap(), &[]), 0.0);
+    }
+
+    #[test]
+    fn test_harder_answers_sort_first() {
+        let report = |word: &str, solver_guesses| AnswerReport {
+            word: Word::from_str(word).unwrap(),
+            in_answer_list: true,
+            solver_guesses,
+            families: Vec::new(),
+            letter_rarity: 0.5,
+        };
+        let mut reports = [
+            report("crane", Some(3)),
+            report("hatch", None),
+            report("jazzy", Some(5)),
+        ];
+        reports.sort_by(AnswerReport::compare_difficulty);
+        let order: Vec<&str> = reports.iter().map(|r| r.word.as_str()).collect();
+        assert_eq!(order, ["hatch", "jazzy", "crane"]);
+
+        assert_eq!(reports[2].forecast(), Difficulty::Easy);
+        assert_eq!(reports[1].forecast(), Difficulty::Hard);
+        reports[2].letter_rarity = 0.9;
+        assert_eq!(reports[2].forecast(), Difficulty::Moderate);
+        assert_eq!(reports[0].forecast(), Difficulty::Brutal);
+    }
+}
@@ -0,0 +1,106 @@
+//! What the latest guess did to the possible words
+//!
+//! Compares the possible words before a guess with those after it. The
+//! notable words are the most typical-looking ones before the guess (made of
+//! the letters most of them share), so the diff shows familiar words being
+//! crossed out rather than the alphabetically first few.
+
+use crate::core::{
+    alphabet::LETTER_COUNT,
+    types::{Guess, Word},
+};
+use std::collections::HashSet;
+
+/// Notable words listed in a diff
+pub const NOTABLE_CANDIDATES: usize = 10;
+
+/// Possible words before and after a guess
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateDiff {
+    pub guess: Guess,
+    /// Possible words before the guess
+    pub before: usize,
+    /// Possible words after it
+    pub after: usize,
+    /// The most notable words before the guess, most notable first, each with
+    /// whether it survived
+    pub notable: Vec<(Word, bool)>,
+}
+
+impl CandidateDiff {
+    /// Diff of `previous` against `remaining` for `guess`, listing up to `limit`
+    /// notable words
+    pub fn between(guess: Guess, previous: &[Word], remaining: &[Word], limit: usize) -> Self {
+        let survivors: HashSet<&Word> = remaining.iter().collect();
+        let notable = most_typical(previous, limit)
+            .into_iter()
+            .map(|word| {
+                let survived = survivors.contains(word);
+                (word.clone(), survived)
+            })
+            .collect();
+        Self {
+            guess,
+            before: previous.len(),
+            after: remaining.len(),
+            notable,
+        }
+    }
+
+    /// Words the guess ruled out
+    pub fn eliminated(&self) -> usize {
+        self.before.saturating_sub(self.after)
+    }
+}
+
+/// Up to `limit` of `words` whose letters are the most common among them, ties
+/// alphabetical
+pub(crate) fn most_typical(words: &[Word], limit: usize) -> Vec<&Word> {
+    let alphabet = Word::ALPHABET;
+    let mut containing = [0usize; LETTER_COUNT];
+    for word in words {
+        let mut seen = [false; LETTER_COUNT];
+        for &b in word.bytes() {
+            seen[alphabet.position(b)] = true;
+        }
+        for (count, seen) in containing.iter_mut().zip(seen) {
+            *count += seen as usize;
+        }
+    }
+    let coverage = |word: &Word| -> usize {
+        word.bytes()
+            .iter()
+            .map(|&b| containing[alphabet.position(b)])
+            .sum()
+    };
+    let mut ranked: Vec<(usize, &Word)> = words.iter().map(|w| (coverage(w), w)).collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(b.1)));
+    ranked.into_iter().take(limit).map(|(_, word)| word).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{FeedbackPattern, testing::words};
+
+    #[test]
+    fn test_diff_marks_survivors() {
+        let previous = words(&["crane", "crate", "trace", "slate", "fuzzy"]);
+        let remaining = words(&["crate", "trace"]);
+        let guess = Guess::new(
+            Word::from_str("tread").unwrap(),
+            FeedbackPattern::from_code_string("11110").unwrap(),
+        );
+
+        let diff = CandidateDiff::between(guess, &previous, &remaining, 4);
+        assert_eq!(diff.before, 5);
+        assert_eq!(diff.eliminated(), 3);
+        // fuzzy shares no letter with the rest, so it's the least notable
+        let listed: Vec<(&str, bool)> =
+            diff.notable.iter().map(|(w, survived)| (w.as_str(), *survived)).collect();
+        assert_eq!(
+            listed,
+            [("crate", true), ("trace", true), ("crane", false), ("slate", false)]
+        );
+    }
+}
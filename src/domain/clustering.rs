@@ -0,0 +1,134 @@
+//! Word families among the remaining answers
+//!
+//! Words that share four letters in place, like batch/catch/hatch/match, form
+//! a family named by their common pattern ("_ATCH"). Each word joins at most
+//! one family, the largest it belongs to.
+
+use crate::core::types::{Word, WordCluster};
+use std::collections::{HashMap, HashSet};
+
+/// Group `words` into families of at least `min_size`, largest first
+///
+/// Greedy: the biggest family is taken first and its words removed before the
+/// next is picked, so overlapping families don't share words. Words in no
+/// family of `min_size` are left out. Ties are ordered by pattern.
+pub fn cluster_words(words: &[Word], min_size: usize) -> Vec<WordCluster> {
+    let min_size = min_size.max(2);
+    let mut families: HashMap<(usize, [u8; Word::LENGTH]), Vec<&Word>> = HashMap::new();
+    for word in words {
+        for pos in 0..Word::LENGTH {
+            let mut key = *word.bytes();
+            key[pos] = b'_';
+            families.entry((pos, key)).or_default().push(word);
+        }
+    }
+
+    let mut families: Vec<_> = families
+        .into_iter()
+        .filter(|(_, members)| members.len() >= min_size)
+        .collect();
+    let mut taken = HashSet::new();
+    let mut clusters = Vec::new();
+    loop {
+        // Sizes shrink as words are taken, so re-rank before every pick
+        families.retain(|(_, members)| members.len() >= min_size);
+        let Some(best) = families
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| b.1.len().cmp(&a.1.len()).then_with(|| a.0 .1.cmp(&b.0 .1)))
+            .map(|(i, _)| i)
+        else {
+            break;
+        };
+        let ((_, key), members) = families.swap_remove(best);
+        let mut members: Vec<Word> = members.into_iter().cloned().collect();
+        members.sort();
+        taken.extend(members.iter().cloned());
+        for (_, others) in families.iter_mut() {
+            others.retain(|w| !taken.contains(*w));
+        }
+        clusters.push(WordCluster {
+            pattern: String::from_utf8_lossy(&key).to_uppercase(),
+            words: members,
+        });
+    }
+    clusters
+}
+
+/// The family every one of `words` belongs to, when they differ in exactly one position
+pub fn common_family(words: &[Word]) -> Option<WordCluster> {
+    let [pos] = crate::domain::differing_positions(words)[..] else {
+        return None;
+    };
+    let mut key = *words[0].bytes();
+    key[pos] = b'_';
+    let mut words = words.to_vec();
+    words.sort();
+    Some(WordCluster {
+        pattern: String::from_utf8_lossy(&key).to_uppercase(),
+        words,
+    })
+}
+
+/// Every family of at least `min_size` among `words` that `word` belongs to, largest first
+///
+/// Unlike [`cluster_words`] the families may overlap, and `word` counts as a
+/// member even if it isn't in `words`.
+pub fn families_of(word: &Word, words: &[Word], min_size: usize) -> Vec<WordCluster> {
+    let mut families: Vec<WordCluster> = (0..Word::LENGTH)
+        .map(|pos| {
+            let mut members: Vec<Word> = words
+                .iter()
+                .filter(|w| (0..Word::LENGTH).all(|i| i == pos || w.bytes()[i] == word.bytes()[i]))
+                .cloned()
+                .collect();
+            if !members.contains(word) {
+                members.push(word.clone());
+            }
+            members.sort();
+            let mut key = *word.bytes();
+            key[pos] = b'_';
+            WordCluster {
+                pattern: String::from_utf8_lossy(&key).to_uppercase(),
+                words: members,
+            }
+        })
+        .filter(|family| family.len() >= min_size.max(2))
+        .collect();
+    families.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.pattern.cmp(&b.pattern)));
+    families
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_families_are_disjoint_and_largest_first() {
+        let words: Vec<Word> = [
+            "batch", "catch", "hatch", "latch", "match", "patch", "watch", "mitch", "mulch",
+            "crane",
+        ]
+        .iter()
+        .map(|w| Word::from_str(w).unwrap())
+        .collect();
+
+        let clusters = cluster_words(&words, 2);
+        assert_eq!(clusters[0].pattern, "_ATCH");
+        assert_eq!(clusters[0].len(), 7);
+        // "match" is already in _ATCH, which leaves M_TCH with only "mitch"
+        assert!(clusters.iter().all(|c| c.pattern != "M_TCH"));
+        assert!(clusters.iter().all(|c| c.words.iter().all(|w| w.as_str() != "crane")));
+
+        assert!(cluster_words(&words, 8).is_empty());
+
+        // Overlapping families are all reported for a single word
+        let families = families_of(&Word::from_str("match").unwrap(), &words, 2);
+        let patterns: Vec<&str> = families.iter().map(|f| f.pattern.as_str()).collect();
+        assert_eq!(patterns, ["_ATCH", "M_TCH"]);
+
+        let left: Vec<Word> = words[1..4].to_vec();
+        assert_eq!(common_family(&left).unwrap().pattern, "_ATCH");
+        assert!(common_family(&words).is_none());
+    }
+}
@@ -0,0 +1,81 @@
+//! Completing a partly typed guess from the dictionary
+//!
+//! [`PrefixIndex`] keeps the valid guesses sorted, so the words starting with a
+//! prefix are one contiguous range, found by two binary searches.
+
+use crate::core::types::Word;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Valid guesses, searchable by prefix
+#[derive(Debug, Clone, Default)]
+pub struct PrefixIndex {
+    words: Arc<Vec<Word>>,
+}
+
+impl PrefixIndex {
+    /// Index `words`, which must be sorted (as the solver's candidate list is)
+    pub fn new(words: Arc<Vec<Word>>) -> Self {
+        debug_assert!(words.windows(2).all(|pair| pair[0] <= pair[1]));
+        Self { words }
+    }
+
+    /// Number of words indexed
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether no words are indexed
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Every word starting with `prefix`, in alphabetical order
+    pub fn matching(&self, prefix: &str) -> &[Word] {
+        let prefix = prefix.to_ascii_lowercase();
+        let start = self
+            .words
+            .partition_point(|word| word.as_str() < prefix.as_str());
+        let len = self.words[start..].partition_point(|word| word.as_str().starts_with(&prefix));
+        &self.words[start..start + len]
+    }
+
+    /// Words starting with `prefix`, the `remaining` possible answers first
+    pub fn completions(&self, prefix: &str, remaining: &[Word]) -> Vec<Word> {
+        let remaining: HashSet<&Word> = remaining.iter().collect();
+        let (mut answers, others): (Vec<Word>, Vec<Word>) = self
+            .matching(prefix)
+            .iter()
+            .cloned()
+            .partition(|word| remaining.contains(word));
+        answers.extend(others);
+        answers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::testing::words;
+
+    #[test]
+    fn test_completions_put_remaining_answers_first() {
+        let index = PrefixIndex::new(Arc::new(words(&[
+            "crane", "crate", "slant", "slate", "sling", "slope", "trace",
+        ])));
+
+        assert_eq!(
+            index.matching("SL"),
+            words(&["slant", "slate", "sling", "slope"])
+        );
+        assert_eq!(index.matching("cra"), words(&["crane", "crate"]));
+        assert!(index.matching("zz").is_empty());
+        assert!(index.matching("tracer").is_empty());
+
+        let remaining = words(&["slope", "sling", "trace"]);
+        assert_eq!(
+            index.completions("sl", &remaining),
+            words(&["sling", "slope", "slant", "slate"])
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::core::{
+    alphabet::LETTER_COUNT,
     traits::ConstraintFilter,
-    types::{Guess, Word},
+    types::{Feedback, Guess, Word},
 };
 
 /// Default implementation of constraint filtering
@@ -38,10 +39,77 @@ impl ConstraintFilter for DefaultConstraintFilter {
     }
 }
 
+/// Whether `word` may be played in hard mode after `history`
+///
+/// Hard mode only requires revealed hints to be reused: green letters stay in
+/// place and every green or yellow letter appears at least as often as it was
+/// revealed. Unlike [`ConstraintFilter::satisfies_constraints`], grey letters
+/// and yellow positions may be played again.
+pub fn is_hard_mode_legal(word: &Word, history: &[Guess]) -> bool {
+    hard_mode_violation(word, history).is_none()
+}
+
+/// Whether every guess in `history` reused the hints revealed before it
+///
+/// Needs at least one hint to have been reused: after an all-grey first row any
+/// second guess is legal, which says nothing about how the player plays.
+pub fn played_in_hard_mode(history: &[Guess]) -> bool {
+    let mut hint_reused = false;
+    for (i, guess) in history.iter().enumerate().skip(1) {
+        let earlier = &history[..i];
+        if !is_hard_mode_legal(&guess.word, earlier) {
+            return false;
+        }
+        hint_reused |= earlier
+            .iter()
+            .any(|g| g.feedback.as_slice().iter().any(|f| *f != Feedback::Absent));
+    }
+    hint_reused
+}
+
+/// The first revealed hint `word` fails to reuse, e.g. "letter 1 must be C"
+///
+/// `None` when the word is legal in hard mode; see [`is_hard_mode_legal`].
+pub fn hard_mode_violation(word: &Word, history: &[Guess]) -> Option<String> {
+    let alphabet = Word::ALPHABET;
+    let letters = word.bytes();
+    let mut available = [0u8; LETTER_COUNT];
+    for &b in letters {
+        available[alphabet.position(b)] += 1;
+    }
+    history.iter().find_map(|guess| {
+        let guessed = guess.word.bytes();
+        let mut required = [0u8; LETTER_COUNT];
+        for (pos, feedback) in guess.feedback.as_slice().iter().enumerate() {
+            let letter = (guessed[pos] as char).to_ascii_uppercase();
+            match feedback {
+                Feedback::Correct if letters[pos] != guessed[pos] => {
+                    return Some(format!("letter {} must be {}", pos + 1, letter));
+                }
+                Feedback::Correct | Feedback::Present => {
+                    required[alphabet.position(guessed[pos])] += 1;
+                }
+                Feedback::Absent => {}
+            }
+        }
+        required
+            .iter()
+            .zip(available)
+            .position(|(&need, have)| have < need)
+            .map(|i| {
+                let letter = alphabet.letter(i).unwrap_or('?').to_ascii_uppercase();
+                match required[i] {
+                    1 => format!("it must contain {}", letter),
+                    n => format!("it must contain {} {}s", n, letter),
+                }
+            })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::types::FeedbackPattern;
+    use crate::core::types::{FeedbackPattern, ManualConstraint};
 
     #[test]
     fn test_constraint_filtering() {
@@ -66,4 +134,69 @@ mod tests {
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0].as_str(), "apple");
     }
+
+    #[test]
+    fn test_manual_constraints_merge_with_guesses() {
+        let filter = DefaultConstraintFilter::new();
+        let words: Vec<Word> = ["crane", "crate", "grate", "trace"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+        // "slate" leaves crate and grate; the friend says R is second and there's no G
+        let guess = Guess::new(
+            Word::from_str("slate").unwrap(),
+            FeedbackPattern::from_code_string("00222").unwrap(),
+        );
+        let manual = ManualConstraint::parse_list("r2 -g").unwrap();
+        let filtered = filter.filter_words_with_manual(&words, &[guess], &manual);
+        assert_eq!(filtered, [Word::from_str("crate").unwrap()]);
+    }
+
+    #[test]
+    fn test_hard_mode_legality() {
+        let history = [Guess::new(
+            Word::from_str("crane").unwrap(),
+            FeedbackPattern::from_code_string("21000").unwrap(),
+        )];
+        let legal = |w: &str| is_hard_mode_legal(&Word::from_str(w).unwrap(), &history);
+
+        // Keeps the green C and reuses the yellow R, even with a grey E
+        assert!(legal("curve"));
+        assert!(!legal("trace"));
+        assert!(!legal("cloud"));
+
+        let why = |w: &str| hard_mode_violation(&Word::from_str(w).unwrap(), &history);
+        assert_eq!(why("trace").unwrap(), "letter 1 must be C");
+        assert_eq!(why("cloud").unwrap(), "it must contain R");
+        assert_eq!(why("curve"), None);
+    }
+
+    #[test]
+    fn test_confirmed_positions_collect_greens() {
+        let history = [
+            Guess::new(
+                Word::from_str("crane").unwrap(),
+                FeedbackPattern::from_code_string("21000").unwrap(),
+            ),
+            Guess::new(
+                Word::from_str("curve").unwrap(),
+                FeedbackPattern::from_code_string("20102").unwrap(),
+            ),
+        ];
+        let confirmed = DefaultConstraintFilter::new().confirmed_positions(&history);
+        assert_eq!(confirmed, [Some('c'), None, None, None, Some('e')]);
+    }
+
+    #[test]
+    fn test_hard_mode_inference() {
+        let guess = |w: &str, f: &str| {
+            Guess::new(Word::from_str(w).unwrap(), FeedbackPattern::from_code_string(f).unwrap())
+        };
+        let crane = guess("crane", "21000");
+        assert!(!played_in_hard_mode(std::slice::from_ref(&crane)));
+        assert!(played_in_hard_mode(&[crane.clone(), guess("curve", "20100")]));
+        assert!(!played_in_hard_mode(&[crane, guess("sloth", "00000")]));
+        // Nothing to reuse after an all-grey row
+        assert!(!played_in_hard_mode(&[guess("sloth", "00000"), guess("crane", "21000")]));
+    }
 }
@@ -0,0 +1,153 @@
+//! Elimination guesses for near-identical remaining words
+//!
+//! When the remaining words only differ in a position or two, guessing them
+//! one by one can take a turn each. A guess packed with the letters that
+//! differ usually settles it in one.
+
+use crate::core::{
+    ranking::score_desc,
+    types::{Disambiguator, SuggestionQuality, TrapCluster, Word},
+};
+use crate::domain::cluster_words;
+
+/// Most positions the remaining words may differ in to count as near-identical
+pub const MAX_DIFFERING_POSITIONS: usize = 2;
+
+/// Positions where not all of `words` have the same letter
+pub fn differing_positions(words: &[Word]) -> Vec<usize> {
+    let Some(first) = words.first() else {
+        return Vec::new();
+    };
+    (0..Word::LENGTH)
+        .filter(|&pos| words.iter().any(|w| w.bytes()[pos] != first.bytes()[pos]))
+        .collect()
+}
+
+/// The candidate containing the most letters that separate `possible_words`
+///
+/// Only applies to three or more words differing in at most
+/// [`MAX_DIFFERING_POSITIONS`] positions; two words are settled by guessing
+/// one of them. Ties go to the guess that splits the words best.
+pub fn find_disambiguator(possible_words: &[Word], candidates: &[Word]) -> Option<Disambiguator> {
+    rank_disambiguators(possible_words, candidates, 1).into_iter().next()
+}
+
+/// Up to `limit` disambiguating guesses, best first, ranked as in [`find_disambiguator`]
+///
+/// Guesses covering fewer than two separating letters are left out.
+pub fn rank_disambiguators(
+    possible_words: &[Word],
+    candidates: &[Word],
+    limit: usize,
+) -> Vec<Disambiguator> {
+    if possible_words.len() < 3 || limit == 0 {
+        return Vec::new();
+    }
+    let positions = differing_positions(possible_words);
+    if positions.is_empty() || positions.len() > MAX_DIFFERING_POSITIONS {
+        return Vec::new();
+    }
+
+    // Letters at the differing positions; one every word contains elsewhere
+    // (the c in catch/hatch) still separates them when it lands in place
+    let mut distinguishing: Vec<u8> = possible_words
+        .iter()
+        .flat_map(|w| positions.iter().map(move |&pos| w.bytes()[pos]))
+        .collect();
+    distinguishing.sort_unstable();
+    distinguishing.dedup();
+
+    let covered = |word: &Word| -> Vec<u8> {
+        distinguishing
+            .iter()
+            .copied()
+            .filter(|b| word.bytes().contains(b))
+            .collect()
+    };
+    let mut by_coverage: Vec<(usize, &Word)> = candidates
+        .iter()
+        .map(|w| (covered(w).len(), w))
+        .filter(|&(count, _)| count >= 2)
+        .collect();
+    by_coverage.sort_by_key(|&(count, _)| std::cmp::Reverse(count));
+
+    // Entropy only breaks ties, so it's only computed for the coverage levels that make the cut
+    let mut ranked = Vec::with_capacity(limit);
+    for level in by_coverage.chunk_by(|a, b| a.0 == b.0) {
+        if ranked.len() >= limit {
+            break;
+        }
+        let mut level: Vec<_> = level
+            .iter()
+            .map(|&(_, w)| (w, SuggestionQuality::evaluate(w, possible_words)))
+            .collect();
+        level.sort_by(|a, b| score_desc(a.1.entropy, b.1.entropy).then_with(|| a.0.cmp(b.0)));
+        let room = limit - ranked.len();
+        ranked.extend(level.into_iter().take(room).map(|(word, quality)| Disambiguator {
+            word: word.clone(),
+            distinguishing: distinguishing.iter().map(|&b| b as char).collect(),
+            covered: covered(word).into_iter().map(char::from).collect(),
+            quality,
+        }));
+    }
+    ranked
+}
+
+/// Families of at least `min_size` among `answers`, largest first, each with up to
+/// `guesses_per_trap` disambiguating guesses from `candidates`
+pub fn find_traps(
+    answers: &[Word],
+    candidates: &[Word],
+    min_size: usize,
+    guesses_per_trap: usize,
+) -> Vec<TrapCluster> {
+    cluster_words(answers, min_size.max(3))
+        .into_iter()
+        .map(|family| TrapCluster {
+            guesses: rank_disambiguators(&family.words, candidates, guesses_per_trap),
+            family,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::testing::words;
+
+    #[test]
+    fn test_finds_guess_covering_differing_letters() {
+        let possible = words(&["shale", "shame", "shake", "share"]);
+        assert_eq!(differing_positions(&possible), vec![3]);
+
+        let candidates = words(&["shale", "mache", "milky", "crane"]);
+        let found = find_disambiguator(&possible, &candidates).unwrap();
+        assert_eq!(found.distinguishing, vec!['k', 'l', 'm', 'r']);
+        assert_eq!(found.word.as_str(), "milky");
+        assert_eq!(found.covered, vec!['k', 'l', 'm']);
+
+        let family = words(&["catch", "hatch", "latch", "match"]);
+        let found = find_disambiguator(&family, &words(&["milch", "clamp"])).unwrap();
+        assert_eq!(found.distinguishing, vec!['c', 'h', 'l', 'm']);
+        assert_eq!(found.word.as_str(), "milch");
+
+        // Words that differ all over aren't a disambiguation problem
+        let spread = words(&["crane", "shale", "pious"]);
+        assert!(find_disambiguator(&spread, &candidates).is_none());
+    }
+
+    #[test]
+    fn test_traps_come_with_ranked_guesses() {
+        let answers = words(&[
+            "fight", "light", "might", "sight", "night", "crane", "slate", "pious",
+        ]);
+        let candidates = words(&["flams", "mings", "crane", "lymph"]);
+
+        let traps = find_traps(&answers, &candidates, 4, 2);
+        assert_eq!(traps.len(), 1);
+        assert_eq!(traps[0].family.pattern, "_IGHT");
+        let guesses: Vec<&str> = traps[0].guesses.iter().map(|g| g.word.as_str()).collect();
+        // Of f, l, m, n and s: flams covers four, mings three and lymph two
+        assert_eq!(guesses, ["flams", "mings"]);
+    }
+}
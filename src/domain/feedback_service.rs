@@ -1,6 +1,7 @@
 use crate::core::{
+    algorithms,
     traits::FeedbackGenerator,
-    types::{Feedback, FeedbackPattern, Word},
+    types::{FeedbackPattern, Word},
 };
 
 /// Default implementation of feedback generation
@@ -21,33 +22,8 @@ impl Default for DefaultFeedbackGenerator {
 
 impl FeedbackGenerator for DefaultFeedbackGenerator {
     fn generate_feedback(&self, guess: &Word, target: &Word) -> FeedbackPattern {
-        let gb = guess.bytes();
-        let tb = target.bytes();
-    let mut out = [Feedback::Absent; 5];
-    let mut used = [false; 5];
-
-        // Greens
-        for i in 0..5 {
-            if gb[i] == tb[i] {
-                out[i] = Feedback::Correct;
-                used[i] = true;
-            }
-        }
-        // Yellows
-        for i in 0..5 {
-            if matches!(out[i], Feedback::Absent) {
-                // scan remaining targets for the letter
-                let g = gb[i];
-                for j in 0..5 {
-                    if !used[j] && g == tb[j] {
-                        out[i] = Feedback::Present;
-                        used[j] = true;
-                        break;
-                    }
-                }
-            }
-        }
-        FeedbackPattern::from_array(out)
+        let digits = algorithms::feedback_digits(guess.bytes(), target.bytes());
+        FeedbackPattern::from_digits(digits)
     }
 
     fn is_consistent(&self, word: &Word, constraints: &[crate::core::types::Guess]) -> bool {
@@ -64,6 +40,7 @@ impl FeedbackGenerator for DefaultFeedbackGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::types::Feedback;
 
     #[test]
     fn test_feedback_generation() {
@@ -94,7 +71,7 @@ mod tests {
         let constraint = crate::core::types::Guess::new(guess, feedback);
 
         // Target should be consistent with the constraint
-        assert!(generator.is_consistent(&target, &[constraint.clone()]));
+        assert!(generator.is_consistent(&target, std::slice::from_ref(&constraint)));
 
         // A different word should not be consistent
         let different_word = Word::from_str("bread").unwrap();
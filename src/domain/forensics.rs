@@ -0,0 +1,179 @@
+//! Working back from a shared result to the guesses behind it
+//!
+//! A shared Wordle result is only a grid of squares, but with the answer known
+//! each row pins the guess down to the words that would have scored exactly
+//! that row. [`infer_guesses`] lists them per row.
+
+use crate::core::{
+    error::{Result, SolverError},
+    traits::FeedbackGenerator,
+    types::{Feedback, FeedbackPattern, Word},
+};
+use crate::domain::DefaultFeedbackGenerator;
+
+/// The rows of a shared emoji grid, first guess first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareGrid {
+    pub rows: Vec<FeedbackPattern>,
+}
+
+impl ShareGrid {
+    /// Read the grid out of a pasted share text
+    ///
+    /// Lines without squares, such as the "Wordle 1,234 4/6" header, are skipped;
+    /// a line with squares must hold exactly five of them.
+    pub fn parse(text: &str) -> Result<Self> {
+        let rows = text
+            .lines()
+            .filter(|line| line.chars().any(|c| Feedback::from_emoji(c).is_some()))
+            .enumerate()
+            .map(|(i, line)| {
+                FeedbackPattern::from_emoji_row(line).map_err(|msg| {
+                    SolverError::InvalidFeedback(format!("row {}: {}", i + 1, msg)).into()
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if rows.is_empty() {
+            let msg = "No feedback squares found".to_string();
+            return Err(SolverError::InvalidFeedback(msg).into());
+        }
+        Ok(Self { rows })
+    }
+
+    /// Whether the last row is all green
+    pub fn is_solved(&self) -> bool {
+        self.rows.last().is_some_and(FeedbackPattern::is_win)
+    }
+}
+
+/// The guesses that fit one row of the grid
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowInference {
+    pub feedback: FeedbackPattern,
+    /// Words that could also be the answer, alphabetically
+    pub answer_guesses: Vec<Word>,
+    /// The other valid guesses, alphabetically
+    pub other_guesses: Vec<Word>,
+}
+
+impl RowInference {
+    pub fn len(&self) -> usize {
+        self.answer_guesses.len() + self.other_guesses.len()
+    }
+
+    /// No word scores this row against the answer: the grid doesn't belong to it
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The only word that fits, when the row gives the guess away
+    pub fn unique(&self) -> Option<&Word> {
+        match (self.answer_guesses.as_slice(), self.other_guesses.as_slice()) {
+            ([word], []) | ([], [word]) => Some(word),
+            _ => None,
+        }
+    }
+}
+
+/// For each row of `grid`, the words from `answers` and `guesses` that score it against
+/// `answer`
+///
+/// With `hard_mode`, a row only keeps words that reuse the greens of the rows before
+/// it. The answer tells which letters those greens were; the letters behind yellows
+/// stay unknown, so they aren't checked.
+pub fn infer_guesses(
+    grid: &ShareGrid,
+    answer: &Word,
+    answers: &[Word],
+    guesses: &[Word],
+    hard_mode: bool,
+) -> Vec<RowInference> {
+    let feedback = DefaultFeedbackGenerator::new();
+    let mut pool: Vec<(Word, bool)> = answers
+        .iter()
+        .map(|w| (w.clone(), true))
+        .chain(guesses.iter().map(|w| (w.clone(), false)))
+        .collect();
+    pool.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+    pool.dedup_by(|a, b| a.0 == b.0);
+
+    let mut greens = [false; Word::LENGTH];
+    grid.rows
+        .iter()
+        .map(|&row| {
+            let mut inference = RowInference {
+                feedback: row,
+                answer_guesses: Vec::new(),
+                other_guesses: Vec::new(),
+            };
+            let reuses_greens = |word: &Word| {
+                (0..Word::LENGTH).all(|i| !greens[i] || word.bytes()[i] == answer.bytes()[i])
+            };
+            for (word, is_answer) in &pool {
+                if feedback.generate_feedback(word, answer) != row {
+                    continue;
+                }
+                if hard_mode && !reuses_greens(word) {
+                    continue;
+                }
+                if *is_answer {
+                    inference.answer_guesses.push(word.clone());
+                } else {
+                    inference.other_guesses.push(word.clone());
+                }
+            }
+            for (i, square) in row.as_slice().iter().enumerate() {
+                greens[i] |= *square == Feedback::Correct;
+            }
+            inference
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::testing::words;
+
+    #[test]
+    fn test_share_grid_skips_the_header() {
+        let grid = ShareGrid::parse("Wordle 1,234 3/6\n\n⬛🟨⬛⬛⬛\n🟩🟨⬛⬛🟩\n🟩🟩🟩🟩🟩\n").unwrap();
+        assert_eq!(grid.rows.len(), 3);
+        assert_eq!(grid.rows[1].to_code_string(), "21002");
+        assert!(grid.is_solved());
+
+        assert!(ShareGrid::parse("Wordle 1,234 X/6").is_err());
+        assert!(ShareGrid::parse("🟩🟩🟩🟩").is_err());
+    }
+
+    #[test]
+    fn test_rows_list_the_words_that_score_them() {
+        let answer = Word::from_str("crane").unwrap();
+        let answers = words(&["crane", "crate", "trace", "brine"]);
+        let guesses = words(&["crone", "crank", "sloth"]);
+        let grid = ShareGrid {
+            rows: ["00000", "22022", "22222"]
+                .iter()
+                .map(|code| FeedbackPattern::from_code_string(code).unwrap())
+                .collect(),
+        };
+
+        let rows = infer_guesses(&grid, &answer, &answers, &guesses, false);
+        assert_eq!(rows[0].other_guesses, words(&["sloth"]));
+        assert_eq!(rows[1].answer_guesses, Vec::<Word>::new());
+        assert_eq!(rows[1].unique(), Some(&Word::from_str("crone").unwrap()));
+        assert_eq!(rows[2].unique(), Some(&answer));
+        assert!(!rows.iter().any(RowInference::is_empty));
+
+        // "brine" scores 02022 against "crane" but drops the C that hard mode would keep
+        let grid = ShareGrid {
+            rows: ["20000", "02022"]
+                .iter()
+                .map(|code| FeedbackPattern::from_code_string(code).unwrap())
+                .collect(),
+        };
+        let answers = words(&["crane", "brine", "chump"]);
+        assert_eq!(infer_guesses(&grid, &answer, &answers, &[], false)[1].len(), 1);
+        assert!(infer_guesses(&grid, &answer, &answers, &[], true)[1].is_empty());
+    }
+}
@@ -6,11 +6,49 @@ use crate::core::{
 use async_trait::async_trait;
 use std::fmt;
 
+/// Guesses allowed in standard Wordle
+pub const DEFAULT_MAX_ATTEMPTS: usize = 6;
+
+/// Rules a game is played by, besides the guess budget and hard mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameRules {
+    /// Refuse a word already guessed this game instead of spending a turn on it,
+    /// as the official game does
+    pub reject_repeated_guesses: bool,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self {
+            reject_repeated_guesses: true,
+        }
+    }
+}
+
+impl GameRules {
+    /// Anything-goes rules: a word may be guessed again
+    pub fn lenient() -> Self {
+        Self {
+            reject_repeated_guesses: false,
+        }
+    }
+
+    /// Check that `word` may follow `history` under these rules
+    pub fn check_guess(&self, word: &Word, history: &[Guess]) -> Result<()> {
+        if self.reject_repeated_guesses && history.iter().any(|guess| &guess.word == word) {
+            return Err(GameError::RepeatedGuess(word.to_string()).into());
+        }
+        Ok(())
+    }
+}
+
 /// Default implementation of the Wordle game engine
 pub struct DefaultGameEngine {
     target_word: Option<Word>,
     history: Vec<Guess>,
     result: GameResult,
+    max_attempts: usize,
+    rules: GameRules,
     feedback_generator: Box<dyn FeedbackGenerator>,
 }
 
@@ -20,6 +58,8 @@ impl fmt::Debug for DefaultGameEngine {
             .field("target_word", &self.target_word)
             .field("history", &self.history)
             .field("result", &self.result)
+            .field("max_attempts", &self.max_attempts)
+            .field("rules", &self.rules)
             .field("feedback_generator", &"Box<dyn FeedbackGenerator>")
             .finish()
     }
@@ -32,10 +72,24 @@ impl DefaultGameEngine {
             target_word: None,
             history: Vec::new(),
             result: GameResult::InProgress,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            rules: GameRules::default(),
             feedback_generator,
         }
     }
 
+    /// Change the guess budget (at least one guess)
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Play by `rules` instead of the official ones
+    pub fn with_rules(mut self, rules: GameRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
     /// Create a new game engine with a feedback generator (async version for compatibility)
     pub async fn with_feedback_generator_async(feedback_generator: Box<dyn FeedbackGenerator>) -> Result<Self> {
         Ok(Self::with_feedback_generator(feedback_generator))
@@ -55,7 +109,9 @@ impl GameEngine for DefaultGameEngine {
     }
 
     fn set_target(&mut self, word: &Word) -> Result<()> {
+        // A new target starts a new game, so earlier guesses don't count against it
         self.target_word = Some(word.clone());
+        self.history.clear();
         self.result = GameResult::InProgress;
         Ok(())
     }
@@ -66,9 +122,10 @@ impl GameEngine for DefaultGameEngine {
         }
 
         let target = self.target_word.as_ref().ok_or(GameError::NoTargetWord)?;
+        self.rules.check_guess(guess, &self.history)?;
 
         let feedback = self.feedback_generator.generate_feedback(guess, target);
-        let guess_entry = Guess::new(guess.clone(), feedback.clone());
+        let guess_entry = Guess::new(guess.clone(), feedback);
 
         self.history.push(guess_entry);
 
@@ -78,8 +135,7 @@ impl GameEngine for DefaultGameEngine {
                 word: target.clone(),
                 attempts: self.history.len(),
             };
-        } else if self.history.len() >= 6 {
-            // Standard Wordle has 6 attempts
+        } else if self.history.len() >= self.max_attempts {
             self.result = GameResult::Failed {
                 attempts: self.history.len(),
                 reason: "Maximum attempts exceeded".to_string(),
@@ -104,6 +160,14 @@ impl GameEngine for DefaultGameEngine {
     fn get_history(&self) -> &[Guess] {
         &self.history
     }
+
+    fn target(&self) -> Option<&Word> {
+        self.target_word.as_ref()
+    }
+
+    fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
 }
 
 #[cfg(test)]
@@ -131,4 +195,41 @@ mod tests {
         assert!(game.is_finished());
         assert!(game.get_result().is_won());
     }
+
+    #[tokio::test]
+    async fn test_guess_budget() {
+        let mut game = DefaultGameEngine::new().await.unwrap().with_max_attempts(2);
+        game.set_target(&Word::from_str("apple").unwrap()).unwrap();
+
+        game.make_guess(&Word::from_str("crane").unwrap()).unwrap();
+        assert!(!game.is_finished());
+        game.make_guess(&Word::from_str("about").unwrap()).unwrap();
+        assert!(matches!(game.get_result(), GameResult::Failed { attempts: 2, .. }));
+
+        // A new target starts over with the full budget
+        game.set_target(&Word::from_str("crane").unwrap()).unwrap();
+        assert_eq!(game.attempts_count(), 0);
+        assert!(!game.is_finished());
+    }
+
+    #[tokio::test]
+    async fn test_repeated_guess_is_refused_without_a_turn() {
+        let mut game = DefaultGameEngine::new().await.unwrap();
+        game.set_target(&Word::from_str("apple").unwrap()).unwrap();
+        let crane = Word::from_str("crane").unwrap();
+        game.make_guess(&crane).unwrap();
+        let error = game.make_guess(&crane).unwrap_err();
+        assert!(error.to_string().contains("already guessed"));
+        assert_eq!(game.attempts_count(), 1);
+
+        let mut lenient = DefaultGameEngine::new()
+            .await
+            .unwrap()
+            .with_rules(GameRules::lenient());
+        let apple = Word::from_str("apple").unwrap();
+        lenient.set_target(&apple).unwrap();
+        lenient.make_guess(&crane).unwrap();
+        lenient.make_guess(&crane).unwrap();
+        assert_eq!(lenient.attempts_count(), 2);
+    }
 }
@@ -0,0 +1,93 @@
+//! Game events for spectators
+//!
+//! The application service publishes what happens in a game on a broadcast
+//! channel. Any number of listeners (the TUI log, webhooks, a streaming
+//! endpoint) subscribe and follow along instead of polling the state after
+//! every command. Publishing never blocks: a listener that falls more than
+//! [`EVENT_BUFFER`] events behind skips the oldest ones.
+
+use crate::core::types::{Guess, Word};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// Events buffered per listener before the oldest are dropped
+pub const EVENT_BUFFER: usize = 64;
+
+/// Something that happened in a game
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    /// A new game began; `target_set` when the game engine scores the guesses
+    GameStarted { target_set: bool },
+    /// A guess was played; its feedback follows
+    GuessMade { word: Word, attempt: usize },
+    /// Feedback for a guess, scored by the engine or entered by the player
+    FeedbackIssued { guess: Guess, remaining: usize },
+    /// The latest guess was taken back
+    GuessUndone { guess: Guess },
+    /// The game was won or ran out of guesses
+    GameFinished {
+        won: bool,
+        /// Target word, when known
+        target: Option<Word>,
+        guesses: Vec<Guess>,
+        duration: Duration,
+    },
+}
+
+/// Sending side of the game event channel
+#[derive(Debug, Clone)]
+pub struct GameEventBus {
+    sender: broadcast::Sender<GameEvent>,
+}
+
+impl Default for GameEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameEventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUFFER);
+        Self { sender }
+    }
+
+    /// Listen to events published from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<GameEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Send `event` to every listener; nothing happens when there are none
+    pub fn publish(&self, event: GameEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    /// Number of active listeners
+    pub fn listener_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_every_listener_gets_each_event() {
+        let bus = GameEventBus::new();
+        // Nobody listening yet: publishing is a no-op
+        bus.publish(GameEvent::GameStarted { target_set: false });
+
+        let mut first = bus.subscribe();
+        let mut second = bus.subscribe();
+        assert_eq!(bus.listener_count(), 2);
+        let event = GameEvent::GuessMade {
+            word: Word::from_str("crane").unwrap(),
+            attempt: 1,
+        };
+        bus.publish(event.clone());
+        assert_eq!(first.recv().await.unwrap(), event);
+        assert_eq!(second.recv().await.unwrap(), event);
+        assert!(first.try_recv().is_err());
+    }
+}
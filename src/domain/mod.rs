@@ -1,10 +1,30 @@
 /// Domain layer containing business logic
 pub mod game_engine;
+pub mod game_events;
 pub mod solver_engine;
 pub mod feedback_service;
 pub mod constraint_filter;
+pub mod elimination;
+pub mod clustering;
+pub mod authoring;
+pub mod word_graph;
+pub mod candidate_diff;
+pub mod forensics;
+pub mod completion;
+pub mod word_page;
+pub mod probe_pair;
 
 pub use game_engine::*;
+pub use game_events::*;
 pub use solver_engine::*;
 pub use feedback_service::*;
 pub use constraint_filter::*;
+pub use elimination::*;
+pub use clustering::*;
+pub use authoring::*;
+pub use word_graph::*;
+pub use candidate_diff::*;
+pub use forensics::*;
+pub use completion::*;
+pub use word_page::*;
+pub use probe_pair::*;
@@ -0,0 +1,166 @@
+//! Fixed pairs of opening probes
+//!
+//! Some players open with the same two words every game, whatever the first one
+//! shows, and only then start solving. A pair is judged by the words it leaves on
+//! average: the answers are split by the feedback to both probes together, and
+//! each group counts as many times as it has answers. Trying every pair is out of
+//! reach, so the first probe comes from a shortlist of the best single openers and
+//! the second is searched over every guess.
+
+use crate::core::{
+    algorithms::PATTERN_COUNT,
+    traits::EntropyCalculator,
+    types::{FeedbackPattern, Word},
+};
+use rayon::prelude::*;
+
+/// Best single openers tried as the first probe of a pair
+pub const PAIR_SHORTLIST: usize = 10;
+
+/// Two opening guesses played in order regardless of feedback
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProbePair {
+    pub first: Word,
+    pub second: Word,
+    /// Answers expected to remain after both probes
+    pub expected_remaining: f64,
+    /// Answers expected to remain after the first probe alone
+    pub first_expected_remaining: f64,
+}
+
+/// Answers expected to remain after `guess`: the mean size of the feedback group
+/// an answer falls in
+pub fn expected_remaining(
+    calculator: &dyn EntropyCalculator,
+    guess: &Word,
+    answers: &[Word],
+) -> f64 {
+    if answers.is_empty() {
+        return 0.0;
+    }
+    squared_total(&calculator.partition_counts(guess, answers)) as f64 / answers.len() as f64
+}
+
+/// Up to `limit` guesses leaving the fewest answers on average, best first
+pub fn rank_single_probes(
+    calculator: &dyn EntropyCalculator,
+    answers: &[Word],
+    guesses: &[Word],
+    limit: usize,
+) -> Vec<(Word, f64)> {
+    let mut ranked: Vec<(Word, f64)> = guesses
+        .par_iter()
+        .map(|guess| {
+            (
+                guess.clone(),
+                expected_remaining(calculator, guess, answers),
+            )
+        })
+        .collect();
+    ranked.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(limit);
+    ranked
+}
+
+/// Up to `limit` pairs leaving the fewest answers on average, best first
+///
+/// The first probe is one of the `shortlist` best single openers. A pair whose
+/// probes are both on the shortlist is listed once, in the better order.
+pub fn rank_probe_pairs(
+    calculator: &dyn EntropyCalculator,
+    answers: &[Word],
+    guesses: &[Word],
+    shortlist: usize,
+    limit: usize,
+) -> Vec<ProbePair> {
+    if answers.is_empty() || limit == 0 {
+        return Vec::new();
+    }
+    let mut pairs: Vec<ProbePair> = Vec::new();
+    let shortlisted = rank_single_probes(calculator, answers, guesses, shortlist);
+    for (first, first_expected_remaining) in shortlisted {
+        // Answers grouped by the first probe's feedback
+        let mut groups: Vec<Vec<Word>> = vec![Vec::new(); PATTERN_COUNT];
+        for answer in answers {
+            groups[FeedbackPattern::index_for(&first, answer) as usize].push(answer.clone());
+        }
+        groups.retain(|group| !group.is_empty());
+
+        let mut seconds: Vec<(&Word, u64)> = guesses
+            .par_iter()
+            .filter(|second| **second != first)
+            .map(|second| {
+                // Both probes' feedback together: each first-probe group split again
+                let total = groups
+                    .iter()
+                    .map(|group| squared_total(&calculator.partition_counts(second, group)))
+                    .sum();
+                (second, total)
+            })
+            .collect();
+        seconds.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+        pairs.extend(
+            seconds
+                .into_iter()
+                .take(limit)
+                .map(|(second, total)| ProbePair {
+                    first: first.clone(),
+                    second: second.clone(),
+                    expected_remaining: total as f64 / answers.len() as f64,
+                    first_expected_remaining,
+                }),
+        );
+    }
+
+    pairs.sort_by(|a, b| a.expected_remaining.total_cmp(&b.expected_remaining));
+    let mut ranked: Vec<ProbePair> = Vec::with_capacity(limit);
+    for pair in pairs {
+        let swapped = ranked
+            .iter()
+            .any(|kept| kept.first == pair.second && kept.second == pair.first);
+        if !swapped {
+            ranked.push(pair);
+        }
+        if ranked.len() == limit {
+            break;
+        }
+    }
+    ranked
+}
+
+/// Sum of squared group sizes of a partition
+fn squared_total(counts: &[usize; PATTERN_COUNT]) -> u64 {
+    counts.iter().map(|&c| (c * c) as u64).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::testing::words;
+    use crate::infrastructure::SimpleEntropyCalculator;
+
+    #[test]
+    fn test_pair_splits_what_the_first_probe_leaves() {
+        let answers = words(&["batch", "catch", "hatch", "latch", "match", "patch"]);
+        let guesses = words(&["batch", "climb", "champ", "helps"]);
+        let calculator = SimpleEntropyCalculator::new();
+
+        // batch leaves the five other _atch words together
+        let batch = expected_remaining(&calculator, &answers[0], &answers);
+        assert!((batch - 26.0 / 6.0).abs() < 1e-9);
+        let pairs = rank_probe_pairs(&calculator, &answers, &guesses, 2, 3);
+        let best = &pairs[0];
+        // climb alone leaves hatch and patch together; champ then tells them apart
+        assert_eq!(
+            (best.first.as_str(), best.second.as_str()),
+            ("climb", "champ")
+        );
+        assert!((best.first_expected_remaining - 8.0 / 6.0).abs() < 1e-9);
+        assert!((best.expected_remaining - 1.0).abs() < 1e-9);
+        assert!(
+            !pairs
+                .iter()
+                .any(|p| p.first.as_str() == "champ" && p.second.as_str() == "climb")
+        );
+    }
+}
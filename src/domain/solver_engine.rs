@@ -1,11 +1,26 @@
 use crate::core::{
-    error::{Result, SolverError},
+    alphabet::LETTER_COUNT,
+    error::{GameError, Result, SolverError},
+    morphology::MorphologyTags,
     traits::{ConstraintFilter, SolvingStrategy, WordListProvider, WordleSolver},
-    types::{FeedbackPattern, Guess, SolverStatistics, Word},
+    types::{
+        Disambiguator, FeedbackPattern, Guess, ManualConstraint, SolverStatistics, Suggestion,
+        SuggestionQuality, SuggestionSet, TrapCluster, TurnEntropy, Word,
+    },
 };
+use crate::domain::GameRules;
 use async_trait::async_trait;
+use rayon::prelude::*;
+use std::collections::HashSet;
 use std::fmt;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio_util::sync::CancellationToken;
+
+/// Guesses left at or below which only possible answers are suggested
+///
+/// With this few left, a guess that can't win spends a turn the game can't spare;
+/// narrowing to the answers gives up some information for a chance to win outright.
+pub const URGENT_GUESSES_LEFT: usize = 2;
 
 /// Default implementation of Wordle solver
 pub struct DefaultWordleSolver {
@@ -15,6 +30,22 @@ pub struct DefaultWordleSolver {
     possible_words: Vec<Word>,
     candidates: Arc<Vec<Word>>,
     guess_history: Vec<Guess>,
+    /// Pinned and banned letters entered by the player
+    manual: Vec<ManualConstraint>,
+    /// Information measured for each guess in `guess_history`
+    turn_entropy: Vec<TurnEntropy>,
+    /// Latest best guess for the current possible words, to compare the next guess with;
+    /// behind a lock so suggesting only needs `&self`
+    suggested: Mutex<Option<Word>>,
+    hard_mode: bool,
+    /// Rules the guesses entered must follow
+    rules: GameRules,
+    /// Guesses a game allows, for knowing when few are left
+    max_guesses: usize,
+    /// Words never suggested as openers or probes (e.g. played in earlier games)
+    excluded: HashSet<Word>,
+    /// Provider generation that `possible_words` and `candidates` were built from
+    generation: u64,
 }
 
 impl fmt::Debug for DefaultWordleSolver {
@@ -25,6 +56,12 @@ impl fmt::Debug for DefaultWordleSolver {
             .field("constraint_filter", &"Box<dyn ConstraintFilter>")
             .field("possible_words_count", &self.possible_words.len())
             .field("guess_history", &self.guess_history)
+            .field("manual", &self.manual)
+            .field("hard_mode", &self.hard_mode)
+            .field("rules", &self.rules)
+            .field("max_guesses", &self.max_guesses)
+            .field("excluded_count", &self.excluded.len())
+            .field("generation", &self.generation)
             .finish()
     }
 }
@@ -37,13 +74,9 @@ impl DefaultWordleSolver {
     ) -> Result<Self> {
         // Load words from provider
         word_list_provider.load_words().await?;
-        let possible_words = word_list_provider.get_answer_words().to_vec();
-
-        // Precompute candidates (answers ∪ guesses), sorted/deduped once
-        let mut candidates = word_list_provider.get_answer_words().to_vec();
-        candidates.extend(word_list_provider.get_guess_words().iter().cloned());
-        candidates.sort();
-        candidates.dedup();
+        let possible_words = Self::sorted_answers(word_list_provider.as_ref());
+        let candidates = Self::all_candidates(word_list_provider.as_ref());
+        let generation = word_list_provider.generation();
 
     let solver = Self {
             word_list_provider,
@@ -52,48 +85,191 @@ impl DefaultWordleSolver {
             possible_words,
             candidates: Arc::new(candidates),
             guess_history: Vec::new(),
+            manual: Vec::new(),
+            turn_entropy: Vec::new(),
+            suggested: Mutex::new(None),
+            hard_mode: false,
+            rules: GameRules::default(),
+            max_guesses: crate::domain::DEFAULT_MAX_ATTEMPTS,
+            excluded: HashSet::new(),
+            generation,
         };
 
         Ok(solver)
     }
 
-    #[allow(dead_code)]
-    fn select_initial_guess_from_frequency(answers: &[Word]) -> Option<Word> {
+    /// Only suggest guesses that reuse every revealed hint
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Check the guesses entered against `rules` instead of the official ones
+    pub fn with_rules(mut self, rules: GameRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Plan for games of `max_guesses` guesses (at least one)
+    pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = max_guesses.max(1);
+        self
+    }
+
+    /// Whether the guess after `history` is one of the last [`URGENT_GUESSES_LEFT`]
+    fn urgent_after(&self, history: &[Guess]) -> bool {
+        self.max_guesses.saturating_sub(history.len()) <= URGENT_GUESSES_LEFT
+    }
+
+    /// Answer with the most common letter in each position, skipping `excluded`
+    fn select_initial_guess_from_frequency(
+        answers: &[Word],
+        excluded: &HashSet<Word>,
+    ) -> Option<Word> {
         if answers.is_empty() {
             return None;
         }
         // Build frequency on the fly to avoid provider coupling; answersのみでポジション頻度を出す
-        let mut pos_counts = [[0u32; 26]; 5];
+        let alphabet = Word::ALPHABET;
+        let mut pos_counts = [[0u32; LETTER_COUNT]; 5];
         for w in answers {
             let b = w.bytes();
             for pos in 0..5 {
-                let idx = (b[pos] - b'a') as usize;
-                if idx < 26 {
+                if let Some(idx) = alphabet.index(b[pos]) {
                     pos_counts[pos][idx] += 1;
                 }
             }
         }
         answers
             .iter()
+            .filter(|w| !excluded.contains(*w))
             .max_by_key(|w| {
                 let b = w.bytes();
                 (0..5)
-                    .map(|pos| pos_counts[pos][(b[pos] - b'a') as usize] as u64)
+                    .map(|pos| pos_counts[pos][alphabet.position(b[pos])] as u64)
                     .sum::<u64>()
             })
             .cloned()
     }
 
+    /// Answer words in lexicographic order, so samples are stable across providers
+    fn sorted_answers(provider: &dyn WordListProvider) -> Vec<Word> {
+        let mut answers = provider.get_answer_words().to_vec();
+        answers.sort();
+        answers
+    }
+
+    /// Candidates (answers ∪ guesses), sorted/deduped once per generation of the lists
+    fn all_candidates(provider: &dyn WordListProvider) -> Vec<Word> {
+        let mut candidates = provider.get_answer_words().to_vec();
+        candidates.extend(provider.get_guess_words().iter().cloned());
+        candidates.sort();
+        candidates.dedup();
+        candidates
+    }
+
+    /// Rebuild everything derived from the word lists if the provider has swapped
+    /// them since; the guesses and manual constraints are applied to the new answers
+    fn sync_word_lists(&mut self) {
+        let generation = self.word_list_provider.generation();
+        if generation == self.generation {
+            return;
+        }
+        let provider = self.word_list_provider.as_ref();
+        self.candidates = Arc::new(Self::all_candidates(provider));
+        self.possible_words = Self::sorted_answers(provider);
+        self.strategy.word_lists_changed(provider.get_answer_words());
+        self.update_possible_words();
+        self.generation = generation;
+    }
+
     /// Update possible words based on constraints
     fn update_possible_words(&mut self) {
-        self.possible_words = self
-            .constraint_filter
-            .filter_words(&self.possible_words, &self.guess_history);
+        self.take_suggested();
+        self.possible_words = self.constraint_filter.filter_words_with_manual(
+            &self.possible_words,
+            &self.guess_history,
+            &self.manual,
+        );
+    }
+
+    /// Forget the recorded suggestion, returning it
+    fn take_suggested(&mut self) -> Option<Word> {
+        self.suggested
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
     }
 
     /// Get all valid candidates for guessing (precomputed and cached)
     fn get_candidates(&self) -> Arc<Vec<Word>> {
-        self.candidates.clone()
+        self.candidates_after(&self.guess_history, &self.possible_words)
+    }
+
+    /// Candidates for the guess after `history`, which leaves `possible_words`
+    ///
+    /// Words already in `history` are never candidates: they reveal nothing new, and
+    /// the official rules refuse them. In hard mode the list is narrowed to guesses
+    /// legal after the history so far. Excluded words are dropped too, unless they could
+    /// still be the answer after the first guess: skipping those could cost the game.
+    /// Once few guesses are left, only `possible_words` remain (see
+    /// [`URGENT_GUESSES_LEFT`]).
+    fn candidates_after(&self, history: &[Guess], possible_words: &[Word]) -> Arc<Vec<Word>> {
+        // Only `sync_word_lists` may let the provider's lists move on
+        debug_assert_eq!(self.generation, self.word_list_provider.generation());
+        if self.urgent_after(history) && !possible_words.is_empty() {
+            // Answers satisfy every hint, so they are legal in hard mode too, and a
+            // word already played isn't one
+            return Arc::new(possible_words.to_vec());
+        }
+        let hard_mode = self.hard_mode && !history.is_empty();
+        if history.is_empty() && self.excluded.is_empty() {
+            return self.candidates.clone();
+        }
+        let played: HashSet<&Word> = history.iter().map(|guess| &guess.word).collect();
+        let possible: HashSet<&Word> = if history.is_empty() {
+            HashSet::new()
+        } else {
+            possible_words.iter().collect()
+        };
+        Arc::new(
+            self.candidates
+                .iter()
+                .filter(|word| !played.contains(word))
+                .filter(|word| !self.excluded.contains(*word) || possible.contains(word))
+                .filter(|word| !hard_mode || crate::domain::is_hard_mode_legal(word, history))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Answers consistent with `history`, whatever game this solver is playing
+    pub fn possible_words_after(&self, history: &[Guess]) -> Vec<Word> {
+        let answers = Self::sorted_answers(self.word_list_provider.as_ref());
+        self.constraint_filter.filter_words(&answers, history)
+    }
+
+    /// Best guess for this game, remembered as the last suggestion
+    fn suggest(&self, cancel: Option<&CancellationToken>) -> Result<Word> {
+        if self.possible_words.is_empty() {
+            return Err(SolverError::NoPossibleWords.into());
+        }
+
+        let best = if self.possible_words.len() == 1 {
+            self.possible_words[0].clone()
+        } else {
+            let candidates = self.get_candidates();
+            match cancel {
+                Some(cancel) => self.strategy.get_best_guess_cancellable(
+                    &self.possible_words,
+                    &candidates,
+                    cancel,
+                )?,
+                None => self.strategy.get_best_guess(&self.possible_words, &candidates)?,
+            }
+        };
+        *self.suggested.lock().unwrap_or_else(PoisonError::into_inner) = Some(best.clone());
+        Ok(best)
     }
 }
 
@@ -110,43 +286,113 @@ impl WordleSolver for DefaultWordleSolver {
         Self::new(word_list_provider, strategy, constraint_filter).await
     }
 
-    fn add_guess_result(&mut self, word: &Word, feedback: &FeedbackPattern) -> Result<()> {
-        // Validate word is a valid guess
+    fn set_hard_mode(&mut self, hard_mode: bool) {
+        self.hard_mode = hard_mode;
+    }
+
+    fn set_excluded_words(&mut self, words: Vec<Word>) {
+        self.excluded = words.into_iter().collect();
+    }
+
+    fn validate_guess(&self, word: &Word) -> Result<()> {
         if !self.word_list_provider.is_valid_guess(word) {
-            return Err(SolverError::InvalidFeedback(format!(
-                "'{}' is not a valid guess word",
-                word.as_str()
-            ))
-            .into());
+            return Err(GameError::WordNotFound(word.to_string()).into());
         }
+        self.rules.check_guess(word, &self.guess_history)?;
+        if self.hard_mode {
+            if let Some(reason) = crate::domain::hard_mode_violation(word, &self.guess_history) {
+                return Err(GameError::HardModeViolation {
+                    word: word.to_string(),
+                    reason,
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    fn add_guess_result(&mut self, word: &Word, feedback: &FeedbackPattern) -> Result<()> {
+        self.validate_guess(word)?;
 
         // Add to history
-        let guess = Guess::new(word.clone(), feedback.clone());
+        let guess = Guess::new(word.clone(), *feedback);
         self.guess_history.push(guess);
 
         // Update possible words
+        let before = self.possible_words.clone();
+        let suggested = self.take_suggested();
         self.update_possible_words();
+        self.turn_entropy.push(TurnEntropy::measure(
+            word.clone(),
+            suggested,
+            &before,
+            self.possible_words.len(),
+        ));
 
         Ok(())
     }
 
-    fn get_best_guess(&mut self) -> Result<Word> {
-        if self.possible_words.is_empty() {
-            return Err(SolverError::NoPossibleWords.into());
+    fn add_manual_constraint(&mut self, constraint: ManualConstraint) -> Result<()> {
+        if self.manual.contains(&constraint) {
+            return Ok(());
+        }
+        if let Some(reason) = self.manual.iter().find_map(|c| constraint.conflict(c)) {
+            return Err(SolverError::InvalidConstraint(reason).into());
         }
+        self.manual.push(constraint);
+        self.update_possible_words();
+        Ok(())
+    }
+
+    fn clear_manual_constraints(&mut self) {
+        self.manual.clear();
+        self.possible_words = Self::sorted_answers(self.word_list_provider.as_ref());
+        self.update_possible_words();
+    }
+
+    fn manual_constraints(&self) -> &[ManualConstraint] {
+        &self.manual
+    }
+
+    fn get_best_guess(&self) -> Result<Word> {
+        self.suggest(None)
+    }
+
+    fn get_best_guess_cancellable(&self, cancel: &CancellationToken) -> Result<Word> {
+        self.suggest(Some(cancel))
+    }
 
-        if self.possible_words.len() == 1 {
-            return Ok(self.possible_words[0].clone());
+    fn get_best_first_guess(&self) -> Result<Word> {
+        let opener = self.strategy.get_best_first_guess()?;
+        // An opener stored with older lists may have left the new ones
+        if !self.excluded.contains(&opener) && self.word_list_provider.is_valid_guess(&opener) {
+            return Ok(opener);
         }
+        // Scoring every candidate against every answer is too slow for a fallback opener
+        let answers = self.word_list_provider.get_answer_words();
+        Self::select_initial_guess_from_frequency(answers, &self.excluded)
+            .ok_or_else(|| SolverError::NoCandidates.into())
+    }
 
-        // Clone small set to avoid borrow conflict; remaining words are usually smaller
-        let possible_words = self.possible_words.clone();
-        let candidates = self.get_candidates();
+    /// Only reads the solver, so one instance behind an `Arc` can answer for many
+    /// games at once, e.g. requests in serve mode or targets in a parallel
+    /// benchmark, sharing the word lists and candidates. Manual constraints are not
+    /// applied; the first guess is the usual opener.
+    fn best_guess_after(&self, history: &[Guess]) -> Result<Word> {
+        if history.is_empty() {
+            return self.get_best_first_guess();
+        }
+        let possible_words = self.possible_words_after(history);
+        if possible_words.is_empty() {
+            return Err(SolverError::NoPossibleWords.into());
+        }
+        let candidates = self.candidates_after(history, &possible_words);
         self.strategy.get_best_guess(&possible_words, &candidates)
     }
 
-    fn get_best_first_guess(&self) -> Result<Word> {
-        self.strategy.get_best_first_guess()
+    fn confirmed_positions(&self) -> [Option<char>; Word::LENGTH] {
+        self.constraint_filter
+            .confirmed_positions(&self.guess_history)
     }
 
     fn remaining_words_count(&self) -> usize {
@@ -162,12 +408,37 @@ impl WordleSolver for DefaultWordleSolver {
         }
     }
 
+    fn guess_words(&self) -> Arc<Vec<Word>> {
+        self.candidates.clone()
+    }
+
     fn reset(&mut self) {
-        self.possible_words = self.word_list_provider.get_answer_words().to_vec();
+        self.possible_words = Self::sorted_answers(self.word_list_provider.as_ref());
         self.guess_history.clear();
+        self.manual.clear();
+        self.turn_entropy.clear();
+        self.take_suggested();
         self.strategy.clear_cache();
     }
 
+    async fn refresh_word_lists(&mut self, force: bool) -> Result<(usize, usize)> {
+        let counts = self.word_list_provider.refresh(force).await?;
+        self.sync_word_lists();
+        Ok(counts)
+    }
+
+    fn word_list_generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn morphology(&self, word: &Word) -> MorphologyTags {
+        self.word_list_provider.morphology(word)
+    }
+
+    fn is_urgent(&self) -> bool {
+        self.urgent_after(&self.guess_history)
+    }
+
     fn is_solved(&self) -> bool {
         self.possible_words.len() == 1
             && self
@@ -188,19 +459,43 @@ impl WordleSolver for DefaultWordleSolver {
             remaining_words: self.possible_words.len(),
             is_solved: self.is_solved(),
             possible_words_sample: sample_words,
-            entropy_scores: Vec::new(),
+            entropy_scores: self.turn_entropy.clone(),
         }
     }
 
-    fn get_top_candidates(&mut self, limit: usize) -> Vec<(Word, f64)> {
+    fn get_top_candidates(&self, limit: usize) -> Vec<(Word, f64)> {
         if self.possible_words.is_empty() {
             return Vec::new();
         }
 
-        let possible_words = self.possible_words.clone();
         let candidates = self.get_candidates();
         self.strategy
-            .get_top_candidates(&possible_words, &candidates, limit)
+            .get_top_candidates(&self.possible_words, &candidates, limit)
+    }
+
+    fn get_suggestion_set(&self) -> Option<SuggestionSet> {
+        if self.possible_words.is_empty() {
+            return None;
+        }
+
+        let candidates = self.get_candidates();
+        let evaluated: Vec<Suggestion> = candidates
+            .par_iter()
+            .map(|word| Suggestion {
+                word: word.clone(),
+                quality: SuggestionQuality::evaluate(word, &self.possible_words),
+            })
+            .collect();
+        SuggestionSet::select(&evaluated, &self.possible_words)
+    }
+
+    fn get_disambiguator(&self) -> Option<Disambiguator> {
+        crate::domain::find_disambiguator(&self.possible_words, &self.get_candidates())
+    }
+
+    fn get_trap_clusters(&self, min_size: usize, guesses_per_trap: usize) -> Vec<TrapCluster> {
+        let answers = Self::sorted_answers(self.word_list_provider.as_ref());
+        crate::domain::find_traps(&answers, &self.candidates, min_size, guesses_per_trap)
     }
 }
 
@@ -269,9 +564,163 @@ impl Default for WordleSolverBuilder {
     }
 }
 
+/// Fixtures for tests elsewhere in the crate
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+
+    /// Fixed answer list, so a solver can be built without the network
+    #[derive(Debug)]
+    pub(crate) struct FixedWords(pub(crate) Vec<Word>);
+
+    #[async_trait]
+    impl WordListProvider for FixedWords {
+        async fn load_words(&mut self) -> Result<Vec<Word>> {
+            Ok(self.0.clone())
+        }
+
+        fn get_answer_words(&self) -> &[Word] {
+            &self.0
+        }
+
+        fn get_guess_words(&self) -> &[Word] {
+            &[]
+        }
+
+        fn is_valid_guess(&self, word: &Word) -> bool {
+            self.0.contains(word)
+        }
+
+        fn is_possible_answer(&self, word: &Word) -> bool {
+            self.0.contains(word)
+        }
+    }
+
+    pub(crate) async fn fixed_solver(words: &[&str]) -> DefaultWordleSolver {
+        let words = words.iter().map(|w| Word::from_str(w).unwrap()).collect();
+        let strategy = crate::infrastructure::EntropyBasedStrategy::new(
+            crate::infrastructure::SimpleEntropyCalculator::new(),
+        )
+        .unwrap();
+        DefaultWordleSolver::new(
+            Box::new(FixedWords(words)),
+            Box::new(strategy),
+            Box::new(crate::domain::DefaultConstraintFilter::new()),
+        )
+        .await
+        .unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::testing::fixed_solver;
     use super::*;
+    use crate::core::traits::FeedbackGenerator;
+
+    #[tokio::test]
+    async fn test_shared_solver_answers_games_concurrently() {
+        fn assert_sync<T: Sync>(_: &T) {}
+
+        let words = ["crane", "crate", "grate", "plate", "slate", "trace", "react", "caret"];
+        let solver = fixed_solver(&words).await;
+        assert_sync(&solver);
+
+        let generator = crate::domain::DefaultFeedbackGenerator::new();
+        let opener = Word::from_str("crane").unwrap();
+        let histories: Vec<Vec<Guess>> = words
+            .iter()
+            .map(|target| {
+                let target = Word::from_str(target).unwrap();
+                let feedback = generator.generate_feedback(&opener, &target);
+                vec![Guess::new(opener.clone(), feedback)]
+            })
+            .collect();
+        let sequential: Vec<Word> =
+            histories.iter().map(|h| solver.best_guess_after(h).unwrap()).collect();
+        let parallel: Vec<Word> =
+            histories.par_iter().map(|h| solver.best_guess_after(h).unwrap()).collect();
+        assert_eq!(parallel, sequential);
+
+        // The solver's own game is untouched
+        assert!(solver.get_guess_history().is_empty());
+        assert_eq!(solver.remaining_words_count(), words.len());
+        assert_eq!(solver.possible_words_after(&histories[1]).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_assisted_repeat_is_refused_unless_lenient() {
+        let words = ["crane", "slate", "plate"];
+        let crane = Word::from_str("crane").unwrap();
+        let miss = FeedbackPattern::from_code_string("00002").unwrap();
+
+        let mut solver = fixed_solver(&words).await;
+        solver.add_guess_result(&crane, &miss).unwrap();
+        let error = solver.add_guess_result(&crane, &miss).unwrap_err();
+        assert!(error.to_string().contains("already guessed"));
+        assert_eq!(solver.get_guess_history().len(), 1);
+
+        let mut lenient = fixed_solver(&words).await.with_rules(GameRules::lenient());
+        lenient.add_guess_result(&crane, &miss).unwrap();
+        assert!(lenient.validate_guess(&crane).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_static_strategy_never_repeats_a_guess() {
+        use crate::core::traits::GameEngine;
+
+        let words: Vec<Word> = [
+            "crane", "crate", "grate", "plate", "slate", "trace", "react", "caret",
+        ]
+        .iter()
+        .map(|w| Word::from_str(w).unwrap())
+        .collect();
+        for target in &words {
+            // Letter frequencies don't follow the game, so the top word stays on top
+            let strategy = crate::infrastructure::FrequencyBasedStrategy::new(&words).unwrap();
+            let mut solver = DefaultWordleSolver::new(
+                Box::new(testing::FixedWords(words.clone())),
+                Box::new(strategy),
+                Box::new(crate::domain::DefaultConstraintFilter::new()),
+            )
+            .await
+            .unwrap();
+            let mut engine = crate::domain::DefaultGameEngine::with_feedback_generator(Box::new(
+                crate::domain::DefaultFeedbackGenerator::new(),
+            ))
+            .with_rules(GameRules::default());
+            engine.set_target(target).unwrap();
+            while !engine.is_finished() {
+                let guess = solver.get_best_guess().unwrap();
+                let feedback = engine.make_guess(&guess).unwrap();
+                solver.add_guess_result(&guess, &feedback).unwrap();
+            }
+            assert!(engine.get_result().is_won(), "{}", target);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_last_guesses_only_suggest_possible_answers() {
+        let words = [
+            "crane", "crate", "grate", "plate", "slate", "trace", "react", "caret",
+        ];
+        let generator = crate::domain::DefaultFeedbackGenerator::new();
+        let crane = Word::from_str("crane").unwrap();
+        let feedback = generator.generate_feedback(&crane, &Word::from_str("slate").unwrap());
+
+        let mut relaxed = fixed_solver(&words).await;
+        relaxed.add_guess_result(&crane, &feedback).unwrap();
+        assert!(!relaxed.is_urgent());
+        assert!(relaxed.get_candidates().len() > relaxed.remaining_words_count());
+
+        let mut urgent = fixed_solver(&words).await.with_max_guesses(3);
+        assert!(!urgent.is_urgent());
+        urgent.add_guess_result(&crane, &feedback).unwrap();
+        assert!(urgent.is_urgent());
+        let possible = urgent.get_possible_words(None);
+        assert_eq!(*urgent.get_candidates(), possible);
+        assert!(possible.contains(&urgent.get_best_guess().unwrap()));
+    }
 
     #[tokio::test]
     async fn test_solver_builder() {
@@ -288,4 +737,98 @@ mod tests {
             }
         }
     }
+
+    /// Serves `lists[0]` until a refresh moves on to the next list
+    #[derive(Debug)]
+    struct RefreshingWords {
+        lists: Vec<Vec<Word>>,
+        generation: u64,
+    }
+
+    #[async_trait]
+    impl WordListProvider for RefreshingWords {
+        async fn load_words(&mut self) -> Result<Vec<Word>> {
+            Ok(self.get_answer_words().to_vec())
+        }
+
+        fn get_answer_words(&self) -> &[Word] {
+            &self.lists[self.generation as usize]
+        }
+
+        fn get_guess_words(&self) -> &[Word] {
+            &[]
+        }
+
+        fn is_valid_guess(&self, word: &Word) -> bool {
+            self.get_answer_words().contains(word)
+        }
+
+        fn is_possible_answer(&self, word: &Word) -> bool {
+            self.get_answer_words().contains(word)
+        }
+
+        fn generation(&self) -> u64 {
+            self.generation
+        }
+
+        async fn refresh(&mut self, _force: bool) -> Result<(usize, usize)> {
+            self.generation += 1;
+            Ok((self.get_answer_words().len(), 0))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refreshed_lists_replace_cached_candidates() {
+        let words = |list: &[&str]| list.iter().map(|w| Word::from_str(w).unwrap()).collect();
+        let provider = RefreshingWords {
+            lists: vec![
+                words(&["crane", "crate", "slate"]),
+                words(&["crane", "crape", "craze", "grace"]),
+            ],
+            generation: 0,
+        };
+        let strategy = crate::infrastructure::EntropyBasedStrategy::new(
+            crate::infrastructure::SimpleEntropyCalculator::new(),
+        )
+        .unwrap();
+        let mut solver = DefaultWordleSolver::new(
+            Box::new(provider),
+            Box::new(strategy),
+            Box::new(crate::domain::DefaultConstraintFilter::new()),
+        )
+        .await
+        .unwrap();
+        let crane = Word::from_str("crane").unwrap();
+        solver
+            .add_guess_result(&crane, &FeedbackPattern::from_code_string("22202").unwrap())
+            .unwrap();
+        assert_eq!(solver.get_possible_words(None), words(&["crate"]));
+
+        assert_eq!(solver.refresh_word_lists(false).await.unwrap(), (4, 0));
+        assert_eq!(solver.word_list_generation(), 1);
+        // The guess still counts, against the new answers
+        assert_eq!(solver.get_guess_history().len(), 1);
+        assert_eq!(solver.get_possible_words(None), words(&["crape", "craze"]));
+        let candidates = solver.get_candidates();
+        assert!(candidates.contains(&Word::from_str("grace").unwrap()));
+        assert!(!candidates.contains(&Word::from_str("slate").unwrap()));
+        assert!(solver.get_possible_words(None).contains(&solver.get_best_guess().unwrap()));
+    }
+
+    #[test]
+    fn test_frequency_opener_skips_excluded() {
+        let answers: Vec<Word> = ["cares", "bares", "cores", "mumpy"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+        let mut excluded = HashSet::new();
+        let pick = |excluded: &HashSet<Word>| {
+            DefaultWordleSolver::select_initial_guess_from_frequency(&answers, excluded)
+        };
+        assert_eq!(pick(&excluded).unwrap().to_string(), "cares");
+        excluded.insert(Word::from_str("cares").unwrap());
+        assert_ne!(pick(&excluded).unwrap().to_string(), "cares");
+        excluded.extend(answers.iter().cloned());
+        assert_eq!(pick(&excluded), None);
+    }
 }
@@ -0,0 +1,351 @@
+//! One-letter-change graph of the remaining words
+//!
+//! Words are linked when they differ in exactly one position (batch-catch,
+//! hatch-hitch). Large connected groups are what makes an endgame
+//! "entangled": guessing along a chain of neighbours rules out one word per
+//! turn. Articulation words hold a group together, and the breaker is the
+//! guess that leaves the fewest words of a group in the worst case.
+
+use crate::core::{
+    ranking::score_desc,
+    traits::WordleSolver,
+    types::{SuggestionQuality, Word},
+};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Words linked to every word differing from them in exactly one position
+#[derive(Debug, Clone, Default)]
+pub struct WordGraph {
+    words: Vec<Word>,
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl WordGraph {
+    /// Build the graph of `words` (duplicates are dropped)
+    pub fn build(words: &[Word]) -> Self {
+        let mut words = words.to_vec();
+        words.sort();
+        words.dedup();
+
+        // Words sharing a wildcard pattern ("_atch") are pairwise neighbours
+        let mut patterns: HashMap<(usize, [u8; Word::LENGTH]), Vec<usize>> = HashMap::new();
+        for (index, word) in words.iter().enumerate() {
+            for pos in 0..Word::LENGTH {
+                let mut key = *word.bytes();
+                key[pos] = b'_';
+                patterns.entry((pos, key)).or_default().push(index);
+            }
+        }
+        let mut neighbours = vec![Vec::new(); words.len()];
+        for members in patterns.values().filter(|members| members.len() > 1) {
+            for &a in members {
+                neighbours[a].extend(members.iter().copied().filter(|&b| b != a));
+            }
+        }
+        for list in &mut neighbours {
+            list.sort_unstable();
+        }
+        Self { words, neighbours }
+    }
+
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
+
+    /// Number of links
+    pub fn edge_count(&self) -> usize {
+        self.neighbours.iter().map(Vec::len).sum::<usize>() / 2
+    }
+
+    /// Words one letter away from `word`, alphabetically
+    pub fn neighbours(&self, word: &Word) -> Vec<&Word> {
+        match self.words.binary_search(word) {
+            Ok(index) => self.neighbours[index].iter().map(|&i| &self.words[i]).collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Connected groups, largest first (ties alphabetical), each sorted; single words
+    /// with no neighbours are groups of one
+    pub fn components(&self) -> Vec<Vec<Word>> {
+        let mut seen = vec![false; self.words.len()];
+        let mut components = Vec::new();
+        for start in 0..self.words.len() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut stack = vec![start];
+            let mut members = Vec::new();
+            while let Some(index) = stack.pop() {
+                members.push(index);
+                for &next in &self.neighbours[index] {
+                    if !seen[next] {
+                        seen[next] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+            members.sort_unstable();
+            components.push(members.into_iter().map(|i| self.words[i].clone()).collect());
+        }
+        components.sort_by(|a: &Vec<Word>, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        components
+    }
+
+    /// Words whose removal splits their group in two or more, alphabetically
+    pub fn articulation_words(&self) -> Vec<Word> {
+        // Iterative Tarjan: discovery order and the lowest order reachable through
+        // the subtree plus one back edge
+        let n = self.words.len();
+        let mut order = vec![0usize; n];
+        let mut low = vec![0usize; n];
+        let mut is_cut = vec![false; n];
+        let mut counter = 0;
+        for root in 0..n {
+            if order[root] != 0 {
+                continue;
+            }
+            counter += 1;
+            order[root] = counter;
+            low[root] = counter;
+            let mut root_children = 0;
+            // (node, parent, next neighbour to visit)
+            let mut stack = vec![(root, usize::MAX, 0)];
+            while let Some(&mut (node, parent, ref mut next)) = stack.last_mut() {
+                if let Some(&child) = self.neighbours[node].get(*next) {
+                    *next += 1;
+                    if order[child] == 0 {
+                        counter += 1;
+                        order[child] = counter;
+                        low[child] = counter;
+                        if node == root {
+                            root_children += 1;
+                        }
+                        stack.push((child, node, 0));
+                    } else if child != parent {
+                        low[node] = low[node].min(order[child]);
+                    }
+                    continue;
+                }
+                stack.pop();
+                if parent != usize::MAX {
+                    low[parent] = low[parent].min(low[node]);
+                    if parent != root && low[node] >= order[parent] {
+                        is_cut[parent] = true;
+                    }
+                }
+            }
+            is_cut[root] = root_children > 1;
+        }
+        (0..n)
+            .filter(|&i| is_cut[i])
+            .map(|i| self.words[i].clone())
+            .collect()
+    }
+}
+
+/// The guess that splits a group best, and how well
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupBreaker {
+    pub word: Word,
+    /// Partition of the group's words by this guess's feedback
+    pub quality: SuggestionQuality,
+}
+
+/// A connected group of two or more remaining words
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordGroup {
+    pub words: Vec<Word>,
+    /// Links between the group's words
+    pub edges: usize,
+    /// Words holding the group together
+    pub articulation: Vec<Word>,
+    /// Best splitting guess; only computed for the largest groups
+    pub breaker: Option<GroupBreaker>,
+}
+
+impl WordGroup {
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+/// How entangled the remaining words are
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WordGraphReport {
+    pub words: usize,
+    pub edges: usize,
+    /// Groups of two or more, largest first
+    pub groups: Vec<WordGroup>,
+    /// Words with no neighbour
+    pub isolated: usize,
+}
+
+impl WordGraphReport {
+    /// Share of the words that sit in a group, 0.0-1.0
+    pub fn entanglement(&self) -> f64 {
+        if self.words == 0 {
+            return 0.0;
+        }
+        (self.words - self.isolated) as f64 / self.words as f64
+    }
+}
+
+/// Graph report for `words`, with breakers from `candidates` for the `max_breakers`
+/// largest groups
+pub fn analyze_word_graph(
+    words: &[Word],
+    candidates: &[Word],
+    max_breakers: usize,
+) -> WordGraphReport {
+    let graph = WordGraph::build(words);
+    let articulation = graph.articulation_words();
+    let mut isolated = 0;
+    let mut groups = Vec::new();
+    for component in graph.components() {
+        if component.len() < 2 {
+            isolated += component.len();
+            continue;
+        }
+        let edges = component.iter().map(|w| graph.neighbours(w).len()).sum::<usize>() / 2;
+        let breaker = if groups.len() < max_breakers {
+            find_group_breaker(&component, candidates)
+        } else {
+            None
+        };
+        groups.push(WordGroup {
+            articulation: articulation
+                .iter()
+                .filter(|w| component.binary_search(w).is_ok())
+                .cloned()
+                .collect(),
+            words: component,
+            edges,
+            breaker,
+        });
+    }
+    WordGraphReport {
+        words: graph.words().len(),
+        edges: graph.edge_count(),
+        groups,
+        isolated,
+    }
+}
+
+/// [`analyze_word_graph`] for the game any [`WordleSolver`] is playing
+///
+/// Kept out of the solver trait, so solvers get it without implementing anything.
+pub trait WordGraphAnalysis {
+    /// One-letter-change groups among the remaining words, with the best splitting
+    /// guess for the `max_breakers` largest
+    fn get_word_graph(&self, max_breakers: usize) -> WordGraphReport;
+}
+
+impl<S: WordleSolver + ?Sized> WordGraphAnalysis for S {
+    fn get_word_graph(&self, max_breakers: usize) -> WordGraphReport {
+        let possible_words = self.get_possible_words(None);
+        // Breakers must be playable now: legal in hard mode, not played already
+        let candidates: Vec<Word> = if self.is_urgent() {
+            possible_words.clone()
+        } else {
+            self.guess_words()
+                .iter()
+                .filter(|word| self.validate_guess(word).is_ok())
+                .cloned()
+                .collect()
+        };
+        analyze_word_graph(&possible_words, &candidates, max_breakers)
+    }
+}
+
+/// The guess among `candidates` (and the group itself) leaving the fewest of `group`
+/// in the worst case; ties go to more information, then to a word in the group
+pub fn find_group_breaker(group: &[Word], candidates: &[Word]) -> Option<GroupBreaker> {
+    if group.len() < 2 {
+        return None;
+    }
+    let members: HashSet<&Word> = group.iter().collect();
+    let in_group = |word: &Word| members.contains(word);
+    group
+        .par_iter()
+        .chain(candidates.par_iter().filter(|w| !in_group(w)))
+        .map(|word| GroupBreaker {
+            word: word.clone(),
+            quality: SuggestionQuality::evaluate(word, group),
+        })
+        .min_by(|a, b| {
+            a.quality
+                .worst_case_remaining
+                .cmp(&b.quality.worst_case_remaining)
+                .then_with(|| score_desc(a.quality.entropy, b.quality.entropy))
+                .then_with(|| in_group(&b.word).cmp(&in_group(&a.word)))
+                .then_with(|| a.word.cmp(&b.word))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::testing::words;
+
+    #[test]
+    fn test_components_and_articulation_words() {
+        // batch-catch-hatch share _atch, ditch-hitch-pitch _itch; hatch-hitch link them
+        let graph = WordGraph::build(&words(&[
+            "batch", "catch", "hatch", "ditch", "hitch", "pitch", "crane", "crane",
+        ]));
+        assert_eq!(graph.words().len(), 7);
+        assert_eq!(graph.edge_count(), 7);
+        let neighbours: Vec<&str> = graph
+            .neighbours(&Word::from_str("hatch").unwrap())
+            .iter()
+            .map(|w| w.as_str())
+            .collect();
+        assert_eq!(neighbours, ["batch", "catch", "hitch"]);
+
+        let components = graph.components();
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].len(), 6);
+        assert_eq!(components[1], words(&["crane"]));
+
+        let cuts: Vec<String> = graph.articulation_words().iter().map(|w| w.to_string()).collect();
+        assert_eq!(cuts, ["hatch", "hitch"]);
+    }
+
+    #[test]
+    fn test_report_finds_group_breaker() {
+        let remaining = words(&["fight", "light", "might", "sight", "crane"]);
+        let candidates = words(&["flams", "slate"]);
+        let report = analyze_word_graph(&remaining, &candidates, 1);
+        assert_eq!(report.words, 5);
+        assert_eq!(report.isolated, 1);
+        assert_eq!(report.groups.len(), 1);
+        let group = &report.groups[0];
+        assert_eq!(group.edges, 6);
+        assert!(group.articulation.is_empty());
+        // flams tells f, l, m and s apart in one guess
+        let breaker = group.breaker.as_ref().unwrap();
+        assert_eq!(breaker.word.as_str(), "flams");
+        assert_eq!(breaker.quality.worst_case_remaining, 1);
+        assert!((report.entanglement() - 0.8).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_any_solver_reports_its_graph() {
+        let solver = crate::domain::solver_engine::testing::fixed_solver(&[
+            "fight", "light", "might", "sight", "crane",
+        ])
+        .await;
+        let solver: &dyn WordleSolver = &solver;
+        let report = solver.get_word_graph(1);
+        assert_eq!(report.words, 5);
+        assert_eq!(report.groups.len(), 1);
+        assert!(report.groups[0].breaker.is_some());
+    }
+}
@@ -0,0 +1,117 @@
+//! Paging through the possible words
+//!
+//! Early in a game thousands of words are still possible, too many to send or
+//! draw at once. A [`WordPage`] is one slice of them in a fixed [`WordOrder`]
+//! together with the total, so a client can scroll or fetch the next page by
+//! offset. Ties are broken alphabetically, so the same offset gives the same
+//! words for as long as the possible words don't change.
+
+use crate::core::types::Word;
+use crate::domain::candidate_diff::most_typical;
+use serde::{Deserialize, Serialize};
+
+/// Order of the possible words across pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WordOrder {
+    #[default]
+    Alphabetical,
+    /// Words made of the letters most possible words share first, as in a
+    /// [`CandidateDiff`](crate::domain::CandidateDiff)
+    Typical,
+}
+
+impl WordOrder {
+    pub const ALL: [WordOrder; 2] = [Self::Alphabetical, Self::Typical];
+
+    /// The order with this [`label`](Self::label), ignoring case
+    pub fn from_label(label: &str) -> Option<Self> {
+        let label = label.trim();
+        Self::ALL
+            .into_iter()
+            .find(|order| order.label().eq_ignore_ascii_case(label))
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Alphabetical => "alphabetical",
+            Self::Typical => "typical",
+        }
+    }
+}
+
+/// One page of the possible words
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordPage {
+    /// Position of the first word of the page among all of them
+    pub offset: usize,
+    /// Number of possible words across all pages
+    pub total: usize,
+    pub words: Vec<Word>,
+}
+
+impl WordPage {
+    /// Up to `limit` of `words` from `offset` on, in `order`; empty past the end
+    pub fn of(words: &[Word], offset: usize, limit: usize, order: WordOrder) -> Self {
+        let words = match order {
+            WordOrder::Alphabetical => {
+                let mut sorted: Vec<&Word> = words.iter().collect();
+                sorted.sort_unstable();
+                sorted
+            }
+            WordOrder::Typical => most_typical(words, words.len()),
+        };
+        Self {
+            offset,
+            total: words.len(),
+            words: words
+                .into_iter()
+                .skip(offset)
+                .take(limit)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Position after the last word of the page
+    pub fn end(&self) -> usize {
+        self.offset + self.words.len()
+    }
+
+    /// Whether more words follow this page
+    pub fn has_more(&self) -> bool {
+        self.end() < self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::testing::words;
+
+    fn listed(page: &WordPage) -> Vec<&str> {
+        page.words.iter().map(Word::as_str).collect()
+    }
+
+    #[test]
+    fn test_pages_cover_the_words_once() {
+        let all = words(&["trace", "crate", "fuzzy", "slate", "crane"]);
+
+        let first = WordPage::of(&all, 0, 2, WordOrder::Alphabetical);
+        assert_eq!(listed(&first), ["crane", "crate"]);
+        assert_eq!((first.total, first.end()), (5, 2));
+        let last = WordPage::of(&all, 4, 2, WordOrder::Alphabetical);
+        assert_eq!(listed(&last), ["trace"]);
+        assert!(first.has_more() && !last.has_more());
+        assert!(
+            WordPage::of(&all, 9, 2, WordOrder::Alphabetical)
+                .words
+                .is_empty()
+        );
+
+        // fuzzy shares no letter with the rest, so it comes last
+        let typical = WordPage::of(&all, 3, 5, WordOrder::Typical);
+        assert_eq!(typical.words.last().unwrap().as_str(), "fuzzy");
+        assert_eq!(WordOrder::from_label(" Typical"), Some(WordOrder::Typical));
+    }
+}
@@ -0,0 +1,125 @@
+//! Past Wordle puzzles by number
+//!
+//! The bundled index lists the answers of past puzzles in order, puzzle 0 first.
+//! `$WORDLE_ARCHIVE` can point at a longer index in the same format: one answer
+//! per line, blank lines and `#` comments ignored.
+
+use crate::core::{
+    error::{DataError, Result},
+    types::Word,
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Environment variable naming an index file to use instead of the bundled one
+pub const ARCHIVE_ENV: &str = "WORDLE_ARCHIVE";
+
+const BUNDLED_INDEX: &str = include_str!("archive_index.txt");
+
+/// Days from 1970-01-01 to the day of puzzle 0, 2021-06-19
+const FIRST_PUZZLE_DAY: u64 = 18_797;
+
+/// Answers of past puzzles, indexed by puzzle number
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuzzleArchive {
+    answers: Vec<Word>,
+}
+
+impl PuzzleArchive {
+    /// The index shipped with the binary
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED_INDEX).expect("bundled archive index is valid")
+    }
+
+    /// The index named by `$WORDLE_ARCHIVE`, or the bundled one
+    pub async fn load() -> Result<Self> {
+        match std::env::var(ARCHIVE_ENV) {
+            Ok(path) if !path.trim().is_empty() => {
+                let text = tokio::fs::read_to_string(path.trim())
+                    .await
+                    .map_err(DataError::from)?;
+                Self::parse(&text)
+            }
+            _ => Ok(Self::bundled()),
+        }
+    }
+
+    /// Read an index: one answer per line in puzzle order
+    pub fn parse(text: &str) -> Result<Self> {
+        let answers = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                Word::from_str(&line.to_lowercase()).map_err(|e| {
+                    DataError::InvalidFormat(format!("archive answer '{}': {}", line, e)).into()
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { answers })
+    }
+
+    /// Answer of puzzle `number`
+    pub fn answer(&self, number: u32) -> Result<&Word> {
+        self.answers.get(number as usize).ok_or_else(|| {
+            let known = match self.answers.len() {
+                0 => "the archive is empty".to_string(),
+                n => format!("the archive has #0-#{}", n - 1),
+            };
+            DataError::MissingData(format!("puzzle #{}: {}", number, known)).into()
+        })
+    }
+
+    /// Number of puzzles in the index
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    /// Number of the puzzle published on `unix_day` (days since 1970-01-01), `None`
+    /// before the first one
+    pub fn number_on_day(unix_day: u64) -> Option<u32> {
+        unix_day
+            .checked_sub(FIRST_PUZZLE_DAY)
+            .and_then(|n| u32::try_from(n).ok())
+    }
+
+    /// Number of today's puzzle, going by the UTC date
+    pub fn todays_number() -> Option<u32> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+        Self::number_on_day(now.as_secs() / 86_400)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_index_starts_at_puzzle_zero() {
+        let archive = PuzzleArchive::bundled();
+        assert!(archive.len() >= 30);
+        assert_eq!(archive.answer(0).unwrap().as_str(), "cigar");
+        assert_eq!(archive.answer(1).unwrap().as_str(), "rebut");
+        assert!(archive.answer(archive.len() as u32).is_err());
+    }
+
+    #[test]
+    fn test_parse_skips_comments_and_rejects_bad_answers() {
+        let archive = PuzzleArchive::parse("# header\nCIGAR\n\n rebut \n").unwrap();
+        assert_eq!(archive.len(), 2);
+        assert_eq!(archive.answer(1).unwrap().as_str(), "rebut");
+        assert!(PuzzleArchive::parse("cigar\ncigars\n").is_err());
+    }
+
+    #[test]
+    fn test_puzzle_numbers_count_days_from_puzzle_zero() {
+        assert_eq!(PuzzleArchive::number_on_day(FIRST_PUZZLE_DAY - 1), None);
+        assert_eq!(PuzzleArchive::number_on_day(FIRST_PUZZLE_DAY), Some(0));
+        // 2022-01-01
+        assert_eq!(PuzzleArchive::number_on_day(18_993), Some(196));
+        assert!(PuzzleArchive::todays_number().is_some());
+    }
+}
@@ -0,0 +1,243 @@
+//! Crash- and race-safe file writes shared by processes using the same cache
+//!
+//! Writes go to a temporary file that is renamed over the target, so readers see
+//! either the old or the new contents, never a partial file. [`FileLock`] serialises
+//! slower read-modify-write cycles such as a word-list refresh.
+
+use crate::core::error::{DataError, Result};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// How often a waiting process checks whether the lock was released
+const LOCK_POLL: Duration = Duration::from_millis(50);
+
+/// Replace `path` with `bytes` in one step
+///
+/// The temporary file sits next to the target (same file system, so the rename is
+/// atomic) and is named after the process and the call, so concurrent writers,
+/// in this process or another, don't collide.
+pub async fn write_atomic(path: impl AsRef<Path>, bytes: impl AsRef<[u8]>) -> Result<()> {
+    let path = path.as_ref();
+    let tmp = sibling(path, &format!("tmp.{}", unique_suffix()));
+    if let Err(e) = tokio::fs::write(&tmp, bytes).await {
+        let _ = tokio::fs::remove_file(&tmp).await;
+        return Err(DataError::from(e).into());
+    }
+    if let Err(e) = tokio::fs::rename(&tmp, path).await {
+        let _ = tokio::fs::remove_file(&tmp).await;
+        return Err(DataError::from(e).into());
+    }
+    Ok(())
+}
+
+/// [`write_atomic`] for callers outside async code, such as autosaves made while
+/// applying a state update
+pub fn write_atomic_blocking(path: impl AsRef<Path>, bytes: impl AsRef<[u8]>) -> Result<()> {
+    let path = path.as_ref();
+    let tmp = sibling(path, &format!("tmp.{}", unique_suffix()));
+    let written = std::fs::write(&tmp, bytes).and_then(|()| std::fs::rename(&tmp, path));
+    if let Err(e) = written {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(DataError::from(e).into());
+    }
+    Ok(())
+}
+
+/// `path` with `.suffix` appended to its file name
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// `<pid>.<n>`, different on every call within the machine's running processes
+fn unique_suffix() -> String {
+    static CALLS: AtomicU64 = AtomicU64::new(0);
+    format!(
+        "{}.{}",
+        std::process::id(),
+        CALLS.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Advisory lock on a file, held as `<file>.lock` until dropped
+///
+/// Only processes that take the lock are serialised; plain readers are kept safe by
+/// [`write_atomic`]. A lock older than [`FileLock::STALE_AFTER`] is assumed to belong
+/// to a process that died and is taken over. The lock file holds an owner id, so a
+/// takeover can tell the stale lock apart from one a faster process just took.
+#[derive(Debug)]
+pub struct FileLock {
+    path: PathBuf,
+}
+
+impl FileLock {
+    /// Age after which a leftover lock is ignored
+    pub const STALE_AFTER: Duration = Duration::from_secs(10 * 60);
+
+    /// Default time to wait for another process to finish
+    pub const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+    /// Lock `target`, waiting up to `wait` for another holder to release it
+    pub async fn acquire(target: impl AsRef<Path>, wait: Duration) -> Result<Self> {
+        let path = sibling(target.as_ref(), "lock");
+        let started = std::time::Instant::now();
+        loop {
+            let created = std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path);
+            match created {
+                Ok(mut file) => {
+                    use std::io::Write;
+                    let owner = format!(
+                        "{}.{}",
+                        unique_suffix(),
+                        SystemTime::now()
+                            .duration_since(SystemTime::UNIX_EPOCH)
+                            .map(|d| d.as_nanos())
+                            .unwrap_or(0)
+                    );
+                    // Until the id is written the lock reads as empty; it is fresh
+                    // then, so nobody tries to take it over
+                    let _ = file.write_all(owner.as_bytes());
+                    return Ok(Self { path });
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    if Self::take_over_stale(&path) {
+                        log::warn!("Removed stale lock {}", path.display());
+                        continue;
+                    }
+                    if started.elapsed() >= wait {
+                        return Err(DataError::Locked(format!(
+                            "{} (remove {} if no other wordle is running)",
+                            target.as_ref().display(),
+                            path.display()
+                        ))
+                        .into());
+                    }
+                    tokio::time::sleep(LOCK_POLL).await;
+                }
+                Err(e) => return Err(DataError::from(e).into()),
+            }
+        }
+    }
+
+    /// Move a stale lock out of the way; false when it isn't stale or another
+    /// process got to it first
+    ///
+    /// Renaming is atomic, so of several processes finding the same stale lock only
+    /// one moves it. If the lock was replaced between the staleness check and the
+    /// rename, the moved file is someone's fresh lock: it goes back in place, and
+    /// the creation race is left to `create_new`.
+    fn take_over_stale(path: &Path) -> bool {
+        let Ok(owner) = std::fs::read(path) else {
+            return false;
+        };
+        if !Self::is_stale(path) {
+            return false;
+        }
+        let moved_to = sibling(path, &format!("stale.{}", unique_suffix()));
+        if std::fs::rename(path, &moved_to).is_err() {
+            return false;
+        }
+        let was_stale = std::fs::read(&moved_to).is_ok_and(|moved| moved == owner);
+        if !was_stale {
+            // hard_link never replaces: if yet another lock exists, that one stays
+            let _ = std::fs::hard_link(&moved_to, path);
+        }
+        let _ = std::fs::remove_file(&moved_to);
+        was_stale
+    }
+
+    fn is_stale(path: &Path) -> bool {
+        std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|age| age > Self::STALE_AFTER)
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_atomic_write_and_lock() {
+        let dir = std::env::temp_dir().join(format!("wordle_atomic_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let target = dir.join("cache.wlf");
+
+        write_atomic(&target, b"first").await.unwrap();
+        write_atomic(&target, b"second").await.unwrap();
+        assert_eq!(std::fs::read(&target).unwrap(), b"second");
+        write_atomic_blocking(&target, b"third").unwrap();
+        assert_eq!(std::fs::read(&target).unwrap(), b"third");
+        // Only the target is left behind
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        let held = FileLock::acquire(&target, Duration::ZERO).await.unwrap();
+        let err = FileLock::acquire(&target, Duration::from_millis(120)).await.unwrap_err();
+        assert!(err.to_string().contains("locked by another process"));
+        drop(held);
+        assert!(FileLock::acquire(&target, Duration::ZERO).await.is_ok());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_writes_in_one_process() {
+        let dir = std::env::temp_dir().join(format!("wordle_atomic_many_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let target = dir.join("cache.wlf");
+
+        let writes = (0..8).map(|i| {
+            let target = target.clone();
+            tokio::spawn(async move { write_atomic(&target, vec![b'a' + i; 4096]).await })
+        });
+        for write in writes.collect::<Vec<_>>() {
+            write.await.unwrap().unwrap();
+        }
+        let contents = std::fs::read(&target).unwrap();
+        assert_eq!(contents.len(), 4096);
+        assert!(contents.iter().all(|&b| b == contents[0]));
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_stale_lock_is_taken_over() {
+        let dir = std::env::temp_dir().join(format!("wordle_atomic_stale_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let target = dir.join("cache.wlf");
+        let lock = sibling(&target, "lock");
+
+        std::fs::write(&lock, "dead.0.0").unwrap();
+        let old = SystemTime::now() - FileLock::STALE_AFTER * 2;
+        std::fs::File::options()
+            .write(true)
+            .open(&lock)
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+
+        let held = FileLock::acquire(&target, Duration::ZERO).await.unwrap();
+        assert_ne!(std::fs::read_to_string(&lock).unwrap(), "dead.0.0");
+        // A fresh lock is left alone
+        assert!(!FileLock::take_over_stale(&lock));
+        drop(held);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,166 @@
+//! Noticing edits to the settings and word-source files while the app runs
+//!
+//! The watcher polls modification times rather than subscribing to OS file
+//! events, so it needs no extra dependency and behaves the same everywhere. A
+//! change is reported once the file has looked the same for two polls in a row,
+//! so an editor that writes in several steps gives one report, for the finished
+//! file.
+
+use crate::infrastructure::UserSettings;
+use crate::infrastructure::word_list::project_root_path;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A configuration file the app picks up again when it changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigFile {
+    /// `settings.json`: strategy, hard mode, theme and the rest of the settings screen
+    Settings,
+    /// `word_sources.json`: where the word lists are downloaded from
+    WordSources,
+}
+
+impl ConfigFile {
+    /// File name, for messages
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Settings => "settings.json",
+            Self::WordSources => "word_sources.json",
+        }
+    }
+
+    /// Where the app reads this file from
+    pub fn default_path(self) -> PathBuf {
+        match self {
+            Self::Settings => UserSettings::default_path(),
+            Self::WordSources => project_root_path("word_sources.json"),
+        }
+    }
+}
+
+/// Modification time of `path`, or `None` while it doesn't exist
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
+/// One watched file
+#[derive(Debug, Clone)]
+struct Watched {
+    file: ConfigFile,
+    path: PathBuf,
+    /// Modification time the app has already applied
+    applied: Option<SystemTime>,
+    /// Modification time seen by the last poll
+    seen: Option<SystemTime>,
+}
+
+/// Polls configuration files for changes
+#[derive(Debug, Clone)]
+pub struct ConfigWatcher {
+    files: Vec<Watched>,
+}
+
+impl ConfigWatcher {
+    /// Watch `files` at the given paths; their current state counts as applied
+    pub fn new(files: impl IntoIterator<Item = (ConfigFile, PathBuf)>) -> Self {
+        let files = files
+            .into_iter()
+            .map(|(file, path)| {
+                let applied = modified(&path);
+                Watched {
+                    file,
+                    path,
+                    applied,
+                    seen: applied,
+                }
+            })
+            .collect();
+        Self { files }
+    }
+
+    /// Watch the settings and word-source files where the app reads them
+    pub fn for_defaults() -> Self {
+        Self::new(
+            [ConfigFile::Settings, ConfigFile::WordSources].map(|file| (file, file.default_path())),
+        )
+    }
+
+    /// Files that changed (including being created or removed) and have since
+    /// settled; each change is reported once
+    pub fn poll(&mut self) -> Vec<ConfigFile> {
+        let mut changed = Vec::new();
+        for watched in &mut self.files {
+            let now = modified(&watched.path);
+            if now != watched.applied && now == watched.seen {
+                watched.applied = now;
+                changed.push(watched.file);
+            }
+            watched.seen = now;
+        }
+        changed
+    }
+
+    /// Treat the current state of `file` as applied, e.g. after the app saved it
+    /// itself
+    pub fn acknowledge(&mut self, file: ConfigFile) {
+        for watched in self.files.iter_mut().filter(|watched| watched.file == file) {
+            watched.applied = modified(&watched.path);
+            watched.seen = watched.applied;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_changes_are_reported_once_settled() {
+        let dir = std::env::temp_dir().join(format!("wordle_watch_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let settings = dir.join("settings.json");
+        let sources = dir.join("word_sources.json");
+        std::fs::write(&settings, "{}").unwrap();
+        let _ = std::fs::remove_file(&sources);
+
+        let mut watcher = ConfigWatcher::new([
+            (ConfigFile::Settings, settings.clone()),
+            (ConfigFile::WordSources, sources.clone()),
+        ]);
+        assert!(watcher.poll().is_empty());
+
+        // Filesystems with coarse timestamps need the new time to differ
+        std::thread::sleep(Duration::from_millis(20));
+        std::fs::write(&sources, "{}").unwrap();
+        let later = SystemTime::now() + Duration::from_secs(5);
+        std::fs::File::options()
+            .write(true)
+            .open(&settings)
+            .and_then(|file| file.set_modified(later))
+            .unwrap();
+        // Still being written, as far as the watcher knows
+        assert!(watcher.poll().is_empty());
+        assert_eq!(
+            watcher.poll(),
+            [ConfigFile::Settings, ConfigFile::WordSources]
+        );
+        assert!(watcher.poll().is_empty());
+
+        // The app's own save isn't reported back to it
+        std::fs::File::options()
+            .write(true)
+            .open(&settings)
+            .and_then(|file| file.set_modified(later + Duration::from_secs(5)))
+            .unwrap();
+        watcher.acknowledge(ConfigFile::Settings);
+        assert!(watcher.poll().is_empty());
+
+        std::fs::remove_file(&sources).unwrap();
+        watcher.poll();
+        assert_eq!(watcher.poll(), [ConfigFile::WordSources]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
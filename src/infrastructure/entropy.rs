@@ -1,36 +1,20 @@
-use crate::core::{traits::EntropyCalculator, types::Word};
-
+use crate::core::{
+    algorithms::{self, PATTERN_COUNT},
+    ranking::CandidateRanker,
+    traits::EntropyCalculator,
+    types::Word,
+};
+
+/// Partition `possible_words` by feedback for `guess` (compact base-3 pattern indices)
 #[inline]
-fn feedback_index_bytes_common(guess_b: &[u8; 5], target_b: &[u8; 5]) -> usize {
-    let mut used = [false; 5];
-    let mut f = [0u8; 5];
-
-    // Greens
-    for i in 0..5 {
-        if guess_b[i] == target_b[i] {
-            f[i] = 2;
-            used[i] = true;
-        }
-    }
-    // Yellows
-    for i in 0..5 {
-        if f[i] == 0 {
-            let g = guess_b[i];
-            for j in 0..5 {
-                if !used[j] && g == target_b[j] {
-                    f[i] = 1;
-                    used[j] = true;
-                    break;
-                }
-            }
-        }
-    }
-    // Encode base-3 little-endian
-    (f[0] as usize)
-        + (f[1] as usize) * 3
-        + (f[2] as usize) * 9
-        + (f[3] as usize) * 27
-        + (f[4] as usize) * 81
+fn pattern_counts(guess: &Word, possible_words: &[Word]) -> ([u32; PATTERN_COUNT], u32) {
+    let mut counts = [0u32; PATTERN_COUNT];
+    let total = algorithms::count_patterns(
+        guess.bytes(),
+        possible_words.iter().map(Word::bytes),
+        &mut counts,
+    );
+    (counts, total)
 }
 
 /// High-performance entropy calculator with caching
@@ -42,40 +26,10 @@ impl CachedEntropyCalculator {
         Self {}
     }
 
-    /// Compute a compact feedback index (0..243) without allocations.
-    /// Encodes feedback as base-3 digits (0=Absent,1=Present,2=Correct) with position-weighted digits.
-    #[inline]
-    fn feedback_index_bytes(&self, guess_b: &[u8; 5], target_b: &[u8; 5]) -> usize {
-        feedback_index_bytes_common(guess_b, target_b)
-    }
-
-    #[inline]
-    fn feedback_index(&self, guess: &Word, target: &Word) -> usize {
-        self.feedback_index_bytes(guess.bytes(), target.bytes())
-    }
-
     /// Calculate information gain based on expected partition sizes
     fn calculate_information_gain_internal(&self, guess: &Word, possible_words: &[Word]) -> f64 {
-        if possible_words.is_empty() {
-            return 0.0;
-        }
-        // Count into 243 buckets
-        let mut counts = [0usize; 243];
-        for w in possible_words {
-            let idx = self.feedback_index(guess, w);
-            counts[idx] += 1;
-        }
-        let total = possible_words.len() as f64;
-        let expected_log_size: f64 = counts
-            .iter()
-            .filter(|&&c| c > 0)
-            .map(|&c| {
-                let p = c as f64 / total;
-                let log_size = (c as f64).log2();
-                p * log_size
-            })
-            .sum();
-        total.log2() - expected_log_size
+        let (counts, total) = pattern_counts(guess, possible_words);
+        algorithms::information_gain_from_counts(&counts, total)
     }
 }
 
@@ -90,20 +44,8 @@ impl EntropyCalculator for CachedEntropyCalculator {
         if possible_words.len() <= 1 {
             return 0.0;
         }
-        let mut counts = [0usize; 243];
-        for w in possible_words {
-            let idx = self.feedback_index(guess, w);
-            counts[idx] += 1;
-        }
-        let total = possible_words.len() as f64;
-        counts
-            .iter()
-            .filter(|&&c| c > 0)
-            .map(|&c| {
-                let p = c as f64 / total;
-                -p * p.log2()
-            })
-            .sum()
+        let (counts, total) = pattern_counts(guess, possible_words);
+        algorithms::entropy_from_counts(&counts, total)
     }
 
     fn calculate_information_gain(&self, guess: &Word, possible_words: &[Word]) -> f64 {
@@ -115,15 +57,36 @@ impl EntropyCalculator for CachedEntropyCalculator {
             return None;
         }
 
-        candidates
-            .iter()
-            .map(|word| {
-                let entropy = self.calculate_entropy(word, possible_words);
-                (word.clone(), entropy)
-            })
-            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-            .map(|(word, _)| word)
+        CandidateRanker::new(possible_words)
+            .best(
+                candidates
+                    .iter()
+                    .map(|word| (word, self.calculate_entropy(word, possible_words))),
+            )
+            .cloned()
+    }
+}
+
+/// Bits of expected information each letter of `guess` is responsible for
+///
+/// Letter `i` is credited with the entropy lost when its tile is hidden, i.e.
+/// when the partition merges feedback patterns that differ only at `i`.
+/// Letters can share information, so the values needn't add up to the total.
+pub fn letter_contributions(guess: &Word, possible_words: &[Word]) -> [f64; Word::LENGTH] {
+    let (counts, total) = pattern_counts(guess, possible_words);
+    let full = algorithms::entropy_from_counts(&counts, total);
+
+    let mut contributions = [0.0; Word::LENGTH];
+    for (pos, contribution) in contributions.iter_mut().enumerate() {
+        let mut masked = [0u32; PATTERN_COUNT];
+        for (pattern, &count) in counts.iter().enumerate().filter(|&(_, &c)| c > 0) {
+            let mut digits = algorithms::decode_pattern(pattern as u16);
+            digits[pos] = algorithms::ABSENT;
+            masked[algorithms::encode_pattern(&digits) as usize] += count;
+        }
+        *contribution = full - algorithms::entropy_from_counts(&masked, total);
     }
+    contributions
 }
 
 /// Simple entropy calculator without caching (for testing/comparison)
@@ -134,67 +97,20 @@ impl SimpleEntropyCalculator {
     pub fn new() -> Self {
         Self
     }
-
-    #[inline]
-    fn feedback_index_bytes(&self, guess_b: &[u8; 5], target_b: &[u8; 5]) -> usize {
-        feedback_index_bytes_common(guess_b, target_b)
-    }
-
-    #[inline]
-    fn feedback_index(&self, guess: &Word, target: &Word) -> usize {
-        self.feedback_index_bytes(guess.bytes(), target.bytes())
-    }
 }
 
 // Default derived above
 
 impl EntropyCalculator for SimpleEntropyCalculator {
     fn calculate_entropy(&self, guess: &Word, possible_words: &[Word]) -> f64 {
-        if possible_words.is_empty() {
-            return 0.0;
-        }
-
-        let mut counts = [0usize; 243];
-        for w in possible_words {
-            let idx = self.feedback_index(guess, w);
-            counts[idx] += 1;
-        }
-        let total = possible_words.len() as f64;
-        counts
-            .iter()
-            .filter(|&&c| c > 0)
-            .map(|&c| {
-                let p = c as f64 / total;
-                -p * p.log2()
-            })
-            .sum()
+        let (counts, total) = pattern_counts(guess, possible_words);
+        algorithms::entropy_from_counts(&counts, total)
     }
 
     fn calculate_information_gain(&self, guess: &Word, possible_words: &[Word]) -> f64 {
-        if possible_words.is_empty() {
-            return 0.0;
-        }
-
         // Use expected log-size reduction, same as cached version
-        if possible_words.is_empty() {
-            return 0.0;
-        }
-        let mut counts = [0usize; 243];
-        for w in possible_words {
-            let idx = self.feedback_index(guess, w);
-            counts[idx] += 1;
-        }
-        let total = possible_words.len() as f64;
-        let expected_log_size: f64 = counts
-            .iter()
-            .filter(|&&c| c > 0)
-            .map(|&c| {
-                let p = c as f64 / total;
-                let log_size = (c as f64).log2();
-                p * log_size
-            })
-            .sum();
-        total.log2() - expected_log_size
+        let (counts, total) = pattern_counts(guess, possible_words);
+        algorithms::information_gain_from_counts(&counts, total)
     }
 
     fn find_max_entropy_guess(&self, candidates: &[Word], possible_words: &[Word]) -> Option<Word> {
@@ -202,14 +118,13 @@ impl EntropyCalculator for SimpleEntropyCalculator {
             return None;
         }
 
-        candidates
-            .iter()
-            .map(|word| {
-                let entropy = self.calculate_entropy(word, possible_words);
-                (word.clone(), entropy)
-            })
-            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-            .map(|(word, _)| word)
+        CandidateRanker::new(possible_words)
+            .best(
+                candidates
+                    .iter()
+                    .map(|word| (word, self.calculate_entropy(word, possible_words))),
+            )
+            .cloned()
     }
 }
 
@@ -237,6 +152,44 @@ mod tests {
         assert_eq!(single_entropy, 0.0);
     }
 
+    #[test]
+    fn test_letter_contributions() {
+        let words: Vec<Word> = ["fight", "light", "might", "sight"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+        let guess = Word::from_str("flame").unwrap();
+
+        let heat = letter_contributions(&guess, &words);
+        // Only F, L and M tell the words apart; A and E carry nothing
+        assert!(heat[0] > 0.0 && heat[1] > 0.0 && heat[3] > 0.0);
+        assert_eq!(heat[2], 0.0);
+        assert_eq!(heat[4], 0.0);
+        assert!(heat.iter().all(|&bits| bits <= 2.0 + 1e-12));
+    }
+
+    #[test]
+    fn test_partition_counts_match_entropy() {
+        use crate::core::types::{FeedbackPattern, SuggestionQuality};
+
+        let calculator = CachedEntropyCalculator::new();
+        let words: Vec<Word> = ["crane", "crate", "trace", "slate", "caret"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+        let guess = Word::from_str("crate").unwrap();
+
+        let counts = calculator.partition_counts(&guess, &words);
+        assert_eq!(counts.iter().sum::<usize>(), words.len());
+        let solved = FeedbackPattern::from_code_string("22222").unwrap();
+        assert_eq!(counts[solved.index() as usize], 1);
+
+        let quality = SuggestionQuality::from_partition(&counts);
+        let entropy = calculator.calculate_entropy(&guess, &words);
+        assert!((quality.entropy - entropy).abs() < 1e-12);
+        assert_eq!(quality, SuggestionQuality::evaluate(&guess, &words));
+    }
+
     #[test]
     fn test_max_entropy_guess() {
         let calculator = SimpleEntropyCalculator::new();
@@ -1,8 +1,30 @@
+pub mod archive;
+pub mod atomic_file;
+pub mod config_watch;
 pub mod entropy;
+pub mod http;
+#[cfg(feature = "net")]
+pub mod notifier;
+pub mod self_update;
+pub mod settings;
+pub mod statistics;
+pub mod stats_backend;
+pub mod stats_import;
 pub mod strategy;
 /// Infrastructure layer for external concerns
 pub mod word_list;
 
+pub use archive::*;
+pub use atomic_file::*;
+pub use config_watch::*;
 pub use entropy::*;
+pub use http::*;
+#[cfg(feature = "net")]
+pub use notifier::*;
+pub use self_update::*;
+pub use settings::*;
+pub use statistics::*;
+pub use stats_backend::*;
+pub use stats_import::*;
 pub use strategy::*;
 pub use word_list::*;
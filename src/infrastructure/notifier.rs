@@ -0,0 +1,290 @@
+use crate::core::{
+    error::{DataError, Result},
+    types::Guess,
+};
+use crate::core::types::Word;
+use crate::domain::GameEvent;
+use crate::infrastructure::http::HttpConfig;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// Environment variable holding comma-separated webhook URLs
+pub const WEBHOOK_URLS_ENV: &str = "WORDLE_WEBHOOK_URLS";
+
+/// One guess row in a webhook payload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookGuess {
+    pub word: String,
+    /// Feedback as a 0/1/2 code string
+    pub feedback: String,
+}
+
+/// JSON payload sent when a game completes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameCompletedPayload {
+    pub event: String,
+    /// Target word, when known
+    pub target: Option<String>,
+    pub won: bool,
+    pub guesses: Vec<WebhookGuess>,
+    pub duration_secs: f64,
+}
+
+impl GameCompletedPayload {
+    pub fn new(target: Option<String>, won: bool, guesses: &[Guess], duration: Duration) -> Self {
+        Self {
+            event: "game_completed".to_string(),
+            target,
+            won,
+            guesses: guesses
+                .iter()
+                .map(|g| WebhookGuess {
+                    word: g.word.to_string(),
+                    feedback: g.feedback.to_code_string(),
+                })
+                .collect(),
+            duration_secs: duration.as_secs_f64(),
+        }
+    }
+
+    /// The payload for a [`GameEvent::GameFinished`] event; None for other events
+    pub fn from_event(event: &GameEvent) -> Option<Self> {
+        match event {
+            GameEvent::GameFinished {
+                won,
+                target,
+                guesses,
+                duration,
+            } => Some(Self::new(target.as_ref().map(Word::to_string), *won, guesses, *duration)),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of delivering to one webhook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryReport {
+    pub url: String,
+    pub attempts: u32,
+    pub delivered: bool,
+    pub last_error: Option<String>,
+}
+
+/// Delivers game-completion payloads to registered webhook URLs with retries
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    urls: Vec<String>,
+    client: reqwest::Client,
+    max_attempts: u32,
+    initial_backoff: Duration,
+}
+
+impl WebhookNotifier {
+    pub fn new() -> Result<Self> {
+        let client = HttpConfig::default()
+            .with_env()
+            .client(Duration::from_secs(10))?;
+        Ok(Self {
+            urls: Vec::new(),
+            client,
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+        })
+    }
+
+    /// Create a notifier with URLs from `WORDLE_WEBHOOK_URLS`, or None when unset
+    pub fn from_env() -> Result<Option<Self>> {
+        let Ok(value) = std::env::var(WEBHOOK_URLS_ENV) else {
+            return Ok(None);
+        };
+        let mut notifier = Self::new()?;
+        for url in value.split(',').map(str::trim).filter(|u| !u.is_empty()) {
+            notifier.register(url)?;
+        }
+        Ok((!notifier.urls.is_empty()).then_some(notifier))
+    }
+
+    /// Set the retry policy (attempts per URL and the first backoff, doubled per retry)
+    pub fn with_retry_policy(mut self, max_attempts: u32, initial_backoff: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Register a webhook URL
+    pub fn register(&mut self, url: &str) -> Result<()> {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(DataError::InvalidFormat(format!(
+                "Webhook URL must start with http:// or https://: {}",
+                url
+            ))
+            .into());
+        }
+        if !self.urls.iter().any(|u| u == url) {
+            self.urls.push(url.to_string());
+        }
+        Ok(())
+    }
+
+    /// Remove a registered URL, returning whether it was present
+    pub fn unregister(&mut self, url: &str) -> bool {
+        let before = self.urls.len();
+        self.urls.retain(|u| u != url);
+        self.urls.len() != before
+    }
+
+    pub fn urls(&self) -> &[String] {
+        &self.urls
+    }
+
+    /// POST the payload to every registered URL, retrying failures with exponential backoff
+    pub async fn notify(&self, payload: &GameCompletedPayload) -> Vec<DeliveryReport> {
+        let mut reports = Vec::with_capacity(self.urls.len());
+        for url in &self.urls {
+            reports.push(self.deliver(url, payload).await);
+        }
+        reports
+    }
+
+    /// Notify for every finished game on `events` until the channel closes
+    ///
+    /// Returns the delivery reports of all of them.
+    pub async fn follow(&self, mut events: broadcast::Receiver<GameEvent>) -> Vec<DeliveryReport> {
+        let mut reports = Vec::new();
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    if let Some(payload) = GameCompletedPayload::from_event(&event) {
+                        reports.extend(self.notify(&payload).await);
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    log::warn!("Webhook listener fell behind; skipped {} game events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return reports,
+            }
+        }
+    }
+
+    async fn deliver(&self, url: &str, payload: &GameCompletedPayload) -> DeliveryReport {
+        let mut backoff = self.initial_backoff;
+        let mut last_error = None;
+
+        for attempt in 1..=self.max_attempts {
+            let result = self.client.post(url).json(payload).send().await;
+            match result {
+                Ok(response) if response.status().is_success() => {
+                    log::info!("Webhook delivered to {} (attempt {})", url, attempt);
+                    return DeliveryReport {
+                        url: url.to_string(),
+                        attempts: attempt,
+                        delivered: true,
+                        last_error: None,
+                    };
+                }
+                Ok(response) => {
+                    last_error = Some(format!("HTTP {}", response.status()));
+                }
+                Err(e) => {
+                    last_error = Some(e.to_string());
+                }
+            }
+            log::warn!(
+                "Webhook delivery to {} failed (attempt {}/{}): {}",
+                url,
+                attempt,
+                self.max_attempts,
+                last_error.as_deref().unwrap_or("unknown error")
+            );
+            if attempt < self.max_attempts {
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+        }
+
+        DeliveryReport {
+            url: url.to_string(),
+            attempts: self.max_attempts,
+            delivered: false,
+            last_error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{FeedbackPattern, Word};
+
+    #[test]
+    fn test_payload_serialization() {
+        let guesses = vec![Guess::new(
+            Word::from_str("crane").unwrap(),
+            FeedbackPattern::from_code_string("22222").unwrap(),
+        )];
+        let payload = GameCompletedPayload::new(
+            Some("crane".to_string()),
+            true,
+            &guesses,
+            Duration::from_secs(42),
+        );
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["event"], "game_completed");
+        assert_eq!(json["guesses"][0]["feedback"], "22222");
+        assert_eq!(json["duration_secs"], 42.0);
+    }
+
+    #[test]
+    fn test_register_validates_urls() {
+        let mut notifier = WebhookNotifier::new().unwrap();
+        assert!(notifier.register("ftp://example.com").is_err());
+        notifier.register("https://example.com/hook").unwrap();
+        notifier.register("https://example.com/hook").unwrap();
+        assert_eq!(notifier.urls().len(), 1);
+        assert!(notifier.unregister("https://example.com/hook"));
+    }
+
+    #[tokio::test]
+    async fn test_follow_notifies_finished_games() {
+        let mut notifier = WebhookNotifier::new()
+            .unwrap()
+            .with_retry_policy(1, Duration::ZERO);
+        notifier.register("http://127.0.0.1:1/hook").unwrap();
+
+        let bus = crate::domain::GameEventBus::new();
+        let events = bus.subscribe();
+        bus.publish(GameEvent::GameStarted { target_set: false });
+        let finished = GameEvent::GameFinished {
+            won: true,
+            target: Some(Word::from_str("crane").unwrap()),
+            guesses: Vec::new(),
+            duration: Duration::from_secs(3),
+        };
+        let payload = GameCompletedPayload::from_event(&finished).unwrap();
+        assert_eq!(payload.target.as_deref(), Some("crane"));
+        bus.publish(finished);
+        drop(bus);
+
+        // Only the finished game is delivered, and following ends with the channel
+        let reports = notifier.follow(events).await;
+        assert_eq!(reports.len(), 1);
+        assert!(!reports[0].delivered);
+    }
+
+    #[tokio::test]
+    async fn test_failed_delivery_is_retried() {
+        let mut notifier = WebhookNotifier::new()
+            .unwrap()
+            .with_retry_policy(2, Duration::ZERO);
+        // Port 1 on localhost refuses connections immediately
+        notifier.register("http://127.0.0.1:1/hook").unwrap();
+
+        let payload = GameCompletedPayload::new(None, false, &[], Duration::ZERO);
+        let reports = notifier.notify(&payload).await;
+        assert_eq!(reports.len(), 1);
+        assert!(!reports[0].delivered);
+        assert_eq!(reports[0].attempts, 2);
+        assert!(reports[0].last_error.is_some());
+    }
+}
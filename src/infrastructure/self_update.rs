@@ -0,0 +1,247 @@
+//! Checking GitHub for a newer release of the prebuilt binary
+//!
+//! `update --check` asks the releases API for the latest release and compares its
+//! tag with this build's version. Replacing the running binary with the release
+//! asset for this platform needs the `self-update` feature; cargo installs should
+//! update through cargo instead.
+
+use crate::core::error::{DataError, Result};
+use crate::infrastructure::http::HttpConfig;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Latest release of this project on GitHub
+pub const RELEASES_URL: &str =
+    "https://api.github.com/repos/gae-22/wordle_solver/releases/latest";
+
+/// Environment variable pointing the check at another releases endpoint (a mirror,
+/// or a local server when testing)
+pub const RELEASES_URL_ENV: &str = "WORDLE_RELEASES_URL";
+
+/// Version of this build
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A file attached to a release
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub browser_download_url: String,
+    #[serde(default)]
+    pub size: u64,
+}
+
+/// The parts of a GitHub release the update check uses
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    pub html_url: String,
+    /// Release notes in Markdown
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+/// Parse "1.2.3" or "v1.2.3" into its numbers; a missing minor or patch counts as 0
+///
+/// Anything after a `-` or `+` (pre-release or build metadata) is ignored.
+pub fn parse_version(text: &str) -> Option<(u64, u64, u64)> {
+    let core = text.trim().trim_start_matches(['v', 'V']);
+    let core = core.split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|part| part.parse::<u64>());
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+    parts.next().is_none().then_some((major, minor, patch))
+}
+
+impl Release {
+    /// Version from the tag, if it is one
+    pub fn version(&self) -> Option<(u64, u64, u64)> {
+        parse_version(&self.tag_name)
+    }
+
+    /// Whether this release is newer than `current`
+    pub fn is_newer_than(&self, current: &str) -> bool {
+        match (self.version(), parse_version(current)) {
+            (Some(latest), Some(current)) => latest > current,
+            _ => false,
+        }
+    }
+
+    /// Up to `limit` bullet points from the release notes, without their markers
+    ///
+    /// Notes without bullets give their first lines of text instead.
+    pub fn highlights(&self, limit: usize) -> Vec<String> {
+        let body = self.body.as_deref().unwrap_or("");
+        let text_lines = body.lines().map(str::trim).filter(|line| !line.is_empty());
+        let bullets: Vec<String> = text_lines
+            .clone()
+            .filter_map(|line| {
+                line.strip_prefix("- ")
+                    .or_else(|| line.strip_prefix("* "))
+                    .map(|item| item.trim().to_string())
+            })
+            .take(limit)
+            .collect();
+        if !bullets.is_empty() {
+            return bullets;
+        }
+        text_lines
+            .filter(|line| !line.starts_with('#'))
+            .take(limit)
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// The plain binary built for this platform, matched by OS and architecture in
+    /// its name (archives and checksums are skipped)
+    pub fn asset_for_platform(&self) -> Option<&ReleaseAsset> {
+        let os = match std::env::consts::OS {
+            "macos" => "darwin",
+            other => other,
+        };
+        let arch = std::env::consts::ARCH;
+        self.assets.iter().find(|asset| {
+            let name = asset.name.to_ascii_lowercase();
+            let packed = [".tar.gz", ".tgz", ".zip", ".sha256", ".sig", ".asc"]
+                .iter()
+                .any(|ext| name.ends_with(ext));
+            !packed
+                && (name.contains(os) || (os == "darwin" && name.contains("macos")))
+                && name.contains(arch)
+        })
+    }
+}
+
+/// The releases endpoint to query: [`RELEASES_URL_ENV`] or [`RELEASES_URL`]
+pub fn releases_url() -> String {
+    std::env::var(RELEASES_URL_ENV)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| RELEASES_URL.to_string())
+}
+
+/// Ask the releases API for the latest release
+#[cfg(feature = "net")]
+pub async fn fetch_latest_release(http: &HttpConfig) -> Result<Release> {
+    let client = http.client(std::time::Duration::from_secs(20))?;
+    let url = releases_url();
+    let failed = |e: reqwest::Error| DataError::MissingData(format!("{}: {}", url, e));
+    let response = client
+        .get(&url)
+        .header(reqwest::header::USER_AGENT, format!("wordle/{}", CURRENT_VERSION))
+        .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(failed)?;
+    let release = response.json::<Release>().await.map_err(failed)?;
+    Ok(release)
+}
+
+#[cfg(not(feature = "net"))]
+pub async fn fetch_latest_release(_http: &HttpConfig) -> Result<Release> {
+    Err(DataError::MissingData(
+        "Update check is unavailable: built without the `net` feature".to_string(),
+    )
+    .into())
+}
+
+/// Download `asset` and put it in place of the running binary; returns its path
+///
+/// The download goes to a file next to the binary first, so a failed download
+/// leaves the installed binary untouched.
+#[cfg(feature = "self-update")]
+pub async fn install_release(asset: &ReleaseAsset, http: &HttpConfig) -> Result<PathBuf> {
+    let client = http.client(std::time::Duration::from_secs(300))?;
+    let failed = |e: reqwest::Error| {
+        DataError::MissingData(format!("{}: {}", asset.browser_download_url, e))
+    };
+    let bytes = client
+        .get(&asset.browser_download_url)
+        .header(reqwest::header::USER_AGENT, format!("wordle/{}", CURRENT_VERSION))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(failed)?
+        .bytes()
+        .await
+        .map_err(failed)?;
+    if asset.size != 0 && bytes.len() as u64 != asset.size {
+        return Err(DataError::InvalidFormat(format!(
+            "{} is {} bytes, expected {}",
+            asset.name,
+            bytes.len(),
+            asset.size
+        ))
+        .into());
+    }
+
+    let exe = std::env::current_exe().map_err(DataError::FileError)?;
+    let staged = exe.with_extension("new");
+    std::fs::write(&staged, &bytes).map_err(DataError::FileError)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o755))
+            .map_err(DataError::FileError)?;
+    }
+    // Windows can't overwrite a running executable, but it can rename it
+    #[cfg(windows)]
+    std::fs::rename(&exe, exe.with_extension("old")).map_err(DataError::FileError)?;
+    std::fs::rename(&staged, &exe).map_err(DataError::FileError)?;
+    Ok(exe)
+}
+
+#[cfg(not(feature = "self-update"))]
+pub async fn install_release(_asset: &ReleaseAsset, _http: &HttpConfig) -> Result<PathBuf> {
+    Err(DataError::MissingData(
+        "Installing updates is unavailable: built without the `self-update` feature"
+            .to_string(),
+    )
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(tag: &str, body: &str) -> Release {
+        Release {
+            tag_name: tag.to_string(),
+            html_url: String::new(),
+            body: Some(body.to_string()),
+            assets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_versions_compare_numerically() {
+        assert_eq!(parse_version("v1.10.2"), Some((1, 10, 2)));
+        assert_eq!(parse_version("2.0"), Some((2, 0, 0)));
+        assert_eq!(parse_version("1.2.3-rc.1"), Some((1, 2, 3)));
+        assert_eq!(parse_version("nightly"), None);
+        assert_eq!(parse_version("1.2.3.4"), None);
+
+        assert!(release("v1.10.0", "").is_newer_than("1.9.9"));
+        assert!(!release("v1.0.0", "").is_newer_than("1.0.0"));
+        assert!(!release("nightly", "").is_newer_than("1.0.0"));
+    }
+
+    #[test]
+    fn test_highlights_prefer_bullets() {
+        let notes = "## What's new\n\n- Faster solver\n* Daily forecast\n\nThanks!";
+        assert_eq!(
+            release("v2.0.0", notes).highlights(5),
+            ["Faster solver", "Daily forecast"]
+        );
+        assert_eq!(release("v2.0.0", notes).highlights(1), ["Faster solver"]);
+        assert_eq!(
+            release("v2.0.0", "## Notes\nBug fixes only.").highlights(3),
+            ["Bug fixes only."]
+        );
+    }
+}
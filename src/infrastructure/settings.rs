@@ -0,0 +1,293 @@
+//! User settings edited from the TUI settings screen
+//!
+//! Stored as JSON next to the statistics file. Unknown or missing fields fall
+//! back to their defaults, so older files keep loading.
+
+use crate::core::{
+    container::{DependencyConfig, StrategyTuning, StrategyType},
+    error::{DataError, Result},
+};
+use crate::domain::GameRules;
+use crate::infrastructure::{StatsBackendKind, WordSourcePreset};
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+/// Color scheme of the terminal UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Self::Dark, Self::Light, Self::HighContrast];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Dark => "dark",
+            Self::Light => "light",
+            Self::HighContrast => "high-contrast",
+        }
+    }
+}
+
+/// TLS certificate configuration for serve mode
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TlsConfig {
+    /// PEM certificate chain
+    pub cert_path: String,
+    /// PEM private key
+    pub key_path: String,
+}
+
+/// Per-key request rate limits
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// Sustained requests allowed per minute
+    pub requests_per_minute: u32,
+    /// Extra requests allowed in a short burst
+    pub burst: u32,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_minute: 120,
+            burst: 20,
+        }
+    }
+}
+
+/// `server` section of the settings: who may drive the solver from other programs,
+/// and where serve mode listens
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    /// Address to bind, e.g. "127.0.0.1:8080"
+    pub bind_address: String,
+    /// Serve over HTTPS when set
+    pub tls: Option<TlsConfig>,
+    /// Accepted API keys; authentication is disabled when empty
+    pub api_keys: Vec<String>,
+    /// Rate limiting; disabled when None
+    pub rate_limit: Option<RateLimitConfig>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind_address: "127.0.0.1:8080".to_string(),
+            tls: None,
+            api_keys: Vec::new(),
+            rate_limit: Some(RateLimitConfig::default()),
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Whether the configuration exposes the server beyond localhost without auth
+    pub fn is_publicly_exposed_without_auth(&self) -> bool {
+        !is_loopback(&self.bind_address) && self.api_keys.is_empty()
+    }
+}
+
+/// Whether `address` (host or host:port) only listens on this machine
+fn is_loopback(address: &str) -> bool {
+    if let Ok(socket) = address.parse::<SocketAddr>() {
+        return socket.ip().is_loopback();
+    }
+    let unbracketed = address.trim_start_matches('[').trim_end_matches(']');
+    if let Ok(ip) = unbracketed.parse::<IpAddr>() {
+        return ip.is_loopback();
+    }
+    let host = address.rsplit_once(':').map_or(address, |(host, _)| host);
+    host.eq_ignore_ascii_case("localhost")
+}
+
+/// Persisted solver and UI preferences
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserSettings {
+    pub strategy: StrategyType,
+    /// Strategy parameters, e.g. as found by `tune`
+    pub tuning: StrategyTuning,
+    pub hard_mode: bool,
+    pub theme: Theme,
+    pub word_source: WordSourcePreset,
+    /// Guesses allowed per game
+    pub max_guesses: usize,
+    /// Refuse a word already guessed in the same game, as the official game does
+    pub reject_repeated_guesses: bool,
+    /// Flip feedback tiles in one by one instead of showing them at once
+    pub animations: bool,
+    /// Don't suggest words the player guessed in earlier games
+    pub avoid_used_words: bool,
+    /// Draw the full empty grid, like the classic game, instead of only played rows
+    pub blank_board: bool,
+    /// Print what the session added to the statistics when quitting the TUI
+    pub session_summary: bool,
+    /// Keep the answer out of sight for players next to someone who knows it
+    pub no_spoilers: bool,
+    /// Where finished games are kept
+    pub stats_backend: StatsBackendKind,
+    /// API keys and rate limits for pipe mode; no checks when absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server: Option<ServerConfig>,
+}
+
+impl Default for UserSettings {
+    fn default() -> Self {
+        let config = DependencyConfig::default();
+        Self {
+            strategy: config.strategy_type,
+            tuning: config.tuning,
+            hard_mode: config.hard_mode,
+            theme: Theme::default(),
+            word_source: config.word_list_config.source_preset,
+            max_guesses: config.max_guesses,
+            reject_repeated_guesses: config.rules.reject_repeated_guesses,
+            animations: true,
+            avoid_used_words: false,
+            blank_board: false,
+            session_summary: false,
+            no_spoilers: false,
+            stats_backend: StatsBackendKind::default(),
+            server: None,
+        }
+    }
+}
+
+impl UserSettings {
+    /// Guess budgets offered by the settings screen
+    pub const GUESS_BUDGETS: RangeInclusive<usize> = 3..=10;
+
+    /// Default settings file in the project root
+    pub fn default_path() -> PathBuf {
+        crate::infrastructure::word_list::project_root_path("settings.json")
+    }
+
+    /// Load settings; a missing file yields the defaults
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Ok(serde_json::from_str(&text).map_err(DataError::from)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(DataError::from(e).into()),
+        }
+    }
+
+    /// Load the default settings file, falling back to defaults if it's unreadable
+    pub fn load_default() -> Self {
+        let path = Self::default_path();
+        Self::load(&path).unwrap_or_else(|e| {
+            log::warn!("Ignoring settings in {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    /// Write the settings to `path`
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let text = serde_json::to_string_pretty(self).map_err(DataError::from)?;
+        std::fs::write(path, text).map_err(DataError::from)?;
+        Ok(())
+    }
+
+    /// Container configuration for these settings
+    ///
+    /// The word-list snapshot isn't a saved setting; it comes from `WORDLE_WORDLIST_TAG`.
+    pub fn dependency_config(&self) -> DependencyConfig {
+        let mut config = DependencyConfig {
+            strategy_type: self.strategy,
+            tuning: self.tuning,
+            hard_mode: self.hard_mode,
+            max_guesses: self.max_guesses,
+            rules: GameRules {
+                reject_repeated_guesses: self.reject_repeated_guesses,
+            },
+            ..DependencyConfig::default()
+        };
+        config.word_list_config.source_preset = self.word_source;
+        config.word_list_config.snapshot_tag = crate::infrastructure::resolve_wordlist_tag(None);
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_round_trip_and_defaults() {
+        let path =
+            std::env::temp_dir().join(format!("wordle_settings_test_{}.json", std::process::id()));
+        assert_eq!(UserSettings::load(&path).unwrap(), UserSettings::default());
+
+        let settings = UserSettings {
+            strategy: StrategyType::Hybrid,
+            tuning: StrategyTuning {
+                entropy_percent: 70,
+                ..StrategyTuning::default()
+            },
+            hard_mode: true,
+            theme: Theme::HighContrast,
+            word_source: WordSourcePreset::Wordle,
+            max_guesses: 8,
+            reject_repeated_guesses: false,
+            animations: false,
+            avoid_used_words: true,
+            blank_board: true,
+            session_summary: true,
+            no_spoilers: true,
+            stats_backend: StatsBackendKind::Json,
+            server: Some(ServerConfig {
+                api_keys: vec!["secret".to_string()],
+                ..ServerConfig::default()
+            }),
+        };
+        settings.save(&path).unwrap();
+        assert_eq!(UserSettings::load(&path).unwrap(), settings);
+
+        std::fs::write(&path, r#"{"theme": "light"}"#).unwrap();
+        let partial = UserSettings::load(&path).unwrap();
+        assert_eq!(partial.theme, Theme::Light);
+        assert_eq!(partial.max_guesses, 6);
+        assert!(partial.reject_repeated_guesses);
+        assert!(!partial.blank_board);
+        assert!(!partial.session_summary);
+        assert!(!partial.no_spoilers);
+        assert_eq!(partial.stats_backend, StatsBackendKind::JsonLines);
+        assert!(partial.server.is_none());
+        std::fs::remove_file(&path).unwrap();
+
+        let config = settings.dependency_config();
+        assert!(config.hard_mode);
+        assert_eq!(config.rules, GameRules::lenient());
+        assert_eq!(config.strategy_type, StrategyType::Hybrid);
+        assert_eq!(config.tuning.entropy_percent, 70);
+        assert_eq!(
+            config.word_list_config.source_preset,
+            WordSourcePreset::Wordle
+        );
+    }
+
+    #[test]
+    fn test_server_config_defaults() {
+        let settings: UserSettings =
+            serde_json::from_str(r#"{ "server": { "bind_address": "0.0.0.0:9000" } }"#).unwrap();
+        let config = settings.server.unwrap();
+        assert!(config.tls.is_none());
+        assert!(config.rate_limit.is_some());
+        assert!(config.is_publicly_exposed_without_auth());
+
+        for local in ["127.0.0.1:8080", "[::1]:8080", "::1", "localhost:8080"] {
+            assert!(is_loopback(local), "{}", local);
+        }
+        for public in ["localhost.evil:8080", "127.example.com:80", "[::]:80"] {
+            assert!(!is_loopback(public), "{}", public);
+        }
+    }
+}
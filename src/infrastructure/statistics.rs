@@ -0,0 +1,1111 @@
+use crate::core::{
+    clock::{Clock, SystemClock, system_clock},
+    error::{DataError, Result},
+    types::{FeedbackPattern, Guess},
+};
+use crate::infrastructure::settings::UserSettings;
+use crate::infrastructure::stats_backend::{JsonBackend, MemoryBackend, StatisticsBackend};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Player name used when none is given
+pub const DEFAULT_PLAYER: &str = "default";
+
+/// Environment variable selecting the current player
+pub const PLAYER_ENV: &str = "WORDLE_PLAYER";
+
+/// Number of rows in the guess distribution (solved in 1..=6)
+pub const DISTRIBUTION_ROWS: usize = 6;
+
+/// Resolve the active player: explicit name, then `WORDLE_PLAYER`, then "default"
+pub fn resolve_player(explicit: Option<&str>) -> String {
+    explicit
+        .map(str::to_string)
+        .or_else(|| std::env::var(PLAYER_ENV).ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| DEFAULT_PLAYER.to_string())
+}
+
+/// A single guess as stored in the statistics file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuessRecord {
+    pub word: String,
+    /// Feedback as a 0/1/2 code string
+    pub feedback: String,
+    /// Milliseconds the player took to enter the guess
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub think_ms: Option<u64>,
+    /// Milliseconds the solver took on the suggestion shown before the guess
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solver_ms: Option<u64>,
+}
+
+/// How long one turn took; either side is missing when it wasn't measured
+/// (solver-played, pasted or resumed guesses, hidden suggestions)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TurnTime {
+    /// Time the player spent before entering the guess
+    pub think: Option<Duration>,
+    /// Time the solver spent on the suggestion for the turn
+    pub solver: Option<Duration>,
+}
+
+/// Mean of the given durations, `None` when there are none
+pub fn average_duration(times: impl IntoIterator<Item = Duration>) -> Option<Duration> {
+    let (total, count) = times
+        .into_iter()
+        .fold((Duration::ZERO, 0u32), |(total, count), t| (total + t, count + 1));
+    (count > 0).then(|| total / count)
+}
+
+/// Short form of a turn time: milliseconds below a second, else tenths of a second
+pub fn format_turn_time(time: Duration) -> String {
+    if time < Duration::from_secs(1) {
+        format!("{}ms", time.as_millis())
+    } else {
+        format!("{:.1}s", time.as_secs_f64())
+    }
+}
+
+/// One finished game
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub player: String,
+    /// Unix timestamp (seconds) when the game finished
+    pub timestamp: u64,
+    pub won: bool,
+    #[serde(default)]
+    pub target: Option<String>,
+    #[serde(default)]
+    pub guesses: Vec<GuessRecord>,
+    /// Archive puzzle number, for games replaying a past puzzle
+    #[serde(default)]
+    pub puzzle: Option<u32>,
+    /// Puzzle pack and position, for games played from a pack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pack: Option<PackGame>,
+}
+
+/// A game's place in a puzzle pack
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackGame {
+    /// Name of the pack
+    pub name: String,
+    /// Position of the puzzle in the pack, from 0
+    pub index: usize,
+}
+
+impl GameRecord {
+    pub fn new(player: &str, won: bool, target: Option<String>, guesses: &[Guess]) -> Self {
+        Self {
+            player: player.to_string(),
+            timestamp: SystemClock.unix_secs(),
+            won,
+            target,
+            guesses: guesses
+                .iter()
+                .map(|g| GuessRecord {
+                    word: g.word.to_string(),
+                    feedback: g.feedback.to_code_string(),
+                    think_ms: None,
+                    solver_ms: None,
+                })
+                .collect(),
+            puzzle: None,
+            pack: None,
+        }
+    }
+
+    /// Attach per-turn timings, matched to the guesses in order
+    pub fn with_turn_times(mut self, times: &[TurnTime]) -> Self {
+        let millis = |d: Duration| d.as_millis() as u64;
+        for (guess, time) in self.guesses.iter_mut().zip(times) {
+            guess.think_ms = time.think.map(millis);
+            guess.solver_ms = time.solver.map(millis);
+        }
+        self
+    }
+
+    /// Date the game as finished at `timestamp` (Unix seconds) instead of when it
+    /// was created, e.g. by a [`Clock`] other than the system one
+    pub fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Record the game against archive puzzle `number`
+    pub fn with_puzzle(mut self, number: Option<u32>) -> Self {
+        self.puzzle = number;
+        self
+    }
+
+    /// Record the game against puzzle `index` of the pack called `name`
+    pub fn with_pack(mut self, name: &str, index: usize) -> Self {
+        self.pack = Some(PackGame {
+            name: name.to_string(),
+            index,
+        });
+        self
+    }
+
+    /// Number of guesses taken
+    pub fn attempts(&self) -> usize {
+        self.guesses.len()
+    }
+
+    /// The game's guesses and feedback in `format`; fails for a game without guesses
+    /// or with feedback that isn't a 0/1/2 code (a hand-edited file)
+    pub fn export(&self, format: ExportFormat) -> Result<String> {
+        let rows = self.feedback_rows()?;
+        Ok(match format {
+            ExportFormat::State => rows
+                .iter()
+                .map(|(word, feedback)| format!("{}:{}", word, feedback.to_code_string()))
+                .collect::<Vec<_>>()
+                .join(","),
+            ExportFormat::Wordlebot => rows
+                .iter()
+                .map(|(word, feedback)| {
+                    format!("{} {}", word.to_uppercase(), feedback.to_initial_string())
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        })
+    }
+
+    /// The emoji grid players post, headed `Wordle 1,234 3/6` (no number for a game
+    /// outside the archive) and marked up for where it will be pasted; fails as
+    /// [`export`](Self::export) does
+    pub fn share_text(&self, format: ShareFormat) -> Result<String> {
+        let rows = self.feedback_rows()?;
+        let score = if self.won {
+            rows.len().to_string()
+        } else {
+            "X".to_string()
+        };
+        let limit = DISTRIBUTION_ROWS.max(rows.len());
+        let header = match self.puzzle {
+            Some(number) => format!("Wordle {} {}/{}", group_digits(number), score, limit),
+            None => format!("Wordle {}/{}", score, limit),
+        };
+        let header = match format {
+            ShareFormat::Slack => format!("*{}*", header),
+            _ => header,
+        };
+        let grid: Vec<String> = rows
+            .iter()
+            .map(|(_, feedback)| feedback.to_string())
+            .collect();
+        let mut text = format!("{}\n\n{}", header, grid.join("\n"));
+        match (format, &self.target) {
+            (ShareFormat::Discord, Some(target)) => {
+                text.push_str(&format!("\n\nAnswer: ||{}||", target.to_uppercase()));
+            }
+            (ShareFormat::Mastodon, _) => text.push_str("\n\n#Wordle"),
+            _ => {}
+        }
+        Ok(text)
+    }
+
+    /// Each guess with its feedback, failing for a game without guesses or with
+    /// feedback that isn't a 0/1/2 code
+    fn feedback_rows(&self) -> Result<Vec<(&str, FeedbackPattern)>> {
+        if self.guesses.is_empty() {
+            return Err(DataError::MissingData("the game has no guesses".to_string()).into());
+        }
+        self.guesses
+            .iter()
+            .map(|guess| {
+                let feedback = FeedbackPattern::from_code_string(&guess.feedback).map_err(|e| {
+                    DataError::InvalidFormat(format!("feedback for {}: {}", guess.word, e))
+                })?;
+                Ok((guess.word.as_str(), feedback))
+            })
+            .collect()
+    }
+}
+
+/// `1234` as `1,234`, the way share headers number puzzles
+fn group_digits(number: u32) -> String {
+    let digits = number.to_string();
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// How [`GameRecord::export`] writes a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// `crane:01020,sloth:20010`, as `solve --state` reads it
+    #[default]
+    State,
+    /// One `CRANE BYBBG` line per guess: the guess list and colour strings that
+    /// analysis bots such as WordleBot take
+    Wordlebot,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [Self::State, Self::Wordlebot];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::State => "state",
+            Self::Wordlebot => "wordlebot",
+        }
+    }
+
+    /// Format called `name`, in any case
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|format| format.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// Where [`GameRecord::share_text`] output is going to be pasted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShareFormat {
+    /// Header and grid, as the game's own share button copies them
+    #[default]
+    Plain,
+    /// The answer added behind a `||spoiler||` tag
+    Discord,
+    /// The header in `*bold*`; Slack has no spoiler markup, so no answer
+    Slack,
+    /// A `#Wordle` hashtag; the answer is left for a content warning
+    Mastodon,
+}
+
+impl ShareFormat {
+    pub const ALL: [ShareFormat; 4] = [Self::Plain, Self::Discord, Self::Slack, Self::Mastodon];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Plain => "plain",
+            Self::Discord => "discord",
+            Self::Slack => "slack",
+            Self::Mastodon => "mastodon",
+        }
+    }
+
+    /// Format called `name`, in any case
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|format| format.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// Aggregated statistics for one player
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerStats {
+    pub player: String,
+    pub games_played: u32,
+    pub games_won: u32,
+    /// Consecutive wins ending with the most recent game
+    pub current_streak: u32,
+    pub max_streak: u32,
+    /// Wins by guess count (index 0 = solved in one); wins that took more than
+    /// six guesses are counted in the last row
+    pub guess_distribution: [u32; DISTRIBUTION_ROWS],
+    /// Guesses with a recorded think time, and their total in milliseconds
+    pub timed_guesses: u32,
+    pub think_ms: u64,
+    /// Suggestions with a recorded solver time, and their total in milliseconds
+    pub timed_suggestions: u32,
+    pub solver_ms: u64,
+}
+
+impl PlayerStats {
+    /// Empty statistics for a player
+    pub fn new(player: &str) -> Self {
+        Self {
+            player: player.to_string(),
+            games_played: 0,
+            games_won: 0,
+            current_streak: 0,
+            max_streak: 0,
+            guess_distribution: [0; DISTRIBUTION_ROWS],
+            timed_guesses: 0,
+            think_ms: 0,
+            timed_suggestions: 0,
+            solver_ms: 0,
+        }
+    }
+
+    /// Start from imported totals so local games extend the imported streaks
+    pub fn from_baseline(baseline: &StatsBaseline) -> Self {
+        Self {
+            player: baseline.player.clone(),
+            games_played: baseline.games_played,
+            games_won: baseline.games_won,
+            current_streak: baseline.current_streak,
+            max_streak: baseline.max_streak,
+            guess_distribution: baseline.guess_distribution,
+            ..Self::new(&baseline.player)
+        }
+    }
+
+    /// Aggregate a player's games, given in chronological order
+    pub fn from_records<'a>(
+        player: &str,
+        records: impl IntoIterator<Item = &'a GameRecord>,
+    ) -> Self {
+        let mut stats = Self::new(player);
+        stats.extend(records);
+        stats
+    }
+
+    /// Fold further games (chronological) for this player into the totals
+    pub fn extend<'a>(&mut self, records: impl IntoIterator<Item = &'a GameRecord>) {
+        for record in records.into_iter().filter(|r| r.player == self.player) {
+            self.games_played += 1;
+            if record.won {
+                self.games_won += 1;
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+                let row = record.attempts().clamp(1, DISTRIBUTION_ROWS) - 1;
+                self.guess_distribution[row] += 1;
+            } else {
+                self.current_streak = 0;
+            }
+            for guess in &record.guesses {
+                if let Some(ms) = guess.think_ms {
+                    self.timed_guesses += 1;
+                    self.think_ms += ms;
+                }
+                if let Some(ms) = guess.solver_ms {
+                    self.timed_suggestions += 1;
+                    self.solver_ms += ms;
+                }
+            }
+        }
+    }
+
+    /// Average time the player took per guess, over the guesses that were timed
+    pub fn average_think_time(&self) -> Option<Duration> {
+        (self.timed_guesses > 0)
+            .then(|| Duration::from_millis(self.think_ms / self.timed_guesses as u64))
+    }
+
+    /// Average time the solver took per suggestion, over the suggestions that were timed
+    pub fn average_solver_time(&self) -> Option<Duration> {
+        (self.timed_suggestions > 0)
+            .then(|| Duration::from_millis(self.solver_ms / self.timed_suggestions as u64))
+    }
+
+    /// Win percentage (0–100)
+    pub fn win_rate(&self) -> f64 {
+        if self.games_played == 0 {
+            0.0
+        } else {
+            self.games_won as f64 * 100.0 / self.games_played as f64
+        }
+    }
+
+    /// Average guesses over won games
+    pub fn average_guesses(&self) -> Option<f64> {
+        if self.games_won == 0 {
+            return None;
+        }
+        let total: u32 = self
+            .guess_distribution
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| (i as u32 + 1) * count)
+            .sum();
+        Some(total as f64 / self.games_won as f64)
+    }
+}
+
+/// Games an opener needs before its results are compared with other openers
+pub const OPENER_MIN_GAMES: u32 = 5;
+
+/// How a player's games went after opening with one word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenerStats {
+    pub word: String,
+    pub games: u32,
+    pub wins: u32,
+    /// Guesses over the won games, the opener included
+    pub winning_guesses: u32,
+}
+
+impl OpenerStats {
+    /// Win percentage (0–100)
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f64 * 100.0 / self.games as f64
+        }
+    }
+
+    /// Average guesses over the won games, the opener included
+    pub fn average_guesses(&self) -> Option<f64> {
+        (self.wins > 0).then(|| self.winning_guesses as f64 / self.wins as f64)
+    }
+
+    /// Whether enough games opened with it to compare it with other openers
+    pub fn is_established(&self) -> bool {
+        self.games >= OPENER_MIN_GAMES
+    }
+}
+
+/// What one TUI session added to a player's statistics, shown on exit
+///
+/// Worked out from the player's totals before and after the session, so it only
+/// reads the local statistics store.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionSummary {
+    pub games_played: u32,
+    pub games_won: u32,
+    /// Average guesses over the session's wins
+    pub average_guesses: Option<f64>,
+    /// How long the session lasted
+    pub duration: Duration,
+    /// Streak at the end of the session
+    pub current_streak: u32,
+    /// How much the best streak grew during the session
+    pub best_streak_gain: u32,
+}
+
+impl SessionSummary {
+    pub fn between(before: &PlayerStats, after: &PlayerStats, duration: Duration) -> Self {
+        let wins_by_row = after
+            .guess_distribution
+            .iter()
+            .zip(before.guess_distribution)
+            .map(|(&after, before)| after.saturating_sub(before));
+        let (wins, guesses) = wins_by_row
+            .enumerate()
+            .fold((0, 0), |(wins, guesses), (i, count)| {
+                (wins + count, guesses + (i as u32 + 1) * count)
+            });
+        Self {
+            games_played: after.games_played.saturating_sub(before.games_played),
+            games_won: after.games_won.saturating_sub(before.games_won),
+            average_guesses: (wins > 0).then(|| guesses as f64 / wins as f64),
+            duration,
+            current_streak: after.current_streak,
+            best_streak_gain: after.max_streak.saturating_sub(before.max_streak),
+        }
+    }
+}
+
+/// Aggregate totals imported from another source, without per-game detail
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatsBaseline {
+    pub player: String,
+    /// Where the totals came from, e.g. "nyt"
+    pub source: String,
+    /// Unix timestamp (seconds) of the import
+    pub imported_at: u64,
+    pub games_played: u32,
+    pub games_won: u32,
+    pub current_streak: u32,
+    pub max_streak: u32,
+    pub guess_distribution: [u32; DISTRIBUTION_ROWS],
+}
+
+/// On-disk shape of the statistics file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatisticsData {
+    #[serde(default)]
+    pub games: Vec<GameRecord>,
+    /// Imported totals, at most one per player
+    #[serde(default)]
+    pub baselines: Vec<StatsBaseline>,
+    /// When each player last cleared their used words, so older games no longer count
+    #[serde(default)]
+    pub used_words_cleared: Vec<UsedWordsCleared>,
+}
+
+impl StatisticsData {
+    /// Add one change, the way the store makes it: a later baseline or clearing
+    /// replaces the player's earlier one
+    pub fn apply(&mut self, entry: StatisticsEntry) {
+        match entry {
+            StatisticsEntry::Game(record) => self.games.push(record),
+            StatisticsEntry::Baseline(baseline) => {
+                self.baselines.retain(|b| b.player != baseline.player);
+                self.baselines.push(baseline);
+            }
+            StatisticsEntry::UsedWordsCleared(cleared) => {
+                self.used_words_cleared
+                    .retain(|c| c.player != cleared.player);
+                self.used_words_cleared.push(cleared);
+            }
+        }
+    }
+
+    /// The changes that build this data up from nothing, in order
+    pub fn entries(&self) -> impl Iterator<Item = StatisticsEntry> + '_ {
+        let baselines = self
+            .baselines
+            .iter()
+            .cloned()
+            .map(StatisticsEntry::Baseline);
+        let cleared = self
+            .used_words_cleared
+            .iter()
+            .cloned()
+            .map(StatisticsEntry::UsedWordsCleared);
+        let games = self.games.iter().cloned().map(StatisticsEntry::Game);
+        baselines.chain(cleared).chain(games)
+    }
+}
+
+/// One change to the statistics, as appended by log-style backends
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatisticsEntry {
+    Game(GameRecord),
+    Baseline(StatsBaseline),
+    UsedWordsCleared(UsedWordsCleared),
+}
+
+/// Point from which a player's guesses count as used again
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsedWordsCleared {
+    pub player: String,
+    /// Unix timestamp (seconds); games finished at or before it are ignored
+    pub timestamp: u64,
+}
+
+/// How a player did on an archive or pack puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ArchiveResult {
+    /// Solved in this many guesses
+    Solved(usize),
+    /// Never solved
+    Failed,
+}
+
+/// Store of finished games for all local players, kept by a [`StatisticsBackend`]
+#[derive(Debug)]
+pub struct StatisticsStore {
+    backend: Box<dyn StatisticsBackend>,
+    data: StatisticsData,
+    /// Changes made since the store was opened or last saved
+    pending: Vec<StatisticsEntry>,
+    /// Dates the clearing of used words
+    clock: Arc<dyn Clock>,
+}
+
+impl StatisticsStore {
+    /// Default JSON statistics document in the project root
+    pub fn default_path() -> PathBuf {
+        crate::infrastructure::word_list::project_root_path("statistics.json")
+    }
+
+    /// Open the store with the backend chosen in the user settings
+    pub async fn open_default() -> Result<Self> {
+        Self::with_backend(UserSettings::load_default().stats_backend.backend()).await
+    }
+
+    /// Open the JSON document at `path`; a missing file yields an empty store
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_backend(Box::new(JsonBackend::new(path))).await
+    }
+
+    /// Open a store kept by `backend`
+    pub async fn with_backend(backend: Box<dyn StatisticsBackend>) -> Result<Self> {
+        let data = backend.load().await?;
+        Ok(Self {
+            backend,
+            data,
+            pending: Vec::new(),
+            clock: system_clock(),
+        })
+    }
+
+    /// Create an empty store kept in memory only, e.g. for tests
+    pub fn in_memory() -> Self {
+        Self {
+            backend: Box::new(MemoryBackend::default()),
+            data: StatisticsData::default(),
+            pending: Vec::new(),
+            clock: system_clock(),
+        }
+    }
+
+    /// Tell the time with `clock` instead of the system clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Hand the changes made since the last save to the backend
+    pub async fn save(&mut self) -> Result<()> {
+        self.backend.save(&self.data, &self.pending).await?;
+        self.pending.clear();
+        Ok(())
+    }
+
+    /// Where the statistics are kept, for display
+    pub fn location(&self) -> String {
+        self.backend.location()
+    }
+
+    fn apply(&mut self, entry: StatisticsEntry) {
+        self.data.apply(entry.clone());
+        self.pending.push(entry);
+    }
+
+    /// Append a finished game
+    pub fn record_game(&mut self, record: GameRecord) {
+        self.apply(StatisticsEntry::Game(record));
+    }
+
+    /// All recorded games in chronological order
+    pub fn games(&self) -> &[GameRecord] {
+        &self.data.games
+    }
+
+    /// Set a player's imported totals, replacing any earlier import for that player.
+    /// Local games are counted on top of the baseline.
+    pub fn set_baseline(&mut self, baseline: StatsBaseline) {
+        self.apply(StatisticsEntry::Baseline(baseline));
+    }
+
+    /// Imported totals for a player, if any
+    pub fn baseline(&self, player: &str) -> Option<&StatsBaseline> {
+        self.data.baselines.iter().find(|b| b.player == player)
+    }
+
+    /// Every word the player has guessed in recorded games since they last cleared the list
+    pub fn used_words(&self, player: &str) -> BTreeSet<String> {
+        let cleared = self
+            .data
+            .used_words_cleared
+            .iter()
+            .find(|c| c.player == player)
+            .map(|c| c.timestamp);
+        self.data
+            .games
+            .iter()
+            .filter(|g| g.player == player && cleared.is_none_or(|t| g.timestamp > t))
+            .flat_map(|g| g.guesses.iter().map(|guess| guess.word.clone()))
+            .collect()
+    }
+
+    /// Forget the player's used words; their games and statistics are kept
+    pub fn clear_used_words(&mut self, player: &str) {
+        let timestamp = self.clock.unix_secs();
+        self.apply(StatisticsEntry::UsedWordsCleared(UsedWordsCleared {
+            player: player.to_string(),
+            timestamp,
+        }));
+    }
+
+    /// The player's best result for each archive puzzle they played: the fewest
+    /// guesses of a win, else a loss
+    pub fn archive_results(&self, player: &str) -> BTreeMap<u32, ArchiveResult> {
+        self.best_results(player, |game| game.puzzle)
+    }
+
+    /// The player's best result for each puzzle of the pack called `pack`, by position
+    pub fn pack_results(&self, player: &str, pack: &str) -> BTreeMap<usize, ArchiveResult> {
+        self.best_results(player, |game| {
+            game.pack.as_ref().filter(|p| p.name == pack).map(|p| p.index)
+        })
+    }
+
+    /// Best result per puzzle, for the player's games that `puzzle` places
+    fn best_results<K: Ord>(
+        &self,
+        player: &str,
+        puzzle: impl Fn(&GameRecord) -> Option<K>,
+    ) -> BTreeMap<K, ArchiveResult> {
+        let mut results = BTreeMap::new();
+        for game in self.data.games.iter().filter(|g| g.player == player) {
+            let Some(number) = puzzle(game) else { continue };
+            let result = if game.won {
+                ArchiveResult::Solved(game.attempts())
+            } else {
+                ArchiveResult::Failed
+            };
+            results
+                .entry(number)
+                .and_modify(|best: &mut ArchiveResult| *best = (*best).min(result))
+                .or_insert(result);
+        }
+        results
+    }
+
+    /// The player's opening words with how their games went, most used first
+    /// (ties alphabetically); imported totals have no guesses, so they don't count
+    pub fn opener_stats(&self, player: &str) -> Vec<OpenerStats> {
+        let mut openers: BTreeMap<&str, OpenerStats> = BTreeMap::new();
+        for game in self.data.games.iter().filter(|g| g.player == player) {
+            let Some(first) = game.guesses.first() else {
+                continue;
+            };
+            let opener = openers.entry(&first.word).or_insert_with(|| OpenerStats {
+                word: first.word.clone(),
+                games: 0,
+                wins: 0,
+                winning_guesses: 0,
+            });
+            opener.games += 1;
+            if game.won {
+                opener.wins += 1;
+                opener.winning_guesses += game.attempts() as u32;
+            }
+        }
+        let mut openers: Vec<OpenerStats> = openers.into_values().collect();
+        openers.sort_by_key(|opener| std::cmp::Reverse(opener.games));
+        openers
+    }
+
+    /// Names of all players with at least one game or import, sorted
+    pub fn players(&self) -> Vec<String> {
+        let mut players: Vec<String> = self
+            .data
+            .games
+            .iter()
+            .map(|g| g.player.clone())
+            .chain(self.data.baselines.iter().map(|b| b.player.clone()))
+            .collect();
+        players.sort();
+        players.dedup();
+        players
+    }
+
+    /// Aggregated statistics for one player, including imported totals
+    pub fn player_stats(&self, player: &str) -> PlayerStats {
+        let mut stats = match self.baseline(player) {
+            Some(baseline) => PlayerStats::from_baseline(baseline),
+            None => PlayerStats::new(player),
+        };
+        stats.extend(&self.data.games);
+        stats
+    }
+
+    /// All players ranked by wins, then win rate, then fewer average guesses, then name
+    pub fn leaderboard(&self) -> Vec<PlayerStats> {
+        let mut board: Vec<PlayerStats> = self
+            .players()
+            .iter()
+            .map(|player| self.player_stats(player))
+            .collect();
+        board.sort_by(|a, b| {
+            b.games_won
+                .cmp(&a.games_won)
+                .then_with(|| b.win_rate().total_cmp(&a.win_rate()))
+                .then_with(|| {
+                    let avg = |s: &PlayerStats| s.average_guesses().unwrap_or(f64::INFINITY);
+                    avg(a).total_cmp(&avg(b))
+                })
+                .then_with(|| a.player.cmp(&b.player))
+        });
+        board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clock::ManualClock;
+
+    fn game(player: &str, won: bool, attempts: usize) -> GameRecord {
+        GameRecord {
+            player: player.to_string(),
+            timestamp: 0,
+            won,
+            target: None,
+            guesses: (0..attempts)
+                .map(|_| GuessRecord {
+                    word: "crane".to_string(),
+                    feedback: "00000".to_string(),
+                    think_ms: None,
+                    solver_ms: None,
+                })
+                .collect(),
+            puzzle: None,
+            pack: None,
+        }
+    }
+
+    #[test]
+    fn test_export_formats() {
+        let guesses = [("crane", "01002"), ("sloth", "22222")].map(|(word, code)| {
+            Guess::new(
+                crate::core::types::Word::from_str(word).unwrap(),
+                FeedbackPattern::from_code_string(code).unwrap(),
+            )
+        });
+        let record = GameRecord::new("alice", true, Some("sloth".to_string()), &guesses);
+
+        assert_eq!(
+            record.export(ExportFormat::State).unwrap(),
+            "crane:01002,sloth:22222"
+        );
+        assert_eq!(
+            record.export(ExportFormat::Wordlebot).unwrap(),
+            "CRANE BYBBG\nSLOTH GGGGG"
+        );
+        assert_eq!(
+            ExportFormat::from_name("WordleBot"),
+            Some(ExportFormat::Wordlebot)
+        );
+        assert_eq!(ExportFormat::from_name("csv"), None);
+
+        assert!(game("alice", false, 0).export(ExportFormat::State).is_err());
+    }
+
+    #[test]
+    fn test_share_text_per_platform() {
+        let guesses = [("crane", "01002"), ("sloth", "22222")].map(|(word, code)| {
+            Guess::new(
+                crate::core::types::Word::from_str(word).unwrap(),
+                FeedbackPattern::from_code_string(code).unwrap(),
+            )
+        });
+        let record = GameRecord::new("alice", true, Some("sloth".to_string()), &guesses)
+            .with_puzzle(Some(1234));
+
+        let plain = record.share_text(ShareFormat::Plain).unwrap();
+        assert_eq!(plain, "Wordle 1,234 2/6\n\n⬜🟨⬜⬜🟩\n🟩🟩🟩🟩🟩");
+        let grid = crate::domain::ShareGrid::parse(&plain).unwrap();
+        assert!(grid.is_solved());
+
+        let discord = record.share_text(ShareFormat::Discord).unwrap();
+        assert!(discord.ends_with("\n\nAnswer: ||SLOTH||"));
+        assert_eq!(crate::domain::ShareGrid::parse(&discord).unwrap(), grid);
+        let slack = record.share_text(ShareFormat::Slack).unwrap();
+        assert!(slack.starts_with("*Wordle 1,234 2/6*\n") && !slack.contains("SLOTH"));
+        let mastodon = record.share_text(ShareFormat::Mastodon).unwrap();
+        assert!(mastodon.ends_with("#Wordle") && !mastodon.contains("SLOTH"));
+
+        let lost = GameRecord::new("alice", false, None, &guesses[..1]);
+        let text = lost.share_text(ShareFormat::Discord).unwrap();
+        assert_eq!(text, "Wordle X/6\n\n⬜🟨⬜⬜🟩");
+        assert_eq!(
+            ShareFormat::from_name("Discord"),
+            Some(ShareFormat::Discord)
+        );
+    }
+
+    #[test]
+    fn test_player_stats_and_streaks() {
+        let records = vec![
+            game("alice", true, 3),
+            game("alice", true, 4),
+            game("bob", true, 2),
+            game("alice", false, 6),
+            game("alice", true, 3),
+        ];
+        let stats = PlayerStats::from_records("alice", &records);
+        assert_eq!(stats.games_played, 4);
+        assert_eq!(stats.games_won, 3);
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.max_streak, 2);
+        assert_eq!(stats.guess_distribution, [0, 0, 2, 1, 0, 0]);
+        assert_eq!(stats.average_guesses(), Some(10.0 / 3.0));
+        assert_eq!(stats.win_rate(), 75.0);
+    }
+
+    #[test]
+    fn test_leaderboard_ordering() {
+        let mut store = StatisticsStore::in_memory();
+        store.record_game(game("bob", true, 4));
+        store.record_game(game("alice", true, 3));
+        store.record_game(game("carol", false, 6));
+        store.record_game(game("dave", true, 2));
+        store.record_game(game("dave", true, 2));
+
+        let names: Vec<_> = store
+            .leaderboard()
+            .into_iter()
+            .map(|s| s.player)
+            .collect();
+        assert_eq!(names, vec!["dave", "alice", "bob", "carol"]);
+    }
+
+    #[tokio::test]
+    async fn test_store_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "wordle_stats_test_{}.json",
+            std::process::id()
+        ));
+        let mut store = StatisticsStore::open(&path).await.unwrap();
+        assert!(store.games().is_empty());
+        store.record_game(game("alice", true, 3));
+        store.save().await.unwrap();
+
+        let reopened = StatisticsStore::open(&path).await.unwrap();
+        assert_eq!(reopened.games().len(), 1);
+        assert_eq!(reopened.players(), vec!["alice"]);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_local_games_extend_baseline() {
+        let mut store = StatisticsStore::in_memory();
+        store.set_baseline(StatsBaseline {
+            player: "alice".to_string(),
+            source: "nyt".to_string(),
+            imported_at: 0,
+            games_played: 10,
+            games_won: 9,
+            current_streak: 4,
+            max_streak: 6,
+            guess_distribution: [0, 1, 3, 3, 2, 0],
+        });
+        store.record_game(game("alice", true, 2));
+        store.record_game(game("alice", true, 5));
+        store.record_game(game("alice", true, 3));
+
+        let stats = store.player_stats("alice");
+        assert_eq!(stats.games_played, 13);
+        assert_eq!(stats.current_streak, 7);
+        assert_eq!(stats.max_streak, 7);
+        assert_eq!(stats.guess_distribution, [0, 2, 4, 3, 3, 0]);
+        assert_eq!(store.players(), vec!["alice"]);
+    }
+
+    #[test]
+    fn test_used_words_and_clearing() {
+        let mut store = StatisticsStore::in_memory();
+        let mut played = game("alice", true, 2);
+        played.guesses[1].word = "slate".to_string();
+        store.record_game(played);
+        store.record_game(game("bob", true, 1));
+        let words: Vec<_> = store.used_words("alice").into_iter().collect();
+        assert_eq!(words, vec!["crane", "slate"]);
+
+        store.clear_used_words("alice");
+        assert!(store.used_words("alice").is_empty());
+        assert_eq!(store.used_words("bob").len(), 1);
+        assert_eq!(store.player_stats("alice").games_played, 1);
+
+        // Only games finished after the clearing count again
+        let clock = Arc::new(ManualClock::at(1_000));
+        let mut store = StatisticsStore::in_memory().with_clock(clock.clone());
+        store.clear_used_words("alice");
+        store.record_game(game("alice", true, 1).with_timestamp(clock.unix_secs()));
+        clock.advance(Duration::from_secs(1));
+        let mut later = game("alice", true, 1).with_timestamp(clock.unix_secs());
+        later.guesses[0].word = "slate".to_string();
+        store.record_game(later);
+        let words: Vec<_> = store.used_words("alice").into_iter().collect();
+        assert_eq!(words, vec!["slate"]);
+    }
+
+    #[test]
+    fn test_opener_stats_group_games_by_first_guess() {
+        let mut store = StatisticsStore::in_memory();
+        let opening = |word: &str, won: bool, attempts: usize| {
+            let mut played = game("alice", won, attempts);
+            played.guesses[0].word = word.to_string();
+            played
+        };
+        store.record_game(opening("slate", true, 3));
+        store.record_game(opening("crane", true, 4));
+        store.record_game(opening("slate", true, 5));
+        store.record_game(opening("slate", false, 6));
+        store.record_game(game("bob", true, 2));
+
+        let openers = store.opener_stats("alice");
+        let words: Vec<&str> = openers.iter().map(|o| o.word.as_str()).collect();
+        assert_eq!(words, ["slate", "crane"]);
+        assert_eq!((openers[0].games, openers[0].wins), (3, 2));
+        assert_eq!(openers[0].average_guesses(), Some(4.0));
+        assert!(!openers[0].is_established());
+        assert!(store.opener_stats("carol").is_empty());
+    }
+
+    #[test]
+    fn test_archive_results_keep_best_per_puzzle() {
+        let mut store = StatisticsStore::in_memory();
+        store.record_game(game("alice", false, 6).with_puzzle(Some(3)));
+        store.record_game(game("alice", true, 5).with_puzzle(Some(3)));
+        store.record_game(game("alice", true, 4).with_puzzle(Some(7)));
+        store.record_game(game("alice", true, 2).with_puzzle(Some(7)));
+        store.record_game(game("alice", true, 3));
+        store.record_game(game("bob", true, 1).with_puzzle(Some(3)));
+
+        let results = store.archive_results("alice");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[&3], ArchiveResult::Solved(5));
+        assert_eq!(results[&7], ArchiveResult::Solved(2));
+
+        // Records written before puzzles were tracked still load
+        let old: GameRecord =
+            serde_json::from_str(r#"{"player":"bob","timestamp":0,"won":true}"#).unwrap();
+        assert_eq!(old.puzzle, None);
+        assert_eq!(old.pack, None);
+
+        store.record_game(game("alice", false, 6).with_pack("night", 0));
+        store.record_game(game("alice", true, 4).with_pack("night", 1));
+        store.record_game(game("alice", true, 2).with_pack("other", 1));
+        let results = store.pack_results("alice", "night");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[&0], ArchiveResult::Failed);
+        assert_eq!(results[&1], ArchiveResult::Solved(4));
+        assert_eq!(store.archive_results("alice").len(), 2);
+    }
+
+    #[test]
+    fn test_turn_times_average_over_timed_guesses() {
+        let ms = Duration::from_millis;
+        let timed = game("alice", true, 3).with_turn_times(&[
+            TurnTime {
+                think: Some(ms(4000)),
+                solver: Some(ms(30)),
+            },
+            TurnTime {
+                think: Some(ms(8000)),
+                solver: None,
+            },
+        ]);
+        assert_eq!(timed.guesses[1].think_ms, Some(8000));
+        assert_eq!(timed.guesses[2].think_ms, None);
+
+        let stats = PlayerStats::from_records("alice", &[timed.clone(), game("alice", true, 2)]);
+        assert_eq!(stats.average_think_time(), Some(ms(6000)));
+        assert_eq!(stats.average_solver_time(), Some(ms(30)));
+        assert_eq!(PlayerStats::new("bob").average_think_time(), None);
+
+        // Untimed guesses are left out of the file, and older files load
+        let json = serde_json::to_string(&timed).unwrap();
+        assert_eq!(json.matches("think_ms").count(), 2);
+        assert_eq!(serde_json::from_str::<GameRecord>(&json).unwrap(), timed);
+        assert_eq!(average_duration([ms(1), ms(3)]), Some(ms(2)));
+        assert_eq!(format_turn_time(ms(35)), "35ms");
+        assert_eq!(format_turn_time(ms(4250)), "4.2s");
+    }
+
+    #[test]
+    fn test_session_summary_counts_only_new_games() {
+        let earlier = [game("alice", true, 2), game("alice", false, 6)];
+        let before = PlayerStats::from_records("alice", &earlier);
+        let mut after = before.clone();
+        after.extend(&[game("alice", true, 3), game("alice", true, 4), game("alice", false, 6)]);
+        after.extend(&[game("alice", true, 5)]);
+
+        let summary = SessionSummary::between(&before, &after, Duration::from_secs(600));
+        assert_eq!((summary.games_played, summary.games_won), (4, 3));
+        assert_eq!(summary.average_guesses, Some(4.0));
+        assert_eq!((summary.current_streak, summary.best_streak_gain), (1, 1));
+
+        let idle = SessionSummary::between(&before, &before, Duration::ZERO);
+        assert_eq!((idle.games_played, idle.average_guesses), (0, None));
+    }
+
+    #[test]
+    fn test_resolve_player_prefers_explicit_name() {
+        assert_eq!(resolve_player(Some(" alice ")), "alice");
+    }
+}
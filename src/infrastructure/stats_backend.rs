@@ -0,0 +1,348 @@
+//! Where [`StatisticsStore`] keeps its games
+//!
+//! The store holds everything in memory and hands each save to a
+//! [`StatisticsBackend`]. The CLI defaults to an append-only JSON-lines file, one
+//! change per line, so a save only writes the games finished since the last one;
+//! the older single JSON document is still available, and an in-memory backend
+//! serves tests. `stats_backend` in the user settings picks one. Other stores,
+//! such as a database for a server, implement the trait and are opened with
+//! [`StatisticsStore::with_backend`].
+//!
+//! [`StatisticsStore`]: crate::infrastructure::StatisticsStore
+//! [`StatisticsStore::with_backend`]: crate::infrastructure::StatisticsStore::with_backend
+
+use crate::core::error::{DataError, Result};
+use crate::infrastructure::atomic_file::write_atomic;
+use crate::infrastructure::statistics::{StatisticsData, StatisticsEntry, StatisticsStore};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+/// Storage for the statistics of all local players
+#[async_trait]
+pub trait StatisticsBackend: Send + Sync + std::fmt::Debug {
+    /// Everything stored so far; nothing stored yet is an empty store
+    async fn load(&self) -> Result<StatisticsData>;
+
+    /// Store `changes`, made since the last load or save; `data` already
+    /// includes them, for backends that rewrite everything
+    async fn save(&self, data: &StatisticsData, changes: &[StatisticsEntry]) -> Result<()>;
+
+    /// Where the statistics are kept, for display
+    fn location(&self) -> String;
+}
+
+/// The backends that can be chosen by configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StatsBackendKind {
+    /// Append-only `statistics.jsonl`
+    #[default]
+    #[serde(rename = "jsonl")]
+    JsonLines,
+    /// The whole store as one `statistics.json` document
+    #[serde(rename = "json")]
+    Json,
+    /// Nothing kept past the process
+    #[serde(rename = "memory")]
+    Memory,
+}
+
+impl StatsBackendKind {
+    /// The backend at its default location in the project root
+    pub fn backend(self) -> Box<dyn StatisticsBackend> {
+        match self {
+            Self::JsonLines => Box::new(
+                JsonLinesBackend::new(JsonLinesBackend::default_path())
+                    .migrating_from(StatisticsStore::default_path()),
+            ),
+            Self::Json => Box::new(JsonBackend::new(StatisticsStore::default_path())),
+            Self::Memory => Box::new(MemoryBackend::default()),
+        }
+    }
+}
+
+/// The whole store as one pretty-printed JSON document, rewritten on every save
+#[derive(Debug, Clone)]
+pub struct JsonBackend {
+    path: PathBuf,
+}
+
+impl JsonBackend {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+}
+
+#[async_trait]
+impl StatisticsBackend for JsonBackend {
+    async fn load(&self) -> Result<StatisticsData> {
+        match tokio::fs::read_to_string(&self.path).await {
+            Ok(text) => Ok(serde_json::from_str(&text).map_err(DataError::from)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(StatisticsData::default()),
+            Err(e) => Err(DataError::from(e).into()),
+        }
+    }
+
+    async fn save(&self, data: &StatisticsData, _changes: &[StatisticsEntry]) -> Result<()> {
+        let text = serde_json::to_string_pretty(data).map_err(DataError::from)?;
+        write_atomic(&self.path, text).await
+    }
+
+    fn location(&self) -> String {
+        self.path.display().to_string()
+    }
+}
+
+/// One [`StatisticsEntry`] per line, appended as changes are saved
+///
+/// A line cut short by a crash during an append is skipped when loading, so the
+/// rest of the history still opens, and cut off before the next append.
+#[derive(Debug, Clone)]
+pub struct JsonLinesBackend {
+    path: PathBuf,
+    /// JSON document whose contents seed the file when it doesn't exist yet
+    legacy: Option<PathBuf>,
+}
+
+impl JsonLinesBackend {
+    /// Default statistics log in the project root
+    pub fn default_path() -> PathBuf {
+        crate::infrastructure::word_list::project_root_path("statistics.jsonl")
+    }
+
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            legacy: None,
+        }
+    }
+
+    /// Start from the JSON document at `legacy` when there is no log yet; the
+    /// document is left in place
+    pub fn migrating_from(mut self, legacy: impl AsRef<Path>) -> Self {
+        self.legacy = Some(legacy.as_ref().to_path_buf());
+        self
+    }
+
+    fn parse(&self, text: &str) -> Result<StatisticsData> {
+        let mut data = StatisticsData::default();
+        let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+        for (number, line) in lines.iter().enumerate() {
+            match serde_json::from_str(line) {
+                Ok(entry) => data.apply(entry),
+                Err(e) if number + 1 == lines.len() => {
+                    log::warn!(
+                        "Skipping a torn last line in {}: {}",
+                        self.path.display(),
+                        e
+                    );
+                }
+                Err(e) => {
+                    let at = format!("{} line {}: {}", self.path.display(), number + 1, e);
+                    return Err(DataError::InvalidFormat(at).into());
+                }
+            }
+        }
+        Ok(data)
+    }
+
+    /// Make sure the file ends in a newline before appending to it
+    ///
+    /// A last line without one is either a complete entry, which gets its newline,
+    /// or what a crash left of an append, which is cut off; appending after it
+    /// would merge it into the next entry and make the whole file unreadable.
+    async fn end_last_line(&self, file: &mut tokio::fs::File) -> Result<()> {
+        let len = file.metadata().await.map_err(DataError::from)?.len();
+        if len == 0 {
+            return Ok(());
+        }
+        file.seek(SeekFrom::End(-1))
+            .await
+            .map_err(DataError::from)?;
+        if file.read_u8().await.map_err(DataError::from)? == b'\n' {
+            return Ok(());
+        }
+        let mut bytes = Vec::new();
+        file.rewind().await.map_err(DataError::from)?;
+        file.read_to_end(&mut bytes)
+            .await
+            .map_err(DataError::from)?;
+        let start = bytes.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+        if serde_json::from_slice::<StatisticsEntry>(&bytes[start..]).is_ok() {
+            file.seek(SeekFrom::End(0)).await.map_err(DataError::from)?;
+            file.write_all(b"\n").await.map_err(DataError::from)?;
+        } else {
+            log::warn!("Cutting a torn last line from {}", self.path.display());
+            file.set_len(start as u64).await.map_err(DataError::from)?;
+        }
+        Ok(())
+    }
+}
+
+/// `entries` as JSON lines, each ending in a newline
+fn to_lines(entries: impl IntoIterator<Item = StatisticsEntry>) -> Result<String> {
+    let mut text = String::new();
+    for entry in entries {
+        text.push_str(&serde_json::to_string(&entry).map_err(DataError::from)?);
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+#[async_trait]
+impl StatisticsBackend for JsonLinesBackend {
+    async fn load(&self) -> Result<StatisticsData> {
+        match tokio::fs::read_to_string(&self.path).await {
+            Ok(text) => return self.parse(&text),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(DataError::from(e).into()),
+        }
+        let Some(legacy) = &self.legacy else {
+            return Ok(StatisticsData::default());
+        };
+        let data = JsonBackend::new(legacy).load().await?;
+        if data.entries().next().is_some() {
+            log::info!("Copying {} into {}", legacy.display(), self.path.display());
+            write_atomic(&self.path, to_lines(data.entries())?).await?;
+        }
+        Ok(data)
+    }
+
+    async fn save(&self, _data: &StatisticsData, changes: &[StatisticsEntry]) -> Result<()> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let text = to_lines(changes.iter().cloned())?;
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .read(true)
+            .write(true)
+            .truncate(false)
+            .open(&self.path)
+            .await
+            .map_err(DataError::from)?;
+        self.end_last_line(&mut file).await?;
+        file.seek(SeekFrom::End(0)).await.map_err(DataError::from)?;
+        file.write_all(text.as_bytes())
+            .await
+            .map_err(DataError::from)?;
+        file.flush().await.map_err(DataError::from)?;
+        Ok(())
+    }
+
+    fn location(&self) -> String {
+        self.path.display().to_string()
+    }
+}
+
+/// Statistics kept in memory; clones share the same data, so a test can open a
+/// second store on what the first one saved
+#[derive(Debug, Clone, Default)]
+pub struct MemoryBackend {
+    data: Arc<Mutex<StatisticsData>>,
+}
+
+#[async_trait]
+impl StatisticsBackend for MemoryBackend {
+    async fn load(&self) -> Result<StatisticsData> {
+        Ok(self.data.lock().unwrap_or_else(|e| e.into_inner()).clone())
+    }
+
+    async fn save(&self, data: &StatisticsData, _changes: &[StatisticsEntry]) -> Result<()> {
+        *self.data.lock().unwrap_or_else(|e| e.into_inner()) = data.clone();
+        Ok(())
+    }
+
+    fn location(&self) -> String {
+        "memory".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::statistics::{GameRecord, StatsBaseline};
+
+    fn baseline(player: &str, games_played: u32) -> StatsBaseline {
+        StatsBaseline {
+            player: player.to_string(),
+            source: "nyt".to_string(),
+            imported_at: 0,
+            games_played,
+            games_won: games_played,
+            current_streak: 0,
+            max_streak: 0,
+            guess_distribution: [0; 6],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_json_lines_appends_and_replays() {
+        let dir = std::env::temp_dir().join(format!("wordle_stats_jsonl_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let legacy = dir.join("statistics.json");
+        let path = dir.join("statistics.jsonl");
+
+        let mut old = StatisticsStore::open(&legacy).await.unwrap();
+        old.record_game(GameRecord::new("alice", false, None, &[]));
+        old.save().await.unwrap();
+
+        let backend = JsonLinesBackend::new(&path).migrating_from(&legacy);
+        let mut store = StatisticsStore::with_backend(Box::new(backend.clone()))
+            .await
+            .unwrap();
+        assert_eq!(store.games().len(), 1);
+        store.set_baseline(baseline("bob", 3));
+        store.set_baseline(baseline("bob", 5));
+        store.save().await.unwrap();
+        store.record_game(GameRecord::new("bob", false, None, &[]));
+        store.save().await.unwrap();
+
+        // Each change is one line: the copied game, two baselines, one game
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(text.lines().count(), 4);
+        std::fs::write(&path, format!("{}{{\"game\":", text)).unwrap();
+        let mut reopened = StatisticsStore::with_backend(Box::new(backend.clone()))
+            .await
+            .unwrap();
+        assert_eq!(reopened.games().len(), 2);
+        assert_eq!(reopened.baseline("bob").unwrap().games_played, 5);
+
+        // Appending after the torn line cuts it off instead of merging into it
+        for _ in 0..2 {
+            reopened.record_game(GameRecord::new("carol", false, None, &[]));
+            reopened.save().await.unwrap();
+        }
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 6);
+        let reopened = StatisticsStore::with_backend(Box::new(backend))
+            .await
+            .unwrap();
+        assert_eq!(reopened.games().len(), 4);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_memory_backend_and_kind_names() {
+        let backend = MemoryBackend::default();
+        let mut store = StatisticsStore::with_backend(Box::new(backend.clone()))
+            .await
+            .unwrap();
+        store.record_game(GameRecord::new("alice", false, None, &[]));
+        store.save().await.unwrap();
+        let reopened = StatisticsStore::with_backend(Box::new(backend))
+            .await
+            .unwrap();
+        assert_eq!(reopened.players(), vec!["alice"]);
+
+        assert_eq!(
+            serde_json::from_str::<StatsBackendKind>(r#""jsonl""#).unwrap(),
+            StatsBackendKind::JsonLines
+        );
+        assert!(serde_json::from_str::<StatsBackendKind>(r#""sqlite""#).is_err());
+    }
+}
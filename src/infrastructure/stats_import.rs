@@ -0,0 +1,155 @@
+use crate::core::error::{DataError, Result};
+use crate::infrastructure::statistics::{StatsBaseline, DISTRIBUTION_ROWS};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Source tag stored on baselines imported from the NYT site
+pub const NYT_SOURCE: &str = "nyt";
+
+/// How deep to look for the statistics object inside an export
+const MAX_SEARCH_DEPTH: usize = 4;
+
+/// Statistics object as stored by the NYT Wordle site
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NytStatistics {
+    #[serde(default)]
+    pub games_played: Option<u32>,
+    #[serde(default)]
+    pub games_won: Option<u32>,
+    #[serde(default)]
+    pub current_streak: u32,
+    #[serde(default)]
+    pub max_streak: u32,
+    /// Wins keyed "1".."6" plus "fail" for losses
+    pub guesses: HashMap<String, u32>,
+}
+
+impl NytStatistics {
+    /// Parse an export. Accepts the bare statistics object, a game-state object
+    /// wrapping it (`stats`/`statistics`), or a localStorage dump where values
+    /// are JSON-encoded strings.
+    pub fn parse(text: &str) -> Result<Self> {
+        let root: Value = serde_json::from_str(text).map_err(DataError::from)?;
+        let found = find_statistics(&root, MAX_SEARCH_DEPTH).ok_or_else(|| {
+            DataError::InvalidFormat("No Wordle statistics found in export".to_string())
+        })?;
+        Ok(serde_json::from_value(found).map_err(DataError::from)?)
+    }
+
+    /// Read and parse an export file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(DataError::from)?;
+        Self::parse(&text)
+    }
+
+    /// Wins per guess count, index 0 = solved in one
+    pub fn distribution(&self) -> [u32; DISTRIBUTION_ROWS] {
+        let mut rows = [0; DISTRIBUTION_ROWS];
+        for (i, row) in rows.iter_mut().enumerate() {
+            *row = self.guesses.get(&(i + 1).to_string()).copied().unwrap_or(0);
+        }
+        rows
+    }
+
+    /// Convert to a baseline for the local statistics store
+    pub fn into_baseline(self, player: &str) -> Result<StatsBaseline> {
+        let distribution = self.distribution();
+        let wins: u32 = distribution.iter().sum();
+        let losses = self.guesses.get("fail").copied().unwrap_or(0);
+
+        let games_won = self.games_won.unwrap_or(wins);
+        let games_played = self.games_played.unwrap_or(wins + losses);
+        if games_won > games_played || self.current_streak > self.max_streak {
+            return Err(DataError::InvalidFormat(format!(
+                "Inconsistent statistics: {} won of {} played, streak {} > max {}",
+                games_won, games_played, self.current_streak, self.max_streak
+            ))
+            .into());
+        }
+
+        Ok(StatsBaseline {
+            player: player.to_string(),
+            source: NYT_SOURCE.to_string(),
+            imported_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            games_played,
+            games_won,
+            current_streak: self.current_streak,
+            max_streak: self.max_streak,
+            guess_distribution: distribution,
+        })
+    }
+}
+
+fn looks_like_statistics(value: &Value) -> bool {
+    value.get("guesses").is_some_and(Value::is_object)
+        && (value.get("gamesPlayed").is_some() || value.get("maxStreak").is_some())
+}
+
+fn find_statistics(value: &Value, depth: usize) -> Option<Value> {
+    if looks_like_statistics(value) {
+        return Some(value.clone());
+    }
+    if depth == 0 {
+        return None;
+    }
+    match value {
+        Value::Object(map) => map.values().find_map(|v| find_statistics(v, depth - 1)),
+        // localStorage values are themselves JSON strings
+        Value::String(text) => serde_json::from_str::<Value>(text)
+            .ok()
+            .and_then(|inner| find_statistics(&inner, depth - 1)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATS: &str = r#"{
+        "currentStreak": 3, "maxStreak": 8,
+        "guesses": {"1": 0, "2": 4, "3": 11, "4": 9, "5": 3, "6": 1, "fail": 2},
+        "winPercentage": 93, "gamesPlayed": 30, "gamesWon": 28, "averageGuesses": 4
+    }"#;
+
+    #[test]
+    fn test_parse_bare_statistics() {
+        let stats = NytStatistics::parse(STATS).unwrap();
+        assert_eq!(stats.distribution(), [0, 4, 11, 9, 3, 1]);
+
+        let baseline = stats.into_baseline("alice").unwrap();
+        assert_eq!(baseline.games_played, 30);
+        assert_eq!(baseline.games_won, 28);
+        assert_eq!(baseline.current_streak, 3);
+        assert_eq!(baseline.source, NYT_SOURCE);
+    }
+
+    #[test]
+    fn test_parse_local_storage_dump() {
+        let dump = serde_json::json!({
+            "nyt-wordle-darkmode": "false",
+            "nyt-wordle-moogle/ANON": serde_json::json!({
+                "game": {"boardState": ["", "", "", "", "", ""]},
+                "stats": serde_json::from_str::<Value>(STATS).unwrap(),
+            })
+            .to_string(),
+        });
+        let stats = NytStatistics::parse(&dump.to_string()).unwrap();
+        assert_eq!(stats.max_streak, 8);
+    }
+
+    #[test]
+    fn test_rejects_unrelated_json() {
+        assert!(NytStatistics::parse(r#"{"theme": "dark"}"#).is_err());
+        let inconsistent = r#"{"gamesPlayed": 1, "gamesWon": 5, "maxStreak": 1, "guesses": {}}"#;
+        let stats = NytStatistics::parse(inconsistent).unwrap();
+        assert!(stats.into_baseline("bob").is_err());
+    }
+}
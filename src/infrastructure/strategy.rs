@@ -1,25 +1,31 @@
 use crate::core::{
+    alphabet::LETTER_COUNT,
+    container::StrategyTuning,
     error::{Result, SolverError},
+    ranking::CandidateRanker,
     traits::{EntropyCalculator, SolvingStrategy},
     types::Word,
 };
 use rayon::prelude::*;
+use tokio_util::sync::CancellationToken;
 
 /// Entropy-based solving strategy
 #[derive(Debug)]
 pub struct EntropyBasedStrategy<E: EntropyCalculator> {
     pub(crate) entropy_calculator: E,
     pub(crate) best_first_guess: Word,
+    tuning: StrategyTuning,
 }
 
 impl<E: EntropyCalculator> EntropyBasedStrategy<E> {
     pub fn new(entropy_calculator: E) -> Result<Self> {
         let best_first_guess =
-            Word::from_str("adieu").map_err(|e| SolverError::AlgorithmFailure(e))?;
+            Word::from_str("adieu").map_err(SolverError::AlgorithmFailure)?;
 
         Ok(Self {
             entropy_calculator,
             best_first_guess,
+            tuning: StrategyTuning::default(),
         })
     }
 
@@ -27,12 +33,66 @@ impl<E: EntropyCalculator> EntropyBasedStrategy<E> {
         Self {
             entropy_calculator,
             best_first_guess: first_guess,
+            tuning: StrategyTuning::default(),
         }
     }
+
+    /// Open with `word` instead of the built-in default, e.g. from a warm cache
+    pub fn with_opener(mut self, word: Word) -> Self {
+        self.best_first_guess = word;
+        self
+    }
+
+    /// Use the endgame threshold and prefilter size of `tuning`
+    pub fn with_tuning(mut self, tuning: StrategyTuning) -> Self {
+        self.tuning = tuning;
+        self
+    }
+
+    /// Remaining words above which only guesses with distinct letters are scored
+    fn prefilter_above(&self) -> Option<usize> {
+        self.tuning.prefilter_above.or_else(|| {
+            let val = std::env::var("WORDLE_FAST_PREFILTER").unwrap_or_default();
+            matches!(val.as_str(), "1" | "true" | "TRUE" | "yes" | "on").then_some(200)
+        })
+    }
 }
 
-impl<E: EntropyCalculator> SolvingStrategy for EntropyBasedStrategy<E> {
-    fn get_best_guess(&mut self, possible_words: &[Word], candidates: &[Word]) -> Result<Word> {
+impl<E: EntropyCalculator> EntropyBasedStrategy<E> {
+    /// Highest-entropy word of `pool`, scored in parallel
+    ///
+    /// Each word checks `cancel` before it is scored, so a cancelled scan stops
+    /// within one entropy calculation per thread and returns `None`.
+    fn scan_parallel(
+        &self,
+        pool: &[Word],
+        possible_words: &[Word],
+        ranker: &CandidateRanker,
+        cancel: Option<&CancellationToken>,
+    ) -> Option<Word> {
+        pool.par_iter()
+            .map(|w| {
+                if cancel.is_some_and(CancellationToken::is_cancelled) {
+                    return None;
+                }
+                let e = self.entropy_calculator.calculate_entropy(w, possible_words);
+                Some((w, e))
+            })
+            .while_some()
+            .min_by(|a, b| ranker.compare(*a, *b))
+            .map(|(w, _)| w.clone())
+    }
+
+    fn best_guess(
+        &self,
+        possible_words: &[Word],
+        candidates: &[Word],
+        cancel: Option<&CancellationToken>,
+    ) -> Result<Word> {
+        let cancelled = || cancel.is_some_and(CancellationToken::is_cancelled);
+        if cancelled() {
+            return Err(SolverError::Cancelled.into());
+        }
         if possible_words.is_empty() {
             return Err(SolverError::NoPossibleWords.into());
         }
@@ -46,7 +106,7 @@ impl<E: EntropyCalculator> SolvingStrategy for EntropyBasedStrategy<E> {
         }
 
     // Use information gain for better performance in endgame
-        let best_word = if possible_words.len() <= 3 {
+        let best_word = if possible_words.len() <= self.tuning.endgame_threshold {
             // When few words remain, prefer words that are possible answers
             let set: std::collections::HashSet<&Word> = possible_words.iter().collect();
             let answer_candidates: Vec<_> = candidates
@@ -67,19 +127,18 @@ impl<E: EntropyCalculator> SolvingStrategy for EntropyBasedStrategy<E> {
                     .find_max_entropy_guess(candidates, possible_words)
             }
         } else {
+            let ranker = CandidateRanker::new(possible_words);
             // Optional heuristic prefilter (disabled by default to preserve accuracy)
-            let use_prefilter = {
-                let val = std::env::var("WORDLE_FAST_PREFILTER").unwrap_or_default();
-                matches!(val.as_str(), "1" | "true" | "TRUE" | "yes" | "on")
-            };
-            if use_prefilter && possible_words.len() > 200 {
+            if self
+                .prefilter_above()
+                .is_some_and(|above| possible_words.len() > above)
+            {
                 let filtered: Vec<_> = candidates
                     .iter()
                     .filter(|w| {
-                        let s = w.as_str().as_bytes();
-                        let mut seen = [false; 26];
-                        for &b in s {
-                            let i = (b - b'a') as usize;
+                        let mut seen = [false; LETTER_COUNT];
+                        for &b in w.bytes() {
+                            let i = Word::ALPHABET.position(b);
                             if seen[i] {
                                 return false;
                             }
@@ -97,13 +156,7 @@ impl<E: EntropyCalculator> SolvingStrategy for EntropyBasedStrategy<E> {
                 // Parallel scan if pool is large
                 let par_threshold = 256;
                 if pool.len() >= par_threshold {
-                    pool.par_iter()
-                        .map(|w| {
-                            let e = self.entropy_calculator.calculate_entropy(w, possible_words);
-                            (w, e)
-                        })
-                        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
-                        .map(|(w, _)| w.clone())
+                    self.scan_parallel(pool, possible_words, &ranker, cancel)
                 } else {
                     self.entropy_calculator
                         .find_max_entropy_guess(pool, possible_words)
@@ -112,14 +165,7 @@ impl<E: EntropyCalculator> SolvingStrategy for EntropyBasedStrategy<E> {
                 // Parallel scan if candidates large
                 let par_threshold = 256;
                 if candidates.len() >= par_threshold {
-                    candidates
-                        .par_iter()
-                        .map(|w| {
-                            let e = self.entropy_calculator.calculate_entropy(w, possible_words);
-                            (w, e)
-                        })
-                        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
-                        .map(|(w, _)| w.clone())
+                    self.scan_parallel(candidates, possible_words, &ranker, cancel)
                 } else {
                     self.entropy_calculator
                         .find_max_entropy_guess(candidates, possible_words)
@@ -127,17 +173,36 @@ impl<E: EntropyCalculator> SolvingStrategy for EntropyBasedStrategy<E> {
             }
         };
 
+        // A cancelled scan stops with whatever it had scored, which isn't the best
+        if cancelled() {
+            return Err(SolverError::Cancelled.into());
+        }
         best_word.ok_or_else(|| {
             SolverError::AlgorithmFailure("Could not find best guess".to_string()).into()
         })
     }
+}
+
+impl<E: EntropyCalculator> SolvingStrategy for EntropyBasedStrategy<E> {
+    fn get_best_guess(&self, possible_words: &[Word], candidates: &[Word]) -> Result<Word> {
+        self.best_guess(possible_words, candidates, None)
+    }
+
+    fn get_best_guess_cancellable(
+        &self,
+        possible_words: &[Word],
+        candidates: &[Word],
+        cancel: &CancellationToken,
+    ) -> Result<Word> {
+        self.best_guess(possible_words, candidates, Some(cancel))
+    }
 
     fn get_best_first_guess(&self) -> Result<Word> {
         Ok(self.best_first_guess.clone())
     }
 
     fn get_top_candidates(
-        &mut self,
+        &self,
         possible_words: &[Word],
         candidates: &[Word],
         limit: usize,
@@ -170,9 +235,8 @@ impl<E: EntropyCalculator> SolvingStrategy for EntropyBasedStrategy<E> {
                 .collect()
         };
 
-        // Sort by score (descending)
-        scored_candidates
-            .sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        // Score desc, then answers first, then lexicographic
+        CandidateRanker::new(possible_words).sort(&mut scored_candidates);
 
         scored_candidates.truncate(limit);
         scored_candidates
@@ -193,6 +257,19 @@ pub struct FrequencyBasedStrategy {
 
 impl FrequencyBasedStrategy {
     pub fn new(word_list: &[Word]) -> Result<Self> {
+        let letter_frequencies = Self::letter_frequencies(word_list);
+
+        let best_first_guess =
+            Word::from_str("adieu").map_err(SolverError::AlgorithmFailure)?;
+
+        Ok(Self {
+            letter_frequencies,
+            best_first_guess,
+        })
+    }
+
+    /// Share of all letters in `word_list` that each letter makes up
+    fn letter_frequencies(word_list: &[Word]) -> std::collections::HashMap<char, f64> {
         let mut letter_counts = std::collections::HashMap::new();
         let mut total_letters = 0;
 
@@ -205,18 +282,16 @@ impl FrequencyBasedStrategy {
         }
 
         // Convert to frequencies
-        let letter_frequencies = letter_counts
+        letter_counts
             .into_iter()
             .map(|(ch, count)| (ch, count as f64 / total_letters as f64))
-            .collect();
-
-        let best_first_guess =
-            Word::from_str("adieu").map_err(|e| SolverError::AlgorithmFailure(e))?;
+            .collect()
+    }
 
-        Ok(Self {
-            letter_frequencies,
-            best_first_guess,
-        })
+    /// Open with `word` instead of the built-in default, e.g. from a warm cache
+    pub fn with_opener(mut self, word: Word) -> Self {
+        self.best_first_guess = word;
+        self
     }
 
     fn score_word(&self, word: &Word) -> f64 {
@@ -235,7 +310,7 @@ impl FrequencyBasedStrategy {
 }
 
 impl SolvingStrategy for FrequencyBasedStrategy {
-    fn get_best_guess(&mut self, possible_words: &[Word], candidates: &[Word]) -> Result<Word> {
+    fn get_best_guess(&self, possible_words: &[Word], candidates: &[Word]) -> Result<Word> {
         if possible_words.is_empty() {
             return Err(SolverError::NoPossibleWords.into());
         }
@@ -249,11 +324,9 @@ impl SolvingStrategy for FrequencyBasedStrategy {
         }
 
         // Score candidates by letter frequency
-        let best_word = candidates
-            .iter()
-            .map(|word| (word.clone(), self.score_word(word)))
-            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-            .map(|(word, _)| word);
+        let best_word = CandidateRanker::new(possible_words)
+            .best(candidates.iter().map(|word| (word, self.score_word(word))))
+            .cloned();
 
         best_word.ok_or_else(|| {
             SolverError::AlgorithmFailure("Could not find best guess".to_string()).into()
@@ -265,8 +338,8 @@ impl SolvingStrategy for FrequencyBasedStrategy {
     }
 
     fn get_top_candidates(
-        &mut self,
-        _possible_words: &[Word],
+        &self,
+        possible_words: &[Word],
         candidates: &[Word],
         limit: usize,
     ) -> Vec<(Word, f64)> {
@@ -275,8 +348,7 @@ impl SolvingStrategy for FrequencyBasedStrategy {
             .map(|word| (word.clone(), self.score_word(word)))
             .collect();
 
-        scored_candidates
-            .sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        CandidateRanker::new(possible_words).sort(&mut scored_candidates);
         scored_candidates.truncate(limit);
         scored_candidates
     }
@@ -284,6 +356,10 @@ impl SolvingStrategy for FrequencyBasedStrategy {
     fn clear_cache(&mut self) {
         // No cache to clear in frequency-based strategy
     }
+
+    fn word_lists_changed(&mut self, answers: &[Word]) {
+        self.letter_frequencies = Self::letter_frequencies(answers);
+    }
 }
 
 /// Hybrid strategy combining entropy and frequency analysis
@@ -292,13 +368,13 @@ pub struct HybridStrategy<E: EntropyCalculator> {
     entropy_calculator: E,
     frequency_weights: std::collections::HashMap<char, f64>,
     best_first_guess: Word,
-    use_entropy_threshold: usize,
+    tuning: StrategyTuning,
 }
 
 impl<E: EntropyCalculator> HybridStrategy<E> {
     pub fn new(entropy_calculator: E) -> Result<Self> {
         let best_first_guess =
-            Word::from_str("adieu").map_err(|e| SolverError::AlgorithmFailure(e))?;
+            Word::from_str("adieu").map_err(SolverError::AlgorithmFailure)?;
 
         // Initialize common letter frequencies
         let mut frequency_weights = std::collections::HashMap::new();
@@ -316,10 +392,22 @@ impl<E: EntropyCalculator> HybridStrategy<E> {
             entropy_calculator,
             frequency_weights,
             best_first_guess,
-            use_entropy_threshold: 50, // Use entropy when more than 50 words remain
+            tuning: StrategyTuning::default(),
         })
     }
 
+    /// Open with `word` instead of the built-in default, e.g. from a warm cache
+    pub fn with_opener(mut self, word: Word) -> Self {
+        self.best_first_guess = word;
+        self
+    }
+
+    /// Use the entropy weight and thresholds of `tuning`
+    pub fn with_tuning(mut self, tuning: StrategyTuning) -> Self {
+        self.tuning = tuning;
+        self
+    }
+
     fn calculate_frequency_score(&self, word: &Word) -> f64 {
         let mut score = 0.0;
         let mut used_chars = std::collections::HashSet::new();
@@ -338,7 +426,7 @@ impl<E: EntropyCalculator> HybridStrategy<E> {
         &self,
         word: &Word,
         possible_words: &[Word],
-        pos_counts: &[[u32; 26]; 5],
+        pos_counts: &[[u32; LETTER_COUNT]; 5],
         denom: f64,
     ) -> f64 {
         let entropy = self
@@ -349,7 +437,7 @@ impl<E: EntropyCalculator> HybridStrategy<E> {
         let mut frequency = self.calculate_frequency_score(word);
         let b = word.bytes();
         for pos in 0..5 {
-            frequency += pos_counts[pos][(b[pos] - b'a') as usize] as f64 / denom;
+            frequency += pos_counts[pos][Word::ALPHABET.position(b[pos])] as f64 / denom;
         }
         let mut bigram_bonus = 0.0;
         for i in 0..4 {
@@ -366,18 +454,25 @@ impl<E: EntropyCalculator> HybridStrategy<E> {
 
         // Weight entropy more heavily when many words remain
         let n = possible_words.len();
-        let entropy_weight = if n > self.use_entropy_threshold { 0.8 } else if n > 10 { 0.6 } else if n > 3 { 0.45 } else { 0.25 };
+        let entropy_weight = if n > self.tuning.entropy_above {
+            f64::from(self.tuning.entropy_percent) / 100.0
+        } else if n > 10 {
+            0.6
+        } else if n > self.tuning.endgame_threshold {
+            0.45
+        } else {
+            0.25
+        };
         let frequency_weight = 1.0 - entropy_weight;
         entropy * entropy_weight + frequency * frequency_weight
     }
 
-    fn build_pos_counts(&self, possible_words: &[Word]) -> [[u32; 26]; 5] {
-        let mut pos_counts = [[0u32; 26]; 5];
+    fn build_pos_counts(&self, possible_words: &[Word]) -> [[u32; LETTER_COUNT]; 5] {
+        let mut pos_counts = [[0u32; LETTER_COUNT]; 5];
         for w in possible_words {
             let b = w.bytes();
             for pos in 0..5 {
-                let idx = (b[pos] - b'a') as usize;
-                if idx < 26 {
+                if let Some(idx) = Word::ALPHABET.index(b[pos]) {
                     pos_counts[pos][idx] += 1;
                 }
             }
@@ -387,7 +482,7 @@ impl<E: EntropyCalculator> HybridStrategy<E> {
 }
 
 impl<E: EntropyCalculator> SolvingStrategy for HybridStrategy<E> {
-    fn get_best_guess(&mut self, possible_words: &[Word], candidates: &[Word]) -> Result<Word> {
+    fn get_best_guess(&self, possible_words: &[Word], candidates: &[Word]) -> Result<Word> {
         if possible_words.is_empty() {
             return Err(SolverError::NoPossibleWords.into());
         }
@@ -400,18 +495,14 @@ impl<E: EntropyCalculator> SolvingStrategy for HybridStrategy<E> {
             return Err(SolverError::NoCandidates.into());
         }
 
-    // Find the best guess using hybrid scoring; precompute counts once
-    let pos_counts = self.build_pos_counts(possible_words);
-    let denom = possible_words.len() as f64;
-    let best_candidate = candidates
-            .iter()
-            .max_by(|a, b| {
-        let score_a = self.calculate_hybrid_score(a, possible_words, &pos_counts, denom);
-        let score_b = self.calculate_hybrid_score(b, possible_words, &pos_counts, denom);
-                score_a
-                    .partial_cmp(&score_b)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            })
+        // Find the best guess using hybrid scoring; precompute counts once
+        let pos_counts = self.build_pos_counts(possible_words);
+        let denom = possible_words.len() as f64;
+        let best_candidate = CandidateRanker::new(possible_words)
+            .best(candidates.iter().map(|word| {
+                let score = self.calculate_hybrid_score(word, possible_words, &pos_counts, denom);
+                (word, score)
+            }))
             .ok_or(SolverError::NoCandidates)?;
 
         Ok(best_candidate.clone())
@@ -422,7 +513,7 @@ impl<E: EntropyCalculator> SolvingStrategy for HybridStrategy<E> {
     }
 
     fn get_top_candidates(
-        &mut self,
+        &self,
         possible_words: &[Word],
         candidates: &[Word],
         limit: usize,
@@ -439,8 +530,7 @@ impl<E: EntropyCalculator> SolvingStrategy for HybridStrategy<E> {
             })
             .collect();
 
-        scored_candidates
-            .sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        CandidateRanker::new(possible_words).sort(&mut scored_candidates);
         scored_candidates.truncate(limit);
         scored_candidates
     }
@@ -454,6 +544,7 @@ impl<E: EntropyCalculator> SolvingStrategy for HybridStrategy<E> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::error::WordleError;
     use crate::infrastructure::SimpleEntropyCalculator;
 
     #[test]
@@ -474,4 +565,31 @@ mod tests {
         let strategy = FrequencyBasedStrategy::new(&words);
         assert!(strategy.is_ok());
     }
+
+    #[test]
+    fn test_cancelled_scan_reports_cancellation() {
+        let answers: Vec<Word> = ["crane", "slate", "trace", "brick", "pound"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+        // Enough candidates for the parallel scan
+        let candidates: Vec<Word> = (b'a'..=b'z')
+            .flat_map(|first| (b'a'..=b'l').map(move |last| [first, b'r', b'a', b't', last]))
+            .map(|letters| Word::from_str(std::str::from_utf8(&letters).unwrap()).unwrap())
+            .collect();
+        let strategy = EntropyBasedStrategy::new(SimpleEntropyCalculator::new()).unwrap();
+
+        let token = CancellationToken::new();
+        assert_eq!(
+            strategy
+                .get_best_guess_cancellable(&answers, &candidates, &token)
+                .unwrap(),
+            strategy.get_best_guess(&answers, &candidates).unwrap()
+        );
+        token.cancel();
+        let err = strategy
+            .get_best_guess_cancellable(&answers, &candidates, &token)
+            .unwrap_err();
+        assert!(matches!(err, WordleError::Solver(SolverError::Cancelled)));
+    }
 }
@@ -1,19 +1,214 @@
 use crate::core::{
+    alphabet::LETTER_COUNT,
+    clock::{Clock, system_clock},
     error::{DataError, Result},
+    morphology::MorphologyTags,
     traits::WordListProvider,
     types::Word,
 };
+use crate::infrastructure::atomic_file::{write_atomic, FileLock};
+use crate::infrastructure::http::{FetchFailure, HttpConfig, HttpFetcher};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::sync::Arc;
+
+/// Resolve `file_name` in the project root (nearest ancestor of the current
+/// directory containing Cargo.toml), falling back to the current directory
+pub(crate) fn project_root_path(file_name: &str) -> PathBuf {
+    let mut current_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    loop {
+        if current_dir.join("Cargo.toml").exists() {
+            return current_dir.join(file_name);
+        }
+        if let Some(parent) = current_dir.parent() {
+            current_dir = parent.to_path_buf();
+        } else {
+            return PathBuf::from(file_name);
+        }
+    }
+}
+
+/// Directory next to the WLF cache that holds frozen word-list snapshots
+pub const SNAPSHOT_DIR: &str = "word_list_snapshots";
+
+/// Reads of a WLF file that fails to parse before giving up
+const WLF_READ_ATTEMPTS: u32 = 3;
+
+/// Pause between those reads
+const WLF_READ_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Ready-made cache shipped with releases, installed when there is no cache yet
+pub const WARM_CACHE_FILE: &str = "word_lists.warm.wlf";
+
+/// Environment variable pointing at a warm cache in another location
+pub const WARM_CACHE_ENV: &str = "WORDLE_WARM_CACHE";
+
+/// Environment variable pinning the CLI and TUI to a word-list snapshot
+pub const WORDLIST_TAG_ENV: &str = "WORDLE_WORDLIST_TAG";
+
+/// Snapshot tag to pin to: `explicit` if given, else a non-empty `WORDLE_WORDLIST_TAG`
+pub fn resolve_wordlist_tag(explicit: Option<&str>) -> Option<String> {
+    explicit
+        .map(str::to_string)
+        .or_else(|| std::env::var(WORDLIST_TAG_ENV).ok())
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+}
 
 /// Configuration for word list sources
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WordListConfig {
     pub answers: Vec<String>,
     pub guesses: Vec<String>,
+    /// Fallback URLs per source URL, tried in order when it fails or returns garbage
+    #[serde(default)]
+    pub mirrors: BTreeMap<String, Vec<String>>,
+    /// License per source or mirror URL (e.g. "MIT"), recorded in the cache for attribution
+    #[serde(default)]
+    pub licenses: BTreeMap<String, String>,
+    /// How hard to try each URL before moving on to its mirrors
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// Proxy, extra root certificates and connect timeout for corporate networks
+    #[serde(default)]
+    pub http: HttpConfig,
+    /// Heuristics trimming unlikely answers from a dictionary-sized answer list
+    #[serde(default)]
+    pub pruning: AnswerPruning,
+}
+
+/// Retries of a word-list download that failed for a possibly temporary reason
+/// (connection error, timeout, HTTP 408/429/5xx)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// Tries per URL, including the first
+    pub attempts: u32,
+    /// Pause before the first retry, doubled after each one
+    pub initial_backoff_ms: u64,
+    /// Longest pause between tries
+    pub max_backoff_ms: u64,
+    /// Random spread applied to each pause, as a fraction of it (0.0-1.0)
+    pub jitter: f64,
+    /// Time limit for each request
+    pub timeout_secs: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: 4,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 8_000,
+            jitter: 0.2,
+            timeout_secs: 20,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Pause before retry number `retry` (1-based), with `unit` in 0.0..=1.0 picking where
+    /// it falls within the jitter range
+    pub fn backoff(&self, retry: u32, unit: f64) -> std::time::Duration {
+        let doublings = retry.saturating_sub(1).min(32);
+        let base = self
+            .initial_backoff_ms
+            .saturating_mul(1 << doublings)
+            .min(self.max_backoff_ms) as f64;
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        let factor = 1.0 - jitter + 2.0 * jitter * unit.clamp(0.0, 1.0);
+        std::time::Duration::from_millis((base * factor).round() as u64)
+    }
+
+    /// Pause before retry number `retry`, with random jitter
+    fn jittered_backoff(&self, retry: u32) -> std::time::Duration {
+        use std::hash::{BuildHasher, Hasher};
+        // RandomState is seeded from OS randomness per instance
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u32(retry);
+        self.backoff(retry, hasher.finish() as f64 / u64::MAX as f64)
+    }
+}
+
+/// Which unlikely answers to drop from the answer list when it is loaded
+///
+/// Wordle answers are almost never plurals, past tenses or names, but an unfiltered
+/// dictionary is full of them. Each heuristic is off by default and can be turned on
+/// on its own; pruned words remain valid guesses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnswerPruning {
+    /// Drop words ending in a plural "s" (but not "ss", "us" or "is", as in "abyss")
+    pub plurals: bool,
+    /// Drop words ending in "ed" (but not "eed", as in "greed")
+    pub past_tense: bool,
+    /// Drop the names and places in [`COMMON_PROPER_NOUNS`](crate::core::COMMON_PROPER_NOUNS)
+    pub proper_nouns: bool,
+    /// Share of answers (0.0-1.0) to drop, starting with those whose letters are
+    /// rarest in their positions
+    pub frequency_floor: f64,
+}
+
+/// Answers removed by each [`AnswerPruning`] heuristic, counted in the order they run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct PruningReport {
+    pub plurals: usize,
+    pub past_tense: usize,
+    pub proper_nouns: usize,
+    pub below_floor: usize,
+}
+
+impl PruningReport {
+    pub fn total(&self) -> usize {
+        self.plurals + self.past_tense + self.proper_nouns + self.below_floor
+    }
+}
+
+impl AnswerPruning {
+    /// Whether any heuristic is turned on
+    pub fn is_enabled(&self) -> bool {
+        self.plurals || self.past_tense || self.proper_nouns || self.frequency_floor > 0.0
+    }
+
+    /// Remove the words the enabled heuristics reject from `answers`
+    ///
+    /// The frequency floor needs `frequency`; without it that step is skipped.
+    pub fn apply(
+        &self,
+        answers: &mut Vec<Word>,
+        frequency: Option<&FrequencyData>,
+    ) -> PruningReport {
+        let mut report = PruningReport::default();
+        let mut drop_tagged = |count: &mut usize, tag: MorphologyTags| {
+            let before = answers.len();
+            answers.retain(|word| !MorphologyTags::of(word.as_str()).contains(tag));
+            *count = before - answers.len();
+        };
+        if self.plurals {
+            drop_tagged(&mut report.plurals, MorphologyTags::PLURAL);
+        }
+        if self.past_tense {
+            drop_tagged(&mut report.past_tense, MorphologyTags::PAST_TENSE);
+        }
+        if self.proper_nouns {
+            drop_tagged(&mut report.proper_nouns, MorphologyTags::PROPER_NOUN);
+        }
+        if let Some(frequency) = frequency.filter(|_| self.frequency_floor > 0.0) {
+            let cut = (answers.len() as f64 * self.frequency_floor.min(1.0)).round() as usize;
+            let mut ranked: Vec<(u64, Word)> = answers
+                .drain(..)
+                .map(|word| (frequency.position_score(&word), word))
+                .collect();
+            ranked.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+            ranked.truncate(ranked.len() - cut);
+            *answers = ranked.into_iter().map(|(_, word)| word).collect();
+            answers.sort();
+            report.below_floor = cut;
+        }
+        report
+    }
 }
 
 impl Default for WordListConfig {
@@ -30,20 +225,199 @@ impl Default for WordListConfig {
                 "https://raw.githubusercontent.com/dwyl/english-words/master/words_alpha.txt"
                     .to_string(),
             ],
+            mirrors: BTreeMap::new(),
+            licenses: BTreeMap::new(),
+            retry: RetryPolicy::default(),
+            http: HttpConfig::default(),
+            pruning: AnswerPruning::default(),
+        }
+    }
+}
+
+/// Named word list sources selectable from the settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WordSourcePreset {
+    /// `word_sources.json` when present, otherwise the built-in dictionary
+    #[default]
+    Auto,
+    /// Every five-letter word of the dwyl English dictionary
+    Dictionary,
+    /// The community list of words accepted by Wordle
+    Wordle,
+}
+
+impl WordSourcePreset {
+    pub const ALL: [WordSourcePreset; 3] = [Self::Auto, Self::Dictionary, Self::Wordle];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Dictionary => "dictionary",
+            Self::Wordle => "wordle",
+        }
+    }
+
+    /// Sources to download from; `None` keeps the provider's own choice
+    pub fn sources(self) -> Option<WordListConfig> {
+        match self {
+            Self::Auto => None,
+            Self::Dictionary => Some(WordListConfig::default()),
+            Self::Wordle => {
+                let url = "https://raw.githubusercontent.com/tabatkins/wordle-list/main/words";
+                Some(WordListConfig {
+                    answers: vec![url.to_string()],
+                    guesses: vec![url.to_string()],
+                    mirrors: BTreeMap::new(),
+                    licenses: BTreeMap::new(),
+                    retry: RetryPolicy::default(),
+                    http: HttpConfig::default(),
+                    pruning: AnswerPruning::default(),
+                })
+            }
         }
     }
 }
 
+/// Fewest five-letter words a downloaded list must contain to replace the cache
+pub const MIN_SOURCE_WORDS: usize = 100;
+
+/// Smallest share of non-empty lines that must be five-letter words; a full dictionary
+/// has about 4%, an error page or unrelated file next to none
+pub const MIN_FIVE_LETTER_RATIO: f64 = 0.01;
+
+/// Check a downloaded word list and return its five-letter words, lowercased
+///
+/// Rejects HTML (error pages, captive portals), lists with fewer than
+/// [`MIN_SOURCE_WORDS`] five-letter words, and lists where under
+/// [`MIN_FIVE_LETTER_RATIO`] of the lines are five-letter words.
+pub fn parse_word_list(text: &str) -> Result<Vec<String>> {
+    Ok(check_word_list(text).map_err(DataError::InvalidFormat)?)
+}
+
+/// [`parse_word_list`] with the reason for rejecting the list as plain text
+fn check_word_list(text: &str) -> std::result::Result<Vec<String>, String> {
+    let head = text.trim_start().get(..256).unwrap_or(text.trim_start());
+    if head.starts_with('<') || head.to_ascii_lowercase().contains("<html") {
+        return Err("got an HTML page instead of a word list".to_string());
+    }
+    let lines: Vec<String> = text
+        .lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|line| !line.is_empty())
+        .collect();
+    let words: Vec<String> = lines
+        .iter()
+        .filter(|word| word.len() == 5 && Word::ALPHABET.spells(word))
+        .cloned()
+        .collect();
+    if words.len() < MIN_SOURCE_WORDS {
+        return Err(format!(
+            "only {} five-letter words (need {})",
+            words.len(),
+            MIN_SOURCE_WORDS
+        ));
+    }
+    let ratio = words.len() as f64 / lines.len() as f64;
+    if ratio < MIN_FIVE_LETTER_RATIO {
+        return Err(format!(
+            "only {:.1}% of {} lines are five-letter words",
+            ratio * 100.0,
+            lines.len()
+        ));
+    }
+    Ok(words)
+}
+
+/// License of well-known sources that `word_sources.json` doesn't label
+fn known_license(url: &str) -> Option<&'static str> {
+    url.contains("/dwyl/english-words/").then_some("Unlicense")
+}
+
+/// Which of the two lists a source feeds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListKind {
+    Answers,
+    Guesses,
+}
+
+impl ListKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Answers => "answers",
+            Self::Guesses => "guesses",
+        }
+    }
+}
+
+/// Provenance of one source in the cache, for attribution by distributors
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceMetadata {
+    pub list: ListKind,
+    /// URL the words were downloaded from
+    pub url: String,
+    /// License as configured in `word_sources.json` or known for the URL
+    pub license: Option<String>,
+    /// Unix timestamp (seconds) of the download
+    pub downloaded_at: u64,
+    /// Five-letter words taken from it
+    pub words: usize,
+}
+
+/// Where one configured source's words came from on the last download
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceReport {
+    pub list: ListKind,
+    /// URL as listed in the configuration
+    pub configured: String,
+    /// URL actually used: the configured one or one of its mirrors
+    pub used: String,
+    /// Five-letter words it provided
+    pub words: usize,
+    /// URLs tried before it, with the reason each was skipped
+    pub failures: Vec<(String, String)>,
+}
+
+impl SourceReport {
+    /// Whether a mirror stood in for the configured URL
+    pub fn used_mirror(&self) -> bool {
+        self.used != self.configured
+    }
+}
+
 /// Frequency data for initial heuristics
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FrequencyData {
     #[serde(default)]
-    pub letter_counts: [u32; 26],
+    pub letter_counts: [u32; LETTER_COUNT],
     #[serde(default)]
-    pub position_counts: [[u32; 26]; 5],
+    pub position_counts: [[u32; LETTER_COUNT]; 5],
     /// Bigram counts for adjacent pairs across positions 0-3 (pairs 0-1,1-2,2-3,3-4)
     #[serde(default)]
-    pub bigram_counts: [[[u32; 26]; 26]; 4],
+    pub bigram_counts: [[[u32; LETTER_COUNT]; LETTER_COUNT]; 4],
+}
+
+impl FrequencyData {
+    /// How common each letter of `word` is in its position, summed
+    pub fn position_score(&self, word: &Word) -> u64 {
+        word.bytes()
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, &b)| Word::ALPHABET.index(b).map(|i| self.position_counts[pos][i]))
+            .map(u64::from)
+            .sum()
+    }
+}
+
+/// Word-form tags of the words in `answers` and `guesses` that have any
+fn tag_words(answers: &[String], guesses: &[String]) -> BTreeMap<String, MorphologyTags> {
+    answers
+        .iter()
+        .chain(guesses)
+        .map(|word| (word.clone(), MorphologyTags::of(word)))
+        .filter(|(_, tags)| !tags.is_empty())
+        .collect()
 }
 
 /// Cached word lists (JSON-compatible in-memory shape)
@@ -55,6 +429,60 @@ pub struct WordListCache {
     /// Optional frequency data (WLF2+)
     #[serde(default)]
     pub frequency: FrequencyData,
+    /// Best first guess per strategy name, computed for these lists (WLF4)
+    #[serde(default)]
+    pub first_guesses: BTreeMap<String, String>,
+    /// Where the lists came from (WLF5)
+    #[serde(default)]
+    pub sources: Vec<SourceMetadata>,
+    /// Word-form tags of the words that have any, from either list (WLF6)
+    #[serde(default)]
+    pub morphology: BTreeMap<String, MorphologyTags>,
+}
+
+/// Reader over the variable-length tables that end a WLF4+ file
+struct WlfTail<'a>(&'a [u8]);
+
+impl<'a> WlfTail<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.0.len() < n {
+            return Err(DataError::InvalidFormat("WLF table truncated".to_string()).into());
+        }
+        let (head, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap_or_default()))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap_or_default()))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap_or_default()))
+    }
+
+    fn text(&mut self, len: usize) -> Result<String> {
+        let bytes = self.take(len)?;
+        Ok(std::str::from_utf8(bytes)
+            .map_err(|e| DataError::InvalidFormat(format!("UTF-8 error: {}", e)))?
+            .to_string())
+    }
+}
+
+/// Word lists fetched from the configured sources
+#[derive(Debug)]
+struct Downloaded {
+    answer_words: Vec<String>,
+    guess_words: Vec<String>,
+    reports: Vec<SourceReport>,
 }
 
 /// File-based word list provider with online fetching
@@ -67,6 +495,26 @@ pub struct FileWordListProvider {
     bin_cache_path: String,
     config: WordListConfig,
     frequency: Option<FrequencyData>,
+    /// Openers stored with the loaded lists, by strategy name
+    first_guesses: BTreeMap<String, String>,
+    /// Sources used by the last download in this process
+    source_reports: Vec<SourceReport>,
+    /// Provenance stored with the loaded lists
+    sources: Vec<SourceMetadata>,
+    /// Word-form tags stored with the loaded lists, for tagged words only
+    morphology: BTreeMap<String, MorphologyTags>,
+    /// When the loaded lists were downloaded or frozen
+    last_updated: Option<u64>,
+    /// Snapshot to read instead of the cache, set by [`Self::with_snapshot`]
+    snapshot_tag: Option<String>,
+    /// Answers dropped by the configured pruning when the lists were loaded
+    pruning_report: PruningReport,
+    /// Bumped each time lists are swapped in; see [`WordListProvider::generation`]
+    generation: u64,
+    /// Downloads go through this instead of a client built from the config
+    fetcher: Option<Arc<dyn HttpFetcher>>,
+    /// Stamps downloads and decides when the cache is stale
+    clock: Arc<dyn Clock>,
 }
 
 impl FileWordListProvider {
@@ -115,6 +563,11 @@ impl FileWordListProvider {
         }
     }
 
+    /// Autosave file for the in-progress interactive game, stored next to the WLF cache
+    pub fn default_session_path() -> PathBuf {
+        Path::new(&Self::get_default_bin_cache_path()).with_file_name("wordle_session.json")
+    }
+
     /// Get the default binary cache path in the project root
     fn get_default_bin_cache_path() -> String {
         // Mirror get_default_cache_path but use .wlf extension
@@ -172,6 +625,16 @@ impl FileWordListProvider {
             bin_cache_path,
             config: WordListConfig::default(),
             frequency: None,
+            first_guesses: BTreeMap::new(),
+            source_reports: Vec::new(),
+            sources: Vec::new(),
+            morphology: BTreeMap::new(),
+            last_updated: None,
+            snapshot_tag: None,
+            pruning_report: PruningReport::default(),
+            generation: 0,
+            fetcher: None,
+            clock: system_clock(),
         };
         // Load optional source override config if present
         if let Some(cfg) = this.load_config_override() {
@@ -188,26 +651,216 @@ impl FileWordListProvider {
             bin_cache_path: Self::get_default_bin_cache_path(),
             config,
             frequency: None,
+            first_guesses: BTreeMap::new(),
+            source_reports: Vec::new(),
+            sources: Vec::new(),
+            morphology: BTreeMap::new(),
+            last_updated: None,
+            snapshot_tag: None,
+            pruning_report: PruningReport::default(),
+            generation: 0,
+            fetcher: None,
+            clock: system_clock(),
+        }
+    }
+
+    /// Download from `config` instead of the default or `word_sources.json` sources
+    pub fn with_sources(mut self, config: WordListConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Download through `fetcher`, e.g. a [`MockFetcher`] in tests, instead of a
+    /// client built from the configured network settings
+    ///
+    /// [`MockFetcher`]: crate::infrastructure::http::MockFetcher
+    pub fn with_fetcher(mut self, fetcher: Arc<dyn HttpFetcher>) -> Self {
+        self.fetcher = Some(fetcher);
+        self
+    }
+
+    /// Tell the time with `clock` instead of the system clock, e.g. to make the
+    /// cache stale in a test
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Read the lists from the frozen snapshot `tag` instead of the cache or the network
+    ///
+    /// Pinned providers never download and ignore refreshes, so results stay
+    /// reproducible after the cache is updated.
+    pub fn with_snapshot(mut self, tag: impl Into<String>) -> Self {
+        self.snapshot_tag = Some(tag.into());
+        self
+    }
+
+    /// Tag of the snapshot this provider is pinned to
+    pub fn snapshot_tag(&self) -> Option<&str> {
+        self.snapshot_tag.as_deref()
+    }
+
+    /// Directory holding frozen word lists, one `<tag>.wlf` per snapshot
+    pub fn snapshot_dir() -> PathBuf {
+        Path::new(&Self::get_default_bin_cache_path()).with_file_name(SNAPSHOT_DIR)
+    }
+
+    /// File of snapshot `tag`; tags may only use letters, digits, `-`, `_` and `.`
+    pub fn snapshot_path(tag: &str) -> Result<PathBuf> {
+        let valid = !tag.is_empty()
+            && !tag.starts_with('.')
+            && tag
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        if !valid {
+            return Err(DataError::InvalidFormat(format!("Invalid snapshot tag: '{}'", tag)).into());
+        }
+        Ok(Self::snapshot_dir().join(format!("{}.wlf", tag)))
+    }
+
+    /// Tags of the saved snapshots, sorted
+    pub fn list_snapshots() -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(Self::snapshot_dir()) else {
+            return Vec::new();
+        };
+        let mut tags: Vec<String> = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.extension()? != "wlf" {
+                    return None;
+                }
+                Some(path.file_stem()?.to_string_lossy().to_string())
+            })
+            .collect();
+        tags.sort();
+        tags
+    }
+
+    /// Write the loaded lists to a new snapshot named `tag`
+    ///
+    /// Existing snapshots are never overwritten; pick a new tag instead.
+    pub async fn freeze(&self, tag: &str) -> Result<PathBuf> {
+        let path = Self::snapshot_path(tag)?;
+        if self.answer_words.is_empty() {
+            return Err(DataError::MissingData("No word lists loaded to freeze".to_string()).into());
+        }
+        if path.exists() {
+            return Err(DataError::InvalidFormat(format!(
+                "Snapshot '{}' already exists at {}",
+                tag,
+                path.display()
+            ))
+            .into());
+        }
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await.map_err(DataError::from)?;
+        }
+
+        let cache = self.loaded_cache()?;
+        self.write_wlf(&path.to_string_lossy(), &cache).await?;
+        Ok(path)
+    }
+
+    /// Write the loaded lists with `first_guesses` (strategy name to opener) as a warm cache
+    ///
+    /// Shipped next to the binary, it spares a fresh install the download and the opener
+    /// search.
+    pub async fn write_warm_cache(
+        &self,
+        path: &Path,
+        first_guesses: BTreeMap<String, String>,
+    ) -> Result<()> {
+        if self.answer_words.is_empty() {
+            return Err(DataError::MissingData("No word lists loaded to write".to_string()).into());
+        }
+        let cache = WordListCache {
+            first_guesses,
+            ..self.loaded_cache()?
+        };
+        self.write_wlf(&path.to_string_lossy(), &cache).await
+    }
+
+    /// The loaded lists as a cache stamped with the current time
+    fn loaded_cache(&self) -> Result<WordListCache> {
+        let to_strings = |words: &[Word]| words.iter().map(|w| w.as_str().to_string()).collect();
+        let answer_words: Vec<String> = to_strings(&self.answer_words);
+        let guess_words: Vec<String> = to_strings(&self.guess_words);
+        let frequency = self
+            .frequency
+            .clone()
+            .unwrap_or_else(|| Self::compute_frequency(&answer_words));
+        let morphology = tag_words(&answer_words, &guess_words);
+        Ok(WordListCache {
+            answer_words,
+            guess_words,
+            last_updated: self.clock.unix_secs(),
+            frequency,
+            first_guesses: self.first_guesses.clone(),
+            sources: self.sources.clone(),
+            morphology,
+        })
+    }
+
+    /// Where a warm cache is looked for: `WORDLE_WARM_CACHE`, next to the executable,
+    /// then the project root
+    pub fn warm_cache_paths() -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = std::env::var(WARM_CACHE_ENV)
+            .ok()
+            .filter(|path| !path.trim().is_empty())
+            .map(PathBuf::from)
+            .into_iter()
+            .collect();
+        if let Ok(exe) = std::env::current_exe() {
+            paths.push(exe.with_file_name(WARM_CACHE_FILE));
         }
+        paths.push(Self::default_warm_cache_path());
+        paths
+    }
+
+    /// Warm cache in the project root, where `wordlist warm-cache` writes by default
+    pub fn default_warm_cache_path() -> PathBuf {
+        project_root_path(WARM_CACHE_FILE)
+    }
+
+    /// Copy the first warm cache found into place as the regular cache and read it
+    async fn install_warm_cache(&self) -> Result<WordListCache> {
+        let path = Self::warm_cache_paths()
+            .into_iter()
+            .find(|path| path.exists())
+            .ok_or_else(|| DataError::MissingData("Warm cache not found".to_string()))?;
+        let cache = self.read_wlf_unchecked(&path.to_string_lossy()).await?;
+        if let Err(e) = self.write_wlf(&self.bin_cache_path, &cache).await {
+            log::warn!("Could not install warm cache {}: {}", path.display(), e);
+        }
+        log::info!("Loaded word lists from warm cache {}", path.display());
+        Ok(cache)
+    }
+
+    /// Opener stored with the loaded lists for `strategy` (e.g. "entropy"), if any
+    pub fn first_guess(&self, strategy: &str) -> Option<Word> {
+        self.first_guesses
+            .get(strategy)
+            .and_then(|word| Word::from_str(word).ok())
+    }
+
+    /// Read snapshot `tag`, with a hint on how to create it when missing
+    async fn read_snapshot(&self, tag: &str) -> Result<WordListCache> {
+        let path = Self::snapshot_path(tag)?;
+        if !path.exists() {
+            return Err(DataError::MissingData(format!(
+                "word list snapshot '{}' (create it with `wordlist freeze --tag {}`)",
+                tag, tag
+            ))
+            .into());
+        }
+        self.read_wlf_unchecked(&path.to_string_lossy()).await
     }
 
     /// Returns the default path for an optional sources override file in the project root
     fn get_default_sources_config_path() -> String {
-        let mut current_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-        loop {
-            let cargo_toml = current_dir.join("Cargo.toml");
-            if cargo_toml.exists() {
-                return current_dir
-                    .join("word_sources.json")
-                    .to_string_lossy()
-                    .to_string();
-            }
-            if let Some(parent) = current_dir.parent() {
-                current_dir = parent.to_path_buf();
-            } else {
-                return "word_sources.json".to_string();
-            }
-        }
+        project_root_path("word_sources.json")
+            .to_string_lossy()
+            .to_string()
     }
 
     /// Load configuration override from `word_sources.json` if it exists
@@ -251,52 +904,49 @@ impl FileWordListProvider {
         Ok(cache)
     }
 
-    async fn download_words(&self) -> Result<(Vec<String>, Vec<String>)> {
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(20))
-            .build()
-            .map_err(|e| DataError::InvalidFormat(format!("HTTP client error: {}", e)))?;
+    /// The injected fetcher, else one over a client with the configured network
+    /// settings
+    fn fetcher(&self) -> Result<Arc<dyn HttpFetcher>> {
+        match &self.fetcher {
+            Some(fetcher) => Ok(fetcher.clone()),
+            None => self.default_fetcher(),
+        }
+    }
+
+    #[cfg(not(feature = "net"))]
+    fn default_fetcher(&self) -> Result<Arc<dyn HttpFetcher>> {
+        Err(DataError::MissingData(
+            "Word list download is unavailable: built without the `net` feature".to_string(),
+        )
+        .into())
+    }
+
+    #[cfg(feature = "net")]
+    fn default_fetcher(&self) -> Result<Arc<dyn HttpFetcher>> {
+        let timeout = std::time::Duration::from_secs(self.config.retry.timeout_secs.max(1));
+        let client = self.config.http.clone().with_env().client(timeout)?;
+        let fetcher = crate::infrastructure::http::ReqwestFetcher::new(client);
+        Ok(Arc::new(fetcher))
+    }
+
+    async fn download_words(&self) -> Result<Downloaded> {
+        use std::collections::HashSet;
+
+        let fetcher = self.fetcher()?;
+        let fetcher = fetcher.as_ref();
         let mut answer_words = HashSet::new();
         let mut guess_words = HashSet::new();
+        let mut reports = Vec::new();
 
-        // Download answer words
         for url in &self.config.answers {
-            log::info!("Downloading answer words from: {}", url);
-            let response = client.get(url).send().await.map_err(|e| {
-                DataError::InvalidFormat(format!("HTTP error fetching {}: {}", url, e))
-            })?;
-
-            let text = response
-                .text()
-                .await
-                .map_err(|e| DataError::InvalidFormat(format!("Response error: {}", e)))?;
-
-            for line in text.lines() {
-                let word = line.trim().to_lowercase();
-                if word.len() == 5 && word.chars().all(|c| c.is_ascii_lowercase()) {
-                    answer_words.insert(word);
-                }
-            }
+            let (words, report) = self.fetch_source(fetcher, ListKind::Answers, url).await?;
+            answer_words.extend(words);
+            reports.push(report);
         }
-
-        // Download guess words
         for url in &self.config.guesses {
-            log::info!("Downloading guess words from: {}", url);
-            let response = client.get(url).send().await.map_err(|e| {
-                DataError::InvalidFormat(format!("HTTP error fetching {}: {}", url, e))
-            })?;
-
-            let text = response
-                .text()
-                .await
-                .map_err(|e| DataError::InvalidFormat(format!("Response error: {}", e)))?;
-
-            for line in text.lines() {
-                let word = line.trim().to_lowercase();
-                if word.len() == 5 && word.chars().all(|c| c.is_ascii_lowercase()) {
-                    guess_words.insert(word);
-                }
-            }
+            let (words, report) = self.fetch_source(fetcher, ListKind::Guesses, url).await?;
+            guess_words.extend(words);
+            reports.push(report);
         }
 
         // Ensure all answer words are valid guesses
@@ -304,37 +954,219 @@ impl FileWordListProvider {
             guess_words.insert(word.clone());
         }
 
-        Ok((
-            answer_words.into_iter().collect(),
-            guess_words.into_iter().collect(),
+        Ok(Downloaded {
+            answer_words: answer_words.into_iter().collect(),
+            guess_words: guess_words.into_iter().collect(),
+            reports,
+        })
+    }
+
+    /// Fetch `url`, falling back to its configured mirrors, until one passes
+    /// [`parse_word_list`]
+    async fn fetch_source(
+        &self,
+        fetcher: &dyn HttpFetcher,
+        list: ListKind,
+        url: &str,
+    ) -> Result<(Vec<String>, SourceReport)> {
+        let mirrors = self.config.mirrors.get(url).map(Vec::as_slice).unwrap_or_default();
+        let mut failures = Vec::new();
+        for candidate in std::iter::once(url).chain(mirrors.iter().map(String::as_str)) {
+            match self.fetch_with_retries(fetcher, list, candidate).await {
+                Ok(words) => {
+                    if candidate != url {
+                        log::warn!("Using mirror {} for {}", candidate, url);
+                    }
+                    let report = SourceReport {
+                        list,
+                        configured: url.to_string(),
+                        used: candidate.to_string(),
+                        words: words.len(),
+                        failures,
+                    };
+                    return Ok((words, report));
+                }
+                Err(reason) => {
+                    log::warn!("Skipping {}: {}", candidate, reason);
+                    failures.push((candidate.to_string(), reason));
+                }
+            }
+        }
+        let tried: Vec<String> = failures
+            .iter()
+            .map(|(url, reason)| format!("{} ({})", url, reason))
+            .collect();
+        Err(DataError::MissingData(format!(
+            "a usable {} list; tried {}",
+            list.name(),
+            tried.join(", ")
         ))
+        .into())
     }
 
-    async fn save_to_cache(&self, answer_words: &[String], guess_words: &[String]) -> Result<()> {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|_| DataError::InvalidFormat("System time error".to_string()))?
-            .as_secs();
+    /// Fetch `url`, retrying temporary failures per the configured [`RetryPolicy`]
+    async fn fetch_with_retries(
+        &self,
+        fetcher: &dyn HttpFetcher,
+        list: ListKind,
+        url: &str,
+    ) -> std::result::Result<Vec<String>, String> {
+        let policy = self.config.retry;
+        let attempts = policy.attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            log::info!(
+                "Downloading {} from: {} (attempt {}/{})",
+                list.name(),
+                url,
+                attempt,
+                attempts
+            );
+            match Self::fetch_word_list(fetcher, url).await {
+                Ok(words) => return Ok(words),
+                Err(failure) if failure.retryable && attempt < attempts => {
+                    let delay = policy.jittered_backoff(attempt);
+                    log::warn!(
+                        "Download of {} failed (attempt {}/{}): {}; retrying in {} ms",
+                        url,
+                        attempt,
+                        attempts,
+                        failure.reason,
+                        delay.as_millis()
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(failure) if attempt > 1 => {
+                    return Err(format!("{} after {} attempts", failure.reason, attempt))
+                }
+                Err(failure) => return Err(failure.reason),
+            }
+        }
+    }
+
+    async fn fetch_word_list(
+        fetcher: &dyn HttpFetcher,
+        url: &str,
+    ) -> std::result::Result<Vec<String>, FetchFailure> {
+        let text = fetcher.get_text(url).await?;
+        // A body that isn't a word list won't turn into one on the next try
+        check_word_list(&text).map_err(|reason| FetchFailure {
+            reason,
+            retryable: false,
+        })
+    }
+
+    /// Write the download (sorted, deduplicated, with letter frequencies and where it came
+    /// from) as the cache
+    async fn save_to_cache(&self, downloaded: &Downloaded) -> Result<WordListCache> {
+        let now = self.clock.unix_secs();
 
         // Dedup for stable output
-        let mut a = answer_words.to_vec();
-        let mut g = guess_words.to_vec();
+        let mut a = downloaded.answer_words.clone();
+        let mut g = downloaded.guess_words.clone();
         a.sort();
         a.dedup();
         g.sort();
         g.dedup();
 
         let frequency = Self::compute_frequency(&a);
+        let morphology = tag_words(&a, &g);
 
         let cache = WordListCache {
             answer_words: a,
             guess_words: g,
             last_updated: now,
             frequency,
+            first_guesses: BTreeMap::new(),
+            sources: self.source_metadata(&downloaded.reports, now),
+            morphology,
         };
 
         // Write compact binary cache for fast load (WLF only)
         self.write_wlf(&self.bin_cache_path, &cache).await?;
+        Ok(cache)
+    }
+
+    /// Download the lists and save them as the cache, holding the cache lock
+    ///
+    /// Unless `force`d, a fresh cache another process wrote while we waited for the
+    /// lock is used instead of downloading again.
+    ///
+    /// Every source is validated (with mirror fallback) before the cache is replaced.
+    async fn download_to_cache(&mut self, force: bool) -> Result<WordListCache> {
+        let _lock = FileLock::acquire(&self.bin_cache_path, FileLock::DEFAULT_WAIT).await?;
+        if !force {
+            if let Ok(cache) = self.load_from_cache().await {
+                log::info!("Cache was refreshed by another process");
+                return Ok(cache);
+            }
+        }
+        let downloaded = self.download_words().await?;
+        let cache = self.save_to_cache(&downloaded).await?;
+        self.source_reports = downloaded.reports;
+        Ok(cache)
+    }
+
+    /// Attribution records for the sources of a download made at `downloaded_at`
+    fn source_metadata(&self, reports: &[SourceReport], downloaded_at: u64) -> Vec<SourceMetadata> {
+        reports
+            .iter()
+            .map(|report| SourceMetadata {
+                list: report.list,
+                url: report.used.clone(),
+                license: self
+                    .config
+                    .licenses
+                    .get(&report.used)
+                    .or_else(|| self.config.licenses.get(&report.configured))
+                    .cloned()
+                    .or_else(|| known_license(&report.used).map(str::to_string)),
+                downloaded_at,
+                words: report.words,
+            })
+            .collect()
+    }
+
+    /// Provenance of the loaded lists (empty for caches written before it was recorded)
+    pub fn sources(&self) -> &[SourceMetadata] {
+        &self.sources
+    }
+
+    /// When the loaded lists were downloaded (or frozen, for snapshots and warm caches)
+    pub fn last_updated(&self) -> Option<u64> {
+        self.last_updated
+    }
+
+    /// Answer pruning applied when loading, from `word_sources.json`
+    pub fn pruning(&self) -> &AnswerPruning {
+        &self.config.pruning
+    }
+
+    /// Answers the pruning removed from the loaded lists
+    pub fn pruning_report(&self) -> PruningReport {
+        self.pruning_report
+    }
+
+    /// Sources used by the last download (empty if the lists came from a cache)
+    pub fn source_reports(&self) -> &[SourceReport] {
+        &self.source_reports
+    }
+
+    /// Take the word lists, frequencies and openers from `cache`
+    fn use_cache(&mut self, cache: WordListCache) -> Result<()> {
+        self.generation += 1;
+        self.answer_words = Self::convert_to_words(cache.answer_words)?;
+        self.guess_words = Self::convert_to_words(cache.guess_words)?;
+        self.pruning_report = self
+            .config
+            .pruning
+            .apply(&mut self.answer_words, Some(&cache.frequency));
+        self.frequency = Some(cache.frequency);
+        self.first_guesses = cache.first_guesses;
+        self.sources = cache.sources;
+        self.morphology = cache.morphology;
+        self.last_updated = Some(cache.last_updated);
         Ok(())
     }
 
@@ -363,19 +1195,20 @@ impl FileWordListProvider {
     /// Force refresh the word lists cache from remote sources.
     /// When `force` is false, it will skip if the cache is still fresh.
     pub async fn refresh_cache(&mut self, force: bool) -> Result<(usize, usize)> {
+        if let Some(tag) = &self.snapshot_tag {
+            log::info!("Word lists pinned to snapshot '{}'; skipping refresh", tag);
+            return Ok((self.answer_words.len(), self.guess_words.len()));
+        }
     let fresh = self.load_from_cache().await.is_ok();
         if fresh && !force {
             log::info!("Cache is fresh; skipping refresh");
             let cache = self.load_from_cache().await?;
-            self.answer_words = Self::convert_to_words(cache.answer_words)?;
-            self.guess_words = Self::convert_to_words(cache.guess_words)?;
+            self.use_cache(cache)?;
             return Ok((self.answer_words.len(), self.guess_words.len()));
         }
 
-        let (answer_strings, guess_strings) = self.download_words().await?;
-        self.save_to_cache(&answer_strings, &guess_strings).await?;
-        self.answer_words = Self::convert_to_words(answer_strings)?;
-        self.guess_words = Self::convert_to_words(guess_strings)?;
+        let cache = self.download_to_cache(force).await?;
+        self.use_cache(cache)?;
         Ok((self.answer_words.len(), self.guess_words.len()))
     }
 }
@@ -383,11 +1216,9 @@ impl FileWordListProvider {
 impl FileWordListProvider {
     /// Ensure cache freshness (< 24h)
     fn ensure_fresh(&self, cache: &WordListCache) -> Result<()> {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|_| DataError::InvalidFormat("System time error".to_string()))?
-            .as_secs();
-        if now - cache.last_updated > 24 * 60 * 60 {
+        let now = self.clock.unix_secs();
+        // A cache stamped ahead of the clock counts as fresh
+        if now.saturating_sub(cache.last_updated) > 24 * 60 * 60 {
             return Err(DataError::InvalidFormat("Cache too old".to_string()).into());
         }
         Ok(())
@@ -395,41 +1226,58 @@ impl FileWordListProvider {
 
     /// Read the compact Wordle List Format (WLF1), verifying freshness
     async fn read_wlf(&self, path: &str) -> Result<WordListCache> {
-        let bytes = tokio::fs::read(path).await.map_err(DataError::from)?;
-        let cache = Self::parse_wlf(&bytes)?;
+        let cache = self.read_wlf_unchecked(path).await?;
         self.ensure_fresh(&cache)?;
         Ok(cache)
     }
 
     /// Read WLF without freshness check
+    ///
+    /// A file that doesn't parse is read again a few times: a writer that predates atomic
+    /// saves may still be filling it in.
     async fn read_wlf_unchecked(&self, path: &str) -> Result<WordListCache> {
-        let bytes = tokio::fs::read(path).await.map_err(DataError::from)?;
-        let cache = Self::parse_wlf(&bytes)?;
-        Ok(cache)
+        let mut attempt = 1;
+        loop {
+            let bytes = tokio::fs::read(path).await.map_err(DataError::from)?;
+            match Self::parse_wlf(&bytes) {
+                Ok(cache) => return Ok(cache),
+                Err(e) if attempt < WLF_READ_ATTEMPTS => {
+                    log::debug!("Rereading {} after: {}", path, e);
+                    tokio::time::sleep(WLF_READ_RETRY_DELAY).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
     }
 
-    /// Write the compact WLF file (WLF3)
+    /// Write the compact WLF file (WLF6)
     async fn write_wlf(&self, path: &str, cache: &WordListCache) -> Result<()> {
-        // Format (WLF3):
-        // magic: b"WLF3" (4)
+        // Format (WLF6):
+        // magic: b"WLF6" (4)
         // last_updated: u64 LE (8)
         // answers_count: u32 LE (4)
         // guesses_count: u32 LE (4)
         // answers words: answers_count * 5 bytes (ASCII a-z)
         // guesses words: guesses_count * 5 bytes
-        // letter_counts: 26 * u32
+        // letter_counts: 26 * u32 (one per letter of Word::ALPHABET)
         // position_counts: 5 * 26 * u32
         // bigram_counts: 4 * 26 * 26 * u32
+        // first_guess_count: u8, then per entry: name_len u8, name (ASCII), word (5)
+        // source_count: u16, then per source: list u8 (0 answers, 1 guesses),
+        //   url_len u16, url, license_len u16, license (empty if unknown),
+        //   downloaded_at u64, words u32
+        // tagged_count: u32, then per tagged word: word (5), tags u8 (MorphologyTags bits)
         let mut buf = Vec::with_capacity(
             4 + 8
                 + 4
                 + 4
                 + (cache.answer_words.len() + cache.guess_words.len()) * 5
-                + 26 * 4
-                + 5 * 26 * 4
-                + 4 * 26 * 26 * 4,
+                + LETTER_COUNT * 4
+                + 5 * LETTER_COUNT * 4
+                + 4 * LETTER_COUNT * LETTER_COUNT * 4,
         );
-        buf.extend_from_slice(b"WLF3");
+        buf.extend_from_slice(b"WLF6");
         buf.extend_from_slice(&cache.last_updated.to_le_bytes());
         let a = cache.answer_words.len() as u32;
         let g = cache.guess_words.len() as u32;
@@ -442,27 +1290,72 @@ impl FileWordListProvider {
             Self::push_word5(&mut buf, w)?;
         }
         // frequency
-        for i in 0..26 {
+        for i in 0..LETTER_COUNT {
             buf.extend_from_slice(&cache.frequency.letter_counts[i].to_le_bytes());
         }
         for pos in 0..5 {
-            for i in 0..26 {
+            for i in 0..LETTER_COUNT {
                 buf.extend_from_slice(&cache.frequency.position_counts[pos][i].to_le_bytes());
             }
         }
         for pair in 0..4 {
-            for a in 0..26 {
-                for b in 0..26 {
+            for a in 0..LETTER_COUNT {
+                for b in 0..LETTER_COUNT {
                     buf.extend_from_slice(&cache.frequency.bigram_counts[pair][a][b].to_le_bytes());
                 }
             }
         }
-        tokio::fs::write(path, buf).await.map_err(DataError::from)?;
+        let entries = u8::try_from(cache.first_guesses.len())
+            .map_err(|_| DataError::InvalidFormat("Too many first guesses".to_string()))?;
+        buf.push(entries);
+        for (strategy, word) in &cache.first_guesses {
+            let name_len = u8::try_from(strategy.len())
+                .ok()
+                .filter(|_| strategy.is_ascii())
+                .ok_or_else(|| {
+                    DataError::InvalidFormat(format!("Invalid strategy name: {}", strategy))
+                })?;
+            buf.push(name_len);
+            buf.extend_from_slice(strategy.as_bytes());
+            Self::push_word5(&mut buf, word)?;
+        }
+        let sources = u16::try_from(cache.sources.len())
+            .map_err(|_| DataError::InvalidFormat("Too many sources".to_string()))?;
+        buf.extend_from_slice(&sources.to_le_bytes());
+        for source in &cache.sources {
+            buf.push(match source.list {
+                ListKind::Answers => 0,
+                ListKind::Guesses => 1,
+            });
+            Self::push_text(&mut buf, &source.url)?;
+            Self::push_text(&mut buf, source.license.as_deref().unwrap_or_default())?;
+            buf.extend_from_slice(&source.downloaded_at.to_le_bytes());
+            buf.extend_from_slice(&(source.words as u32).to_le_bytes());
+        }
+        let tagged: Vec<_> = cache
+            .morphology
+            .iter()
+            .filter(|(_, t)| !t.is_empty())
+            .collect();
+        buf.extend_from_slice(&(tagged.len() as u32).to_le_bytes());
+        for (word, tags) in tagged {
+            Self::push_word5(&mut buf, word)?;
+            buf.push(tags.bits());
+        }
+        write_atomic(path, buf).await
+    }
+
+    /// Write `text` with a u16 length prefix
+    fn push_text(buf: &mut Vec<u8>, text: &str) -> Result<()> {
+        let len = u16::try_from(text.len())
+            .map_err(|_| DataError::InvalidFormat(format!("Text too long for WLF: {}", text)))?;
+        buf.extend_from_slice(&len.to_le_bytes());
+        buf.extend_from_slice(text.as_bytes());
         Ok(())
     }
 
     fn push_word5(buf: &mut Vec<u8>, w: &str) -> Result<()> {
-        if w.len() != 5 || !w.chars().all(|c| c.is_ascii_lowercase()) {
+        if w.len() != 5 || !Word::ALPHABET.spells(w) {
             return Err(DataError::InvalidFormat(format!("Invalid word in cache: {}", w)).into());
         }
         for b in w.as_bytes() {
@@ -478,7 +1371,10 @@ impl FileWordListProvider {
         let magic = &bytes[0..4];
         let is_v1 = magic == b"WLF1";
         let is_v2 = magic == b"WLF2";
-        let is_v3 = magic == b"WLF3";
+        let is_v6 = magic == b"WLF6";
+        let is_v5 = magic == b"WLF5" || is_v6;
+        let is_v4 = magic == b"WLF4" || is_v5;
+        let is_v3 = magic == b"WLF3" || is_v4;
         if !is_v1 && !is_v2 && !is_v3 {
             return Err(DataError::InvalidFormat("WLF magic mismatch".to_string()).into());
         }
@@ -500,12 +1396,13 @@ impl FileWordListProvider {
         let g = read_u32(bytes, &mut off) as usize;
         let mut needed = 4 + 8 + 4 + 4 + (a + g) * 5;
         if is_v2 || is_v3 {
-            needed += 26 * 4 + 5 * 26 * 4;
+            needed += LETTER_COUNT * 4 + 5 * LETTER_COUNT * 4;
             if is_v3 {
-                needed += 4 * 26 * 26 * 4;
+                needed += 4 * LETTER_COUNT * LETTER_COUNT * 4;
             }
         }
-        if bytes.len() != needed {
+        // WLF4+ end in variable-length tables
+        if bytes.len() != needed && !(is_v4 && bytes.len() > needed) {
             return Err(DataError::InvalidFormat("WLF size mismatch".to_string()).into());
         }
         let mut answer_words = Vec::with_capacity(a);
@@ -514,7 +1411,7 @@ impl FileWordListProvider {
             let end = start + 5;
             let s = std::str::from_utf8(&bytes[start..end])
                 .map_err(|e| DataError::InvalidFormat(format!("UTF-8 error: {}", e)))?;
-            if !s.chars().all(|c| c.is_ascii_lowercase()) {
+            if !Word::ALPHABET.spells(s) {
                 return Err(DataError::InvalidFormat("Non-lowercase word".to_string()).into());
             }
             answer_words.push(s.to_string());
@@ -526,7 +1423,7 @@ impl FileWordListProvider {
             let end = start + 5;
             let s = std::str::from_utf8(&bytes[start..end])
                 .map_err(|e| DataError::InvalidFormat(format!("UTF-8 error: {}", e)))?;
-            if !s.chars().all(|c| c.is_ascii_lowercase()) {
+            if !Word::ALPHABET.spells(s) {
                 return Err(DataError::InvalidFormat("Non-lowercase word".to_string()).into());
             }
             guess_words.push(s.to_string());
@@ -534,14 +1431,14 @@ impl FileWordListProvider {
         let mut frequency = FrequencyData::default();
         if is_v2 || is_v3 {
             let mut p = base + g * 5;
-            for i in 0..26 {
+            for i in 0..LETTER_COUNT {
                 let mut arr = [0u8; 4];
                 arr.copy_from_slice(&bytes[p..p + 4]);
                 p += 4;
                 frequency.letter_counts[i] = u32::from_le_bytes(arr);
             }
             for pos in 0..5 {
-                for i in 0..26 {
+                for i in 0..LETTER_COUNT {
                     let mut arr = [0u8; 4];
                     arr.copy_from_slice(&bytes[p..p + 4]);
                     p += 4;
@@ -550,8 +1447,8 @@ impl FileWordListProvider {
             }
             if is_v3 {
                 for pair in 0..4 {
-                    for a in 0..26 {
-                        for b in 0..26 {
+                    for a in 0..LETTER_COUNT {
+                        for b in 0..LETTER_COUNT {
                             let mut arr = [0u8; 4];
                             arr.copy_from_slice(&bytes[p..p + 4]);
                             p += 4;
@@ -561,22 +1458,109 @@ impl FileWordListProvider {
                 }
             }
         }
+        let mut tail = WlfTail(&bytes[needed..]);
+        let first_guesses = if is_v4 {
+            Self::parse_first_guesses(&mut tail)?
+        } else {
+            BTreeMap::new()
+        };
+        let sources = if is_v5 {
+            Self::parse_sources(&mut tail)?
+        } else {
+            Vec::new()
+        };
+        // Older files were written before tagging; tag their words now
+        let morphology = if is_v6 {
+            Self::parse_morphology(&mut tail)?
+        } else {
+            tag_words(&answer_words, &guess_words)
+        };
+        if !tail.0.is_empty() {
+            return Err(DataError::InvalidFormat("WLF size mismatch".to_string()).into());
+        }
         Ok(WordListCache {
             answer_words,
             guess_words,
             last_updated,
             frequency,
+            first_guesses,
+            sources,
+            morphology,
         })
     }
 
+    /// Parse the WLF4 first-guess table
+    fn parse_first_guesses(tail: &mut WlfTail) -> Result<BTreeMap<String, String>> {
+        let count = tail.u8()?;
+        let mut first_guesses = BTreeMap::new();
+        for _ in 0..count {
+            let name_len = tail.u8()? as usize;
+            let name = tail.text(name_len)?;
+            let word = tail.text(5)?;
+            if !Word::ALPHABET.spells(&word) {
+                return Err(DataError::InvalidFormat("Non-lowercase word".to_string()).into());
+            }
+            first_guesses.insert(name, word);
+        }
+        Ok(first_guesses)
+    }
+
+    /// Parse the WLF5 source table
+    fn parse_sources(tail: &mut WlfTail) -> Result<Vec<SourceMetadata>> {
+        let count = tail.u16()?;
+        let mut sources = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let list = match tail.u8()? {
+                0 => ListKind::Answers,
+                1 => ListKind::Guesses,
+                other => {
+                    return Err(
+                        DataError::InvalidFormat(format!("Unknown WLF list kind {}", other)).into()
+                    )
+                }
+            };
+            let url_len = tail.u16()? as usize;
+            let url = tail.text(url_len)?;
+            let license_len = tail.u16()? as usize;
+            let license = Some(tail.text(license_len)?).filter(|l| !l.is_empty());
+            let downloaded_at = tail.u64()?;
+            let words = tail.u32()? as usize;
+            sources.push(SourceMetadata {
+                list,
+                url,
+                license,
+                downloaded_at,
+                words,
+            });
+        }
+        Ok(sources)
+    }
+
+    /// Parse the WLF6 table of tagged words
+    fn parse_morphology(tail: &mut WlfTail) -> Result<BTreeMap<String, MorphologyTags>> {
+        let count = tail.u32()?;
+        let mut morphology = BTreeMap::new();
+        for _ in 0..count {
+            let word = tail.text(5)?;
+            if !Word::ALPHABET.spells(&word) {
+                return Err(DataError::InvalidFormat("Non-lowercase word".to_string()).into());
+            }
+            let tags = MorphologyTags::from_bits(tail.u8()?);
+            if !tags.is_empty() {
+                morphology.insert(word, tags);
+            }
+        }
+        Ok(morphology)
+    }
+
     fn compute_frequency(words: &[String]) -> FrequencyData {
         let mut freq = FrequencyData::default();
         for w in words {
+            let alphabet = Word::ALPHABET;
             let bytes = w.as_bytes();
-            let mut seen = [false; 26];
+            let mut seen = [false; LETTER_COUNT];
             for (pos, &b) in bytes.iter().enumerate().take(5) {
-                let idx = (b - b'a') as usize;
-                if idx < 26 {
+                if let Some(idx) = alphabet.index(b) {
                     freq.position_counts[pos][idx] += 1;
                     if !seen[idx] {
                         freq.letter_counts[idx] += 1;
@@ -586,9 +1570,9 @@ impl FileWordListProvider {
             }
             // bigrams: pairs 0-1,1-2,2-3,3-4
             for pair in 0..4 {
-                let a = (bytes[pair] - b'a') as usize;
-                let b = (bytes[pair + 1] - b'a') as usize;
-                if a < 26 && b < 26 {
+                let a = alphabet.index(bytes[pair]);
+                let b = alphabet.index(bytes[pair + 1]);
+                if let (Some(a), Some(b)) = (a, b) {
                     freq.bigram_counts[pair][a][b] += 1;
                 }
             }
@@ -597,6 +1581,23 @@ impl FileWordListProvider {
     }
 }
 
+impl FileWordListProvider {
+    /// Sort and dedup the loaded lists for binary search; returns their union
+    fn sorted_word_union(&mut self) -> Vec<Word> {
+        self.answer_words.sort();
+        self.answer_words.dedup();
+        self.guess_words.sort();
+        self.guess_words.dedup();
+
+        let mut all_words = Vec::with_capacity(self.answer_words.len() + self.guess_words.len());
+        all_words.extend(self.answer_words.iter().cloned());
+        all_words.extend(self.guess_words.iter().cloned());
+        all_words.sort();
+        all_words.dedup();
+        all_words
+    }
+}
+
 impl Default for FileWordListProvider {
     fn default() -> Self {
         Self::new()
@@ -606,57 +1607,46 @@ impl Default for FileWordListProvider {
 #[async_trait]
 impl WordListProvider for FileWordListProvider {
     async fn load_words(&mut self) -> Result<Vec<Word>> {
+        if let Some(tag) = self.snapshot_tag.clone() {
+            let cache = self.read_snapshot(&tag).await?;
+            log::info!("Loaded word lists from snapshot '{}'", tag);
+            self.use_cache(cache)?;
+            return Ok(self.sorted_word_union());
+        }
+
         // Prefer fast local binary cache without freshness check (bundled file)
         match self.load_cache_unchecked().await {
             Ok(cache) => {
+                crate::core::metrics::global().record_cache_lookup(true);
                 log::info!("Loaded word lists from local binary cache (unchecked)");
-                self.answer_words = Self::convert_to_words(cache.answer_words)?;
-                self.guess_words = Self::convert_to_words(cache.guess_words)?;
-                self.frequency = Some(cache.frequency);
+                self.use_cache(cache)?;
             }
             Err(_) => {
-                // Try fresh-checked cache next
-                if let Ok(cache) = self.load_from_cache().await {
+                // Try fresh-checked cache next, then a warm cache shipped with the release
+                let cached = match self.load_from_cache().await {
+                    Ok(cache) => Ok(cache),
+                    Err(_) => self.install_warm_cache().await,
+                };
+                if let Ok(cache) = cached {
+                    crate::core::metrics::global().record_cache_lookup(true);
                     log::info!("Loaded word lists from cache (fresh)");
-                    self.answer_words = Self::convert_to_words(cache.answer_words)?;
-                    self.guess_words = Self::convert_to_words(cache.guess_words)?;
-                    self.frequency = Some(cache.frequency);
+                    self.use_cache(cache)?;
                 } else {
                     // Last resort: network download
+                    crate::core::metrics::global().record_cache_lookup(false);
                     log::info!("Downloading fresh word lists");
-                    match self.download_words().await {
-                        Ok((answer_strings, guess_strings)) => {
-                            self.answer_words = Self::convert_to_words(answer_strings.clone())?;
-                            self.guess_words = Self::convert_to_words(guess_strings.clone())?;
-                            // Save to cache
-                            self.save_to_cache(&answer_strings, &guess_strings).await?;
-                            // After save, reload frequency from written cache
-                            if let Ok(cache) = self.load_cache_unchecked().await {
-                                self.frequency = Some(cache.frequency);
-                            }
-                        }
+                    match self.download_to_cache(false).await {
+                        Ok(cache) => self.use_cache(cache)?,
                         Err(e) => {
                             log::warn!("Download failed: {}. No local cache available.", e);
-                            return Err(e.into());
+                            return Err(e);
                         }
                     }
                 }
             }
         }
 
-        // Ensure sorted unique internal lists for fast binary_search
-        self.answer_words.sort();
-        self.answer_words.dedup();
-        self.guess_words.sort();
-        self.guess_words.dedup();
-
-        let mut all_words = Vec::with_capacity(self.answer_words.len() + self.guess_words.len());
-        all_words.extend(self.answer_words.iter().cloned());
-        all_words.extend(self.guess_words.iter().cloned());
-        all_words.sort();
-        all_words.dedup();
-
-        Ok(all_words)
+        Ok(self.sorted_word_union())
     }
 
     fn get_answer_words(&self) -> &[Word] {
@@ -677,6 +1667,17 @@ impl WordListProvider for FileWordListProvider {
         self.answer_words.binary_search(word).is_ok()
     }
 
+    fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn morphology(&self, word: &Word) -> MorphologyTags {
+        self.morphology
+            .get(word.as_str())
+            .copied()
+            .unwrap_or_default()
+    }
+
     async fn refresh(&mut self, force: bool) -> Result<(usize, usize)> {
         self.refresh_cache(force).await
     }
@@ -694,4 +1695,271 @@ mod tests {
         assert_eq!(provider.answer_words.len(), 0);
         assert_eq!(provider.guess_words.len(), 0);
     }
+
+    #[tokio::test]
+    async fn test_snapshot_freeze_and_pin() {
+        assert!(FileWordListProvider::snapshot_path("../v1").is_err());
+        assert!(FileWordListProvider::snapshot_path("").is_err());
+        assert!(FileWordListProvider::snapshot_path("v1.2-beta_3").is_ok());
+
+        let tag = format!("test-snapshot-{}", std::process::id());
+        let mut pinned = FileWordListProvider::new().with_snapshot(tag.clone());
+        assert!(pinned.load_words().await.is_err());
+
+        let mut source = FileWordListProvider::new();
+        source.answer_words = vec![Word::from_str("crane").unwrap()];
+        source.guess_words = vec![Word::from_str("slate").unwrap()];
+        let path = source.freeze(&tag).await.unwrap();
+        // Tags are write-once
+        assert!(source.freeze(&tag).await.is_err());
+        assert!(FileWordListProvider::list_snapshots().contains(&tag));
+
+        let words = pinned.load_words().await.unwrap();
+        assert_eq!(words.len(), 2);
+        assert_eq!(pinned.get_answer_words(), source.answer_words.as_slice());
+        // Refreshing a pinned provider keeps the snapshot
+        assert_eq!(pinned.refresh_cache(true).await.unwrap(), (1, 1));
+
+        std::fs::remove_file(path).unwrap();
+        let _ = std::fs::remove_dir(FileWordListProvider::snapshot_dir());
+    }
+
+    #[test]
+    fn test_parse_word_list_rejects_garbage() {
+        let dictionary: String = (0..MIN_SOURCE_WORDS)
+            .map(|i| format!("ab{}cd\nlonger\n", (b'a' + (i % 26) as u8) as char))
+            .collect();
+        assert_eq!(parse_word_list(&dictionary).unwrap().len(), MIN_SOURCE_WORDS);
+
+        let html = format!("<!DOCTYPE html>\n<html>{}</html>", dictionary);
+        assert!(parse_word_list(&html).is_err());
+        assert!(parse_word_list("crane\nslate\n").is_err());
+        let mostly_other = format!("{}{}", dictionary, "elongated\n".repeat(20_000));
+        let err = parse_word_list(&mostly_other).unwrap_err().to_string();
+        assert!(err.contains("five-letter words"), "{}", err);
+    }
+
+    #[test]
+    fn test_retry_backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy {
+            jitter: 0.0,
+            ..RetryPolicy::default()
+        };
+        let ms = |retry| policy.backoff(retry, 0.5).as_millis();
+        assert_eq!([ms(1), ms(2), ms(3)], [500, 1_000, 2_000]);
+        assert_eq!(ms(10), 8_000);
+
+        let jittered = RetryPolicy::default();
+        assert_eq!(jittered.backoff(1, 0.0).as_millis(), 400);
+        assert_eq!(jittered.backoff(1, 1.0).as_millis(), 600);
+
+        // Older word_sources.json files have no retry section
+        let config: WordListConfig =
+            serde_json::from_str(r#"{"answers": [], "guesses": [], "retry": {"attempts": 2}}"#)
+                .unwrap();
+        assert_eq!(config.retry.attempts, 2);
+        assert_eq!(config.retry.timeout_secs, 20);
+    }
+
+    #[test]
+    fn test_answer_pruning_heuristics_are_independent() {
+        use crate::core::morphology::COMMON_PROPER_NOUNS;
+        assert!(COMMON_PROPER_NOUNS.windows(2).all(|w| w[0] < w[1]));
+        let words = |list: &[&str]| -> Vec<Word> {
+            list.iter().map(|w| Word::from_str(w).unwrap()).collect()
+        };
+        let all = words(&["abyss", "bonus", "crane", "cubes", "greed", "paris", "taped", "texts"]);
+        let mut answers = all.clone();
+        let report = AnswerPruning::default().apply(&mut answers, None);
+        assert_eq!((answers.len(), report.total()), (all.len(), 0));
+
+        let pruning = AnswerPruning {
+            plurals: true,
+            proper_nouns: true,
+            ..AnswerPruning::default()
+        };
+        let mut answers = all.clone();
+        let report = pruning.apply(&mut answers, None);
+        assert_eq!((report.plurals, report.past_tense, report.proper_nouns), (2, 0, 1));
+        assert_eq!(answers, words(&["abyss", "bonus", "crane", "greed", "taped"]));
+
+        let mut answers = all.clone();
+        let pruning = AnswerPruning {
+            past_tense: true,
+            frequency_floor: 0.5,
+            ..AnswerPruning::default()
+        };
+        let frequency = FileWordListProvider::compute_frequency(
+            &["crane", "crate", "trace", "grace"].map(String::from),
+        );
+        let report = pruning.apply(&mut answers, Some(&frequency));
+        assert_eq!((report.past_tense, report.below_floor), (1, 4));
+        assert!(answers.contains(&Word::from_str("crane").unwrap()));
+    }
+
+    #[cfg(feature = "net")]
+    #[tokio::test]
+    async fn test_unreachable_source_is_retried() {
+        let provider = FileWordListProvider::with_config(WordListConfig {
+            retry: RetryPolicy {
+                attempts: 2,
+                initial_backoff_ms: 0,
+                ..RetryPolicy::default()
+            },
+            ..WordListConfig::default()
+        });
+        let fetcher = crate::infrastructure::http::ReqwestFetcher::new(reqwest::Client::new());
+        // Port 1 on localhost refuses connections immediately
+        let err = provider
+            .fetch_with_retries(&fetcher, ListKind::Answers, "http://127.0.0.1:1/words.txt")
+            .await
+            .unwrap_err();
+        assert!(err.ends_with("after 2 attempts"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_download_retries_then_falls_back_to_a_mirror() {
+        use crate::infrastructure::http::MockFetcher;
+
+        let list: String = (0..MIN_SOURCE_WORDS)
+            .map(|i| format!("ab{}cd\n", (b'a' + (i % 26) as u8) as char))
+            .collect();
+        let (answers, mirror, guesses) = ("https://a/words", "https://m/words", "https://g/words");
+        let fetcher = Arc::new(
+            MockFetcher::new()
+                .fail(answers, "HTTP error: 503", true)
+                .respond(mirror, "<html>captive portal</html>")
+                .respond(mirror, list.as_str())
+                .respond(guesses, list.replace("ab", "cd")),
+        );
+        let provider = FileWordListProvider::with_config(WordListConfig {
+            answers: vec![answers.to_string()],
+            guesses: vec![guesses.to_string()],
+            mirrors: BTreeMap::from([(answers.to_string(), vec![mirror.to_string()])]),
+            retry: RetryPolicy {
+                attempts: 3,
+                initial_backoff_ms: 0,
+                ..RetryPolicy::default()
+            },
+            ..WordListConfig::default()
+        })
+        .with_fetcher(fetcher.clone());
+
+        // An HTML page is not retried: it won't become a word list on the next try
+        let err = provider
+            .fetch_with_retries(fetcher.as_ref(), ListKind::Answers, mirror)
+            .await
+            .unwrap_err();
+        assert!(err.contains("HTML"), "{}", err);
+
+        let downloaded = provider.download_words().await.unwrap();
+        assert_eq!(
+            fetcher.requests(),
+            [mirror, answers, answers, answers, mirror, guesses]
+        );
+        let report = &downloaded.reports[0];
+        assert_eq!(report.used, mirror);
+        assert!(report.failures[0].1.ends_with("after 3 attempts"));
+        // Answers are always valid guesses
+        let answer_count = downloaded.answer_words.len();
+        assert_eq!(downloaded.guess_words.len(), 2 * answer_count);
+    }
+
+    #[test]
+    fn test_cache_goes_stale_after_a_day() {
+        use crate::core::clock::ManualClock;
+
+        let clock = Arc::new(ManualClock::at(1_000_000));
+        let provider = FileWordListProvider::new().with_clock(clock.clone());
+        let cache = WordListCache {
+            answer_words: Vec::new(),
+            guess_words: Vec::new(),
+            last_updated: clock.unix_secs(),
+            frequency: FrequencyData::default(),
+            first_guesses: BTreeMap::new(),
+            sources: Vec::new(),
+            morphology: BTreeMap::new(),
+        };
+        assert!(provider.ensure_fresh(&cache).is_ok());
+        clock.advance(std::time::Duration::from_secs(24 * 60 * 60 + 1));
+        assert!(provider.ensure_fresh(&cache).is_err());
+        // Written by a machine whose clock runs ahead
+        let ahead = WordListCache {
+            last_updated: clock.unix_secs() + 60,
+            ..cache
+        };
+        assert!(provider.ensure_fresh(&ahead).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_warm_cache_keeps_first_guesses() {
+        let path = std::env::temp_dir().join(format!("wordle_warm_{}.wlf", std::process::id()));
+        let mut source = FileWordListProvider::new();
+        source.answer_words = vec![Word::from_str("crane").unwrap()];
+        source.guess_words = vec![Word::from_str("slate").unwrap()];
+        source.sources = vec![SourceMetadata {
+            list: ListKind::Guesses,
+            url: "https://example.com/words.txt".to_string(),
+            license: Some("MIT".to_string()),
+            downloaded_at: 1_700_000_000,
+            words: 2,
+        }];
+        let openers = BTreeMap::from([
+            ("entropy".to_string(), "slate".to_string()),
+            ("hybrid".to_string(), "crane".to_string()),
+        ]);
+        source.write_warm_cache(&path, openers.clone()).await.unwrap();
+
+        let cache = FileWordListProvider::parse_wlf(&std::fs::read(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(cache.answer_words, vec!["crane"]);
+        assert_eq!(cache.first_guesses, openers);
+        assert_eq!(cache.sources, source.sources);
+
+        // Older caches have no table; a truncated table is rejected
+        let mut v3 = b"WLF3".to_vec();
+        v3.extend_from_slice(&0u64.to_le_bytes());
+        v3.extend_from_slice(&[0; 8]);
+        v3.resize(20 + (26 + 5 * 26 + 4 * 26 * 26) * 4, 0);
+        assert!(FileWordListProvider::parse_wlf(&v3).unwrap().first_guesses.is_empty());
+        let mut v4 = v3.clone();
+        v4[3] = b'4';
+        v4.extend_from_slice(&[1, 7]);
+        assert!(FileWordListProvider::parse_wlf(&v4).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_wlf_stores_morphology_tags() {
+        let path = std::env::temp_dir().join(format!("wordle_tags_{}.wlf", std::process::id()));
+        let mut source = FileWordListProvider::new();
+        source.answer_words = ["cares", "crane", "doing"]
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+        source.guess_words = vec![Word::from_str("fried").unwrap()];
+        source
+            .write_warm_cache(&path, BTreeMap::new())
+            .await
+            .unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let cache = FileWordListProvider::parse_wlf(&bytes).unwrap();
+        let expected = BTreeMap::from([
+            ("cares".to_string(), MorphologyTags::PLURAL),
+            ("doing".to_string(), MorphologyTags::GERUND),
+            ("fried".to_string(), MorphologyTags::PAST_TENSE),
+        ]);
+        assert_eq!(cache.morphology, expected);
+        let mut provider = FileWordListProvider::new();
+        provider.use_cache(cache).unwrap();
+        let cares = Word::from_str("cares").unwrap();
+        assert_eq!(provider.morphology(&cares), MorphologyTags::PLURAL);
+
+        // A WLF5 file has no tag table; its words are tagged when read
+        let mut v5 = bytes[..bytes.len() - 4 - 3 * 6].to_vec();
+        v5[3] = b'5';
+        let cache = FileWordListProvider::parse_wlf(&v5).unwrap();
+        assert_eq!(cache.morphology, expected);
+    }
 }
@@ -15,9 +15,21 @@ pub mod application;
 /// Presentation layer - user interfaces (TUI, etc.)
 pub mod presentation;
 
-// Re-export main types for easy access
+/// Semver-stable solver facade for library consumers
+pub mod stable;
+
+/// Curated set of commonly used types
+pub mod prelude;
+
+// Re-export main types for easy access. Each name is listed explicitly so the root stays
+// free of duplicates: the container's word list settings live at
+// `core::container::WordListConfig`, the download sources at `infrastructure::WordListConfig`.
 pub use core::error::{Result, WordleError};
-pub use core::container::{Container, DependencyConfig, StrategyType, WordListConfig};
+pub use core::metrics::MetricsRegistry;
+pub use core::container::{Container, DependencyConfig, StrategyType};
+
+// Token for cancelling long suggestions, see `WordleApplicationService::suggest_with_cancel`
+pub use tokio_util::sync::CancellationToken;
 
 // Primary interfaces
 pub use core::traits::{
@@ -28,8 +40,9 @@ pub use core::traits::{
 
 // Core types
 pub use core::types::{
-    Feedback as CoreFeedback, FeedbackPattern, GameResult as CoreGameResult, Guess,
-    SolverStatistics as CoreSolverStatistics, Word,
+    Disambiguator, Feedback as CoreFeedback, FeedbackPattern, GameResult as CoreGameResult, Guess,
+    PrefixScore, RankedCandidate, SolverStatistics as CoreSolverStatistics, Suggestion,
+    SuggestionKind, SuggestionQuality, SuggestionSet, TurnEntropy, Word,
 };
 
 // Domain implementations
@@ -40,7 +53,7 @@ pub use domain::{
 // Infrastructure implementations
 pub use infrastructure::{
     CachedEntropyCalculator, EntropyBasedStrategy, FileWordListProvider, FrequencyBasedStrategy,
-    HybridStrategy, SimpleEntropyCalculator,
+    HybridStrategy, SimpleEntropyCalculator, StatisticsStore,
 };
 
 // Application layer
@@ -50,4 +63,5 @@ pub use application::{
 };
 
 // Presentation layer
-pub use presentation::{run_tui, TuiApp};
+#[cfg(feature = "tui")]
+pub use presentation::{run_tui, run_tui_archive, run_tui_as, run_tui_pack, TuiApp};
@@ -1,7 +1,40 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, IsTerminal};
+use std::time::{Duration, Instant};
 use wordle::{
-    Command, CommandExecutor, CommandResult, Container, Word, core::types::FeedbackPattern, run_tui,
+    Command, CommandExecutor, CommandResult, Container, StatisticsStore, Word,
+    WordleApplicationService, WordleError,
+    application::{
+        AccessGuard, BenchmarkGoal, BenchmarkReport, BenchmarkSummary, CommandScript,
+        DEFAULT_DETERMINISM_RUNS, DEFAULT_SESSION_TTL, DecisionTree, OpenerEstimate,
+        OpenerSearchConfig, PackConstraints, PackProgress, PipeSessions, PuzzlePack, RandomTargets,
+        SessionFile, SimulatedGame, TuningMetric, TuningTrial, audit_filter, check_determinism,
+        default_thread_counts, generate_targets, parse_guess_batch, parse_guess_entry, run_pipe,
+    },
+    core::{
+        container::{DependencyConfig, StrategyTuning, StrategyType},
+        error::SolverError,
+        traits::{ConstraintFilter, DataPersistence, WordListProvider},
+        types::{FeedbackPattern, GameResult, Guess},
+    },
+    domain::{
+        DefaultConstraintFilter, Difficulty, PAIR_SHORTLIST, ShareGrid, WordGraph, infer_guesses,
+    },
+    infrastructure::{
+        ARCHIVE_ENV, ArchiveResult, CURRENT_VERSION, ExportFormat, FileWordListProvider,
+        GameRecord, HttpConfig, NytStatistics, OPENER_MIN_GAMES, OpenerStats, PlayerStats,
+        PuzzleArchive, ShareFormat, UserSettings, WARM_CACHE_ENV, WORDLIST_TAG_ENV,
+        fetch_latest_release, format_turn_time, install_release, releases_url, resolve_player,
+        resolve_wordlist_tag,
+    },
+    presentation::{
+        chart,
+        spoilers::{REVEAL_ENV, RevealPolicy, SpoilerFeature, SpoilerGuard},
+        tui::{missing_terminal, run_benchmark_tui},
+    },
+    run_tui_archive, run_tui_as, run_tui_pack,
 };
 
 #[derive(Parser)]
@@ -9,6 +42,23 @@ use wordle::{
 #[command(about = "Modern AI Wordle Solver with Clean Architecture")]
 #[command(version = "1.0.0")]
 struct Cli {
+    /// Player name for statistics (defaults to $WORDLE_PLAYER or "default")
+    #[arg(short, long, global = true)]
+    player: Option<String>,
+    /// Load word lists from a frozen snapshot (defaults to $WORDLE_WORDLIST_TAG)
+    #[arg(long, global = true, value_name = "TAG")]
+    wordlist_tag: Option<String>,
+    /// Show hidden answers in full instead of masked as C___E (or set $WORDLE_REVEAL=1)
+    #[arg(long, global = true)]
+    reveal: bool,
+    /// Write the run's metrics in Prometheus text format to PATH on exit
+    /// (e.g. for node_exporter's textfile collector)
+    #[arg(long, global = true, value_name = "PATH")]
+    metrics_file: Option<std::path::PathBuf>,
+    /// Keep the sessions of interactive's line protocol (no terminal) in PATH: they
+    /// are restored from it on start and saved back on exit
+    #[arg(long, global = true, value_name = "PATH")]
+    sessions_file: Option<std::path::PathBuf>,
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -17,22 +67,185 @@ struct Cli {
 enum Commands {
     /// Run the interactive TUI mode
     Interactive,
+    /// Play a past puzzle by its number in the TUI; the answer stays hidden until solved
+    Archive {
+        /// Puzzle number (0 is the first puzzle)
+        #[arg(short, long)]
+        number: u32,
+    },
+    /// Today's puzzle number, and with --forecast how hard its answer is, without
+    /// revealing it (unless --reveal is given)
+    Daily {
+        /// Puzzle number instead of today's
+        #[arg(short, long)]
+        number: Option<u32>,
+        /// Forecast this answer instead of a puzzle from the archive
+        #[arg(short, long, conflicts_with = "number", value_parser = parse_word_arg)]
+        answer: Option<Word>,
+        /// Forecast the difficulty: solver guesses, trap family and letter rarity
+        #[arg(short, long)]
+        forecast: bool,
+    },
+    /// Pick random answers for a puzzle pack file, for teachers and game nights
+    Generate {
+        /// Puzzles in the pack
+        #[arg(short = 'n', long, default_value_t = 5)]
+        count: usize,
+        /// Forecast difficulty, e.g. `hard` or `easy..moderate` (plays each candidate
+        /// with the solver, so it takes longer)
+        #[arg(short, long, value_parser = parse_difficulty_band)]
+        difficulty: Option<(Difficulty, Difficulty)>,
+        /// Letters every answer must contain, e.g. `rs`
+        #[arg(short, long)]
+        contains: Option<String>,
+        /// Skip the answers of the daily puzzles of the last DAYS days
+        #[arg(long, value_name = "DAYS", default_value_t = 365)]
+        recent: u32,
+        /// Seed for a reproducible pack (random by default)
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Name stored in the pack
+        #[arg(long, default_value = "Custom puzzles")]
+        name: String,
+        /// File to write the pack to
+        #[arg(short, long, default_value = "puzzle_pack.json")]
+        out: std::path::PathBuf,
+    },
+    /// Play a puzzle pack in the TUI, or see how you did on one
+    Pack {
+        #[command(subcommand)]
+        action: PackCommand,
+    },
     /// Solve a specific wordle puzzle
     Solve {
         /// Target word to solve (for testing)
         #[arg(short, long)]
         target: Option<String>,
-        /// Previous guesses in format "word feedback" (e.g., "adieu 20100")
-        #[arg(short, long, value_delimiter = ' ', num_args = 2)]
-        guess: Vec<String>,
+        /// A previous guess as WORD=FEEDBACK (e.g., "adieu=20100" or "adieu=g b y b b");
+        /// repeat for each turn
+        #[arg(short, long, value_name = "WORD=FEEDBACK", value_parser = parse_guess_arg)]
+        guess: Vec<(Word, FeedbackPattern)>,
+        /// All previous guesses at once (e.g., "crane:01020,sloth:20010");
+        /// newlines also separate turns, so a pasted transcript works
+        #[arg(long, value_name = "STATE", conflicts_with = "guess", value_parser = parse_state_arg)]
+        state: Option<GuessList>,
+        /// Also list the remaining words grouped into families (e.g. _ATCH)
+        #[arg(long)]
+        clusters: bool,
+        /// Don't suggest words the player guessed in earlier games
+        #[arg(long)]
+        avoid_used: bool,
     },
     /// Get the best first guess
-    FirstGuess,
+    FirstGuess {
+        /// Don't suggest words the player guessed in earlier games
+        #[arg(long)]
+        avoid_used: bool,
+    },
+    /// Rank opening words by the answers they leave on average
+    Openers {
+        /// Rank fixed pairs of first two guesses instead, the second played whatever
+        /// the first shows
+        #[arg(long)]
+        pair: bool,
+        /// Openers (or pairs) to list
+        #[arg(short = 'n', long, default_value = "5")]
+        top: usize,
+    },
+    /// Search for openers that play better than the solver's own, by simulated annealing
+    /// over full games (experimental; results depend on the seed)
+    SearchOpeners {
+        /// Moves to try; each new opener plays --sample games
+        #[arg(long, default_value_t = 200)]
+        steps: usize,
+        /// Answers each opener plays while searching
+        #[arg(long, default_value_t = 60)]
+        sample: usize,
+        /// Answers the best openers are replayed on for the report
+        #[arg(long, default_value_t = 300)]
+        validate: usize,
+        /// Openers to report
+        #[arg(short = 'n', long, default_value_t = 5)]
+        top: usize,
+        /// Seed for a reproducible search (random by default)
+        #[arg(long)]
+        seed: Option<u64>,
+    },
     /// Benchmark solver performance
     Benchmark {
         /// Number of words to test (default: 100)
         #[arg(short, long, default_value = "100")]
         count: usize,
+        /// Instead of a fixed count, solve random targets until this much time has passed
+        /// (e.g. 10s, 2m, 500ms; a bare number means seconds)
+        #[arg(short, long, value_parser = parse_duration_arg)]
+        duration: Option<Duration>,
+        /// Compare with the results saved by an earlier `--emit`, and fail when a metric
+        /// got worse by more than --fail-threshold
+        #[arg(long)]
+        baseline: Option<std::path::PathBuf>,
+        /// Save the results as JSON, e.g. to serve as a later baseline
+        #[arg(long)]
+        emit: Option<std::path::PathBuf>,
+        /// Largest change for the worse allowed against the baseline, as a fraction
+        #[arg(long, default_value_t = 0.05, requires = "baseline")]
+        fail_threshold: f64,
+    },
+    /// Benchmark a strategy with every combination of its parameters on the same answers,
+    /// report the best per metric and save the best one as a settings profile
+    Tune {
+        /// Strategy to tune (the frequency strategy has no parameters)
+        #[arg(long, default_value = "entropy", value_parser = parse_strategy_arg)]
+        strategy: StrategyType,
+        /// Answers to benchmark each combination on, drawn at random
+        #[arg(short = 'n', long, default_value_t = 100)]
+        count: usize,
+        /// Seed for a reproducible draw of answers (random by default)
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Metric that picks the saved parameters: win-rate, average or worst
+        #[arg(short, long, default_value = "win-rate", value_parser = parse_metric_arg)]
+        metric: TuningMetric,
+        /// Settings file to write, with the current settings and the best parameters
+        #[arg(short, long, default_value = "tuned_settings.json")]
+        out: std::path::PathBuf,
+    },
+    /// Check the constraint filter against brute force over sampled solver games (for
+    /// development; exits with an error on any divergence)
+    VerifyFilter {
+        /// Games to sample
+        #[arg(short = 'n', long, default_value_t = 200)]
+        games: usize,
+        /// Seed for a reproducible sample (random by default)
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Compare the solver's guesses with a published decision tree, node by node
+    CompareTree {
+        /// Tree file: one line per answer, e.g. "salet BBBYB1 courd BYBBB2 thing GGGGG3"
+        file: std::path::PathBuf,
+        /// Play in hard mode, for trees built under hard-mode rules
+        #[arg(long)]
+        hard: bool,
+    },
+    /// Ask each strategy for its guess in one position many times, on thread pools of
+    /// several sizes, and fail if the guesses differ
+    DeterminismCheck {
+        /// A previous guess as WORD=FEEDBACK; repeat for each turn (default: the opening)
+        #[arg(short, long, value_name = "WORD=FEEDBACK", value_parser = parse_guess_arg)]
+        guess: Vec<(Word, FeedbackPattern)>,
+        /// All previous guesses at once (e.g., "crane:01020,sloth:20010")
+        #[arg(long, value_name = "STATE", conflicts_with = "guess", value_parser = parse_state_arg)]
+        state: Option<GuessList>,
+        /// Calls per thread count
+        #[arg(short = 'n', long, default_value_t = DEFAULT_DETERMINISM_RUNS)]
+        runs: usize,
+        /// Thread pool sizes to try, e.g. 1,4,16 (default: 1, 2 and every core)
+        #[arg(long, value_delimiter = ',')]
+        threads: Vec<usize>,
+        /// Check only this strategy
+        #[arg(long, value_parser = parse_strategy_arg)]
+        strategy: Option<StrategyType>,
     },
     /// Update remote word lists and refresh cache
     UpdateWords {
@@ -40,6 +253,171 @@ enum Commands {
         #[arg(short, long, default_value_t = false)]
         force: bool,
     },
+    /// Check GitHub for a newer release of the prebuilt binary
+    Update {
+        /// Only report what's new, without installing (the default)
+        #[arg(long, conflicts_with = "install")]
+        check: bool,
+        /// Download the release for this platform and replace this binary
+        /// (needs the `self-update` feature)
+        #[arg(long)]
+        install: bool,
+    },
+    /// Show player statistics
+    Stats {
+        #[command(subcommand)]
+        action: Option<StatsCommand>,
+    },
+    /// Print a finished game's guesses and feedback, e.g. to check it with another analyzer
+    Export {
+        /// state ("crane:01020,...", what solve --state reads) or wordlebot (one
+        /// "CRANE BYBBG" line per guess)
+        #[arg(short, long, default_value = "state", value_parser = parse_export_format)]
+        format: ExportFormat,
+        /// Print the emoji grid to share instead, marked up for plain, discord (answer
+        /// in a spoiler tag), slack or mastodon
+        #[arg(long, conflicts_with = "format", value_parser = parse_share_format)]
+        share_format: Option<ShareFormat>,
+        /// Which game, counting back from the latest (1)
+        #[arg(short = 'n', long, default_value_t = 1)]
+        game: usize,
+    },
+    /// Report families of near-identical answers (like _IGHT) and the guesses that split them
+    Traps {
+        /// Smallest family to report (at least 3)
+        #[arg(short, long, default_value = "4")]
+        min_size: usize,
+        /// Disambiguating guesses to list per family
+        #[arg(short, long, default_value = "3")]
+        guesses: usize,
+        /// Most families to list, largest first
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+    },
+    /// Show how the remaining words link up by one-letter changes (batch-catch-hatch),
+    /// which words hold each group together and the guess that splits it fastest
+    Graph {
+        /// A previous guess as WORD=FEEDBACK; repeat for each turn
+        #[arg(short, long, value_name = "WORD=FEEDBACK", value_parser = parse_guess_arg)]
+        guess: Vec<(Word, FeedbackPattern)>,
+        /// All previous guesses at once (e.g., "crane:01020,sloth:20010")
+        #[arg(long, value_name = "STATE", conflicts_with = "guess", value_parser = parse_state_arg)]
+        state: Option<GuessList>,
+        /// Most groups to show, largest first
+        #[arg(short, long, default_value = "5")]
+        limit: usize,
+    },
+    /// Work out which guesses could be behind someone's shared result, row by row
+    Reverse {
+        /// The answer of the shared game
+        #[arg(short, long, value_parser = parse_word_arg)]
+        answer: Word,
+        /// The shared text with its emoji grid; read from stdin when left out
+        #[arg(short, long)]
+        grid: Option<String>,
+        /// Only keep guesses that reuse the greens of the rows before them
+        #[arg(long)]
+        hard: bool,
+        /// Most words to list per row
+        #[arg(short, long, default_value = "12")]
+        limit: usize,
+    },
+    /// Tools for puzzle authors
+    Author {
+        #[command(subcommand)]
+        action: AuthorCommand,
+    },
+    /// Manage frozen word-list snapshots
+    Wordlist {
+        #[command(subcommand)]
+        action: WordlistCommand,
+    },
+    /// Replay command scripts recorded with WORDLE_RECORD_SCRIPT=<file>
+    Script {
+        #[command(subcommand)]
+        action: ScriptCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum AuthorCommand {
+    /// Rate proposed answers by how hard they are for the solver, hardest first
+    Analyze {
+        /// Proposed answers, comma-separated or repeated (e.g. "hatch,crane")
+        #[arg(short, long, required = true, value_delimiter = ',', value_parser = parse_word_arg)]
+        words: Vec<Word>,
+    },
+}
+
+#[derive(Subcommand)]
+enum WordlistCommand {
+    /// Save the current answer and guess lists under a tag
+    Freeze {
+        /// Snapshot name (letters, digits, '-', '_' and '.')
+        #[arg(long)]
+        tag: String,
+    },
+    /// List saved snapshots
+    List,
+    /// Show where the cached lists came from: URL, license, download date and word count
+    Info {
+        /// Print machine-readable JSON instead
+        #[arg(long)]
+        json: bool,
+    },
+    /// Build a ready-made cache (lists, letter frequencies and each strategy's opener)
+    /// to ship with a release; installs use it instead of downloading
+    WarmCache {
+        /// Output file (default: word_lists.warm.wlf in the project root)
+        #[arg(long)]
+        out: Option<std::path::PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ScriptCommand {
+    /// Execute a script and check every step against its recorded result; exits with an
+    /// error if any step diverged
+    Run {
+        /// Script file (JSON)
+        file: std::path::PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum PackCommand {
+    /// Play the pack's puzzles in order, from the first one you haven't played
+    Play {
+        /// Pack file (JSON)
+        file: std::path::PathBuf,
+    },
+    /// Your result on each puzzle of the pack
+    Stats {
+        /// Pack file (JSON)
+        file: std::path::PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum StatsCommand {
+    /// Show statistics for the current player
+    Show,
+    /// Rank all players on this machine
+    Leaderboard,
+    /// The opening words the current player uses, how their games went, and whether
+    /// another opener would do better
+    Openers,
+    /// Import statistics exported from the NYT Wordle site for the current player
+    Import {
+        /// JSON export (statistics object or localStorage dump)
+        file: std::path::PathBuf,
+    },
+    /// List the words the current player has guessed (what --avoid-used skips)
+    UsedWords {
+        /// Forget them instead; games and statistics are kept
+        #[arg(long)]
+        clear: bool,
+    },
 }
 
 #[tokio::main]
@@ -47,41 +425,418 @@ async fn main() -> Result<()> {
     env_logger::init();
 
     let cli = Cli::parse();
+    let metrics_file = cli.metrics_file.clone();
+    let result = run(cli).await;
+    // Failed runs are written too: their error counts are part of the picture
+    if let Some(path) = metrics_file {
+        let metrics = wordle::core::metrics::global().render_prometheus();
+        wordle::infrastructure::write_atomic(&path, metrics).await?;
+    }
+    result
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    let tag = resolve_wordlist_tag(cli.wordlist_tag.as_deref());
+    let tag = tag.as_deref();
+    if cli.reveal {
+        std::env::set_var(REVEAL_ENV, "1");
+    }
 
     match cli.command {
         Some(Commands::Interactive) | None => {
-            run_interactive_mode().await?;
+            run_interactive_mode(cli.player.as_deref(), tag, cli.sessions_file).await?;
         }
-        Some(Commands::Solve { target, guess }) => {
-            solve_puzzle(target, guess).await?;
+        Some(Commands::Archive { number }) => {
+            play_archive_puzzle(number, cli.player.as_deref(), tag).await?;
         }
-        Some(Commands::FirstGuess) => {
-            get_first_guess().await?;
+        Some(Commands::Daily {
+            number,
+            answer,
+            forecast,
+        }) => daily(number, answer, forecast, tag).await?,
+        Some(Commands::Generate {
+            count,
+            difficulty,
+            contains,
+            recent,
+            seed,
+            name,
+            out,
+        }) => {
+            let must_contain = parse_letters(contains.as_deref().unwrap_or(""))?;
+            let constraints = PackConstraints {
+                count,
+                difficulty,
+                must_contain,
+                exclude: recent_answers(recent).await?,
+            };
+            generate_pack(&constraints, seed, &name, &out, tag).await?
         }
-        Some(Commands::Benchmark { count }) => {
-            run_benchmark(count).await?;
+        Some(Commands::Pack {
+            action: PackCommand::Play { file },
+        }) => play_pack(&file, cli.player.as_deref(), tag).await?,
+        Some(Commands::Pack {
+            action: PackCommand::Stats { file },
+        }) => show_pack_stats(&file, &resolve_player(cli.player.as_deref())).await?,
+        Some(Commands::Solve {
+            target,
+            guess,
+            state,
+            clusters,
+            avoid_used,
+        }) => {
+            let guesses = state.map(|list| list.0).unwrap_or(guess);
+            let player = avoid_used.then(|| resolve_player(cli.player.as_deref()));
+            solve_puzzle(target, guesses, clusters, player.as_deref(), tag).await?;
+        }
+        Some(Commands::FirstGuess { avoid_used }) => {
+            let player = avoid_used.then(|| resolve_player(cli.player.as_deref()));
+            get_first_guess(player.as_deref(), tag).await?;
+        }
+        Some(Commands::Openers { pair, top }) => rank_openers(pair, top, tag).await?,
+        Some(Commands::SearchOpeners {
+            steps,
+            sample,
+            validate,
+            top,
+            seed,
+        }) => {
+            let config = OpenerSearchConfig {
+                steps,
+                sample,
+                validation: validate,
+                keep: top,
+                seed,
+                ..OpenerSearchConfig::default()
+            };
+            search_openers(&config, tag).await?;
+        }
+        Some(Commands::Benchmark {
+            count,
+            duration,
+            baseline,
+            emit,
+            fail_threshold,
+        }) => {
+            let summary = run_benchmark(count, duration, tag).await?;
+            let report = BenchmarkReport::from(&summary);
+            if let Some(path) = &emit {
+                std::fs::write(path, serde_json::to_string_pretty(&report)?)?;
+                println!("💾 Results saved to {}", path.display());
+            }
+            if let Some(path) = &baseline {
+                compare_with_baseline(&report, path, fail_threshold)?;
+            }
+        }
+        Some(Commands::Tune {
+            strategy,
+            count,
+            seed,
+            metric,
+            out,
+        }) => {
+            tune_strategy(strategy, count, seed, metric, &out, tag).await?;
+        }
+        Some(Commands::VerifyFilter { games, seed }) => {
+            verify_filter(games, seed, tag).await?;
+        }
+        Some(Commands::CompareTree { file, hard }) => {
+            compare_tree(&file, hard, tag).await?;
+        }
+        Some(Commands::DeterminismCheck {
+            guess,
+            state,
+            runs,
+            threads,
+            strategy,
+        }) => {
+            let guesses = state.map(|list| list.0).unwrap_or(guess);
+            determinism_check(guesses, runs, threads, strategy, tag).await?;
         }
         Some(Commands::UpdateWords { force }) => {
             update_words(force).await?;
         }
+        Some(Commands::Update { check: _, install }) => {
+            check_for_update(install).await?;
+        }
+        Some(Commands::Stats { action }) => match action {
+            Some(StatsCommand::Show) | None => show_stats(cli.player.as_deref()).await?,
+            Some(StatsCommand::Leaderboard) => show_leaderboard().await?,
+            Some(StatsCommand::Openers) => opener_report(cli.player.as_deref(), tag).await?,
+            Some(StatsCommand::Import { file }) => {
+                import_stats(&file, cli.player.as_deref()).await?
+            }
+            Some(StatsCommand::UsedWords { clear }) => {
+                used_words(cli.player.as_deref(), clear).await?
+            }
+        },
+        Some(Commands::Export {
+            format,
+            share_format,
+            game,
+        }) => export_game(cli.player.as_deref(), format, share_format, game).await?,
+        Some(Commands::Traps {
+            min_size,
+            guesses,
+            limit,
+        }) => report_traps(min_size, guesses, limit, tag).await?,
+        Some(Commands::Graph {
+            guess,
+            state,
+            limit,
+        }) => {
+            let guesses = state.map(|list| list.0).unwrap_or(guess);
+            show_word_graph(guesses, limit, tag).await?
+        }
+        Some(Commands::Reverse {
+            answer,
+            grid,
+            hard,
+            limit,
+        }) => reverse_share(&answer, grid, hard, limit, tag).await?,
+        Some(Commands::Author {
+            action: AuthorCommand::Analyze { words },
+        }) => analyze_answers(&words, tag).await?,
+        Some(Commands::Wordlist { action }) => match action {
+            WordlistCommand::Freeze { tag: new_tag } => freeze_word_lists(&new_tag, tag).await?,
+            WordlistCommand::List => list_word_list_snapshots(),
+            WordlistCommand::Info { json } => show_word_list_info(json, tag).await?,
+            WordlistCommand::WarmCache { out } => build_warm_cache(out, tag).await?,
+        },
+        Some(Commands::Script {
+            action: ScriptCommand::Run { file },
+        }) => run_script(&file, tag).await?,
     }
 
     Ok(())
 }
 
-async fn run_interactive_mode() -> Result<()> {
+async fn run_interactive_mode(
+    player: Option<&str>,
+    tag: Option<&str>,
+    sessions_file: Option<std::path::PathBuf>,
+) -> Result<()> {
+    // The TUI builds its container from the settings, which read the tag from the environment
+    if let Some(tag) = tag {
+        std::env::set_var(WORDLIST_TAG_ENV, tag);
+    }
+    if let Some(stream) = missing_terminal() {
+        return run_pipe_mode(stream, sessions_file).await;
+    }
     log::info!("Starting modern TUI mode...");
 
     // Run the new TUI application
-    run_tui().await
+    run_tui_as(player).await
+}
+
+/// Play archive puzzle `number` in the TUI, failing before it opens if the puzzle
+/// isn't in the archive
+async fn play_archive_puzzle(number: u32, player: Option<&str>, tag: Option<&str>) -> Result<()> {
+    PuzzleArchive::load().await?.answer(number)?;
+    if let Some(tag) = tag {
+        std::env::set_var(WORDLIST_TAG_ENV, tag);
+    }
+    run_tui_archive(player, number).await
+}
+
+/// Report on a day's puzzle; the answer is only printed when it was given
+async fn daily(
+    number: Option<u32>,
+    answer: Option<Word>,
+    forecast: bool,
+    tag: Option<&str>,
+) -> Result<()> {
+    if forecast {
+        let no_spoilers = UserSettings::load_default().no_spoilers;
+        if let Err(reason) = SpoilerGuard::new(no_spoilers).allow(SpoilerFeature::Forecast) {
+            anyhow::bail!(reason);
+        }
+    }
+    // Puzzle answers stay hidden; a given answer is no secret
+    let (answer, puzzle) = match answer {
+        Some(answer) => (answer, None),
+        None => {
+            let number = match number.or_else(PuzzleArchive::todays_number) {
+                Some(number) => number,
+                None => anyhow::bail!("The system date is before the first puzzle"),
+            };
+            let archive = PuzzleArchive::load().await?;
+            match archive.answer(number) {
+                Ok(answer) => {
+                    println!("📅 Puzzle #{} - answer known locally", number);
+                    (answer.clone(), Some(number))
+                }
+                Err(_) => {
+                    println!(
+                        "📅 Puzzle #{} - answer not known locally yet; point {} at a longer index",
+                        number, ARCHIVE_ENV
+                    );
+                    return Ok(());
+                }
+            }
+        }
+    };
+    if !forecast {
+        println!("   Run with --forecast for its difficulty (the answer stays hidden)");
+        return Ok(());
+    }
+
+    // The forecast is for the entropy solver, whatever the configured default
+    let config = DependencyConfig {
+        strategy_type: StrategyType::Entropy,
+        ..container_for(tag).config().clone()
+    };
+    let mut app_service = Container::with_config(config)
+        .create_application_service()
+        .await?;
+    let report = app_service.analyze_answer(&answer)?;
+
+    // Too few letters of a puzzle answer to mask, so it shows only when revealed
+    let reveal = RevealPolicy::from_env().is_revealed();
+    let subject = match puzzle {
+        Some(number) if reveal => {
+            format!("puzzle #{} ({})", number, answer.as_str().to_uppercase())
+        }
+        Some(number) => format!("puzzle #{}", number),
+        None => answer.as_str().to_uppercase(),
+    };
+    println!("🔮 Forecast for {}: {}", subject, report.forecast().label());
+    match report.solver_guesses {
+        Some(guesses) => println!("   Entropy solver: {} guesses", guesses),
+        None => println!("   Entropy solver: not solved within the guess limit"),
+    }
+    match report.families.first() {
+        Some(family) if puzzle.is_some() && !reveal => {
+            println!("   Trap family: {} near-identical answers", family.len())
+        }
+        Some(family) => println!("   Trap family: {} ({} answers)", family.pattern, family.len()),
+        None => println!("   Trap family: none"),
+    }
+    println!(
+        "   Letter rarity: {:.0}% (share of answers missing each letter, on average)",
+        report.letter_rarity * 100.0
+    );
+    if !report.in_answer_list {
+        println!("   ⚠️  Not in the loaded answer list; the solver can't find it");
+    }
+    Ok(())
+}
+
+/// Previous guesses given with `solve --state`
+#[derive(Debug, Clone)]
+struct GuessList(Vec<(Word, FeedbackPattern)>);
+
+fn parse_guess_arg(value: &str) -> std::result::Result<(Word, FeedbackPattern), String> {
+    parse_guess_entry(value).map_err(arg_error)
+}
+
+fn parse_word_arg(value: &str) -> std::result::Result<Word, String> {
+    Word::from_str(value.trim())
+}
+
+/// Parse a difficulty like `hard`, or a band like `easy..moderate`
+fn parse_difficulty_band(value: &str) -> std::result::Result<(Difficulty, Difficulty), String> {
+    let parse = |label: &str| {
+        Difficulty::from_label(label).ok_or_else(|| {
+            let labels: Vec<&str> = Difficulty::ALL.iter().map(|d| d.label()).collect();
+            format!("unknown difficulty '{}' (expected {})", label, labels.join(", "))
+        })
+    };
+    let (lowest, highest) = match value.split_once("..") {
+        Some((lowest, highest)) => (parse(lowest)?, parse(highest)?),
+        None => (parse(value)?, parse(value)?),
+    };
+    if lowest > highest {
+        return Err(format!("'{}' runs from harder to easier", value));
+    }
+    Ok((lowest, highest))
+}
+
+fn parse_export_format(value: &str) -> std::result::Result<ExportFormat, String> {
+    ExportFormat::from_name(value).ok_or_else(|| {
+        let names: Vec<&str> = ExportFormat::ALL.iter().map(|f| f.name()).collect();
+        format!("unknown format '{}' (expected {})", value, names.join(", "))
+    })
+}
+
+fn parse_share_format(value: &str) -> std::result::Result<ShareFormat, String> {
+    ShareFormat::from_name(value).ok_or_else(|| {
+        let names: Vec<&str> = ShareFormat::ALL.iter().map(|f| f.name()).collect();
+        let expected = names.join(", ");
+        format!("unknown share format '{}' (expected {})", value, expected)
+    })
+}
+
+fn parse_strategy_arg(value: &str) -> std::result::Result<StrategyType, String> {
+    StrategyType::ALL
+        .into_iter()
+        .find(|strategy| strategy.name().eq_ignore_ascii_case(value))
+        .ok_or_else(|| {
+            let names: Vec<&str> = StrategyType::ALL.iter().map(|s| s.name()).collect();
+            let expected = names.join(", ");
+            format!("unknown strategy '{}' (expected {})", value, expected)
+        })
+}
+
+fn parse_metric_arg(value: &str) -> std::result::Result<TuningMetric, String> {
+    TuningMetric::from_label(value).ok_or_else(|| {
+        let labels: Vec<&str> = TuningMetric::ALL.iter().map(|m| m.label()).collect();
+        let expected = labels.join(", ");
+        format!("unknown metric '{}' (expected {})", value, expected)
+    })
+}
+
+fn parse_state_arg(value: &str) -> std::result::Result<GuessList, String> {
+    parse_guess_batch(value).map(GuessList).map_err(arg_error)
 }
 
-async fn solve_puzzle(target: Option<String>, guess_pairs: Vec<String>) -> Result<()> {
+/// Parse a wall-clock budget like `10s`, `2m`, `500ms` or `30`
+fn parse_duration_arg(value: &str) -> std::result::Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("'{}' should be a number with a unit, e.g. 10s", value))?;
+    let seconds = match unit.trim() {
+        "" | "s" | "sec" => number,
+        "ms" => number / 1000.0,
+        "m" | "min" => number * 60.0,
+        "h" => number * 3600.0,
+        other => return Err(format!("unknown unit '{}' (use ms, s, m or h)", other)),
+    };
+    if seconds <= 0.0 {
+        return Err("duration must be positive".to_string());
+    }
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+/// clap already says which argument was invalid, so drop the error category prefix
+fn arg_error(err: WordleError) -> String {
+    match err {
+        WordleError::Solver(SolverError::InvalidFeedback(msg)) => msg,
+        other => other.to_string(),
+    }
+}
+
+async fn solve_puzzle(
+    target: Option<String>,
+    guesses: Vec<(Word, FeedbackPattern)>,
+    clusters: bool,
+    avoid_used_by: Option<&str>,
+    tag: Option<&str>,
+) -> Result<()> {
     // Use dependency injection with default configuration
-    let container = Container::new();
+    let container = container_for(tag);
     let mut app_service = container.create_application_service().await?;
+    if let Some(player) = avoid_used_by {
+        avoid_used_words(&mut app_service, player).await?;
+    }
 
     // Set target word if provided
+    let target_set = target.is_some();
     if let Some(target_word) = target {
         let word = Word::from_str(&target_word)
             .map_err(|e| anyhow::anyhow!("Invalid target word: {}", e))?;
@@ -92,36 +847,39 @@ async fn solve_puzzle(target: Option<String>, guess_pairs: Vec<String>) -> Resul
     }
 
     // Process previous guesses
-    let mut i = 0;
-    while i < guess_pairs.len() {
-        if i + 1 < guess_pairs.len() {
-            let word_str = &guess_pairs[i];
-            let feedback_str = &guess_pairs[i + 1];
-
-            let word = Word::from_str(word_str)
-                .map_err(|e| anyhow::anyhow!("Invalid guess word '{}': {}", word_str, e))?;
-
-            let feedback = FeedbackPattern::from_code_string(feedback_str)
-                .map_err(|e| anyhow::anyhow!("Invalid feedback '{}': {}", feedback_str, e))?;
-
-            let result = app_service.execute(Command::AddGuessResult {
-                word,
-                feedback: feedback.clone(),
-            })?;
+    if !guesses.is_empty() {
+        let result = app_service.execute(Command::AddGuessBatch {
+            guesses: guesses.clone(),
+        })?;
 
-            if let CommandResult::GuessResultAdded { remaining_words } = result {
+        if let CommandResult::GuessBatchAdded { remaining_words } = result {
+            for ((word, feedback), remaining) in guesses.iter().zip(remaining_words) {
                 println!(
                     "📝 Added guess: {} -> {} (🔢 {} words remaining)",
-                    word_str, feedback, remaining_words
+                    word,
+                    feedback.to_code_string(),
+                    remaining
                 );
             }
-
-            i += 2;
-        } else {
-            break;
         }
     }
 
+    // With a target, play the game out using real feedback for each guess
+    if target_set {
+        return simulate_to_target(&mut app_service);
+    }
+
+    if clusters {
+        print_word_families(&app_service);
+    }
+    if let Some(trap) = app_service.active_trap() {
+        println!(
+            "⚠️  Trap: all {} remaining words are the {} family - one guess each may run out",
+            trap.len(),
+            trap.pattern
+        );
+    }
+
     // Get next best guess
     let result = app_service.execute(Command::GetBestGuess)?;
     match result {
@@ -131,6 +889,56 @@ async fn solve_puzzle(target: Option<String>, guess_pairs: Vec<String>) -> Resul
                 word, confidence
             );
 
+            // Which letters earn the suggestion its information
+            let heat = app_service.letter_heat(&word);
+            let letters: Vec<String> = word
+                .chars()
+                .iter()
+                .zip(heat)
+                .map(|(letter, bits)| format!("{} {:.2}", letter.to_ascii_uppercase(), bits))
+                .collect();
+            println!("🔥 Bits per letter: {}", letters.join(" · "));
+
+            // How risky the suggestion is before playing it
+            if let CommandResult::GuessEvaluated { quality, .. } =
+                app_service.execute(Command::EvaluateGuess { word })?
+            {
+                println!(
+                    "⚖️  Worst case: {} words left · {} feedback buckets · {:.1}% chance it's the answer",
+                    quality.worst_case_remaining,
+                    quality.bucket_count,
+                    quality.answer_probability * 100.0
+                );
+            }
+
+            // Alternatives for a riskier or safer turn
+            if let CommandResult::SuggestionSet { set: Some(set) } =
+                app_service.execute(Command::GetSuggestionSet)?
+            {
+                println!("🧭 Pick your risk:");
+                for (kind, suggestion) in set.iter() {
+                    println!(
+                        "   {:<12} {}  {:.2} bits · worst case {} · {:.1}% answer",
+                        kind.label(),
+                        suggestion.word.as_str().to_uppercase(),
+                        suggestion.quality.entropy,
+                        suggestion.quality.worst_case_remaining,
+                        suggestion.quality.answer_probability * 100.0
+                    );
+                }
+            }
+
+            if let CommandResult::Disambiguator { found: Some(found) } =
+                app_service.execute(Command::FindDisambiguator)?
+            {
+                println!(
+                    "🔀 Disambiguator: {} (checks {} of {})",
+                    found.word.as_str().to_uppercase(),
+                    found.covered.iter().collect::<String>(),
+                    found.distinguishing.iter().collect::<String>()
+                );
+            }
+
             // Show additional statistics
             let stats_result = app_service.execute(Command::GetStatistics)?;
             if let CommandResult::Statistics { stats } = stats_result {
@@ -157,9 +965,73 @@ async fn solve_puzzle(target: Option<String>, guess_pairs: Vec<String>) -> Resul
     Ok(())
 }
 
-async fn get_first_guess() -> Result<()> {
-    let container = Container::new();
-    let app_service = container.create_application_service().await?;
+/// Print the remaining words grouped into families, largest first
+fn print_word_families(app_service: &WordleApplicationService) {
+    let clusters = app_service.word_clusters(2);
+    if clusters.is_empty() {
+        println!("🧩 No word families among the remaining words");
+        return;
+    }
+    println!("🧩 Word families:");
+    for cluster in &clusters {
+        let words: Vec<&str> = cluster.words.iter().map(Word::as_str).collect();
+        println!(
+            "   {} family ({}): {}",
+            cluster.pattern,
+            cluster.len(),
+            words.join(", ")
+        );
+    }
+}
+
+/// Play the solver's best guess each turn against the target and print the trace
+fn simulate_to_target(app_service: &mut WordleApplicationService) -> Result<()> {
+    println!("🤖 Solving:");
+    let mut turn = app_service.get_state().guess_count();
+    loop {
+        let (guess, game_finished) = app_service.play_best_guess()?;
+        turn += 1;
+        let state = app_service.get_state();
+        let bits = state
+            .solver_stats
+            .entropy_scores
+            .last()
+            .map(|entropy| (entropy.played_entropy, entropy.bits_gained))
+            .unwrap_or_default();
+        println!(
+            "  {}. {} {} {} (🔢 {} words remaining · 📶 {:.2} bits expected, {:.2} gained)",
+            turn,
+            guess.word.as_str().to_uppercase(),
+            guess.feedback,
+            guess.feedback.to_code_string(),
+            state.remaining_words_count,
+            bits.0,
+            bits.1
+        );
+        if game_finished {
+            break;
+        }
+    }
+
+    match &app_service.get_state().game_result {
+        GameResult::Won { word, .. } => println!(
+            "🎉 Solved {} in {} guesses ({:.2} bits gained)",
+            word,
+            turn,
+            app_service.get_state().solver_stats.bits_gained()
+        ),
+        GameResult::Failed { reason, .. } => println!("💥 Not solved: {}", reason),
+        GameResult::InProgress => {}
+    }
+    Ok(())
+}
+
+async fn get_first_guess(avoid_used_by: Option<&str>, tag: Option<&str>) -> Result<()> {
+    let container = container_for(tag);
+    let mut app_service = container.create_application_service().await?;
+    if let Some(player) = avoid_used_by {
+        avoid_used_words(&mut app_service, player).await?;
+    }
     let first_guess = app_service.get_best_first_guess()?;
 
     println!("🌟 Best first guess: {}", first_guess);
@@ -168,98 +1040,460 @@ async fn get_first_guess() -> Result<()> {
     Ok(())
 }
 
-async fn run_benchmark(count: usize) -> Result<()> {
-    println!("🚀 Running benchmark with {} words...", count);
-
-    let container = Container::new();
-    let mut app_service = container.create_application_service().await?;
-    let first_guess = app_service.get_best_first_guess()?;
+/// Play the solver against answers and report its win rate and guess counts
+///
+/// A fixed `count` takes answers in list order; a `budget` draws random answers until
+/// it runs out. The budget is only checked between games, so the run can overshoot by
+/// the games in flight. Games run concurrently; on a terminal a live progress screen
+/// follows them, elsewhere a line is printed every ten games.
+async fn run_benchmark(
+    count: usize,
+    budget: Option<Duration>,
+    tag: Option<&str>,
+) -> Result<BenchmarkSummary> {
+    let container = container_for(tag);
+    let app_service = container.create_application_service().await?;
+    let answers = app_service.possible_words(None);
+    let runner = app_service.benchmark_runner();
+    println!("🥇 Best first guess: {}", app_service.get_best_first_guess()?);
 
-    // Get word list for testing
-    let stats_result = app_service.execute(Command::GetStatistics)?;
-    let test_words = if let CommandResult::Statistics { stats } = stats_result {
-        stats
-            .possible_words_sample
-            .into_iter()
-            .take(count)
-            .collect::<Vec<_>>()
-    } else {
-        vec![
-            Word::from_str("apple").map_err(|e| anyhow::anyhow!(e))?,
-            Word::from_str("bread").map_err(|e| anyhow::anyhow!(e))?,
-            Word::from_str("crane").map_err(|e| anyhow::anyhow!(e))?,
-        ]
+    let live = io::stdout().is_terminal();
+    let summary = match budget {
+        Some(budget) => {
+            let targets = RandomTargets::new(answers).for_duration(budget);
+            if live {
+                run_benchmark_tui(&runner, targets, BenchmarkGoal::Duration(budget))?
+            } else {
+                println!("⏱️  Solving random words for {:.1?}...", budget);
+                runner.run_concurrent(targets, &mut ())?
+            }
+        }
+        None => {
+            let targets: Vec<Word> = answers.into_iter().take(count).collect();
+            let goal = BenchmarkGoal::Games(targets.len());
+            if live {
+                run_benchmark_tui(&runner, targets, goal)?
+            } else {
+                println!("🚀 Running benchmark with {} words...", targets.len());
+                let mut progress = |_: &SimulatedGame, summary: &BenchmarkSummary| {
+                    if summary.games.is_multiple_of(10) {
+                        println!("⏳ Processed {} words...", summary.games);
+                    }
+                };
+                runner.run_concurrent(targets, &mut progress)?
+            }
+        }
     };
 
-    println!("📊 Benchmark Results:");
-    println!("🥇 Best first guess: {}", first_guess);
-    println!("📈 Testing against {} words", test_words.len());
+    println!("🎯 Benchmark Complete!");
+    println!(
+        "🏁 Games: {} in {:.1}s ({:.2} games/s)",
+        summary.games,
+        summary.elapsed.as_secs_f64(),
+        summary.games_per_second()
+    );
+    if summary.games > 0 {
+        println!(
+            "✅ Success rate: {:.1}% ({}/{})",
+            summary.win_rate() * 100.0,
+            summary.wins,
+            summary.games
+        );
+        println!("📊 Average guesses per game: {:.2}", summary.average_guesses());
+        println!(
+            "📊 Average guesses per solved word: {:.2}",
+            summary.average_winning_guesses()
+        );
+    }
+    Ok(summary)
+}
 
-    let mut total_guesses = 0;
-    let mut success_count = 0;
+/// Print how `report` compares with the baseline at `path`, and fail when a metric
+/// got worse by more than `threshold`
+fn compare_with_baseline(
+    report: &BenchmarkReport,
+    path: &std::path::Path,
+    threshold: f64,
+) -> Result<()> {
+    if threshold.is_nan() || threshold < 0.0 {
+        anyhow::bail!("--fail-threshold must be 0 or more");
+    }
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read baseline {}: {}", path.display(), e))?;
+    let baseline: BenchmarkReport = serde_json::from_str(&text)
+        .map_err(|e| anyhow::anyhow!("Invalid baseline {}: {}", path.display(), e))?;
 
-    for (i, target_word) in test_words.iter().enumerate() {
-        if i >= count {
-            break;
-        }
+    println!();
+    println!(
+        "📐 Against {} ({} games; fail above {:.1}% worse):",
+        path.display(),
+        baseline.games,
+        threshold * 100.0
+    );
+    for change in report.compare(&baseline) {
+        let mark = if change.change > threshold {
+            "❌"
+        } else {
+            "✅"
+        };
+        let delta = match change.change {
+            0.0 => "unchanged".to_string(),
+            worse if worse > 0.0 => format!("{:.1}% worse", worse * 100.0),
+            better => format!("{:.1}% better", -better * 100.0),
+        };
+        println!(
+            "  {} {:<16} {:>10.3} → {:>10.3}  ({})",
+            mark, change.metric, change.baseline, change.current, delta
+        );
+    }
+    let regressions = report.regressions(&baseline, threshold);
+    if !regressions.is_empty() {
+        let metrics: Vec<&str> = regressions.iter().map(|r| r.metric).collect();
+        anyhow::bail!(
+            "Regressed by more than {:.1}%: {}",
+            threshold * 100.0,
+            metrics.join(", ")
+        );
+    }
+    Ok(())
+}
 
-        // Reset for each test
-        app_service.execute(Command::Reset)?;
-        app_service.execute(Command::StartGame {
-            target_word: Some(target_word.clone()),
-        })?;
+/// Benchmark `strategy` with each tuning of its grid on the same random answers
+///
+/// Every tuning plays the same answers (repeats drawn at random are played once), so
+/// the results differ only by the parameters. The best tuning under each metric is
+/// printed, and the one `metric` picks is saved with the rest of the current settings.
+async fn tune_strategy(
+    strategy: StrategyType,
+    count: usize,
+    seed: Option<u64>,
+    metric: TuningMetric,
+    out: &std::path::Path,
+    tag: Option<&str>,
+) -> Result<()> {
+    let base = DependencyConfig {
+        strategy_type: strategy,
+        record_script: None,
+        ..container_for(tag).config().clone()
+    };
+    let mut provider = Container::with_config(base.clone()).create_file_word_list_provider();
+    provider.load_words().await?;
+    let answers = provider.get_answer_words().to_vec();
+    let draw = match seed {
+        Some(seed) => RandomTargets::with_seed(answers, seed),
+        None => RandomTargets::new(answers),
+    };
+    let mut targets: Vec<Word> = draw.take(count).collect();
+    targets.sort();
+    targets.dedup();
 
-        let mut guesses = 0;
-        let mut solved = false;
-
-        // Simulate solving (simplified version)
-        while guesses < 6 && !solved {
-            let guess_result = app_service.execute(Command::GetBestGuess)?;
-            if let CommandResult::BestGuess { word, .. } = guess_result {
-                guesses += 1;
-
-                // Check if this would be the correct guess
-                if word.as_str() == target_word.as_str() {
-                    solved = true;
-                    success_count += 1;
-                    total_guesses += guesses;
-                    break;
-                }
+    let grid = StrategyTuning::grid(strategy);
+    println!(
+        "🎛️  Tuning the {} strategy: {} combinations on {} answers...",
+        strategy.name(),
+        grid.len(),
+        targets.len()
+    );
+    let mut trials = Vec::with_capacity(grid.len());
+    for (i, tuning) in grid.into_iter().enumerate() {
+        let config = DependencyConfig {
+            tuning,
+            ..base.clone()
+        };
+        let app_service = Container::with_config(config)
+            .create_application_service()
+            .await?;
+        let summary = app_service
+            .benchmark_runner()
+            .run_concurrent(targets.clone(), &mut ())?;
+        println!(
+            "  {:>3}. {}: {:.1}% won, {:.3} guesses, worst {}",
+            i + 1,
+            describe_tuning(strategy, &tuning),
+            summary.win_rate() * 100.0,
+            summary.average_guesses(),
+            summary.worst_guesses
+        );
+        trials.push(TuningTrial { tuning, summary });
+    }
 
-                // Create mock feedback (this would normally come from the game)
-                // For benchmarking, we'll assume we get some feedback and continue
-                let mock_feedback = FeedbackPattern::from_code_string("01020").unwrap();
-                app_service.execute(Command::AddGuessResult {
-                    word,
-                    feedback: mock_feedback,
-                })?;
-            }
+    for each in TuningMetric::ALL {
+        if let Some(best) = each.best(&trials) {
+            println!(
+                "🏆 Best by {}: {}",
+                each.label(),
+                describe_tuning(strategy, &best.tuning)
+            );
         }
+    }
+    let Some(best) = metric.best(&trials) else {
+        anyhow::bail!("No answers to tune on");
+    };
+    let settings = UserSettings {
+        strategy,
+        tuning: best.tuning,
+        ..UserSettings::load_default()
+    };
+    settings.save(out)?;
+    println!(
+        "💾 Saved the best by {} to {}; copy it over {} to use it",
+        metric.label(),
+        out.display(),
+        UserSettings::default_path().display()
+    );
+    Ok(())
+}
 
-        if !solved {
-            total_guesses += 6; // Failed attempts count as 6 guesses
-        }
+/// The parameters of `tuning` that `strategy` reads
+fn describe_tuning(strategy: StrategyType, tuning: &StrategyTuning) -> String {
+    let prefilter = match tuning.prefilter_above {
+        Some(above) => format!("prefilter above {}", above),
+        None => "no prefilter".to_string(),
+    };
+    match strategy {
+        StrategyType::Entropy => format!("endgame at {}, {}", tuning.endgame_threshold, prefilter),
+        StrategyType::Hybrid => format!(
+            "{}% entropy above {}, endgame at {}",
+            tuning.entropy_percent, tuning.entropy_above, tuning.endgame_threshold
+        ),
+        StrategyType::Frequency => "defaults".to_string(),
+    }
+}
 
-        if (i + 1) % 10 == 0 {
-            println!("⏳ Processed {} words...", i + 1);
+/// Replay sampled solver games and compare the constraint filter's survivors with a
+/// brute-force re-simulation after every turn
+async fn verify_filter(games: usize, seed: Option<u64>, tag: Option<&str>) -> Result<()> {
+    let app_service = container_for(tag).create_application_service().await?;
+    let answers = app_service.possible_words(None);
+    let targets = match seed {
+        Some(seed) => RandomTargets::with_seed(answers.clone(), seed),
+        None => RandomTargets::new(answers.clone()),
+    };
+    println!(
+        "🔍 Playing {} games and checking {} answers after every turn...",
+        games,
+        answers.len()
+    );
+    let mut played = Vec::with_capacity(games);
+    let mut keep = |game: &SimulatedGame, _: &BenchmarkSummary| played.push(game.clone());
+    app_service
+        .benchmark_runner()
+        .run(targets.take(games), &mut keep)?;
+
+    let started = Instant::now();
+    let audit = audit_filter(&DefaultConstraintFilter::new(), &answers, &played);
+    println!(
+        "🧮 {} turns, {} word checks in {:.1}s",
+        audit.turns,
+        audit.words_checked,
+        started.elapsed().as_secs_f64()
+    );
+    if audit.passed() {
+        println!("✅ The filter agreed with brute force on every turn");
+        return Ok(());
+    }
+
+    const SHOWN: usize = 5;
+    let list = |words: &[Word]| {
+        let listed: Vec<String> = words.iter().map(ToString::to_string).collect();
+        if listed.is_empty() {
+            "none".to_string()
+        } else {
+            listed.join(" ")
         }
+    };
+    for divergence in audit.divergences.iter().take(SHOWN) {
+        let guesses: Vec<String> = divergence
+            .guesses
+            .iter()
+            .map(|guess| format!("{}={}", guess.word, guess.feedback.to_code_string()))
+            .collect();
+        println!("❌ Target {} after {}", divergence.target, guesses.join(" "));
+        println!("   Kept but contradicted: {}", list(&divergence.kept));
+        println!("   Dropped but consistent: {}", list(&divergence.dropped));
+    }
+    if audit.divergences.len() > SHOWN {
+        println!("   ... and {} more", audit.divergences.len() - SHOWN);
     }
+    anyhow::bail!(
+        "The constraint filter diverged from brute force on {} of {} turns",
+        audit.divergences.len(),
+        audit.turns
+    )
+}
+
+/// Ask each strategy for its guess after `guesses` `runs` times per thread count, and
+/// fail if any of them came up with different guesses
+async fn determinism_check(
+    guesses: Vec<(Word, FeedbackPattern)>,
+    runs: usize,
+    threads: Vec<usize>,
+    strategy: Option<StrategyType>,
+    tag: Option<&str>,
+) -> Result<()> {
+    let container = container_for(tag);
+    let mut provider = container.create_file_word_list_provider();
+    provider.load_words().await?;
+    let mut answers = provider.get_answer_words().to_vec();
+    answers.sort();
+    let mut candidates = answers.clone();
+    candidates.extend(provider.get_guess_words().iter().cloned());
+    candidates.sort();
+    candidates.dedup();
 
-    let avg_guesses = if success_count > 0 {
-        total_guesses as f64 / success_count as f64
+    let history: Vec<Guess> = guesses
+        .into_iter()
+        .map(|(word, feedback)| Guess::new(word, feedback))
+        .collect();
+    let possible_words = DefaultConstraintFilter::new().filter_words(&answers, &history);
+    if possible_words.is_empty() {
+        anyhow::bail!("No answer fits those guesses");
+    }
+    if threads.contains(&0) {
+        anyhow::bail!("Thread counts start at 1");
+    }
+    let threads = if threads.is_empty() {
+        default_thread_counts()
     } else {
-        6.0
+        threads
     };
+    let thread_list: Vec<String> = threads.iter().map(ToString::to_string).collect();
+    println!(
+        "🔁 {} answers left; asking for a guess {} times on {} threads",
+        possible_words.len(),
+        runs,
+        thread_list.join("/")
+    );
 
-    println!("🎯 Benchmark Complete!");
+    let strategies = match strategy {
+        Some(strategy) => vec![strategy],
+        None => StrategyType::ALL.to_vec(),
+    };
+    let mut differing = Vec::new();
+    for strategy_type in strategies {
+        let config = DependencyConfig {
+            strategy_type,
+            ..container.config().clone()
+        };
+        let strategy = Container::with_config(config).create_strategy().await?;
+        let started = Instant::now();
+        let check = check_determinism(
+            strategy.as_ref(),
+            &possible_words,
+            &candidates,
+            runs,
+            &threads,
+        )?;
+        let outcomes = check.outcomes();
+        if check.is_deterministic() {
+            let outcome = outcomes.keys().next().map_or("nothing", String::as_str);
+            println!(
+                "✅ {:<9} {} every time ({} calls, {:.1}s)",
+                strategy_type.name(),
+                outcome,
+                check.runs.len(),
+                started.elapsed().as_secs_f64()
+            );
+            continue;
+        }
+        println!(
+            "❌ {:<9} gave {} different guesses",
+            strategy_type.name(),
+            outcomes.len()
+        );
+        for &count in &threads {
+            let guesses: Vec<String> = check
+                .runs
+                .iter()
+                .filter(|run| run.threads == count)
+                .map(|run| match &run.guess {
+                    Ok(word) => word.to_string(),
+                    Err(_) => "error".to_string(),
+                })
+                .collect();
+            println!("   {:>3} threads: {}", count, guesses.join(" "));
+        }
+        differing.push(strategy_type.name());
+    }
+    if !differing.is_empty() {
+        anyhow::bail!("Guesses depend on the run for: {}", differing.join(", "));
+    }
+    Ok(())
+}
+
+async fn compare_tree(file: &std::path::Path, hard: bool, tag: Option<&str>) -> Result<()> {
+    let tree = DecisionTree::load(file)?;
+    let config = DependencyConfig {
+        hard_mode: hard,
+        ..container_for(tag).config().clone()
+    };
+    let app_service = Container::with_config(config)
+        .create_application_service()
+        .await?;
+    let answers: BTreeSet<Word> = app_service.possible_words(None).into_iter().collect();
+    let (known, missing): (Vec<Word>, Vec<Word>) = tree
+        .answers()
+        .into_iter()
+        .partition(|answer| answers.contains(answer));
     println!(
-        "✅ Success rate: {:.1}% ({}/{})",
-        (success_count as f64 / test_words.len() as f64) * 100.0,
-        success_count,
-        test_words.len()
+        "🌳 {} answers, {:.3} guesses on average{}",
+        tree.paths.len(),
+        tree.average_guesses(),
+        if hard { " (hard mode)" } else { "" }
     );
-    println!("📊 Average guesses per solved word: {:.2}", avg_guesses);
+    if !missing.is_empty() {
+        println!(
+            "⚠️  {} tree answers aren't in the solver's answer list; nodes leading to them may \
+             diverge",
+            missing.len()
+        );
+    }
+
+    let comparison = app_service.compare_with_tree(&tree)?;
+    println!(
+        "🧭 Agreed on {} of {} nodes ({:.1}%)",
+        comparison.agreed,
+        comparison.nodes,
+        comparison.agreement() * 100.0
+    );
+    for (turn, (nodes, agreed)) in comparison.by_turn.iter().enumerate() {
+        println!("   Guess {}: {}/{} nodes", turn + 1, agreed, nodes);
+    }
+
+    const SHOWN: usize = 10;
+    for divergence in comparison.divergences.iter().take(SHOWN) {
+        let history: Vec<String> = divergence
+            .history
+            .iter()
+            .map(|guess| format!("{}={}", guess.word, guess.feedback.to_code_string()))
+            .collect();
+        let history = if history.is_empty() {
+            "opening".to_string()
+        } else {
+            history.join(" ")
+        };
+        let solver_guess = divergence
+            .solver_guess
+            .as_ref()
+            .map_or("nothing".to_string(), ToString::to_string);
+        println!(
+            "❌ After {}: tree {}, solver {} ({} answers)",
+            history, divergence.tree_guess, solver_guess, divergence.answers
+        );
+    }
+    if comparison.divergences.len() > SHOWN {
+        println!("   ... and {} more", comparison.divergences.len() - SHOWN);
+    }
 
+    if !known.is_empty() {
+        let summary = app_service.benchmark_runner().run(known, &mut ())?;
+        println!(
+            "📊 Solver: {:.3} guesses on average over {} of those answers, {:.1}% won",
+            summary.average_guesses(),
+            summary.games,
+            summary.win_rate() * 100.0
+        );
+    }
     Ok(())
 }
 
@@ -270,12 +1504,945 @@ async fn update_words(force: bool) -> Result<()> {
     );
     // Use the container to get the default provider and call refresh
     let container = Container::new();
-    let mut provider = container.create_word_list_provider()?;
+    let mut provider = container.create_file_word_list_provider();
 
-    let (a, g) = provider.refresh(force).await?;
+    let (a, g) = provider.refresh_cache(force).await?;
     println!("✅ Updated. Answers: {}, Guesses: {}", a, g);
+    for report in provider.source_reports() {
+        for (url, reason) in &report.failures {
+            println!("⚠️  Skipped {}: {}", url, reason);
+        }
+        let via = if report.used_mirror() { " (mirror)" } else { "" };
+        println!("🌐 {} words from {}{}", report.words, report.used, via);
+    }
 
     // Provide a hint about overriding sources
     println!("ℹ️  You can customize sources via word_sources.json at the project root.");
     Ok(())
 }
+
+/// Compare this build with the latest release and, with `install`, replace it
+async fn check_for_update(install: bool) -> Result<()> {
+    let http = HttpConfig::default().with_env();
+    println!("🔎 Checking {} ...", releases_url());
+    let release = fetch_latest_release(&http).await?;
+    if !release.is_newer_than(CURRENT_VERSION) {
+        println!(
+            "✅ Up to date: {} is the latest (newest release {})",
+            CURRENT_VERSION, release.tag_name
+        );
+        return Ok(());
+    }
+
+    println!("🆕 {} is available (this is {})", release.tag_name, CURRENT_VERSION);
+    for highlight in release.highlights(8) {
+        println!("  • {}", highlight);
+    }
+    if !release.html_url.is_empty() {
+        println!("📝 Full notes: {}", release.html_url);
+    }
+    let Some(asset) = release.asset_for_platform() else {
+        println!(
+            "No prebuilt binary for {}-{}; build from source with `cargo install`.",
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        );
+        return Ok(());
+    };
+    if !install {
+        println!("⬇️  Run `wordle update --install` to replace this binary with {}", asset.name);
+        return Ok(());
+    }
+    let path = install_release(asset, &http).await?;
+    println!("✅ Installed {} to {}", release.tag_name, path.display());
+    Ok(())
+}
+
+/// List the riskiest answer families with the guesses that split each one
+/// The best single openers, or with `pair` the best fixed pairs of first two guesses
+async fn rank_openers(pair: bool, top: usize, tag: Option<&str>) -> Result<()> {
+    let app_service = container_for(tag).create_application_service().await?;
+    let answers = app_service.possible_words(None).len();
+    if !pair {
+        println!(
+            "🚪 Openers leaving the fewest of {} answers on average:",
+            answers
+        );
+        for (i, (word, remaining)) in app_service.single_probes(top).iter().enumerate() {
+            println!(
+                "{:>3}. {}  {:.1} left",
+                i + 1,
+                word.as_str().to_uppercase(),
+                remaining
+            );
+        }
+        return Ok(());
+    }
+
+    println!(
+        "🚪 Fixed opening pairs leaving the fewest of {} answers on average",
+        answers
+    );
+    println!(
+        "   (first guesses from the {} best single openers)",
+        PAIR_SHORTLIST
+    );
+    for (i, pair) in app_service.probe_pairs(top).iter().enumerate() {
+        println!(
+            "{:>3}. {} + {}  {:.2} left (first alone {:.1})",
+            i + 1,
+            pair.first.as_str().to_uppercase(),
+            pair.second.as_str().to_uppercase(),
+            pair.expected_remaining,
+            pair.first_expected_remaining
+        );
+    }
+    Ok(())
+}
+
+/// Anneal over openers with full games and list the best found with 95% intervals
+async fn search_openers(config: &OpenerSearchConfig, tag: Option<&str>) -> Result<()> {
+    let app_service = container_for(tag).create_application_service().await?;
+    println!(
+        "🔬 Searching openers by simulated annealing (experimental): {} steps, {} answers each...",
+        config.steps, config.sample
+    );
+    let started = Instant::now();
+    let report = app_service.search_openers(config)?;
+    println!(
+        "   From {}: {} openers played in {} games ({:.1}s), {} of {} moves taken",
+        report.start.opener.as_str().to_uppercase(),
+        report.openers_played,
+        report.games_played,
+        started.elapsed().as_secs_f64(),
+        report.accepted,
+        report.steps
+    );
+    println!(
+        "🏁 Best openers replayed on {} answers (guesses per game, 95% interval):",
+        report.start.games
+    );
+    for (i, estimate) in report.best.iter().enumerate() {
+        println!("{:>3}. {}", i + 1, describe_estimate(estimate));
+    }
+    println!("   Solver's own: {}", describe_estimate(&report.start));
+    println!("   Repeat with --seed {}", report.seed);
+    Ok(())
+}
+
+fn describe_estimate(estimate: &OpenerEstimate) -> String {
+    let (low, high) = estimate.interval();
+    format!(
+        "{}  {:.3} ± {:.3} ({:.3}–{:.3}), {:.1}% won",
+        estimate.opener.as_str().to_uppercase(),
+        estimate.mean_guesses,
+        estimate.margin,
+        low,
+        high,
+        estimate.win_rate() * 100.0
+    )
+}
+
+async fn report_traps(
+    min_size: usize,
+    guesses: usize,
+    limit: usize,
+    tag: Option<&str>,
+) -> Result<()> {
+    let container = container_for(tag);
+    let app_service = container.create_application_service().await?;
+    let traps = app_service.trap_clusters(min_size, guesses);
+    if traps.is_empty() {
+        println!("🪤 No answer families of {} or more words", min_size.max(3));
+        return Ok(());
+    }
+
+    let trapped: usize = traps.iter().map(|trap| trap.family.len()).sum();
+    println!(
+        "🪤 {} trap families holding {} answers (showing {}):",
+        traps.len(),
+        trapped,
+        traps.len().min(limit)
+    );
+    for trap in traps.iter().take(limit) {
+        let words: Vec<&str> = trap.family.words.iter().map(Word::as_str).collect();
+        println!(
+            "   {} ({}): {}",
+            trap.family.pattern,
+            trap.family.len(),
+            words.join(", ")
+        );
+        if trap.guesses.is_empty() {
+            println!("      no single guess covers two of their differing letters");
+        }
+        for guess in &trap.guesses {
+            println!(
+                "      🔀 {}  checks {} of {} · worst case {}",
+                guess.word.as_str().to_uppercase(),
+                guess.covered.iter().collect::<String>(),
+                guess.distinguishing.iter().collect::<String>(),
+                guess.quality.worst_case_remaining
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Words listed with their neighbours per group before the rest are summarised
+const GRAPH_ROWS_PER_GROUP: usize = 12;
+
+/// Print the one-letter-change groups among the words left after `guesses`
+async fn show_word_graph(
+    guesses: Vec<(Word, FeedbackPattern)>,
+    limit: usize,
+    tag: Option<&str>,
+) -> Result<()> {
+    let container = container_for(tag);
+    let mut app_service = container.create_application_service().await?;
+    if !guesses.is_empty() {
+        app_service.execute(Command::AddGuessBatch { guesses })?;
+    }
+
+    let report = app_service.word_graph(limit);
+    println!(
+        "🕸️  {} words, {} one-letter links: {} in {} groups, {} stand alone ({:.0}% entangled)",
+        report.words,
+        report.edges,
+        report.words - report.isolated,
+        report.groups.len(),
+        report.isolated,
+        report.entanglement() * 100.0
+    );
+    for (index, group) in report.groups.iter().take(limit).enumerate() {
+        println!();
+        println!("#{} · {} words, {} links", index + 1, group.len(), group.edges);
+        if !group.articulation.is_empty() {
+            let cuts: Vec<String> =
+                group.articulation.iter().map(|w| w.as_str().to_uppercase()).collect();
+            println!("   ✂️  Holding it together: {}", cuts.join(", "));
+        }
+        if let Some(breaker) = &group.breaker {
+            println!(
+                "   🔀 Breaks fastest: {} · worst case {} · {:.2} bits",
+                breaker.word.as_str().to_uppercase(),
+                breaker.quality.worst_case_remaining,
+                breaker.quality.entropy
+            );
+        }
+        // Best-connected words first; they show the group's shape
+        let graph = WordGraph::build(&group.words);
+        let mut rows: Vec<(&Word, Vec<&Word>)> =
+            group.words.iter().map(|w| (w, graph.neighbours(w))).collect();
+        rows.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(b.0)));
+        for (word, neighbours) in rows.iter().take(GRAPH_ROWS_PER_GROUP) {
+            let neighbours: Vec<&str> = neighbours.iter().map(|w| w.as_str()).collect();
+            println!("   {} ─ {}", word.as_str(), neighbours.join(" "));
+        }
+        if rows.len() > GRAPH_ROWS_PER_GROUP {
+            println!("   … and {} more", rows.len() - GRAPH_ROWS_PER_GROUP);
+        }
+    }
+    if report.groups.len() > limit {
+        println!();
+        let hidden = report.groups.len() - limit;
+        println!("ℹ️  {} smaller groups not shown (raise --limit)", hidden);
+    }
+    Ok(())
+}
+
+/// List the guesses that fit each row of a shared grid for `answer`
+async fn reverse_share(
+    answer: &Word,
+    grid: Option<String>,
+    hard_mode: bool,
+    limit: usize,
+    tag: Option<&str>,
+) -> Result<()> {
+    let text = match grid {
+        Some(text) => text,
+        None => std::io::read_to_string(std::io::stdin())?,
+    };
+    let grid = ShareGrid::parse(&text)?;
+    let mut provider = container_for(tag).create_word_list_provider()?;
+    provider.load_words().await?;
+    let rows = infer_guesses(
+        &grid,
+        answer,
+        provider.get_answer_words(),
+        provider.get_guess_words(),
+        hard_mode,
+    );
+
+    println!(
+        "🔍 Guesses behind this {} grid for {}{}:",
+        if grid.is_solved() { "solved" } else { "unsolved" },
+        answer.as_str().to_uppercase(),
+        if hard_mode { " (hard mode)" } else { "" }
+    );
+    for (i, row) in rows.iter().enumerate() {
+        if row.is_empty() {
+            println!("   {} {}  ⚠️ no word scores this against the answer", i + 1, row.feedback);
+            continue;
+        }
+        let words: Vec<String> = row
+            .answer_guesses
+            .iter()
+            .map(|w| w.as_str().to_uppercase())
+            .chain(row.other_guesses.iter().map(|w| w.as_str().to_string()))
+            .take(limit)
+            .collect();
+        let more = row.len().saturating_sub(limit);
+        println!(
+            "   {} {}  {} fit, {} of them answers: {}{}",
+            i + 1,
+            row.feedback,
+            row.len(),
+            row.answer_guesses.len(),
+            words.join(", "),
+            if more > 0 { format!(" +{} more", more) } else { String::new() }
+        );
+    }
+    println!("   Answer words are in capitals and listed first.");
+    Ok(())
+}
+
+/// Compare proposed answers: solver guesses, trap families and letter rarity
+async fn analyze_answers(words: &[Word], tag: Option<&str>) -> Result<()> {
+    let container = container_for(tag);
+    let mut app_service = container.create_application_service().await?;
+
+    let mut reports = Vec::with_capacity(words.len());
+    for word in words {
+        reports.push(app_service.analyze_answer(word)?);
+    }
+    reports.sort_by(|a, b| a.compare_difficulty(b));
+
+    println!("✍️  Answer difficulty, hardest first:");
+    println!("   {:<7}{:<9}{:<8}TRAP FAMILIES", "WORD", "GUESSES", "RARITY");
+    for report in &reports {
+        let guesses = report
+            .solver_guesses
+            .map_or_else(|| "failed".to_string(), |n| n.to_string());
+        let families: Vec<String> = report
+            .families
+            .iter()
+            .map(|family| format!("{} ({})", family.pattern, family.len()))
+            .collect();
+        let families = if families.is_empty() {
+            "-".to_string()
+        } else {
+            families.join(", ")
+        };
+        println!(
+            "   {:<7}{:<9}{:<8}{}{}",
+            report.word.as_str(),
+            guesses,
+            format!("{:.0}%", report.letter_rarity * 100.0),
+            families,
+            if report.in_answer_list {
+                ""
+            } else {
+                "  ⚠️ not in the answer list"
+            }
+        );
+    }
+    Ok(())
+}
+
+/// Lowercase letters of `text`, refusing anything else
+fn parse_letters(text: &str) -> Result<Vec<u8>> {
+    let letters = text.trim().to_lowercase();
+    if let Some(bad) = letters.chars().find(|c| !c.is_ascii_lowercase()) {
+        anyhow::bail!("'{}' is not a letter", bad);
+    }
+    Ok(letters.into_bytes())
+}
+
+/// Answers of the daily puzzles of the last `days` days that the archive knows
+async fn recent_answers(days: u32) -> Result<BTreeSet<Word>> {
+    let archive = PuzzleArchive::load().await?;
+    let Some(today) = PuzzleArchive::todays_number() else {
+        return Ok(BTreeSet::new());
+    };
+    Ok((today.saturating_sub(days)..=today)
+        .filter_map(|number| archive.answer(number).ok().cloned())
+        .collect())
+}
+
+/// Draw targets for `constraints` and write them to a pack at `out`
+async fn generate_pack(
+    constraints: &PackConstraints,
+    seed: Option<u64>,
+    name: &str,
+    out: &std::path::Path,
+    tag: Option<&str>,
+) -> Result<()> {
+    let seed = seed.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0)
+    });
+    let mut app_service = container_for(tag).create_application_service().await?;
+    if constraints.difficulty.is_some() {
+        println!("🔮 Forecasting candidates with the solver...");
+    }
+    let targets = generate_targets(&mut app_service, constraints, seed)?;
+    if targets.is_empty() {
+        anyhow::bail!("No answers fit: {}", constraints.describe());
+    }
+
+    let words: Vec<Word> = targets.iter().map(|t| t.word.clone()).collect();
+    let pack = PuzzlePack::new(name, &words).with_description(constraints.describe());
+    pack.save(out)?;
+    println!("🎲 {} ({}, seed {})", pack.name, constraints.describe(), seed);
+    let reveal = RevealPolicy::from_env();
+    for (i, target) in targets.iter().enumerate() {
+        let difficulty = target
+            .difficulty
+            .map(|d| format!(" ({})", d.label()))
+            .unwrap_or_default();
+        let word = reveal.answer(target.word.as_str());
+        println!("  {:>2}. {}{}", i + 1, word, difficulty);
+    }
+    if !reveal.is_revealed() {
+        println!("   Answers are masked; run with --reveal to list them in full");
+    }
+    if targets.len() < constraints.count {
+        println!("⚠️  Only {} of {} puzzles fit the constraints", targets.len(), constraints.count);
+    }
+    println!("💾 Wrote {}", out.display());
+    Ok(())
+}
+
+/// Play the puzzles of the pack in `file` in the TUI
+async fn play_pack(file: &std::path::Path, player: Option<&str>, tag: Option<&str>) -> Result<()> {
+    let pack = PuzzlePack::load(file)?;
+    if let Some(tag) = tag {
+        std::env::set_var(WORDLIST_TAG_ENV, tag);
+    }
+    run_tui_pack(player, pack).await
+}
+
+/// Print `player`'s best result on each puzzle of the pack in `file`
+async fn show_pack_stats(file: &std::path::Path, player: &str) -> Result<()> {
+    let pack = PuzzlePack::load(file)?;
+    let store = StatisticsStore::open_default().await?;
+    let progress = PackProgress::new(&pack, store.pack_results(player, &pack.name));
+
+    println!("📦 {} - {} puzzles", pack.name, progress.total);
+    if let Some(description) = &pack.description {
+        println!("   {}", description);
+    }
+    for index in 0..progress.total {
+        let result = match progress.results.get(&index) {
+            Some(ArchiveResult::Solved(guesses)) => format!("solved in {}", guesses),
+            Some(ArchiveResult::Failed) => "not solved".to_string(),
+            None => "not played".to_string(),
+        };
+        println!("  {:>2}. {}", index + 1, result);
+    }
+    let average = progress
+        .average_guesses()
+        .map(|avg| format!(", {:.1} guesses on average", avg))
+        .unwrap_or_default();
+    println!("✅ {}/{} solved{}", progress.solved(), progress.total, average);
+    Ok(())
+}
+
+/// Replay a recorded command script against a fresh game and report divergences
+async fn run_script(file: &std::path::Path, tag: Option<&str>) -> Result<()> {
+    let script = CommandScript::load(file)?;
+    // Don't record the replay, least of all over the script being replayed
+    let mut config = container_for(tag).config().clone();
+    config.record_script = None;
+    let mut app_service = Container::with_config(config)
+        .create_application_service()
+        .await?;
+
+    println!("📜 Replaying {} steps from {}", script.steps.len(), file.display());
+    let report = script.replay(&mut app_service)?;
+    for (i, step) in report.steps.iter().enumerate() {
+        match (&step.expected, step.diverged()) {
+            (Some(expected), true) => println!(
+                "  {}. {} ❌ expected {}, got {}",
+                i + 1,
+                step.name,
+                expected,
+                step.actual
+            ),
+            (Some(_), false) => println!("  {}. {} ✅ {}", i + 1, step.name, step.actual),
+            (None, _) => println!("  {}. {} ➖ {}", i + 1, step.name, step.actual),
+        }
+    }
+
+    let diverged = report.divergences().count();
+    if diverged > 0 {
+        anyhow::bail!("{} of {} steps diverged", diverged, report.steps.len());
+    }
+    println!("🎉 All steps matched");
+    Ok(())
+}
+
+/// Default container, pinned to the word-list snapshot `tag` when given
+fn container_for(tag: Option<&str>) -> Container {
+    if let Some(tag) = tag {
+        println!("📌 Using word-list snapshot '{}'", tag);
+    }
+    let mut config = DependencyConfig::default();
+    config.word_list_config.snapshot_tag = tag.map(str::to_string);
+    Container::with_config(config)
+}
+
+/// Snapshot the lists currently loaded (the cache, or the pinned snapshot `from`)
+async fn freeze_word_lists(tag: &str, from: Option<&str>) -> Result<()> {
+    let mut provider = container_for(from).create_file_word_list_provider();
+    provider.load_words().await?;
+    let path = provider.freeze(tag).await?;
+    println!(
+        "🧊 Froze {} answers and {} guesses as '{}' ({})",
+        provider.get_answer_words().len(),
+        provider.get_guess_words().len(),
+        tag,
+        path.display()
+    );
+    println!("ℹ️  Pin runs to it with --wordlist-tag {}", tag);
+    Ok(())
+}
+
+/// Print the loaded lists' provenance for attribution
+async fn show_word_list_info(json: bool, from: Option<&str>) -> Result<()> {
+    let mut provider = container_for(from).create_file_word_list_provider();
+    provider.load_words().await?;
+    if json {
+        let info = serde_json::json!({
+            "answers": provider.get_answer_words().len(),
+            "guesses": provider.get_guess_words().len(),
+            "last_updated": provider.last_updated(),
+            "sources": provider.sources(),
+            "pruning": provider.pruning(),
+            "pruned": provider.pruning_report(),
+        });
+        println!("{}", serde_json::to_string_pretty(&info)?);
+        return Ok(());
+    }
+
+    println!(
+        "📚 {} answers, {} guesses",
+        provider.get_answer_words().len(),
+        provider.get_guess_words().len()
+    );
+    if let Some(updated) = provider.last_updated() {
+        println!("🕒 Updated {}", utc_date(updated));
+    }
+    let pruning = provider.pruning();
+    if pruning.is_enabled() {
+        let pruned = provider.pruning_report();
+        let step = |on: bool, count: usize| {
+            if on {
+                count.to_string()
+            } else {
+                "off".to_string()
+            }
+        };
+        println!(
+            "✂️ Pruned {} answers: plurals {}, past tense {}, proper nouns {}, \
+             frequency floor {}",
+            pruned.total(),
+            step(pruning.plurals, pruned.plurals),
+            step(pruning.past_tense, pruned.past_tense),
+            step(pruning.proper_nouns, pruned.proper_nouns),
+            step(pruning.frequency_floor > 0.0, pruned.below_floor)
+        );
+    } else {
+        println!("✂️ Answer pruning off");
+    }
+    if provider.sources().is_empty() {
+        println!("No source details recorded; refresh with `update-words --force` to add them.");
+        return Ok(());
+    }
+    println!("🔗 Sources:");
+    for source in provider.sources() {
+        println!(
+            "  {:<8} {} ({} words, {}, license: {})",
+            source.list.name(),
+            source.url,
+            source.words,
+            utc_date(source.downloaded_at),
+            source.license.as_deref().unwrap_or("unknown")
+        );
+    }
+    Ok(())
+}
+
+/// Format a Unix timestamp as a UTC calendar date (YYYY-MM-DD)
+fn utc_date(secs: u64) -> String {
+    // Days-to-civil conversion from Howard Hinnant's date algorithms
+    let z = (secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Answer line requests on stdin/stdout, for `interactive` without a terminal
+///
+/// Notes go to stderr so stdout carries nothing but replies.
+async fn run_pipe_mode(stream: &str, sessions_file: Option<std::path::PathBuf>) -> Result<()> {
+    eprintln!(
+        "ℹ️  {} is not a terminal, so the TUI can't start; reading one request per line \
+         instead (send `help` for the list)",
+        stream
+    );
+    let settings = UserSettings::load_default();
+    let guard = settings.server.as_ref().map(AccessGuard::from_config);
+    let container = Container::with_config(settings.dependency_config());
+    let mut app_service = container.create_application_service().await?;
+    app_service.execute(Command::StartGame { target_word: None })?;
+
+    // `session new` builds another service from the same config; the pipe reads
+    // stdin synchronously, so wait for it on this worker thread
+    let runtime = tokio::runtime::Handle::current();
+    let mut sessions = PipeSessions::new(DEFAULT_SESSION_TTL, move || {
+        let mut service = tokio::task::block_in_place(|| {
+            runtime.block_on(container.create_application_service())
+        })?;
+        service.execute(Command::StartGame { target_word: None })?;
+        Ok(service)
+    });
+    let mut sessions_file = sessions_file.map(SessionFile::new);
+    if let Some(file) = sessions_file.as_mut() {
+        if file.exists().await {
+            sessions.restore(file.load().await?)?;
+            eprintln!("ℹ️  Restored {} sessions", sessions.len());
+        }
+    }
+    run_pipe(
+        &mut app_service,
+        &mut sessions,
+        guard.as_ref(),
+        io::stdin().lock(),
+        io::stdout().lock(),
+    )?;
+    if let Some(file) = sessions_file.as_mut() {
+        file.save(&sessions.snapshot()).await?;
+    }
+    Ok(())
+}
+
+/// Search each strategy's opener over the loaded lists and write them out as a warm cache
+async fn build_warm_cache(out: Option<std::path::PathBuf>, from: Option<&str>) -> Result<()> {
+    let container = container_for(from);
+    let mut provider = container.create_file_word_list_provider();
+    provider.load_words().await?;
+    let answers = provider.get_answer_words().to_vec();
+    let mut candidates = answers.clone();
+    candidates.extend(provider.get_guess_words().iter().cloned());
+    candidates.sort();
+    candidates.dedup();
+
+    let mut first_guesses = BTreeMap::new();
+    for strategy_type in StrategyType::ALL {
+        let started = Instant::now();
+        let config = DependencyConfig {
+            strategy_type,
+            ..container.config().clone()
+        };
+        let strategy = Container::with_config(config).create_strategy().await?;
+        let opener = strategy.get_best_guess(&answers, &candidates)?;
+        println!(
+            "🥇 {:<9} opens with {} ({:.1}s)",
+            strategy_type.name(),
+            opener,
+            started.elapsed().as_secs_f64()
+        );
+        first_guesses.insert(strategy_type.name().to_string(), opener.to_string());
+    }
+
+    let path = out.unwrap_or_else(FileWordListProvider::default_warm_cache_path);
+    provider.write_warm_cache(&path, first_guesses).await?;
+    println!(
+        "🔥 Wrote {} answers and {} guesses to {}",
+        answers.len(),
+        provider.get_guess_words().len(),
+        path.display()
+    );
+    println!("ℹ️  Ship it next to the binary (or point {} at it)", WARM_CACHE_ENV);
+    Ok(())
+}
+
+fn list_word_list_snapshots() {
+    let tags = FileWordListProvider::list_snapshots();
+    if tags.is_empty() {
+        println!("No word-list snapshots yet. Create one with `wordlist freeze --tag <TAG>`.");
+        return;
+    }
+    println!("🧊 Word-list snapshots:");
+    for tag in tags {
+        println!("  {}", tag);
+    }
+}
+
+async fn show_stats(player: Option<&str>) -> Result<()> {
+    let store = StatisticsStore::open_default().await?;
+    let stats = store.player_stats(&resolve_player(player));
+
+    println!("📊 Statistics for {}", stats.player);
+    if stats.games_played == 0 {
+        println!("No games recorded yet.");
+        return Ok(());
+    }
+    println!(
+        "🎮 Played: {}  ✅ Win %: {:.0}  🔥 Current streak: {}  🏆 Max streak: {}",
+        stats.games_played,
+        stats.win_rate(),
+        stats.current_streak,
+        stats.max_streak
+    );
+    let (think, solver) = (stats.average_think_time(), stats.average_solver_time());
+    if think.is_some() || solver.is_some() {
+        let show = |time: Option<Duration>| time.map_or("-".to_string(), format_turn_time);
+        println!(
+            "⏱️  Avg. think time: {}  🤖 Avg. solver time: {}",
+            show(think),
+            show(solver)
+        );
+    }
+    println!();
+    println!("Guess distribution:");
+    print!(
+        "{}",
+        chart::render_guess_distribution(&stats, chart::terminal_width())
+    );
+
+    let archive = store.archive_results(&stats.player);
+    if !archive.is_empty() {
+        println!();
+        println!("🗄️  Archive puzzles:");
+        for (number, result) in archive {
+            let result = match result {
+                ArchiveResult::Solved(guesses) => format!("solved in {}", guesses),
+                ArchiveResult::Failed => "not solved yet".to_string(),
+            };
+            println!("   #{:<6} {}", number, result);
+        }
+    }
+    Ok(())
+}
+
+async fn show_leaderboard() -> Result<()> {
+    let store = StatisticsStore::open_default().await?;
+    let board = store.leaderboard();
+    if board.is_empty() {
+        println!("No games recorded yet.");
+        return Ok(());
+    }
+
+    println!("🏆 Leaderboard");
+    println!(
+        "{:>3}  {:<16} {:>6} {:>5} {:>6} {:>7} {:>7}",
+        "#", "Player", "Played", "Won", "Win %", "Avg", "Streak"
+    );
+    for (rank, stats) in board.iter().enumerate() {
+        println!("{:>3}  {}", rank + 1, format_leaderboard_row(stats));
+    }
+    Ok(())
+}
+
+async fn import_stats(file: &std::path::Path, player: Option<&str>) -> Result<()> {
+    let player = resolve_player(player);
+    let baseline = NytStatistics::load(file)?.into_baseline(&player)?;
+    let mut store = StatisticsStore::open_default().await?;
+    let replaced = store.baseline(&player).is_some();
+    println!(
+        "📥 Imported {} games ({} won, max streak {}) for {}",
+        baseline.games_played, baseline.games_won, baseline.max_streak, player
+    );
+    store.set_baseline(baseline);
+    store.save().await?;
+    if replaced {
+        println!("ℹ️  Replaced the previous import for {}", player);
+    }
+    Ok(())
+}
+
+/// Keep the words `player` guessed in recorded games out of the suggestions
+async fn avoid_used_words(app_service: &mut WordleApplicationService, player: &str) -> Result<()> {
+    let store = StatisticsStore::open_default().await?;
+    let words: Vec<Word> = store
+        .used_words(player)
+        .iter()
+        .filter_map(|w| Word::from_str(w).ok())
+        .collect();
+    println!("🚫 Skipping {} words {} guessed in earlier games", words.len(), player);
+    app_service.set_excluded_words(words)?;
+    Ok(())
+}
+
+async fn export_game(
+    player: Option<&str>,
+    format: ExportFormat,
+    share_format: Option<ShareFormat>,
+    game: usize,
+) -> Result<()> {
+    let player = resolve_player(player);
+    let store = StatisticsStore::open_default().await?;
+    let games: Vec<&GameRecord> = store
+        .games()
+        .iter()
+        .filter(|record| record.player == player)
+        .collect();
+    if games.is_empty() {
+        anyhow::bail!("{} has no recorded games to export", player);
+    }
+    let Some(record) = game
+        .checked_sub(1)
+        .and_then(|back| games.iter().rev().nth(back))
+    else {
+        anyhow::bail!(
+            "{} has {} recorded games; pick one with -n 1 to {}",
+            player,
+            games.len(),
+            games.len()
+        );
+    };
+    let text = match share_format {
+        Some(share_format) => record.share_text(share_format)?,
+        None => record.export(format)?,
+    };
+    println!("{}", text);
+    Ok(())
+}
+
+async fn used_words(player: Option<&str>, clear: bool) -> Result<()> {
+    let player = resolve_player(player);
+    let mut store = StatisticsStore::open_default().await?;
+    let words = store.used_words(&player);
+    if clear {
+        store.clear_used_words(&player);
+        store.save().await?;
+        println!("🧹 Cleared {} used words for {}", words.len(), player);
+        return Ok(());
+    }
+    if words.is_empty() {
+        println!("No used words for {}.", player);
+        return Ok(());
+    }
+    println!("📚 {} words guessed by {}:", words.len(), player);
+    let words: Vec<String> = words.into_iter().collect();
+    for row in words.chunks(10) {
+        println!("  {}", row.join(" "));
+    }
+    Ok(())
+}
+
+/// Fewer guesses per win another established opener needs before it's recommended
+const OPENER_GAIN_GUESSES: f64 = 0.25;
+/// More first-guess information the solver's opener needs before it's recommended
+const OPENER_GAIN_BITS: f64 = 0.2;
+
+/// The player's openers with their results and first-guess information, and advice
+///
+/// Information is measured against the full answer list, as at the start of a game.
+/// Another of the player's openers is only recommended from results once it and the
+/// usual opener both have [`OPENER_MIN_GAMES`] games; the solver's opener is
+/// recommended when it brings clearly more information than the usual one.
+async fn opener_report(player: Option<&str>, tag: Option<&str>) -> Result<()> {
+    let player = resolve_player(player);
+    let store = StatisticsStore::open_default().await?;
+    let openers = store.opener_stats(&player);
+    println!("🚪 Openers used by {}", player);
+    let Some(usual) = openers.first() else {
+        println!("No games with recorded guesses yet.");
+        return Ok(());
+    };
+
+    let app_service = container_for(tag).create_application_service().await?;
+    let bits = |word: &str| {
+        let word = Word::from_str(word).ok()?;
+        Some(app_service.suggestion_quality(&word).entropy)
+    };
+    let show = |value: Option<f64>| value.map_or("-".to_string(), |v| format!("{:.2}", v));
+    println!(
+        "{:<7} {:>5} {:>6} {:>6} {:>6}",
+        "Opener", "Games", "Win %", "Avg.", "Bits"
+    );
+    for opener in &openers {
+        println!(
+            "{:<7} {:>5} {:>6.0} {:>6} {:>6}",
+            opener.word.to_uppercase(),
+            opener.games,
+            opener.win_rate(),
+            show(opener.average_guesses()),
+            show(bits(&opener.word))
+        );
+    }
+    println!();
+
+    let mut advised = false;
+    let average = |o: &OpenerStats| o.average_guesses().unwrap_or(f64::INFINITY);
+    let best = openers
+        .iter()
+        .filter(|o| o.is_established())
+        .min_by(|a, b| average(a).total_cmp(&average(b)));
+    if let Some(best) = best.filter(|best| {
+        usual.is_established() && average(usual) - average(best) >= OPENER_GAIN_GUESSES
+    }) {
+        println!(
+            "📈 {} has needed {:.2} guesses per win against {} for {}, your usual opener",
+            best.word.to_uppercase(),
+            average(best),
+            show(usual.average_guesses()),
+            usual.word.to_uppercase()
+        );
+        advised = true;
+    }
+    let solver_opener = app_service.get_best_first_guess()?;
+    let solver_bits = app_service.suggestion_quality(&solver_opener).entropy;
+    if let Some(usual_bits) = bits(&usual.word).filter(|b| solver_bits - b >= OPENER_GAIN_BITS) {
+        println!(
+            "🧠 The solver opens with {}: {:.2} bits against {:.2} for {}, so switching should \
+             leave fewer words after the first guess",
+            solver_opener.as_str().to_uppercase(),
+            solver_bits,
+            usual_bits,
+            usual.word.to_uppercase()
+        );
+        advised = true;
+    }
+    if !advised {
+        println!(
+            "✅ {} holds up - no reason to switch",
+            usual.word.to_uppercase()
+        );
+    }
+    if !usual.is_established() {
+        println!(
+            "ℹ️  Results are compared once an opener has {} games",
+            OPENER_MIN_GAMES
+        );
+    }
+    Ok(())
+}
+
+fn format_leaderboard_row(stats: &PlayerStats) -> String {
+    let average = stats
+        .average_guesses()
+        .map(|avg| format!("{:.2}", avg))
+        .unwrap_or_else(|| "-".to_string());
+    format!(
+        "{:<16} {:>6} {:>5} {:>6.0} {:>7} {:>3}/{:<3}",
+        stats.player,
+        stats.games_played,
+        stats.games_won,
+        stats.win_rate(),
+        average,
+        stats.current_streak,
+        stats.max_streak
+    )
+}
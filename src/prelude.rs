@@ -0,0 +1,26 @@
+//! Commonly used types in one import
+//!
+//! `use wordle::prelude::*;` brings in words and feedback patterns, the solver builder
+//! and the [`stable`](crate::stable) facade, the built-in strategies, and the
+//! application commands. Everything else stays under its layer module.
+//!
+//! ```no_run
+//! use wordle::prelude::*;
+//!
+//! # async fn demo() -> Result<(), WordleError> {
+//! let solver = WordleSolverBuilder::new().build().await?;
+//! let opener = solver.get_best_guess()?;
+//! let pattern = FeedbackPattern::from_code_string("01020").expect("five digits 0-2");
+//! println!("open with {}, pattern #{}", opener, pattern.index());
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::application::{Command, CommandExecutor, CommandResult, WordleApplicationService};
+pub use crate::core::container::{Container, StrategyType};
+pub use crate::core::error::WordleError;
+pub use crate::core::traits::{SolvingStrategy, WordleSolver};
+pub use crate::core::types::{Feedback, FeedbackPattern, Guess, Word};
+pub use crate::domain::{DefaultWordleSolver, WordleSolverBuilder};
+pub use crate::infrastructure::{EntropyBasedStrategy, FrequencyBasedStrategy, HybridStrategy};
+pub use crate::stable::SolverHandle;
@@ -0,0 +1,75 @@
+//! Plain-text charts for terminal-only output
+
+use crate::infrastructure::statistics::PlayerStats;
+
+/// Width used when the terminal size can't be determined
+pub const DEFAULT_CHART_WIDTH: usize = 80;
+
+/// Current terminal width, falling back to `COLUMNS` and then [`DEFAULT_CHART_WIDTH`]
+pub fn terminal_width() -> usize {
+    #[cfg(feature = "tui")]
+    let detected = crossterm::terminal::size()
+        .ok()
+        .map(|(cols, _)| cols as usize)
+        .filter(|&cols| cols > 0);
+    #[cfg(not(feature = "tui"))]
+    let detected = None;
+
+    detected
+        .or_else(|| std::env::var("COLUMNS").ok()?.parse().ok())
+        .unwrap_or(DEFAULT_CHART_WIDTH)
+}
+
+/// Render the classic guess distribution (rows 1–6 plus X for losses) as ASCII bars
+/// scaled so the longest row fits in `width` columns
+pub fn render_guess_distribution(stats: &PlayerStats, width: usize) -> String {
+    let losses = stats.games_played.saturating_sub(stats.games_won);
+    let rows: Vec<(String, u32)> = stats
+        .guess_distribution
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| ((i + 1).to_string(), count))
+        .chain(std::iter::once(("X".to_string(), losses)))
+        .collect();
+
+    let max = rows.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    let count_width = max.to_string().len();
+    // "N | " prefix and " count" suffix
+    let bar_width = width.saturating_sub(4 + 1 + count_width).max(1);
+
+    let mut out = String::new();
+    for (label, count) in rows {
+        let len = if max == 0 {
+            0
+        } else {
+            // Any non-zero row gets at least one mark
+            ((count as usize * bar_width) / max as usize).max((count > 0) as usize)
+        };
+        let bar = "#".repeat(len);
+        let gap = if bar.is_empty() { "" } else { " " };
+        out.push_str(&format!("{} | {}{}{}\n", label, bar, gap, count));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bars_scale_to_width() {
+        let mut stats = PlayerStats::new("alice");
+        stats.games_played = 12;
+        stats.games_won = 11;
+        stats.guess_distribution = [0, 1, 10, 0, 0, 0];
+
+        let chart = render_guess_distribution(&stats, 20);
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], "1 | 0");
+        assert_eq!(lines[1], "2 | # 1");
+        assert_eq!(lines[2], format!("3 | {} 10", "#".repeat(13)));
+        assert_eq!(lines[6], "X | # 1");
+        assert!(lines.iter().all(|line| line.len() <= 20));
+    }
+}
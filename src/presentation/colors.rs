@@ -0,0 +1,127 @@
+//! How much color the terminal can show
+//!
+//! The TUI is drawn in the sixteen ANSI colors, and the light theme adds an RGB
+//! shade. Terminals announced as 256-color or true-color show all of it; basic
+//! ones (the Linux console, plain `xterm`, legacy Windows consoles) get RGB and
+//! indexed colors rounded to the nearest of the sixteen; and under the `NO_COLOR`
+//! convention (<https://no-color.org>) or `TERM=dumb` no color is sent at all, so
+//! feedback tiles are told apart by reverse video, bold and underline instead.
+//! [`ColorSupport::detect`] reads `COLORTERM` and the terminfo name in `TERM`;
+//! `WORDLE_COLORS=full`, `16` or `none` overrides the guess.
+
+/// Environment variable forcing `full`, `16` or `none`
+pub const COLORS_ENV: &str = "WORDLE_COLORS";
+
+/// Parts of terminfo names for terminals with more than sixteen colors
+const WIDE_PALETTE_TERMS: [&str; 3] = ["256color", "direct", "truecolor"];
+
+/// The colors the terminal can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSupport {
+    /// The 256-color palette and RGB
+    #[default]
+    Full,
+    /// The sixteen ANSI colors only
+    Basic,
+    /// No color: attributes only
+    Monochrome,
+}
+
+impl ColorSupport {
+    /// Work out the support from this process's environment
+    pub fn detect() -> Self {
+        Self::from_env(|name| std::env::var(name).ok(), cfg!(windows))
+    }
+
+    /// Work out the support from environment variables looked up with `var`
+    ///
+    /// `NO_COLOR` counts when it is set to anything but an empty string. A
+    /// `COLORTERM` of `truecolor` or `24bit`, or a `TERM` naming a 256-color or
+    /// direct-color terminfo entry, gets full color; any other `TERM` gets the
+    /// sixteen colors. Without `TERM`, Windows hosts that announce themselves are
+    /// trusted with full color and anything else gets the sixteen.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>, windows: bool) -> Self {
+        let var = |name: &str| var(name).filter(|value| !value.trim().is_empty());
+        match var(COLORS_ENV)
+            .map(|v| v.trim().to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("full") => return Self::Full,
+            Some("16") => return Self::Basic,
+            Some("none") => return Self::Monochrome,
+            _ => {}
+        }
+        if var("NO_COLOR").is_some() {
+            return Self::Monochrome;
+        }
+        let term = var("TERM").map(|term| term.to_ascii_lowercase());
+        if term.as_deref() == Some("dumb") {
+            return Self::Monochrome;
+        }
+        let colorterm = var("COLORTERM").map(|c| c.to_ascii_lowercase());
+        if matches!(colorterm.as_deref(), Some("truecolor" | "24bit")) {
+            return Self::Full;
+        }
+        match term {
+            Some(term) if WIDE_PALETTE_TERMS.iter().any(|t| term.contains(t)) => Self::Full,
+            Some(_) => Self::Basic,
+            None if windows => {
+                let modern_host = ["WT_SESSION", "ConEmuANSI", "TERM_PROGRAM"]
+                    .iter()
+                    .any(|name| var(name).is_some());
+                if modern_host { Self::Full } else { Self::Basic }
+            }
+            None => Self::Basic,
+        }
+    }
+
+    pub fn is_monochrome(self) -> bool {
+        self == Self::Monochrome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn support(vars: &[(&str, &str)], windows: bool) -> ColorSupport {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        ColorSupport::from_env(|name| vars.get(name).cloned(), windows)
+    }
+
+    #[test]
+    fn test_support_follows_no_color_and_terminal() {
+        assert_eq!(
+            support(&[("TERM", "xterm-256color")], false),
+            ColorSupport::Full
+        );
+        assert_eq!(
+            support(&[("TERM", "xterm"), ("COLORTERM", "truecolor")], false),
+            ColorSupport::Full
+        );
+        assert_eq!(support(&[("TERM", "linux")], false), ColorSupport::Basic);
+        assert_eq!(support(&[], true), ColorSupport::Basic);
+        assert_eq!(support(&[("WT_SESSION", "1")], true), ColorSupport::Full);
+        assert_eq!(
+            support(&[("TERM", "dumb")], false),
+            ColorSupport::Monochrome
+        );
+        assert_eq!(
+            support(&[("NO_COLOR", "1"), ("TERM", "xterm-256color")], false),
+            ColorSupport::Monochrome
+        );
+        // An empty NO_COLOR doesn't count
+        assert_eq!(
+            support(&[("NO_COLOR", ""), ("TERM", "xterm-256color")], false),
+            ColorSupport::Full
+        );
+        assert_eq!(
+            support(&[("WORDLE_COLORS", "16"), ("NO_COLOR", "1")], false),
+            ColorSupport::Basic
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! ASCII stand-ins for terminals that can't draw Unicode
+//!
+//! The TUI and the post-game summary are written with Unicode: rounded borders,
+//! arrows, block cursors and a few emoji. Legacy Windows consoles and some CI
+//! terminals show those as boxes or question marks, so [`GlyphSupport::detect`]
+//! looks at the environment and [`ascii_fallback`] gives each glyph a one-column
+//! ASCII replacement. `WORDLE_GLYPHS=ascii` (or `unicode`) overrides the guess.
+
+use std::borrow::Cow;
+
+/// Environment variable forcing `ascii` or `unicode` glyphs
+pub const GLYPHS_ENV: &str = "WORDLE_GLYPHS";
+
+/// What the terminal can draw
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GlyphSupport {
+    /// Box drawing, arrows and emoji
+    #[default]
+    Unicode,
+    /// Plain ASCII only
+    Ascii,
+}
+
+impl GlyphSupport {
+    /// Work out the support from this process's environment
+    pub fn detect() -> Self {
+        Self::from_env(|name| std::env::var(name).ok(), cfg!(windows))
+    }
+
+    /// Work out the support from environment variables looked up with `var`
+    ///
+    /// On Windows only hosts that announce themselves (Windows Terminal, ConEmu,
+    /// VS Code, MSYS/Cygwin shells setting `TERM`) are trusted with Unicode; the
+    /// legacy console sets none of them. Elsewhere the locale decides, as it does
+    /// for the C library: a locale that isn't UTF-8 gets ASCII.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>, windows: bool) -> Self {
+        let var = |name: &str| var(name).filter(|value| !value.trim().is_empty());
+        match var(GLYPHS_ENV)
+            .map(|v| v.trim().to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("ascii") => return Self::Ascii,
+            Some("unicode") => return Self::Unicode,
+            _ => {}
+        }
+        if var("TERM").as_deref() == Some("dumb") {
+            return Self::Ascii;
+        }
+        if windows {
+            let modern_host = ["WT_SESSION", "ConEmuANSI", "TERM_PROGRAM", "TERM"]
+                .iter()
+                .any(|name| var(name).is_some());
+            return if modern_host {
+                Self::Unicode
+            } else {
+                Self::Ascii
+            };
+        }
+        match ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .find_map(|name| var(name))
+        {
+            Some(locale) => {
+                let locale = locale.to_ascii_lowercase();
+                if locale.contains("utf-8") || locale.contains("utf8") {
+                    Self::Unicode
+                } else {
+                    Self::Ascii
+                }
+            }
+            None => Self::Unicode,
+        }
+    }
+
+    pub fn is_ascii(self) -> bool {
+        self == Self::Ascii
+    }
+
+    /// `text` as this terminal can show it
+    pub fn render(self, text: &str) -> Cow<'_, str> {
+        if self == Self::Unicode || text.is_ascii() {
+            return Cow::Borrowed(text);
+        }
+        let mut out = String::with_capacity(text.len());
+        let mut buffer = [0; 4];
+        for c in text.chars() {
+            let glyph: &str = c.encode_utf8(&mut buffer);
+            match ascii_fallback(glyph) {
+                Some(ascii) => out.push_str(ascii),
+                None => out.push(c),
+            }
+        }
+        Cow::Owned(out)
+    }
+}
+
+/// ASCII replacement for one glyph (as held by a terminal cell, so possibly with an
+/// emoji variation selector); `None` when it is ASCII already
+///
+/// Variation selectors on their own become empty, and glyphs without a closer
+/// match become `*` (emoji and symbols) or `?`.
+pub fn ascii_fallback(glyph: &str) -> Option<&'static str> {
+    if glyph.is_ascii() {
+        return None;
+    }
+    let Some(c) = glyph.chars().find(|&c| c != '\u{fe0f}') else {
+        return Some("");
+    };
+    Some(match c {
+        '─' | '━' | '═' | '╌' | '╍' | '—' | '–' => "-",
+        '│' | '┃' | '║' | '╎' | '╏' => "|",
+        '\u{2500}'..='\u{257f}' => "+",
+        '░' => ".",
+        '\u{2580}'..='\u{259f}' => "#",
+        '→' | '▶' | '►' | '▸' | '❯' => ">",
+        '←' | '◀' | '◄' | '◂' | '❮' => "<",
+        '↑' | '▲' | '▴' => "^",
+        '↓' | '▼' | '▾' => "v",
+        '·' | '…' => ".",
+        '•' | '●' | '◆' => "*",
+        '✓' | '✔' | '✅' => "v",
+        '✗' | '✘' | '❌' => "x",
+        '⚠' | '❗' => "!",
+        '🟩' => "G",
+        '🟨' => "Y",
+        '⬜' | '⬛' => ".",
+        '\u{2190}'..='\u{2bff}' | '\u{1f000}'.. => "*",
+        _ => "?",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn support(vars: &[(&str, &str)], windows: bool) -> GlyphSupport {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        GlyphSupport::from_env(|name| vars.get(name).cloned(), windows)
+    }
+
+    #[test]
+    fn test_support_follows_host_and_locale() {
+        assert_eq!(support(&[], true), GlyphSupport::Ascii);
+        assert_eq!(support(&[("WT_SESSION", "1")], true), GlyphSupport::Unicode);
+        assert_eq!(support(&[], false), GlyphSupport::Unicode);
+        assert_eq!(support(&[("LANG", "C")], false), GlyphSupport::Ascii);
+        assert_eq!(
+            support(&[("LC_ALL", "en_US.UTF-8"), ("LANG", "C")], false),
+            GlyphSupport::Unicode
+        );
+        assert_eq!(
+            support(&[("LANG", "C.utf8"), ("TERM", "dumb")], false),
+            GlyphSupport::Ascii
+        );
+        assert_eq!(
+            support(&[("WORDLE_GLYPHS", "unicode"), ("TERM", "dumb")], false),
+            GlyphSupport::Unicode
+        );
+    }
+
+    #[test]
+    fn test_ascii_rendering_keeps_one_column_per_glyph() {
+        let ascii = GlyphSupport::Ascii;
+        assert_eq!(ascii.render("╭─ Guess → SLATE ─╮"), "+- Guess > SLATE -+");
+        assert_eq!(ascii.render("⚠️  Careful · 3 left"), "!  Careful . 3 left");
+        assert_eq!(ascii.render("🔥 Streak"), "* Streak");
+        assert_eq!(GlyphSupport::Unicode.render("→"), "→");
+        assert_eq!(ascii_fallback("⚠️"), Some("!"));
+        assert_eq!(ascii_fallback("a"), None);
+    }
+}
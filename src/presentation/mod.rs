@@ -3,7 +3,13 @@
 //! This module contains all user interface implementations including
 //! TUI (Terminal User Interface) and potential future GUI implementations.
 
+pub mod chart;
+pub mod colors;
+pub mod glyphs;
+pub mod spoilers;
+#[cfg(feature = "tui")]
 pub mod tui;
 
 // Re-export TUI components for easy access
-pub use tui::{run_tui, TuiApp};
+#[cfg(feature = "tui")]
+pub use tui::{run_tui, run_tui_archive, run_tui_as, run_tui_pack, TuiApp};
@@ -0,0 +1,199 @@
+//! No-spoiler mode
+//!
+//! For households where someone at the screen already knows the answer, the
+//! mode keeps it from showing up by accident: the answer is masked in every
+//! status and log line, lists of possible words stay hidden until the player
+//! asks for them, and the features that play or rate the answer (the auto-solve
+//! demo and the difficulty forecast) are refused. The TUI and the CLI both ask a
+//! [`SpoilerGuard`] instead of each widget or command checking on its own.
+//!
+//! Apart from the mode, every place that would print a hidden answer in full (a lost
+//! game, the auto-solve demo, a forecast or a generated pack) asks the
+//! [`RevealPolicy`], which masks it as `C___E` until the player passes `--reveal` or
+//! confirms in the TUI.
+
+use std::borrow::Cow;
+
+/// What the answer is replaced with in text
+pub const SPOILER_MASK: &str = "?????";
+
+/// Environment variable that, set to `1`, shows answers in full; `--reveal` sets it
+pub const REVEAL_ENV: &str = "WORDLE_REVEAL";
+
+/// Features no-spoiler mode turns off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpoilerFeature {
+    /// The solver playing a hidden answer on its own
+    AutoSolve,
+    /// Rating how hard an answer is
+    Forecast,
+}
+
+impl SpoilerFeature {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::AutoSolve => "The auto-solve demo",
+            Self::Forecast => "The difficulty forecast",
+        }
+    }
+}
+
+/// What no-spoiler mode hides at the moment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SpoilerGuard<'a> {
+    enabled: bool,
+    answer: Option<&'a str>,
+    revealed: bool,
+}
+
+impl<'a> SpoilerGuard<'a> {
+    /// A guard for the mode on or off, with no answer to mask
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            ..Self::default()
+        }
+    }
+
+    /// Also mask `answer`, the target the player hasn't found yet
+    pub fn with_answer(mut self, answer: Option<&'a str>) -> Self {
+        self.answer = answer;
+        self
+    }
+
+    /// Show the possible words anyway: the player asked for them
+    pub fn with_candidates_revealed(mut self, revealed: bool) -> Self {
+        self.revealed = revealed;
+        self
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Whether lists of possible words and hints naming them are held back
+    pub fn hides_candidates(&self) -> bool {
+        self.enabled && !self.revealed
+    }
+
+    /// `text` with every occurrence of the answer, in any case, masked
+    pub fn redact<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        let Some(answer) = self.answer.filter(|a| self.enabled && !a.is_empty()) else {
+            return Cow::Borrowed(text);
+        };
+        // ASCII lowercasing keeps every byte where it was
+        let lower = text.to_ascii_lowercase();
+        let answer = answer.to_ascii_lowercase();
+        let mut masked = String::with_capacity(text.len());
+        let mut copied = 0;
+        for (at, _) in lower.match_indices(&answer) {
+            masked.push_str(&text[copied..at]);
+            masked.push_str(SPOILER_MASK);
+            copied = at + answer.len();
+        }
+        if copied == 0 {
+            return Cow::Borrowed(text);
+        }
+        masked.push_str(&text[copied..]);
+        Cow::Owned(masked)
+    }
+
+    /// Refuse `feature` with a message for the player while the mode is on
+    pub fn allow(&self, feature: SpoilerFeature) -> Result<(), String> {
+        if self.enabled {
+            Err(format!(
+                "{} is off in no-spoiler mode (turn off \"No spoilers\" in the settings)",
+                feature.name()
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Whether hidden answers may be shown in full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RevealPolicy {
+    revealed: bool,
+}
+
+impl RevealPolicy {
+    pub fn new(revealed: bool) -> Self {
+        Self { revealed }
+    }
+
+    /// Revealed when `WORDLE_REVEAL` is `1` or `true`
+    pub fn from_env() -> Self {
+        let value = std::env::var(REVEAL_ENV).unwrap_or_default();
+        Self::new(matches!(value.trim(), "1" | "true"))
+    }
+
+    /// Show answers from now on: the player confirmed
+    pub fn reveal(&mut self) {
+        self.revealed = true;
+    }
+
+    pub fn is_revealed(&self) -> bool {
+        self.revealed
+    }
+
+    /// `answer` in capitals, or masked to its first and last letters until revealed
+    pub fn answer(&self, answer: &str) -> String {
+        if self.revealed {
+            answer.to_uppercase()
+        } else {
+            mask_answer(answer)
+        }
+    }
+}
+
+/// `answer` in capitals with every letter but the first and last blanked: `C___E`
+pub fn mask_answer(answer: &str) -> String {
+    let letters: Vec<char> = answer.to_uppercase().chars().collect();
+    let last = letters.len().saturating_sub(1);
+    letters
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| if i == 0 || i == last { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guard_masks_the_answer_only_when_enabled() {
+        let text = "Out of guesses - the word was CRANE (crane twice)";
+        let guard = SpoilerGuard::new(true).with_answer(Some("crane"));
+        assert_eq!(
+            guard.redact(text),
+            "Out of guesses - the word was ????? (????? twice)"
+        );
+        assert!(matches!(guard.redact("No answer here"), Cow::Borrowed(_)));
+        assert_eq!(
+            SpoilerGuard::new(false)
+                .with_answer(Some("crane"))
+                .redact(text),
+            text
+        );
+
+        assert!(guard.hides_candidates());
+        assert!(!guard.with_candidates_revealed(true).hides_candidates());
+        assert!(guard.allow(SpoilerFeature::AutoSolve).is_err());
+        assert!(
+            SpoilerGuard::new(false)
+                .allow(SpoilerFeature::Forecast)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_reveal_policy_masks_until_revealed() {
+        let mut policy = RevealPolicy::default();
+        assert_eq!(policy.answer("crane"), "C___E");
+        policy.reveal();
+        assert_eq!(policy.answer("crane"), "CRANE");
+        assert_eq!(mask_answer(""), "");
+    }
+}
@@ -0,0 +1,86 @@
+//! Tile reveal animation for committed feedback
+//!
+//! Tiles flip one after another, one frame apart, like the real game. Frames
+//! are advanced by the app's animation tick while an animation is running.
+
+use crate::core::types::Word;
+
+/// Frames a tile spends mid-flip before its color shows
+const FLIP_FRAMES: usize = 1;
+
+/// Frames between the start of one tile's flip and the next
+const TILE_STAGGER: usize = 1;
+
+/// How a tile is drawn at the current frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TilePhase {
+    /// Letter only, no feedback color yet
+    Hidden,
+    /// Turned edge-on
+    Flipping,
+    /// Feedback color shown
+    Revealed,
+}
+
+/// Reveal of one history row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevealAnimation {
+    row: usize,
+    frame: usize,
+}
+
+impl RevealAnimation {
+    /// Start revealing history row `row`
+    pub fn new(row: usize) -> Self {
+        Self { row, frame: 0 }
+    }
+
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Move to the next frame
+    pub fn advance(&mut self) {
+        self.frame += 1;
+    }
+
+    /// Whether every tile has been revealed
+    pub fn is_finished(&self) -> bool {
+        self.frame >= (Word::LENGTH - 1) * TILE_STAGGER + FLIP_FRAMES
+    }
+
+    pub fn phase(&self, tile: usize) -> TilePhase {
+        let start = tile * TILE_STAGGER;
+        if self.frame < start {
+            TilePhase::Hidden
+        } else if self.frame < start + FLIP_FRAMES {
+            TilePhase::Flipping
+        } else {
+            TilePhase::Revealed
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiles_reveal_in_order() {
+        let mut reveal = RevealAnimation::new(2);
+        assert_eq!(reveal.phase(0), TilePhase::Flipping);
+        assert_eq!(reveal.phase(1), TilePhase::Hidden);
+
+        reveal.advance();
+        assert_eq!(reveal.phase(0), TilePhase::Revealed);
+        assert_eq!(reveal.phase(1), TilePhase::Flipping);
+
+        let mut frames = 1;
+        while !reveal.is_finished() {
+            reveal.advance();
+            frames += 1;
+        }
+        assert_eq!(frames, 5);
+        assert!((0..5).all(|tile| reveal.phase(tile) == TilePhase::Revealed));
+    }
+}
@@ -1,25 +1,83 @@
 use anyhow::Result;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{
+        DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        KeyCode, KeyEvent,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
-use std::io;
-use tokio::time::{Duration, interval};
+use std::io::{self, IsTerminal};
+use tokio::sync::broadcast::{self, error::TryRecvError};
+use tokio::time::{Duration, Instant, interval};
 
 use crate::{
     Command, CommandResult, Container,
-    application::{WordleApplicationService, commands::CommandExecutor},
-    core::types::{FeedbackPattern, Word},
-    presentation::tui::{
-        events::{EventLoop, KeyAction, TuiEvent},
-        feedback::FeedbackInputManager,
-        layout::{LayoutManager, LayoutPreset},
-        state::{LogLevel, MessageType, TuiState},
+    application::{
+        PackProgress, PuzzlePack, SavedGame, WordleApplicationService,
+        commands::CommandExecutor, parse_guess_batch,
+    },
+    core::{
+        error::WordleError,
+        morphology::MorphologyTags,
+        types::{FeedbackPattern, ManualConstraint, Word},
+    },
+    domain::{GameEvent, NOTABLE_CANDIDATES, PrefixIndex, WordOrder},
+    infrastructure::{
+        ArchiveResult, FileWordListProvider, GameRecord, PlayerStats, PuzzleArchive,
+        SessionSummary, StatisticsStore, TurnTime, UserSettings, format_turn_time, resolve_player,
+    },
+    presentation::{
+        chart,
+        colors::{COLORS_ENV, ColorSupport},
+        glyphs::{GLYPHS_ENV, GlyphSupport},
+        spoilers::SpoilerFeature,
+        tui::{
+            events::{EventLoop, KeyAction, TuiEvent},
+            feedback::{CONFIRM_FEEDBACK_ENV, FeedbackInputManager},
+            focus::Panel,
+            keyboard::OnScreenKeyboard,
+            layout::{LayoutManager, LayoutPreset},
+            mode::InputTarget,
+            screen::{GameMode, MenuItem, Screen, SettingField},
+            state::{Completion, LogLevel, MessageType, RevealPrompt, TuiState},
+            theme::{apply_colors, apply_glyphs, apply_theme},
+            tray::{PinChange, TRAY_CAPACITY},
+        },
     },
 };
 
+#[cfg(feature = "hot-reload")]
+use crate::{
+    application::AppEvent,
+    infrastructure::{ConfigFile, ConfigWatcher},
+};
+
+/// Frame interval of the tile reveal animation
+const ANIMATION_TICK: Duration = Duration::from_millis(90);
+
+/// Delay between moves of the auto-solve demo
+const AUTO_SOLVE_STEP: Duration = Duration::from_millis(800);
+
+/// Most words listed on the explorer screen
+const EXPLORER_LIMIT: usize = 500;
+
+/// Guesses scrolled by PageUp/PageDown in the history panel
+const HISTORY_PAGE: usize = 5;
+
+/// Possible words per page of the candidates panel (shown in full when zoomed)
+const CANDIDATE_PAGE: usize = 200;
+
+/// Letters to type before Tab completes them
+const COMPLETION_MIN_LETTERS: usize = 2;
+
+/// Smallest word family listed in the candidates panel
+const CLUSTER_MIN_SIZE: usize = 2;
+
+/// Largest one-letter-change groups to find a splitting guess for
+const WORD_GRAPH_BREAKERS: usize = 3;
+
 /// Main TUI application
 pub struct TuiApp {
     /// Terminal interface
@@ -34,15 +92,61 @@ pub struct TuiApp {
     feedback_manager: FeedbackInputManager,
     /// Whether the application should quit
     should_quit: bool,
+    /// What the terminal can draw; other glyphs are swapped for ASCII
+    glyphs: GlyphSupport,
+    /// What colors the terminal can show; others are rounded or dropped
+    colors: ColorSupport,
+    /// How the current game is played
+    game_mode: GameMode,
+    /// Hidden target in versus, archive and auto-solve games
+    hidden_target: Option<Word>,
+    /// Past puzzles, loaded the first time one is picked
+    archive: Option<PuzzleArchive>,
+    /// When the auto-solve demo last played a guess
+    last_auto_step: Instant,
+    /// Input box contents at the last tick; scored once a tick passes without typing
+    typed_prefix: String,
+    /// Valid guesses by prefix, for Tab-completion; rebuilt with the service
+    guess_index: PrefixIndex,
+    /// All answers with their word-form tags, loaded the first time the explorer opens
+    answer_words: Vec<(String, MorphologyTags)>,
+    /// Player whose statistics are shown and recorded
+    player: String,
+    /// Events of the current service's games
+    game_events: broadcast::Receiver<GameEvent>,
+    /// Webhooks from WORDLE_WEBHOOK_URLS, told about each solved game as it ends
+    #[cfg(feature = "net")]
+    webhooks: Option<crate::infrastructure::WebhookNotifier>,
+    /// Webhook deliveries started this session; failures are reported on exit
+    #[cfg(feature = "net")]
+    webhook_deliveries: Vec<WebhookDelivery>,
+    /// Puzzle pack being played, with the player's progress through it
+    pack: Option<(PuzzlePack, PackProgress)>,
+    /// Settings and word-source files, picked up again when edited
+    #[cfg(feature = "hot-reload")]
+    config_watcher: ConfigWatcher,
 }
 
 impl TuiApp {
     /// Create a new TUI application
     pub async fn new() -> Result<Self> {
+        // Fail early with a clear message instead of deep inside crossterm
+        if let Some(stream) = missing_terminal() {
+            return Err(anyhow::anyhow!(
+                "Interactive mode requires a terminal: {} is not a tty",
+                stream
+            ));
+        }
+
         // Setup terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        execute!(
+            stdout,
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableBracketedPaste
+        )?;
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend)?;
 
@@ -62,9 +166,10 @@ impl TuiApp {
             ));
         }
 
-        // Initialize application components
-        let container = Container::new();
+        // Initialize application components with the saved settings
         let mut state = TuiState::new();
+        state.settings = UserSettings::load_default();
+        let container = Container::with_config(state.settings.dependency_config());
 
         // Optionally refresh word lists on interactive start
         // Controlled by WORDLE_REFRESH_ON_START=1
@@ -72,38 +177,7 @@ impl TuiApp {
             .map(|v| matches!(v.as_str(), "1" | "true" | "TRUE"))
             .unwrap_or(false)
         {
-            state.add_log(
-                LogLevel::Info,
-                "Refreshing word lists from remote sources...".to_string(),
-            );
-            match container.create_word_list_provider() {
-                Ok(mut provider) => match provider.refresh(true).await {
-                    Ok((answers, guesses)) => {
-                        state.add_log(
-                            LogLevel::Info,
-                            format!(
-                                "Word lists updated. Answers: {}, Guesses: {}",
-                                answers, guesses
-                            ),
-                        );
-                    }
-                    Err(e) => {
-                        state.add_log(
-                            LogLevel::Warning,
-                            format!(
-                                "Failed to refresh word lists: {} (using cache if available)",
-                                e
-                            ),
-                        );
-                    }
-                },
-                Err(e) => {
-                    state.add_log(
-                        LogLevel::Warning,
-                        format!("Could not create word list provider: {}", e),
-                    );
-                }
-            }
+            refresh_word_lists(&container, &mut state).await;
         } else {
             state.add_log(
                 LogLevel::Info,
@@ -113,51 +187,247 @@ impl TuiApp {
         }
 
         // Create application service after refresh so it picks up fresh cache
-        let app_service = container.create_application_service().await?;
+        let app_service = Self::create_app_service(&container).await?;
+        let guess_index = app_service.prefix_index();
         let event_loop = EventLoop::default();
 
+        let glyphs = GlyphSupport::detect();
+        if glyphs.is_ascii() {
+            let note = format!("Using ASCII glyphs ({}=unicode overrides)", GLYPHS_ENV);
+            state.add_log(LogLevel::Info, note);
+        }
+        let colors = ColorSupport::detect();
+        let palette = match colors {
+            ColorSupport::Full => None,
+            ColorSupport::Basic => Some("the 16 basic colors"),
+            ColorSupport::Monochrome => Some("no colors"),
+        };
+        if let Some(palette) = palette {
+            let note = format!("Using {} ({}=full overrides)", palette, COLORS_ENV);
+            state.add_log(LogLevel::Info, note);
+        }
+
+        #[cfg(feature = "net")]
+        let webhooks = crate::infrastructure::WebhookNotifier::from_env().unwrap_or_else(|e| {
+            state.add_log(
+                LogLevel::Warning,
+                format!("Invalid webhook configuration: {}", e),
+            );
+            None
+        });
+
         // Get initial suggestion
         state.add_log(LogLevel::Info, "Initializing Wordle Solver...".to_string());
 
         Ok(Self {
             terminal,
             state,
+            game_events: app_service.subscribe(),
+            #[cfg(feature = "net")]
+            webhooks,
+            #[cfg(feature = "net")]
+            webhook_deliveries: Vec::new(),
             app_service,
             event_loop,
             feedback_manager: FeedbackInputManager::new(),
             should_quit: false,
+            glyphs,
+            colors,
+            game_mode: GameMode::default(),
+            hidden_target: None,
+            archive: None,
+            last_auto_step: Instant::now(),
+            typed_prefix: String::new(),
+            guess_index,
+            answer_words: Vec::new(),
+            player: resolve_player(None),
+            pack: None,
+            #[cfg(feature = "hot-reload")]
+            config_watcher: ConfigWatcher::for_defaults(),
         })
     }
 
+    /// Container configured from the current settings
+    fn container(&self) -> Container {
+        Container::with_config(self.state.settings.dependency_config())
+    }
+
+    /// Switch to `service` and follow its game events; a game finished on the old
+    /// one is left behind like the rest of it
+    fn set_app_service(&mut self, service: WordleApplicationService) {
+        self.drain_game_events();
+        self.game_events = service.subscribe();
+        self.guess_index = service.prefix_index();
+        self.app_service = service;
+        self.lock_confirmed_letters();
+    }
+
+    /// Lock the letters confirmed green into the input row while hard mode is on
+    fn lock_confirmed_letters(&mut self) {
+        let letters = if self.state.settings.hard_mode {
+            self.app_service.confirmed_positions()
+        } else {
+            [None; Word::LENGTH]
+        };
+        self.state.set_locked_letters(letters);
+    }
+
+    /// Handle the game events published since the last call
+    fn drain_game_events(&mut self) {
+        loop {
+            match self.game_events.try_recv() {
+                Ok(event) => self.on_game_event(event),
+                Err(TryRecvError::Lagged(skipped)) => self
+                    .state
+                    .add_log(LogLevel::Warning, format!("Missed {} game events", skipped)),
+                Err(TryRecvError::Empty | TryRecvError::Closed) => break,
+            }
+        }
+    }
+
+    /// Log a game event; a won game that counts towards the statistics goes to the webhooks
+    fn on_game_event(&mut self, event: GameEvent) {
+        match &event {
+            GameEvent::FeedbackIssued { guess, remaining } => self.state.add_log(
+                LogLevel::Info,
+                format!(
+                    "Added guess: {} -> {} ({} remaining)",
+                    guess.word.as_str().to_uppercase(),
+                    guess.feedback.to_code_string(),
+                    remaining
+                ),
+            ),
+            GameEvent::GuessUndone { guess } => self.state.add_log(
+                LogLevel::Info,
+                format!("Took back {}", guess.word.as_str().to_uppercase()),
+            ),
+            GameEvent::GameFinished { won, guesses, .. } => {
+                let outcome = if *won { "Solved" } else { "Lost" };
+                self.state.add_log(
+                    LogLevel::Info,
+                    format!("{} after {} guesses", outcome, guesses.len()),
+                );
+                #[cfg(feature = "net")]
+                if *won && self.game_mode.records_statistics() {
+                    self.notify_webhooks(&event);
+                }
+            }
+            GameEvent::GameStarted { .. } | GameEvent::GuessMade { .. } => {}
+        }
+    }
+
+    /// Start delivering a finished game to the webhooks in the background
+    #[cfg(feature = "net")]
+    fn notify_webhooks(&mut self, event: &GameEvent) {
+        let Some(notifier) = self.webhooks.clone() else {
+            return;
+        };
+        let Some(payload) = crate::infrastructure::GameCompletedPayload::from_event(event) else {
+            return;
+        };
+        self.webhook_deliveries
+            .push(tokio::spawn(async move { notifier.notify(&payload).await }));
+    }
+
+    /// Create the application service with autosave of the in-progress game enabled
+    async fn create_app_service(container: &Container) -> Result<WordleApplicationService> {
+        let mut app_service = container.create_application_service().await?;
+        app_service.enable_autosave(FileWordListProvider::default_session_path());
+        Ok(app_service)
+    }
+
+    /// Hand the player's used words to the solver when "Avoid used words" is on
+    async fn apply_used_words(&mut self) {
+        let words: Vec<Word> = if self.state.settings.avoid_used_words {
+            match StatisticsStore::open_default().await {
+                Ok(store) => store
+                    .used_words(&self.player)
+                    .iter()
+                    .filter_map(|w| Word::from_str(w).ok())
+                    .collect(),
+                Err(e) => {
+                    self.state
+                        .add_log(LogLevel::Warning, format!("Could not load used words: {}", e));
+                    Vec::new()
+                }
+            }
+        } else {
+            Vec::new()
+        };
+        if !words.is_empty() {
+            self.state.add_log(
+                LogLevel::Info,
+                format!("Not suggesting {} words from earlier games", words.len()),
+            );
+        }
+        if let Err(e) = self.app_service.set_excluded_words(words) {
+            self.state
+                .add_log(LogLevel::Warning, format!("Could not apply used words: {}", e));
+        }
+    }
+
+    /// Replay the guesses of a saved game
+    pub async fn resume(&mut self, saved: SavedGame) -> Result<()> {
+        let count = saved.guesses.len();
+        for guess in saved.guesses {
+            self.add_guess_result(guess.word, guess.feedback).await?;
+        }
+        // Replayed guesses weren't thought about just now
+        self.state.stats.turn_times.fill(TurnTime::default());
+        self.state
+            .add_log(LogLevel::Info, format!("Resumed game with {} guesses", count));
+        Ok(())
+    }
+
     /// Run the TUI application
     pub async fn run(&mut self) -> Result<()> {
-        // Get initial first guess
-        self.get_first_guess().await?;
+        // Get initial first guess (the start menu asks for it when a game begins)
+        if self.state.screen == Screen::Game {
+            self.get_first_guess().await?;
+        }
 
         // Main application loop
         let mut tick_interval = interval(Duration::from_millis(250));
+        let mut animation_interval = interval(ANIMATION_TICK);
 
         loop {
             // Draw the UI
             self.draw()?;
 
+            // Keep the first animation frame a full tick long
+            if self.state.reveal.is_none() {
+                animation_interval.reset();
+            }
+
             // Handle events
             tokio::select! {
                 // Handle keyboard/terminal events
                 event_result = self.event_loop.next_event() => {
                     match event_result {
                         Ok(event) => {
-                            // If solved, exit on any key press
-                            if self.state.is_solved {
+                            let game_over = self.state.is_solved || self.state.game_over;
+                            if self.state.reveal_prompt.is_some() {
+                                if let TuiEvent::Key(key) = event {
+                                    self.answer_reveal_prompt(key).await?;
+                                }
+                            } else if game_over && self.state.screen == Screen::Game {
                                 if let TuiEvent::Key(_) = event {
-                                    self.should_quit = true;
+                                    self.leave_finished_game().await?;
                                 }
-                            } else {
+                            } else if self.state.screen == Screen::Game
+                                && self.state.constraint_dialog.is_some()
+                            {
+                                let action = self.event_loop.process_dialog_event(event);
+                                self.handle_dialog_action(action);
+                            } else if self.state.screen == Screen::Game {
                                 // Normal event processing
-                                let is_typing = !self.state.input.is_empty();
+                                let is_typing = self.state.has_typed_input();
                                 let current_mode = self.state.interaction_mode();
                                 let action = self.event_loop.process_event(event, current_mode, is_typing);
                                 self.handle_action(action).await?;
+                            } else {
+                                let action = self.event_loop.process_navigation_event(event);
+                                self.handle_screen_action(action).await?;
                             }
                         }
                         Err(e) => {
@@ -173,6 +443,11 @@ impl TuiApp {
                 _ = tick_interval.tick() => {
                     self.update().await?;
                 }
+
+                // Animation frames, only while something is animating
+                _ = animation_interval.tick(), if self.state.reveal.is_some() => {
+                    self.state.advance_animations();
+                }
             }
 
             if self.should_quit {
@@ -185,14 +460,41 @@ impl TuiApp {
 
     /// Draw the UI
     fn draw(&mut self) -> Result<()> {
+        self.state.last_operation = crate::core::metrics::global().last_operation();
+        // Only a keyboard drawn this time can be clicked
+        self.state.keyboard_area.set(None);
         self.terminal.draw(|frame| {
             let size = frame.size();
             let preset = LayoutPreset::from_size(size.width, size.height);
             preset.render(frame, &self.state, &self.feedback_manager);
+            apply_theme(self.state.settings.theme, frame.buffer_mut());
+            apply_colors(self.colors, frame.buffer_mut());
+            apply_glyphs(self.glyphs, frame.buffer_mut());
         })?;
         Ok(())
     }
 
+    /// What `action` means to the on-screen keyboard: a click on a key presses
+    /// it (switching to INPUT mode), and while the keyboard is open in INPUT mode
+    /// the arrows and Enter drive the highlight instead of the input line
+    fn keyboard_action(&mut self, action: KeyAction) -> KeyAction {
+        match action {
+            KeyAction::Click { column, row } => {
+                let hit = (self.state.keyboard_area.get())
+                    .and_then(|area| OnScreenKeyboard::key_at(area, column, row));
+                let Some((row, index)) = hit else {
+                    return KeyAction::None;
+                };
+                self.state.switch_to_input_mode();
+                self.state.keyboard.press(row, index)
+            }
+            action if self.state.interaction_mode().is_input() => {
+                self.state.keyboard.intercept(&action).unwrap_or(action)
+            }
+            action => action,
+        }
+    }
+
     /// Handle user actions
     async fn handle_action(&mut self, action: KeyAction) -> Result<()> {
         // If we're in feedback mode, handle feedback-specific actions
@@ -200,13 +502,16 @@ impl TuiApp {
             return self.handle_feedback_action(action).await;
         }
 
-        match action {
+        match self.keyboard_action(action) {
             KeyAction::AddChar(c) => {
-                // Only handle character input in input mode and for relevant characters
+                // A pending guess takes letters only; digits wait for the feedback row
                 if self.state.interaction_mode().is_input() {
-                    if c.is_ascii_alphabetic() || c.is_ascii_digit() {
-                        self.state.add_char(c);
-                        self.state.clear_status();
+                    if InputTarget::Guess.accepts(c) {
+                        if self.state.add_char(c) {
+                            self.state.clear_status();
+                        }
+                    } else {
+                        self.state.reject_input(c, InputTarget::Guess);
                     }
                 }
             }
@@ -255,53 +560,1160 @@ impl TuiApp {
             }
 
             KeyAction::Reset => {
-                self.reset_game().await?;
+                self.start_game(self.game_mode).await?;
+            }
+
+            KeyAction::Quit => {
+                self.should_quit = true;
+            }
+
+            KeyAction::SwitchToInputMode => {
+                self.state.switch_to_input_mode();
+            }
+
+            KeyAction::SwitchToOperationMode => {
+                self.state.switch_to_operation_mode();
+            }
+
+            KeyAction::ToggleMode => {
+                // Esc leaves a zoomed panel before it switches modes
+                if !self.state.focus.unzoom() {
+                    self.state.toggle_interaction_mode();
+                }
+            }
+
+            KeyAction::Complete => self.complete_guess(),
+
+            KeyAction::FocusPrevious => self.state.focus.focus_previous(),
+
+            KeyAction::FocusNext => self.state.focus.focus_next(),
+
+            KeyAction::ToggleZoom => self.state.focus.toggle_zoom(),
+
+            KeyAction::ToggleClusters => {
+                self.state.show_clusters = !self.state.show_clusters;
+                self.state.show_word_graph = false;
+            }
+            KeyAction::ToggleWordGraph => {
+                self.state.show_word_graph = !self.state.show_word_graph;
+                self.state.show_clusters = false;
+                self.refresh_word_graph();
+            }
+            KeyAction::EnableHardMode => self.enable_hard_mode().await?,
+
+            KeyAction::OpenConstraintDialog => self.open_constraint_dialog(),
+
+            KeyAction::TogglePin => self.toggle_pin(),
+
+            KeyAction::ToggleCandidates => {
+                let (text, kind) = if !self.state.toggle_candidates_revealed() {
+                    (
+                        "No-spoiler mode is off, so the possible words are shown",
+                        MessageType::Info,
+                    )
+                } else if self.state.candidates_revealed {
+                    ("Showing the possible words", MessageType::Warning)
+                } else {
+                    ("Possible words hidden again", MessageType::Info)
+                };
+                self.state.set_status(text.to_string(), kind);
+            }
+
+            KeyAction::ToggleKeyboard => {
+                let text = if self.state.keyboard.toggle() {
+                    "Keyboard shown: arrows move, Enter presses a key, or click one"
+                } else {
+                    "Keyboard hidden"
+                };
+                self.state.set_status(text.to_string(), MessageType::Info);
+            }
+
+            KeyAction::ClearTray => {
+                self.state.tray.clear();
+                self.state
+                    .set_status("Comparison tray cleared".to_string(), MessageType::Info);
+            }
+
+            KeyAction::Undo => self.undo_guess(),
+
+            KeyAction::ScrollUp if self.state.focus.is_focused(Panel::Candidates) => {
+                self.state.move_candidate_cursor(true)
+            }
+
+            KeyAction::ScrollDown if self.state.focus.is_focused(Panel::Candidates) => {
+                self.state.move_candidate_cursor(false)
+            }
+
+            KeyAction::ScrollUp => self.state.scroll_history_up(1),
+
+            KeyAction::ScrollDown => self.state.scroll_history_down(1),
+
+            KeyAction::PageUp if self.state.focus.zoomed() == Some(Panel::Candidates) => {
+                let offset = self.state.remaining_words_offset;
+                if offset > 0 {
+                    self.page_remaining_words(offset.saturating_sub(CANDIDATE_PAGE));
+                }
+            }
+
+            KeyAction::PageDown if self.state.focus.zoomed() == Some(Panel::Candidates) => {
+                let offset = self.state.remaining_words_offset + CANDIDATE_PAGE;
+                if offset < self.state.remaining_words {
+                    self.page_remaining_words(offset);
+                }
+            }
+
+            KeyAction::PageUp => self.state.scroll_history_up(HISTORY_PAGE),
+
+            KeyAction::PageDown => self.state.scroll_history_down(HISTORY_PAGE),
+
+            KeyAction::Paste(text) => {
+                if self.state.interaction_mode().is_input() {
+                    self.paste_input(&text).await?;
+                }
+            }
+
+            KeyAction::ShowMenu => {
+                self.open_menu();
+            }
+
+            KeyAction::SelectPrevious
+            | KeyAction::SelectNext
+            | KeyAction::CloseDialog
+            | KeyAction::Click { .. }
+            | KeyAction::None => {
+                // Do nothing
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle actions on the start menu and the other non-game screens
+    async fn handle_screen_action(&mut self, action: KeyAction) -> Result<()> {
+        match (self.state.screen, action) {
+            (_, KeyAction::Quit) | (Screen::Menu, KeyAction::AddChar('q')) => {
+                self.should_quit = true;
+            }
+            (Screen::Menu, KeyAction::SelectPrevious) => self.state.menu.select_previous(),
+            (Screen::Menu, KeyAction::SelectNext) => self.state.menu.select_next(),
+            (Screen::Menu, KeyAction::Submit) => {
+                let item = self.state.menu.selected();
+                self.open_menu_item(item).await?;
+            }
+            (Screen::Explorer, KeyAction::AddChar(c))
+                if c.is_ascii_alphabetic() && self.state.explorer_query.len() < Word::LENGTH =>
+            {
+                self.state.explorer_query.push(c.to_ascii_lowercase());
+                self.update_explorer_matches();
+            }
+            (Screen::Explorer, KeyAction::DeleteChar) => {
+                self.state.explorer_query.pop();
+                self.update_explorer_matches();
+            }
+            (Screen::Archive, KeyAction::AddChar(c))
+                if c.is_ascii_digit() && self.state.archive_query.len() < 6 =>
+            {
+                self.state.archive_query.push(c);
+                self.state.clear_status();
+            }
+            (Screen::Archive, KeyAction::DeleteChar) => {
+                self.state.archive_query.pop();
+                self.state.clear_status();
+            }
+            (Screen::Archive, KeyAction::Submit) => match self.state.archive_query.parse() {
+                Ok(number) => self.start_archive_puzzle(number).await?,
+                Err(_) => self
+                    .state
+                    .set_status("Type a puzzle number".to_string(), MessageType::Warning),
+            },
+            (Screen::Settings, KeyAction::SelectPrevious) => {
+                self.state.settings_menu.select_previous()
+            }
+            (Screen::Settings, KeyAction::SelectNext) => self.state.settings_menu.select_next(),
+            (Screen::Settings, KeyAction::Submit | KeyAction::MoveCursorRight) => {
+                self.change_setting(true).await;
+            }
+            (Screen::Settings, KeyAction::MoveCursorLeft) => self.change_setting(false).await,
+            (Screen::Menu, _) => {}
+            (_, KeyAction::ShowMenu) => self.open_menu(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Any key after a finished game exits; demo games just go back to the menu
+    async fn leave_finished_game(&mut self) -> Result<()> {
+        if let Some(index) = self.next_pack_puzzle() {
+            self.start_game(GameMode::Pack { index }).await?;
+        } else if self.game_mode.records_statistics() {
+            self.should_quit = true;
+        } else {
+            self.open_menu();
+        }
+        Ok(())
+    }
+
+    /// `y` at the reveal prompt shows what it held back; any other key declines
+    async fn answer_reveal_prompt(&mut self, key: KeyEvent) -> Result<()> {
+        let confirmed = matches!(key.code, KeyCode::Char('y' | 'Y'));
+        let prompt = if confirmed {
+            self.state.confirm_reveal()
+        } else {
+            self.state.reveal_prompt.take()
+        };
+        match (prompt, confirmed) {
+            (Some(RevealPrompt::Answer(answer)), true) => self.state.set_status(
+                format!(
+                    "The word was {}. Press any key to continue.",
+                    self.state.reveal_policy.answer(&answer)
+                ),
+                MessageType::Warning,
+            ),
+            (Some(RevealPrompt::Answer(_)), false) => self.leave_finished_game().await?,
+            (Some(RevealPrompt::AutoSolve), true) => self.start_game(GameMode::AutoSolve).await?,
+            (Some(RevealPrompt::AutoSolve), false) | (None, _) => self.state.clear_status(),
+        }
+        Ok(())
+    }
+
+    /// Ask before showing `answer` of a lost game in full, unless the reveal policy
+    /// already allows it; the words to put before what any key does next
+    fn offer_reveal(&mut self, answer: &str) -> &'static str {
+        if self.state.reveal_policy.is_revealed() {
+            return "";
+        }
+        self.state.reveal_prompt = Some(RevealPrompt::Answer(answer.to_string()));
+        "y reveals it. "
+    }
+
+    /// Show the start menu
+    fn open_menu(&mut self) {
+        self.state.show_screen(Screen::Menu);
+    }
+
+    /// Route a start menu entry to its screen
+    async fn open_menu_item(&mut self, item: MenuItem) -> Result<()> {
+        match item {
+            MenuItem::NewAssistedGame => self.start_game(GameMode::Assisted).await?,
+            MenuItem::PlayVsSolver => self.start_game(GameMode::VersusSolver).await?,
+            MenuItem::ArchivePuzzle => self.open_archive().await,
+            MenuItem::AutoSolveDemo => self.start_game(GameMode::AutoSolve).await?,
+            MenuItem::Statistics => {
+                self.state.screen_lines = self.statistics_lines().await;
+                self.state.show_screen(Screen::Statistics);
+            }
+            MenuItem::WordExplorer => {
+                if self.answer_words.is_empty() {
+                    self.answer_words = load_answer_words(&self.container()).await;
+                }
+                self.state.explorer_query.clear();
+                self.update_explorer_matches();
+                self.state.show_screen(Screen::Explorer);
+            }
+            MenuItem::Settings => {
+                self.state.screen_lines = self.settings_lines();
+                self.state.show_screen(Screen::Settings);
+            }
+            MenuItem::Quit => self.should_quit = true,
+        }
+        Ok(())
+    }
+
+    /// Show the archive picker with the player's results so far
+    async fn open_archive(&mut self) {
+        if let Err(e) = self.load_archive().await {
+            self.state
+                .set_status(format!("Could not load the archive: {}", e), MessageType::Error);
+            return;
+        }
+        self.state.archive_query.clear();
+        self.state.screen_lines = self.archive_lines().await;
+        self.state.show_screen(Screen::Archive);
+    }
+
+    /// Load the puzzle archive unless it already is
+    async fn load_archive(&mut self) -> Result<&PuzzleArchive> {
+        if self.archive.is_none() {
+            self.archive = Some(PuzzleArchive::load().await?);
+        }
+        Ok(self.archive.as_ref().expect("archive loaded above"))
+    }
+
+    /// Archive size and the player's result for each puzzle they played
+    async fn archive_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        match self.archive.as_ref().map(PuzzleArchive::len) {
+            Some(0) | None => lines.push("The archive is empty.".to_string()),
+            Some(n) => lines.push(format!("Puzzles #0-#{} are available.", n - 1)),
+        }
+        lines.push(String::new());
+        let results = match StatisticsStore::open_default().await {
+            Ok(store) => store.archive_results(&self.player),
+            Err(e) => {
+                lines.push(format!("Could not load statistics: {}", e));
+                return lines;
+            }
+        };
+        if results.is_empty() {
+            lines.push("No archive puzzles played yet.".to_string());
+        }
+        for (number, result) in results {
+            lines.push(match result {
+                ArchiveResult::Solved(guesses) => format!("#{:<6} solved in {}", number, guesses),
+                ArchiveResult::Failed => format!("#{:<6} not solved yet", number),
+            });
+        }
+        lines
+    }
+
+    /// Play archive puzzle `number`, staying on the picker if it isn't in the archive
+    pub async fn start_archive_puzzle(&mut self, number: u32) -> Result<()> {
+        let known = match self.load_archive().await {
+            Ok(archive) => archive.answer(number).map(|_| ()).map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        match known {
+            Ok(()) => self.start_game(GameMode::Archive { puzzle: number }).await,
+            Err(reason) => {
+                self.state.set_status(reason, MessageType::Error);
+                Ok(())
+            }
+        }
+    }
+
+    /// Play `pack` under its rules, from the first puzzle the player has no result for
+    pub async fn start_pack(&mut self, pack: PuzzlePack) -> Result<()> {
+        let results = match StatisticsStore::open_default().await {
+            Ok(store) => store.pack_results(&self.player, &pack.name),
+            Err(_) => Default::default(),
+        };
+        let progress = PackProgress::new(&pack, results);
+        let index = progress.current;
+        self.state.settings = pack.rules.apply(&self.state.settings);
+        self.pack = Some((pack, progress));
+        self.start_game(GameMode::Pack { index }).await
+    }
+
+    /// The pack puzzle after the one just finished, if a pack is being played
+    fn next_pack_puzzle(&self) -> Option<usize> {
+        self.game_mode.pack_index()?;
+        self.pack.as_ref().and_then(|(_, progress)| progress.next())
+    }
+
+    /// The hidden target for a new game in `mode`: the archive answer for archive
+    /// games, the pack's puzzle for pack games, otherwise a random answer
+    async fn pick_target(&mut self, mode: GameMode) -> std::result::Result<Word, String> {
+        if let Some(index) = mode.pack_index() {
+            let (pack, _) = self.pack.as_ref().ok_or("No puzzle pack loaded")?;
+            let targets = pack.target_words().map_err(|e| e.to_string())?;
+            return targets
+                .get(index)
+                .cloned()
+                .ok_or_else(|| format!("The pack has no puzzle {}", index + 1));
+        }
+        match mode.archive_puzzle() {
+            Some(number) => {
+                let archive = self.load_archive().await.map_err(|e| e.to_string())?;
+                archive.answer(number).cloned().map_err(|e| e.to_string())
+            }
+            None => self
+                .app_service
+                .random_answer()
+                .ok_or_else(|| "No answer words available".to_string()),
+        }
+    }
+
+    /// Start a fresh game in `mode`; versus and demo games get a hidden random target,
+    /// archive games the puzzle's answer
+    async fn start_game(&mut self, mode: GameMode) -> Result<()> {
+        if mode == GameMode::AutoSolve {
+            if let Err(reason) = self.state.spoilers().allow(SpoilerFeature::AutoSolve) {
+                self.state.set_status(reason, MessageType::Warning);
+                return Ok(());
+            }
+            if !self.state.reveal_policy.is_revealed() {
+                let prompt = RevealPrompt::AutoSolve;
+                let question = prompt.question().trim().to_string();
+                self.state.reveal_prompt = Some(prompt);
+                self.state.set_status(question, MessageType::Warning);
+                return Ok(());
+            }
+        }
+        self.reset_game().await?;
+        self.game_mode = mode;
+        self.hidden_target = None;
+        if mode == GameMode::Assisted {
+            return Ok(());
+        }
+
+        // Games against a hidden target can't be resumed without it
+        self.app_service.disable_autosave();
+        let target = match self.pick_target(mode).await {
+            Ok(target) => target,
+            Err(reason) => {
+                self.state.set_status(reason, MessageType::Error);
+                return Ok(());
+            }
+        };
+        if let Err(e) = self.app_service.execute(Command::StartGame {
+            target_word: Some(target.clone()),
+        }) {
+            // e.g. an archive answer missing from the current word lists
+            self.state.set_status(rejection_reason(&e), MessageType::Error);
+            return Ok(());
+        }
+        self.state.spoiler_answer = Some(target.to_string());
+        self.hidden_target = Some(target);
+
+        if let Some(number) = mode.archive_puzzle() {
+            self.state.hide_suggestion = true;
+            self.state.set_status(
+                format!("Archive puzzle #{} - feedback is filled in for you", number),
+                MessageType::Info,
+            );
+        } else if let Some(index) = mode.pack_index() {
+            self.state.hide_suggestion = true;
+            if let Some((_, progress)) = &mut self.pack {
+                progress.current = index;
+                self.state.pack = Some(progress.clone());
+                self.state.set_status(
+                    format!(
+                        "Pack puzzle {}/{} - feedback is filled in for you",
+                        index + 1,
+                        progress.total
+                    ),
+                    MessageType::Info,
+                );
+            }
+        } else if mode == GameMode::VersusSolver {
+            self.state.hide_suggestion = true;
+            self.state.set_status(
+                "Guess the hidden word - feedback is filled in for you".to_string(),
+                MessageType::Info,
+            );
+        } else {
+            self.last_auto_step = Instant::now();
+            self.state
+                .set_status("Watching the solver play...".to_string(), MessageType::Info);
+        }
+        Ok(())
+    }
+
+    /// Score a guess against the hidden target (versus and archive modes)
+    async fn play_versus_guess(&mut self, guess: String) -> Result<()> {
+        let word = Word::from_str(&guess).map_err(|e| anyhow::anyhow!("Invalid word: {}", e))?;
+        let (feedback, game_finished) =
+            match self.app_service.execute(Command::MakeGuess { word }) {
+                Ok(CommandResult::GuessMade {
+                    feedback,
+                    game_finished,
+                }) => (feedback, game_finished),
+                Ok(_) => return Ok(()),
+                Err(e) => {
+                    self.state.set_status(rejection_reason(&e), MessageType::Error);
+                    return Ok(());
+                }
+            };
+
+        let remaining = self.app_service.get_state().remaining_words_count;
+        self.state
+            .add_guess(guess.clone(), feedback.to_code_string(), remaining);
+        self.state.update_remaining_words(remaining, vec![]);
+        self.lock_confirmed_letters();
+        if !game_finished {
+            self.state.set_status(
+                format!("{} {} ({} words possible)", guess.to_uppercase(), feedback, remaining),
+                MessageType::Info,
+            );
+            return Ok(());
+        }
+
+        let solver = match &self.hidden_target {
+            Some(target) => match solver_guess_count(&self.container(), target).await {
+                Ok(Some(count)) => format!("the solver needed {}", count),
+                Ok(None) => "the solver failed too".to_string(),
+                Err(_) => "the solver couldn't be run".to_string(),
+            },
+            None => String::new(),
+        };
+        if self.game_mode.pack_index().is_some() {
+            self.finish_pack_puzzle(feedback.is_win(), &solver).await;
+            return Ok(());
+        }
+        let puzzle = self
+            .game_mode
+            .archive_puzzle()
+            .map(|number| format!("puzzle #{} ", number))
+            .unwrap_or_default();
+        let target = self.hidden_target.as_ref().map(Word::to_string).unwrap_or_default();
+        let revealed = self.state.reveal_policy.is_revealed();
+        if feedback.is_win() {
+            // Found, so no longer a spoiler
+            self.state.spoiler_answer = None;
+            self.state.set_solved(true);
+            self.state.set_status(
+                format!(
+                    "🎉 Solved {}in {} - {}. Press any key to exit.",
+                    puzzle,
+                    self.state.guess_history.len(),
+                    solver
+                ),
+                MessageType::Success,
+            );
+        } else if self.game_mode.archive_puzzle().is_some() && !revealed {
+            // Keep the answer hidden so the puzzle can be tried again unspoiled
+            self.state.game_over = true;
+            let offer = self.offer_reveal(&target);
+            self.state.set_status(
+                format!(
+                    "Out of guesses on {}- the answer stays hidden for another try ({}). \
+                     {}Press any key to exit.",
+                    puzzle, solver, offer
+                ),
+                MessageType::Warning,
+            );
+        } else {
+            self.state.game_over = true;
+            let offer = self.offer_reveal(&target);
+            self.state.set_status(
+                format!(
+                    "Out of guesses - the word was {} ({}). {}Press any key to exit.",
+                    self.state.reveal_policy.answer(&target),
+                    solver,
+                    offer
+                ),
+                MessageType::Warning,
+            );
+        }
+        Ok(())
+    }
+
+    /// Record a finished pack puzzle right away, since a pack plays several games in
+    /// one run, and say what comes next
+    async fn finish_pack_puzzle(&mut self, won: bool, solver: &str) {
+        let guesses = self.state.guess_history.len();
+        let target = self.hidden_target.as_ref().map(Word::to_string).unwrap_or_default();
+        let Some((pack, progress)) = &mut self.pack else {
+            return;
+        };
+        let record = GameRecord::new(
+            &self.player,
+            won,
+            won.then(|| target.clone()),
+            &self.app_service.get_state().guess_history,
+        )
+        .with_pack(&pack.name, progress.current)
+        .with_turn_times(&self.state.stats.turn_times)
+        .with_timestamp(self.state.clock.unix_secs());
+        if let Err(e) = record_game(record).await {
+            self.state
+                .add_log(LogLevel::Warning, format!("Failed to save statistics: {}", e));
+        }
+
+        let result = if won {
+            ArchiveResult::Solved(guesses)
+        } else {
+            ArchiveResult::Failed
+        };
+        progress
+            .results
+            .entry(progress.current)
+            .and_modify(|best| *best = (*best).min(result))
+            .or_insert(result);
+        self.state.pack = Some(progress.clone());
+
+        let next = match progress.next() {
+            Some(next) => format!("Press any key for puzzle {}/{}.", next + 1, progress.total),
+            None => {
+                let average = progress
+                    .average_guesses()
+                    .map(|avg| format!(", {:.1} guesses on average", avg))
+                    .unwrap_or_default();
+                format!(
+                    "Pack done: {}/{} solved{}. Press any key to exit.",
+                    progress.solved(),
+                    progress.total,
+                    average
+                )
+            }
+        };
+        if won {
+            self.state.spoiler_answer = None;
+            self.state.set_solved(true);
+            self.state.set_status(
+                format!("🎉 Solved in {} - {}. {}", guesses, solver, next),
+                MessageType::Success,
+            );
+        } else {
+            self.state.game_over = true;
+            let offer = self.offer_reveal(&target);
+            self.state.set_status(
+                format!(
+                    "Out of guesses - the word was {} ({}). {}{}",
+                    self.state.reveal_policy.answer(&target),
+                    solver,
+                    offer,
+                    next
+                ),
+                MessageType::Warning,
+            );
+        }
+    }
+
+    /// Play one move of the auto-solve demo
+    fn auto_solve_step(&mut self) {
+        self.last_auto_step = Instant::now();
+        let solver_started = Instant::now();
+        let (guess, game_finished) = match self.app_service.play_best_guess() {
+            Ok(step) => step,
+            Err(e) => {
+                self.state.game_over = true;
+                self.state
+                    .set_status(format!("Demo stopped: {}", e), MessageType::Error);
+                return;
+            }
+        };
+
+        let app_state = self.app_service.get_state();
+        let remaining = app_state.remaining_words_count;
+        let suggestion = app_state.current_suggestion.as_ref().map(Word::to_string);
+        let word = guess.word.to_string();
+        self.state
+            .add_guess(word.clone(), guess.feedback.to_code_string(), remaining);
+        self.state.set_last_turn_time(TurnTime {
+            think: None,
+            solver: Some(solver_started.elapsed()),
+        });
+        self.state.update_remaining_words(remaining, vec![]);
+        self.state.set_suggestion(suggestion);
+
+        if guess.is_winning() {
+            self.state.set_solved(true);
+            self.state.set_status(
+                format!(
+                    "🤖 Solved {} in {} guesses. Press any key for the menu.",
+                    self.state.reveal_policy.answer(&word),
+                    self.state.guess_history.len()
+                ),
+                MessageType::Success,
+            );
+        } else if game_finished {
+            self.state.game_over = true;
+            self.state.set_status(
+                "🤖 The solver ran out of guesses. Press any key for the menu.".to_string(),
+                MessageType::Warning,
+            );
+        }
+    }
+
+    /// Lines for the statistics screen
+    async fn statistics_lines(&self) -> Vec<String> {
+        let store = match StatisticsStore::open_default().await {
+            Ok(store) => store,
+            Err(e) => return vec![format!("Could not load statistics: {}", e)],
+        };
+        let stats = store.player_stats(&self.player);
+
+        let mut lines = vec![format!("Player: {}", stats.player), String::new()];
+        if stats.games_played == 0 {
+            lines.push("No games recorded yet.".to_string());
+            return lines;
+        }
+        lines.push(format!(
+            "Played: {}   Win %: {:.0}   Current streak: {}   Max streak: {}",
+            stats.games_played,
+            stats.win_rate(),
+            stats.current_streak,
+            stats.max_streak
+        ));
+        let (think, solver) = (stats.average_think_time(), stats.average_solver_time());
+        if think.is_some() || solver.is_some() {
+            let show = |time: Option<Duration>| time.map_or("-".to_string(), format_turn_time);
+            lines.push(format!(
+                "Avg. think time: {}   Avg. solver time: {}",
+                show(think),
+                show(solver)
+            ));
+        }
+        lines.push(String::new());
+        lines.push("Guess distribution:".to_string());
+        let width = self.terminal.size().map(|r| r.width as usize).unwrap_or(80);
+        lines.extend(
+            chart::render_guess_distribution(&stats, width.saturating_sub(4))
+                .lines()
+                .map(str::to_string),
+        );
+        lines
+    }
+
+    /// Read-only details below the editable settings
+    fn settings_lines(&self) -> Vec<String> {
+        let on_off = |on: bool| if on { "on" } else { "off" };
+        vec![
+            format!("Player:                 {}", self.player),
+            format!(
+                "Feedback confirmation:  {}  ({}=0 to turn off)",
+                on_off(self.feedback_manager.confirmation_enabled()),
+                CONFIRM_FEEDBACK_ENV
+            ),
+            format!(
+                "Refresh lists on start: {}  (WORDLE_REFRESH_ON_START=1)",
+                on_off(std::env::var("WORDLE_REFRESH_ON_START").is_ok_and(|v| v == "1"))
+            ),
+            format!(
+                "Autosave file:          {}",
+                FileWordListProvider::default_session_path().display()
+            ),
+            format!(
+                "Statistics:             {}  (stats_backend in settings.json)",
+                self.state.settings.stats_backend.backend().location()
+            ),
+            format!(
+                "Settings file:          {}",
+                UserSettings::default_path().display()
+            ),
+        ]
+    }
+
+    /// Turn hard mode on without ending the game, e.g. after the footer noticed hard-mode play
+    async fn enable_hard_mode(&mut self) -> Result<()> {
+        if self.state.settings.hard_mode {
+            self.state
+                .set_status("Hard mode is already on".to_string(), MessageType::Info);
+            return Ok(());
+        }
+        self.state.settings.hard_mode = true;
+        self.app_service.set_hard_mode(true)?;
+        self.lock_confirmed_letters();
+        if let Err(e) = self.state.settings.save(UserSettings::default_path()) {
+            self.state.add_log(LogLevel::Warning, format!("Could not save settings: {}", e));
+        }
+        if self.state.guess_history.is_empty() {
+            self.get_first_guess().await?;
+        } else {
+            self.get_next_guess().await?;
+        }
+        self.state.set_status(
+            "Hard mode on: suggestions now reuse every revealed hint".to_string(),
+            MessageType::Success,
+        );
+        Ok(())
+    }
+
+    /// Step the highlighted setting, save it, and rebuild the service if the solver changed
+    ///
+    /// A setting that fails to apply (e.g. no word lists for the new strategy) is reverted.
+    async fn change_setting(&mut self, forward: bool) {
+        let field = self.state.settings_menu.selected();
+        let previous = self.state.settings.clone();
+        field.cycle(&mut self.state.settings, forward);
+
+        if field.rebuilds_service() {
+            let refresh = field == SettingField::WordSource;
+            if let Err(e) = self.rebuild_service(refresh).await {
+                self.state.settings = previous;
+                self.state.set_status(
+                    format!("Could not apply {}: {}", field.label().to_lowercase(), e),
+                    MessageType::Error,
+                );
+                return;
+            }
+        }
+
+        if field == SettingField::AvoidUsedWords {
+            self.apply_used_words().await;
+        }
+
+        // The pack's rules are in the settings; saving them would outlast the pack
+        if self.pack.is_some() {
+            self.state.set_status(
+                format!(
+                    "{} set to {} until the pack ends",
+                    field.label(),
+                    field.value(&self.state.settings)
+                ),
+                MessageType::Info,
+            );
+            return;
+        }
+
+        let path = UserSettings::default_path();
+        let value = field.value(&self.state.settings);
+        let saved = self.state.settings.save(&path);
+        // Our own save isn't an edit to pick up
+        #[cfg(feature = "hot-reload")]
+        self.config_watcher.acknowledge(ConfigFile::Settings);
+        match saved {
+            Ok(()) => self.state.set_status(
+                format!("{} set to {}", field.label(), value),
+                MessageType::Success,
+            ),
+            Err(e) => self.state.set_status(
+                format!(
+                    "{} set to {}, but saving {} failed: {}",
+                    field.label(),
+                    value,
+                    path.display(),
+                    e
+                ),
+                MessageType::Warning,
+            ),
+        }
+    }
+
+    /// Switch to a service built from the current settings, downloading the word lists
+    /// again first if `refresh_lists`
+    async fn rebuild_service(&mut self, refresh_lists: bool) -> Result<()> {
+        let container = self.container();
+        if refresh_lists {
+            refresh_word_lists(&container, &mut self.state).await;
+            self.answer_words.clear();
+        }
+        let service = Self::create_app_service(&container).await?;
+        self.set_app_service(service);
+        self.apply_used_words().await;
+        Ok(())
+    }
+
+    /// Whether a new service can take over now: anywhere but in a game that has
+    /// started, or whose target came from the old word lists
+    #[cfg(feature = "hot-reload")]
+    fn can_switch_service(&self) -> bool {
+        self.state.screen != Screen::Game
+            || (self.game_mode == GameMode::Assisted && self.state.guess_history.is_empty())
+    }
+
+    /// React to an application event from outside the game
+    #[cfg(feature = "hot-reload")]
+    async fn handle_app_event(&mut self, event: AppEvent) {
+        if let AppEvent::ConfigChanged(file) = event {
+            self.state
+                .add_log(LogLevel::Info, format!("{} changed on disk", file.name()));
+            match file {
+                ConfigFile::Settings => self.reload_settings().await,
+                ConfigFile::WordSources => self.reload_word_sources().await,
+            }
+        }
+    }
+
+    /// Apply an edited `settings.json`; a game in progress keeps its solver, and the
+    /// next game uses the new one
+    #[cfg(feature = "hot-reload")]
+    async fn reload_settings(&mut self) {
+        let mut settings = match UserSettings::load(UserSettings::default_path()) {
+            Ok(settings) => settings,
+            Err(e) => {
+                self.state.set_status(
+                    format!("Ignoring the edited {}: {}", ConfigFile::Settings.name(), e),
+                    MessageType::Warning,
+                );
+                return;
+            }
+        };
+        // A pack's rules still win over the file
+        if let Some((pack, _)) = &self.pack {
+            settings = pack.rules.apply(&settings);
+        }
+        let changed: Vec<SettingField> = SettingField::ALL
+            .into_iter()
+            .filter(|field| field.value(&self.state.settings) != field.value(&settings))
+            .collect();
+        if changed.is_empty() {
+            return;
+        }
+
+        let previous = std::mem::replace(&mut self.state.settings, settings);
+        let summary = changed
+            .iter()
+            .map(|field| {
+                format!(
+                    "{} {}",
+                    field.label().to_lowercase(),
+                    field.value(&self.state.settings)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let rebuild = changed.iter().any(|field| field.rebuilds_service());
+        let mut when = String::new();
+        if rebuild && self.can_switch_service() {
+            let refresh = changed.contains(&SettingField::WordSource);
+            if let Err(e) = self.rebuild_service(refresh).await {
+                self.state.settings = previous;
+                self.state.set_status(
+                    format!("Could not apply the edited settings: {}", e),
+                    MessageType::Error,
+                );
+                return;
+            }
+            if self.state.screen == Screen::Game {
+                let _ = self.get_first_guess().await;
+            }
+        } else if rebuild {
+            when = " from the next game".to_string();
+        } else if changed.contains(&SettingField::AvoidUsedWords) {
+            self.apply_used_words().await;
+        }
+        self.state.set_status(
+            format!("Settings reloaded: {}{}", summary, when),
+            MessageType::Info,
+        );
+    }
+
+    /// Download the word lists from an edited `word_sources.json`
+    #[cfg(feature = "hot-reload")]
+    async fn reload_word_sources(&mut self) {
+        if !self.can_switch_service() {
+            refresh_word_lists(&self.container(), &mut self.state).await;
+            self.answer_words.clear();
+            self.state.set_status(
+                "Word sources reloaded; the next game uses the new lists".to_string(),
+                MessageType::Info,
+            );
+            return;
+        }
+        match self.rebuild_service(true).await {
+            Ok(()) => {
+                if self.state.screen == Screen::Game {
+                    let _ = self.get_first_guess().await;
+                }
+                self.state
+                    .set_status("Word sources reloaded".to_string(), MessageType::Info);
+            }
+            Err(e) => self.state.set_status(
+                format!("Could not load the new word lists: {}", e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    /// Refresh the explorer results for the current search
+    fn update_explorer_matches(&mut self) {
+        let query = &self.state.explorer_query;
+        self.state.explorer_matches = self
+            .answer_words
+            .iter()
+            .filter(|(word, _)| word.starts_with(query.as_str()))
+            .take(EXPLORER_LIMIT)
+            .cloned()
+            .collect();
+    }
+
+    /// Take back the latest guess of an assisted game
+    fn open_constraint_dialog(&mut self) {
+        if self.game_mode != GameMode::Assisted {
+            self.state.set_status(
+                "Pinning letters only works in assisted games".to_string(),
+                MessageType::Warning,
+            );
+            return;
+        }
+        self.state.constraint_dialog = Some(String::new());
+    }
+
+    /// Handle keys while the pin/ban dialog is open
+    fn handle_dialog_action(&mut self, action: KeyAction) {
+        let Some(text) = self.state.constraint_dialog.as_mut() else {
+            return;
+        };
+        match action {
+            KeyAction::AddChar(c) => text.push(c),
+            KeyAction::DeleteChar => {
+                text.pop();
+            }
+            KeyAction::Submit => {
+                let text = text.clone();
+                self.apply_manual_constraints(&text);
+            }
+            KeyAction::CloseDialog => self.state.constraint_dialog = None,
+            KeyAction::Quit => self.should_quit = true,
+            _ => {}
+        }
+    }
+
+    /// Apply the dialog's text: `a3` pins A as the third letter, `-x` bans X, and
+    /// `clear` drops every pinned and banned letter
+    ///
+    /// The dialog stays open with the text when something can't be applied.
+    fn apply_manual_constraints(&mut self, text: &str) {
+        let result = if text.trim().eq_ignore_ascii_case("clear") {
+            self.app_service.execute(Command::ClearManualConstraints)
+        } else {
+            match ManualConstraint::parse_list(text) {
+                Ok(constraints) => constraints.into_iter().try_fold(
+                    CommandResult::ManualConstraintsUpdated {
+                        remaining_words: self.state.remaining_words,
+                    },
+                    |_, constraint| {
+                        self.app_service
+                            .execute(Command::AddManualConstraint { constraint })
+                    },
+                ),
+                Err(e) => Err(e.into()),
+            }
+        };
+        // Constraints before a failing one still apply
+        self.state.manual_constraints = self.app_service.manual_constraints().to_vec();
+
+        match result {
+            Ok(CommandResult::ManualConstraintsUpdated { remaining_words }) => {
+                self.state.constraint_dialog = None;
+                self.show_remaining_words(remaining_words);
+                let listed: Vec<String> = self
+                    .state
+                    .manual_constraints
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+                let listed = if listed.is_empty() { "none".to_string() } else { listed.join(" ") };
+                self.state
+                    .add_log(LogLevel::Info, format!("Pinned/banned letters: {}", listed));
+                if remaining_words == 0 {
+                    self.state.set_status(
+                        "No word fits the guesses and pinned letters".to_string(),
+                        MessageType::Warning,
+                    );
+                } else {
+                    self.state.set_status(
+                        format!("{} words possible", remaining_words),
+                        MessageType::Info,
+                    );
+                }
             }
+            Ok(_) => self.state.constraint_dialog = None,
+            Err(e) => self.state.set_status(e.to_string(), MessageType::Error),
+        }
+    }
 
-            KeyAction::Quit => {
-                self.should_quit = true;
-            }
+    /// Show `remaining_words` with a fresh sample and the solver's new suggestion
+    fn show_remaining_words(&mut self, remaining_words: usize) {
+        self.state
+            .update_remaining_words(remaining_words, Vec::new());
+        self.page_remaining_words(0);
+        self.lock_confirmed_letters();
+        let suggestion = self.app_service.get_state().current_suggestion.as_ref();
+        self.state.set_suggestion(suggestion.map(Word::to_string));
+        self.refresh_suggestion_details();
+    }
 
-            KeyAction::SwitchToInputMode => {
-                self.state.switch_to_input_mode();
+    /// List the remaining words from `offset` on in the candidates panel
+    fn page_remaining_words(&mut self, offset: usize) {
+        let command = Command::GetPossibleWordsPage {
+            offset,
+            limit: CANDIDATE_PAGE,
+            sort: WordOrder::Alphabetical,
+        };
+        match self.app_service.execute(command) {
+            Ok(CommandResult::PossibleWordsPage { page }) => {
+                let words = page.words.iter().map(Word::to_string).collect();
+                self.state.set_remaining_words_page(page.offset, words);
             }
+            Ok(_) => {}
+            Err(e) => self.state.set_status(
+                format!("Could not list the possible words: {}", e),
+                MessageType::Error,
+            ),
+        }
+    }
 
-            KeyAction::SwitchToOperationMode => {
-                self.state.switch_to_operation_mode();
-            }
+    /// Pin the highlighted top candidate to the comparison tray, or unpin it
+    fn toggle_pin(&mut self) {
+        if !self.state.focus.is_focused(Panel::Candidates) {
+            self.state.set_status(
+                "Focus the candidates panel (←/→) and pick a word with ↑/↓ to pin it".to_string(),
+                MessageType::Info,
+            );
+            return;
+        }
+        let Some(word) = self.state.selected_candidate().map(|c| c.word.clone()) else {
+            self.state
+                .set_status("No candidates to pin".to_string(), MessageType::Info);
+            return;
+        };
+        let label = word.as_str().to_uppercase();
+        let (text, message_type) = match self.state.tray.toggle(word) {
+            PinChange::Pinned => (
+                format!("Pinned {} ({}/{})", label, self.state.tray.pinned().len(), TRAY_CAPACITY),
+                MessageType::Success,
+            ),
+            PinChange::Unpinned => (format!("Unpinned {}", label), MessageType::Info),
+            PinChange::Full => (
+                format!(
+                    "The tray holds {} words - unpin one (t) or clear it (T)",
+                    TRAY_CAPACITY
+                ),
+                MessageType::Warning,
+            ),
+        };
+        self.state.set_status(text, message_type);
+        self.refresh_tray();
+    }
 
-            KeyAction::ToggleMode => {
-                self.state.toggle_interaction_mode();
-            }
+    /// Recompute the pinned words' metrics for the words still possible
+    fn refresh_tray(&mut self) {
+        if !self.state.tray.is_empty() {
+            let possible_words = self.app_service.possible_words(None);
+            self.state.tray.refresh(&possible_words);
+        }
+    }
 
-            KeyAction::None => {
-                // Do nothing
+    fn undo_guess(&mut self) {
+        if self.game_mode != GameMode::Assisted {
+            self.state.set_status(
+                "Undo only works in assisted games".to_string(),
+                MessageType::Warning,
+            );
+            return;
+        }
+        match self.app_service.execute(Command::UndoLastGuess) {
+            Ok(CommandResult::GuessUndone {
+                guess: Some(guess),
+                remaining_words,
+            }) => {
+                self.state.remove_last_guess();
+                self.show_remaining_words(remaining_words);
+                self.state.set_status(
+                    format!(
+                        "Undid {} - {} words possible",
+                        guess.word.as_str().to_uppercase(),
+                        remaining_words
+                    ),
+                    MessageType::Info,
+                );
+            }
+            Ok(_) => {
+                self.state
+                    .set_status("Nothing to undo".to_string(), MessageType::Info);
+            }
+            Err(e) => {
+                self.state
+                    .set_status(format!("Undo failed: {}", e), MessageType::Error);
             }
         }
-
-        Ok(())
     }
 
     /// Handle actions when in feedback input mode
     async fn handle_feedback_action(&mut self, action: KeyAction) -> Result<()> {
         match action {
-            KeyAction::AddChar(c) if matches!(c, '0' | '1' | '2') => {
+            // Spaces between codes ("g g y b b") are fine, they just don't count
+            KeyAction::AddChar(c) if c.is_whitespace() => {}
+
+            KeyAction::AddChar(c) if InputTarget::Feedback.accepts(c) => {
+                self.state.rejected_input = None;
                 self.feedback_manager.add_feedback_char(c, &mut self.state);
             }
 
+            KeyAction::AddChar('y' | 'Y') if self.feedback_manager.is_awaiting_confirmation() => {
+                if let Some((word, feedback)) =
+                    self.feedback_manager.submit_feedback(&mut self.state)
+                {
+                    self.process_guess_feedback(word, feedback).await?;
+                }
+            }
+
             KeyAction::AddChar(c) => {
                 // 無効な文字が入力された場合の処理
-                if c.is_ascii_digit() {
-                    self.state.set_status(
-                        "Only use 0 (gray), 1 (yellow), or 2 (green) for feedback".to_string(),
-                        MessageType::Warning,
-                    );
-                } else {
-                    self.state.set_status(
-                        "Please enter feedback numbers: 0, 1, or 2".to_string(),
-                        MessageType::Warning,
-                    );
-                }
+                self.state.reject_input(c, InputTarget::Feedback);
             }
 
             KeyAction::DeleteChar => {
@@ -333,6 +1745,18 @@ impl TuiApp {
                 self.feedback_manager.cancel_feedback(&mut self.state);
             }
 
+            KeyAction::Paste(text) => {
+                for c in text.chars().filter(|c| !c.is_whitespace()) {
+                    if !InputTarget::Feedback.accepts(c) {
+                        self.state.reject_input(c, InputTarget::Feedback);
+                        break;
+                    }
+                    if !self.feedback_manager.add_feedback_char(c, &mut self.state) {
+                        break;
+                    }
+                }
+            }
+
             _ => {
                 // Other actions are ignored in feedback mode
             }
@@ -357,10 +1781,19 @@ impl TuiApp {
         }
 
         let guess_word = self.state.input.clone();
+        if let Err(e) = self.app_service.check_guess(&guess_word) {
+            // Keep the input so the word can be fixed
+            self.state.set_status(rejection_reason(&e), MessageType::Warning);
+            return Ok(());
+        }
 
         // Clear input immediately for better UX
         self.state.clear_input();
 
+        if self.game_mode.scores_guesses() {
+            return self.play_versus_guess(guess_word).await;
+        }
+
         // Start feedback input process
         self.feedback_manager
             .start_feedback_input(guess_word.clone(), &mut self.state);
@@ -376,14 +1809,79 @@ impl TuiApp {
         Ok(())
     }
 
+    /// Handle pasted text: a bare word is typed into the input, while
+    /// "word feedback" pairs (one per line) are applied as a batch
+    async fn paste_input(&mut self, text: &str) -> Result<()> {
+        let trimmed = text.trim();
+        if trimmed.chars().all(|c| c.is_ascii_alphabetic()) {
+            for c in trimmed.chars() {
+                self.state.add_char(c);
+            }
+            return Ok(());
+        }
+
+        let guesses = match parse_guess_batch(trimmed) {
+            Ok(guesses) => guesses,
+            Err(e) => {
+                self.state.set_status(
+                    format!("Could not read pasted guesses: {}", e),
+                    MessageType::Error,
+                );
+                return Ok(());
+            }
+        };
+
+        let remaining_words = match self.app_service.execute(Command::AddGuessBatch {
+            guesses: guesses.clone(),
+        }) {
+            Ok(CommandResult::GuessBatchAdded { remaining_words }) => remaining_words,
+            Ok(_) => return Ok(()),
+            Err(e) => {
+                self.state.set_status(
+                    format!("Could not apply pasted guesses: {}", e),
+                    MessageType::Error,
+                );
+                return Ok(());
+            }
+        };
+
+        for ((word, feedback), remaining) in guesses.iter().zip(&remaining_words) {
+            let word = word.to_string();
+            let feedback = feedback.to_code_string();
+            self.state.add_log(
+                LogLevel::Info,
+                format!(
+                    "Added guess: {} -> {} ({} remaining)",
+                    word.to_uppercase(),
+                    feedback,
+                    remaining
+                ),
+            );
+            self.state.add_guess(word, feedback, *remaining);
+            self.state.set_last_turn_time(TurnTime::default());
+        }
+
+        let remaining = remaining_words.last().copied().unwrap_or_default();
+        self.state.update_remaining_words(remaining, vec![]);
+        self.lock_confirmed_letters();
+        match guesses.last() {
+            Some((word, feedback)) if feedback.is_win() => self.show_solved(word.as_str()),
+            _ => self.get_next_guess().await?,
+        }
+        Ok(())
+    }
+
     /// Get the best first guess
     async fn get_first_guess(&mut self) -> Result<()> {
         self.state
             .add_log(LogLevel::Info, "Getting best first guess...".to_string());
 
-        match self.app_service.get_best_first_guess() {
-            Ok(guess) => {
+        let solver_started = Instant::now();
+        match self.app_service.execute(Command::GetBestFirstGuess) {
+            Ok(CommandResult::BestFirstGuess { word: guess }) => {
+                self.state.record_solver_time(solver_started.elapsed());
                 self.state.set_suggestion(Some(guess.to_string()));
+                self.refresh_suggestion_details();
                 self.state.set_status(
                     format!("Best first guess: {}", guess.to_string().to_uppercase()),
                     MessageType::Success,
@@ -393,6 +1891,12 @@ impl TuiApp {
                     format!("Got first guess: {}", guess.to_string().to_uppercase()),
                 );
             }
+            Ok(_) => {
+                self.state.set_status(
+                    "Unexpected response from solver".to_string(),
+                    MessageType::Warning,
+                );
+            }
             Err(e) => {
                 self.state.set_status(
                     format!("Error getting first guess: {}", e),
@@ -408,18 +1912,35 @@ impl TuiApp {
 
     /// Show detailed statistics
     async fn show_detailed_stats(&mut self) -> Result<()> {
-        let stats_text = format!(
-            "Detailed Statistics:\n• Total Guesses: {}\n• Remaining Words: {}\n• Average Remaining: {:.1}\n• Entropy Values: {:?}",
+        let mut stats_text = format!(
+            "Detailed Statistics:\n• Total Guesses: {}\n• Remaining Words: {}\n• Average Remaining: {:.1}\n• Bits Gained: {:.2}",
             self.state.stats.total_guesses,
             self.state.remaining_words,
             self.state.stats.average_remaining_words,
-            self.state
-                .stats
-                .entropy_values
-                .iter()
-                .take(5)
-                .collect::<Vec<_>>()
+            self.state.stats.bits_gained()
         );
+        if let Some(think) = self.state.stats.average_think_time() {
+            stats_text.push_str(&format!("\n• Avg. Think Time: {}", format_turn_time(think)));
+        }
+        if let Some(solver) = self.state.stats.average_solver_time() {
+            stats_text.push_str(&format!("\n• Avg. Solver Time: {}", format_turn_time(solver)));
+        }
+        for (turn, entropy) in self.state.stats.entropy.iter().enumerate() {
+            stats_text.push_str(&format!(
+                "\n• Turn {}: {} expected {:.2} bits, gained {:.2}",
+                turn + 1,
+                entropy.played.as_str().to_uppercase(),
+                entropy.played_entropy,
+                entropy.bits_gained
+            ));
+            if let Some((word, bits)) = &entropy.suggested {
+                stats_text.push_str(&format!(
+                    " (suggested {} {:.2})",
+                    word.as_str().to_uppercase(),
+                    bits
+                ));
+            }
+        }
 
         self.state.set_status(stats_text, MessageType::Info);
         self.state
@@ -430,8 +1951,16 @@ impl TuiApp {
 
     /// Reset the game
     async fn reset_game(&mut self) -> Result<()> {
+        let settings = std::mem::take(&mut self.state.settings);
         self.state = TuiState::new();
-        self.app_service = Container::new().create_application_service().await?;
+        self.state.settings = settings;
+        let service = Self::create_app_service(&self.container()).await?;
+        self.set_app_service(service);
+        self.apply_used_words().await;
+        if let Err(e) = SavedGame::remove(FileWordListProvider::default_session_path()) {
+            self.state
+                .add_log(LogLevel::Warning, format!("Failed to clear saved game: {}", e));
+        }
         self.feedback_manager = FeedbackInputManager::new();
 
         self.state.add_log(LogLevel::Info, "Game reset".to_string());
@@ -446,6 +1975,21 @@ impl TuiApp {
 
     /// Periodic update function
     async fn update(&mut self) -> Result<()> {
+        let demo_running = self.game_mode == GameMode::AutoSolve
+            && self.state.screen == Screen::Game
+            && !self.state.is_solved
+            && !self.state.game_over;
+        if demo_running && self.last_auto_step.elapsed() >= AUTO_SOLVE_STEP {
+            self.auto_solve_step();
+        }
+        self.drain_game_events();
+        self.score_typed_prefix();
+
+        #[cfg(feature = "hot-reload")]
+        for file in self.config_watcher.poll() {
+            self.handle_app_event(AppEvent::ConfigChanged(file)).await;
+        }
+
         // Clear temporary status messages after some time
         if let Some(_status) = &self.state.status_message {
             // This is a simple implementation - in a real app you might want
@@ -455,6 +1999,99 @@ impl TuiApp {
         Ok(())
     }
 
+    /// Complete the typed letters to a dictionary word, remaining answers first; Tab
+    /// again on a completed word moves on to the next one
+    fn complete_guess(&mut self) {
+        let input = self.state.input.to_ascii_lowercase();
+        if let Some(completion) = self
+            .state
+            .completion
+            .as_mut()
+            .filter(|completion| completion.current() == input)
+        {
+            let word = completion.advance().to_string();
+            self.state.set_input(&word);
+            self.show_completion();
+            return;
+        }
+
+        self.state.completion = None;
+        if input.len() < COMPLETION_MIN_LETTERS || input.len() >= Word::LENGTH {
+            self.state.set_status(
+                format!(
+                    "Type {} to {} letters, then Tab to complete them",
+                    COMPLETION_MIN_LETTERS,
+                    Word::LENGTH - 1
+                ),
+                MessageType::Info,
+            );
+            return;
+        }
+        // Putting the remaining answers first would give them away while suggestions
+        // or possible words are hidden
+        let hidden = self.state.hide_suggestion || self.state.spoilers().hides_candidates();
+        let remaining = if hidden {
+            Vec::new()
+        } else {
+            self.app_service.possible_words(None)
+        };
+        let words: Vec<String> = self
+            .guess_index
+            .completions(&input, &remaining)
+            .iter()
+            .map(Word::to_string)
+            .collect();
+        if words.is_empty() {
+            self.state.set_status(
+                format!("No word starts with {}", input.to_uppercase()),
+                MessageType::Warning,
+            );
+            return;
+        }
+        let completion = Completion {
+            prefix: input,
+            words,
+            index: 0,
+        };
+        self.state.set_input(completion.current());
+        self.state.completion = Some(completion);
+        self.show_completion();
+    }
+
+    /// Say which of the completions is in the input box
+    fn show_completion(&mut self) {
+        let Some(completion) = &self.state.completion else {
+            return;
+        };
+        let prefix = completion.prefix.to_uppercase();
+        let text = match completion.words.len() {
+            1 => format!("The only word starting with {}", prefix),
+            count => format!(
+                "Word {} of {} starting with {} (Tab for the next)",
+                completion.index + 1,
+                count,
+                prefix
+            ),
+        };
+        self.state.set_status(text, MessageType::Info);
+    }
+
+    /// Score a partly typed guess once it has stayed the same for a whole tick, so fast
+    /// typing doesn't rescore every letter
+    fn score_typed_prefix(&mut self) {
+        let prefix = self.state.input.to_ascii_lowercase();
+        let settled = prefix == std::mem::replace(&mut self.typed_prefix, prefix.clone());
+        let playing =
+            self.state.screen == Screen::Game && !self.state.is_solved && !self.state.game_over;
+        if !playing || !(1..Word::LENGTH).contains(&prefix.len()) {
+            self.state.prefix_score = None;
+            return;
+        }
+        if settled && self.state.typed_prefix_score().is_none() {
+            self.state.prefix_score = Some(self.app_service.score_prefix(&prefix));
+        }
+    }
+
     /// Add a guess result to the game
     pub async fn add_guess_result(&mut self, word: String, feedback: String) -> Result<()> {
         // Parse the word and feedback
@@ -467,51 +2104,120 @@ impl TuiApp {
         // Add to application service
         let result = self.app_service.execute(Command::AddGuessResult {
             word: word_obj,
-            feedback: feedback_pattern.clone(),
+            feedback: feedback_pattern,
         })?;
 
         if let CommandResult::GuessResultAdded { remaining_words } = result {
             // Update UI state
             self.state
                 .add_guess(word.clone(), feedback.clone(), remaining_words);
-            self.state.update_remaining_words(remaining_words, vec![]); // TODO: Get actual sample
+            self.state
+                .update_remaining_words(remaining_words, Vec::new());
+            self.page_remaining_words(0);
+            self.lock_confirmed_letters();
 
             // Check if solved
             if feedback == "22222" {
-                self.state.set_solved(true);
-                self.state.set_status(
-                    format!(
-                        "🎉 Congratulations! You solved it with '{}'!  Press any key to exit.",
-                        word.to_uppercase()
-                    ),
-                    MessageType::Success,
-                );
+                self.show_solved(&word);
             } else {
                 // Get next best guess
                 self.get_next_guess().await?;
             }
-
-            self.state.add_log(
-                LogLevel::Info,
-                format!(
-                    "Added guess: {} -> {} ({} remaining)",
-                    word.to_uppercase(),
-                    feedback,
-                    remaining_words
-                ),
-            );
         }
 
         Ok(())
     }
 
+    /// Mark the puzzle solved by `word`
+    fn show_solved(&mut self, word: &str) {
+        self.state.set_solved(true);
+        self.state.suggestion_heat = None;
+        self.state.suggestion_set = None;
+        self.state.disambiguator = None;
+        self.state.candidate_diff = None;
+        self.state.trap = None;
+        self.state.ranked_candidates.clear();
+        self.state.set_status(
+            format!(
+                "🎉 Congratulations! You solved it with '{}'!  Press any key to exit.",
+                word.to_uppercase()
+            ),
+            MessageType::Success,
+        );
+    }
+
+    /// Recompute the one-letter-change groups, only while they're shown and after the
+    /// first guess (the full answer list is one sprawling group)
+    fn refresh_word_graph(&mut self) {
+        self.state.word_graph = (self.state.show_word_graph
+            && !self.state.hide_suggestion
+            && !self.state.guess_history.is_empty())
+        .then(|| self.app_service.word_graph(WORD_GRAPH_BREAKERS));
+    }
+
+    /// Recompute the letter heat, ranked candidates, three-way suggestions,
+    /// disambiguator, candidate diff, word families and word graph, and pick up
+    /// the per-turn entropy
+    ///
+    /// Only the heat and the ranked candidates, which the solver has already
+    /// scored, are computed before the first guess; the rest would mean
+    /// scoring every guess against the full answer list. Nothing is shown while
+    /// suggestions are hidden.
+    fn refresh_suggestion_details(&mut self) {
+        let entropy = self.app_service.get_state().solver_stats.entropy_scores.clone();
+        self.state.set_entropy(entropy);
+        self.state.suggestion_heat = None;
+        self.state.suggestion_set = None;
+        self.state.disambiguator = None;
+        self.state.clusters.clear();
+        self.state.word_graph = None;
+        self.state.candidate_diff = None;
+        self.state.trap = None;
+        self.state.ranked_candidates.clear();
+        self.state.hard_mode_detected = false;
+        self.state.urgent = false;
+        self.refresh_tray();
+        if self.state.hide_suggestion {
+            return;
+        }
+        self.state.urgent = self.app_service.is_urgent();
+        self.state.ranked_candidates = self.app_service.ranked_candidates();
+        let suggestion = self.state.current_suggestion.as_deref().map(Word::from_str);
+        if let Some(Ok(word)) = suggestion {
+            let heat = self.app_service.letter_heat(&word);
+            self.state.suggestion_heat = Some((word.to_string(), heat));
+        }
+        if self.state.guess_history.is_empty() {
+            return;
+        }
+        self.state.candidate_diff = self.app_service.candidate_diff(NOTABLE_CANDIDATES);
+        self.state.clusters = self.app_service.word_clusters(CLUSTER_MIN_SIZE);
+        self.refresh_word_graph();
+        self.state.trap = self.app_service.active_trap();
+        self.state.hard_mode_detected =
+            !self.state.settings.hard_mode && self.app_service.played_in_hard_mode();
+        if let Ok(CommandResult::SuggestionSet { set }) =
+            self.app_service.execute(Command::GetSuggestionSet)
+        {
+            self.state.suggestion_set = set;
+        }
+        if let Ok(CommandResult::Disambiguator { found }) =
+            self.app_service.execute(Command::FindDisambiguator)
+        {
+            self.state.disambiguator = found;
+        }
+    }
+
     /// Get the next best guess
     async fn get_next_guess(&mut self) -> Result<()> {
+        let solver_started = Instant::now();
         match self.app_service.execute(Command::GetBestGuess) {
             Ok(CommandResult::BestGuess {
                 word, confidence, ..
             }) => {
+                self.state.record_solver_time(solver_started.elapsed());
                 self.state.set_suggestion(Some(word.to_string()));
+                self.refresh_suggestion_details();
                 // We'll need to get remaining words count separately
 
                 self.state.set_status(
@@ -550,7 +2256,8 @@ impl Drop for TuiApp {
         if let Err(e) = execute!(
             self.terminal.backend_mut(),
             LeaveAlternateScreen,
-            DisableMouseCapture
+            DisableMouseCapture,
+            DisableBracketedPaste
         ) {
             eprintln!("Failed to cleanup terminal: {}", e);
         }
@@ -559,17 +2266,109 @@ impl Drop for TuiApp {
 
 /// Helper function to run the TUI application
 pub async fn run_tui() -> Result<()> {
+    run_tui_as(None).await
+}
+
+/// The first of stdin and stdout that isn't a terminal, if any; the TUI needs both
+pub fn missing_terminal() -> Option<&'static str> {
+    if !io::stdin().is_terminal() {
+        Some("stdin")
+    } else if !io::stdout().is_terminal() {
+        Some("stdout")
+    } else {
+        None
+    }
+}
+
+/// Run the TUI, recording the finished game under `player` (see `resolve_player`)
+pub async fn run_tui_as(player: Option<&str>) -> Result<()> {
+    run_tui_with(player, StartAt::Menu).await
+}
+
+/// Run the TUI straight into archive puzzle `number` (see [`PuzzleArchive`]),
+/// recording the result against the puzzle under `player`
+pub async fn run_tui_archive(player: Option<&str>, number: u32) -> Result<()> {
+    run_tui_with(player, StartAt::Archive(number)).await
+}
+
+/// Run the TUI through the puzzles of `pack` in order, starting with the first one
+/// `player` hasn't played, and record each result against the pack
+pub async fn run_tui_pack(player: Option<&str>, pack: PuzzlePack) -> Result<()> {
+    run_tui_with(player, StartAt::Pack(pack)).await
+}
+
+/// Where a TUI run begins
+enum StartAt {
+    /// The start menu, or a saved game the player chooses to resume
+    Menu,
+    Archive(u32),
+    Pack(PuzzlePack),
+}
+
+async fn run_tui_with(player: Option<&str>, start: StartAt) -> Result<()> {
+    let session_path = FileWordListProvider::default_session_path();
+    // Archive and pack games aren't autosaved; a saved game stays for a later run
+    let resume = match start {
+        StartAt::Menu => prompt_resume(&session_path)?,
+        StartAt::Archive(_) | StartAt::Pack(_) => None,
+    };
+
     let mut app = TuiApp::new().await?;
-    let run_result = app.run().await;
+    app.player = resolve_player(player);
+    app.apply_used_words().await;
+    let session_started = Instant::now();
+    let stats_before = player_stats(&app.player).await;
+    let run_result = match (resume, start) {
+        (Some(saved), _) => match app.resume(saved).await {
+            Ok(()) => app.run().await,
+            Err(e) => Err(e),
+        },
+        (None, StartAt::Archive(number)) => match app.start_archive_puzzle(number).await {
+            Ok(()) => app.run().await,
+            Err(e) => Err(e),
+        },
+        (None, StartAt::Pack(pack)) => match app.start_pack(pack).await {
+            Ok(()) => app.run().await,
+            Err(e) => Err(e),
+        },
+        (None, StartAt::Menu) => {
+            app.state.screen = Screen::Menu;
+            app.run().await
+        }
+    };
 
     // Capture needed info before dropping the app (which tears down the TUI)
-    let solved = app.state.is_solved;
+    // Pack games were recorded as they finished
+    let records_statistics =
+        app.game_mode.records_statistics() && app.game_mode.pack_index().is_none();
+    let autosaved = !app.game_mode.scores_guesses();
+    let show_summary = app.state.settings.session_summary;
+    let glyphs = app.glyphs;
+    let colors = app.colors;
+    let player_name = app.player.clone();
+    let solved = app.state.is_solved && records_statistics;
     let history: Vec<(String, String)> = app
         .state
         .guess_history
         .iter()
         .map(|e| (e.word.clone(), e.feedback.clone()))
         .collect();
+    // Finished games (solved, or out of guesses) go to the statistics store
+    let max_guesses = app.state.settings.max_guesses;
+    let game_record = (records_statistics && (solved || history.len() >= max_guesses)).then(|| {
+        GameRecord::new(
+            &app.player,
+            solved,
+            history.last().filter(|_| solved).map(|(word, _)| word.clone()),
+            &app.app_service.get_state().guess_history,
+        )
+        .with_puzzle(app.game_mode.archive_puzzle())
+        .with_turn_times(&app.state.stats.turn_times)
+        .with_timestamp(app.state.clock.unix_secs())
+    });
+    app.drain_game_events();
+    #[cfg(feature = "net")]
+    let webhook_deliveries = std::mem::take(&mut app.webhook_deliveries);
 
     // Explicitly drop TUI to leave alternate screen and raw mode
     drop(app);
@@ -578,22 +2377,234 @@ pub async fn run_tui() -> Result<()> {
     if solved {
         // Show only per-guess feedback like real Wordle (no legend/message/attempts)
         if !history.is_empty() {
-            print_history_summary(&history);
+            print_history_summary(&history, glyphs, colors);
+        }
+    }
+
+    if let Some(record) = game_record {
+        // A lost game is over; don't offer to resume it
+        if !record.won && autosaved {
+            let _ = SavedGame::remove(&session_path);
+        }
+        if let Err(e) = record_game(record).await {
+            let message = format!("⚠️  Failed to save statistics: {}", e);
+            eprintln!("{}", glyphs.render(&message));
+        }
+    }
+
+    if let (true, Some(before)) = (show_summary, stats_before) {
+        if let Some(after) = player_stats(&player_name).await {
+            let summary = SessionSummary::between(&before, &after, session_started.elapsed());
+            print_session_summary(&summary, glyphs);
         }
     }
 
+    #[cfg(feature = "net")]
+    finish_webhook_deliveries(webhook_deliveries).await;
+
     run_result
 }
 
-fn print_history_summary(rows: &[(String, String)]) {
-    println!("");
+/// Load the full answer list, tagged, for the word explorer
+async fn load_answer_words(container: &Container) -> Vec<(String, MorphologyTags)> {
+    let mut provider = match container.create_word_list_provider() {
+        Ok(provider) => provider,
+        Err(_) => return Vec::new(),
+    };
+    if provider.load_words().await.is_err() {
+        return Vec::new();
+    }
+    let mut words: Vec<(String, MorphologyTags)> = provider
+        .get_answer_words()
+        .iter()
+        .map(|word| (word.to_string(), provider.morphology(word)))
+        .collect();
+    words.sort_by(|a, b| a.0.cmp(&b.0));
+    words
+}
+
+/// Why a guess was refused, without the error category prefix
+fn rejection_reason(error: &WordleError) -> String {
+    match error {
+        WordleError::Game(e) => e.to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// How many guesses the solver needs for `target`, or None if it fails
+async fn solver_guess_count(container: &Container, target: &Word) -> Result<Option<usize>> {
+    let mut service = container.create_application_service().await?;
+    service.execute(Command::StartGame {
+        target_word: Some(target.clone()),
+    })?;
+    let mut count = 0;
+    loop {
+        let (guess, finished) = service.play_best_guess()?;
+        count += 1;
+        if guess.is_winning() {
+            return Ok(Some(count));
+        }
+        if finished {
+            return Ok(None);
+        }
+    }
+}
+
+/// Re-download the word lists, logging the outcome; the cache is kept on failure
+async fn refresh_word_lists(container: &Container, state: &mut TuiState) {
+    state.add_log(
+        LogLevel::Info,
+        "Refreshing word lists from remote sources...".to_string(),
+    );
+    let mut provider = container.create_file_word_list_provider();
+    match provider.refresh_cache(true).await {
+        Ok((answers, guesses)) => {
+            state.add_log(
+                LogLevel::Info,
+                format!("Word lists updated. Answers: {}, Guesses: {}", answers, guesses),
+            );
+            for report in provider.source_reports().iter().filter(|r| r.used_mirror()) {
+                state.add_log(
+                    LogLevel::Warning,
+                    format!("{} failed; used mirror {}", report.configured, report.used),
+                );
+            }
+        }
+        Err(e) => {
+            state.add_log(
+                LogLevel::Warning,
+                format!("Failed to refresh word lists: {} (using cache if available)", e),
+            );
+        }
+    }
+}
+
+/// Offer to resume an unfinished game; declining discards it
+fn prompt_resume(path: &std::path::Path) -> Result<Option<SavedGame>> {
+    use std::io::{BufRead, Write};
+
+    let saved = match SavedGame::load(path) {
+        Ok(Some(saved)) if !saved.guesses.is_empty() => saved,
+        Ok(_) => return Ok(None),
+        Err(e) => {
+            eprintln!("⚠️  Ignoring unreadable saved game: {}", e);
+            return Ok(None);
+        }
+    };
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Ok(None);
+    }
+
+    let saved_at = chrono::DateTime::from_timestamp(saved.saved_at as i64, 0)
+        .map(|t| t.with_timezone(&chrono::Local).format("%H:%M").to_string())
+        .unwrap_or_else(|| "earlier".to_string());
+    print!(
+        "You have an unfinished game from {} ({} guesses) — resume? [y/n] ",
+        saved_at,
+        saved.guesses.len()
+    );
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    if matches!(answer.trim(), "y" | "Y" | "yes") {
+        Ok(Some(saved))
+    } else {
+        SavedGame::remove(path)?;
+        Ok(None)
+    }
+}
+
+/// Append a finished game to the statistics store
+async fn record_game(record: GameRecord) -> crate::Result<()> {
+    let mut store = StatisticsStore::open_default().await?;
+    store.record_game(record);
+    store.save().await
+}
+
+/// The player's totals in the local statistics store, if it can be read
+async fn player_stats(player: &str) -> Option<PlayerStats> {
+    let store = StatisticsStore::open_default().await.ok()?;
+    Some(store.player_stats(player))
+}
+
+/// Webhook deliveries of one finished game
+#[cfg(feature = "net")]
+type WebhookDelivery = tokio::task::JoinHandle<Vec<crate::infrastructure::DeliveryReport>>;
+
+/// Wait for the webhook deliveries still running and report the ones that failed
+#[cfg(feature = "net")]
+async fn finish_webhook_deliveries(deliveries: Vec<WebhookDelivery>) {
+    for delivery in deliveries {
+        for report in delivery.await.unwrap_or_default() {
+            if !report.delivered {
+                eprintln!(
+                    "⚠️  Webhook delivery to {} failed after {} attempts: {}",
+                    report.url,
+                    report.attempts,
+                    report.last_error.unwrap_or_default()
+                );
+            }
+        }
+    }
+}
+
+/// The finished game's rows; without color (and on consoles without Unicode, which
+/// often lack ANSI colors too) the feedback is G/Y/- letters instead of colored squares
+fn print_history_summary(rows: &[(String, String)], glyphs: GlyphSupport, colors: ColorSupport) {
+    println!();
     for (i, (word, feedback)) in rows.iter().enumerate() {
-        let squares = format_feedback_squares(feedback);
+        let squares = if glyphs.is_ascii() || colors.is_monochrome() {
+            format_feedback_letters(feedback)
+        } else {
+            format_feedback_squares(feedback)
+        };
         println!("{:>2}) {:<8} {}", i + 1, word.to_uppercase(), squares);
     }
     println!();
 }
 
+fn print_session_summary(summary: &SessionSummary, glyphs: GlyphSupport) {
+    let secs = summary.duration.as_secs();
+    let duration = match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m {:02}s", secs / 60, secs % 60),
+        _ => format!("{}h {:02}m", secs / 3600, secs / 60 % 60),
+    };
+    let average = summary
+        .average_guesses
+        .map(|avg| format!(", {:.2} guesses per win", avg))
+        .unwrap_or_default();
+    let line = format!(
+        "📋 This session: {} game{} ({} won){}, {}",
+        summary.games_played,
+        if summary.games_played == 1 { "" } else { "s" },
+        summary.games_won,
+        average,
+        duration
+    );
+    println!("{}", glyphs.render(&line));
+    let best = match summary.best_streak_gain {
+        0 => String::new(),
+        gain => format!(" (best streak +{})", gain),
+    };
+    let line = format!("🔥 Streak: {}{}", summary.current_streak, best);
+    println!("{}", glyphs.render(&line));
+}
+
+fn format_feedback_letters(feedback_code: &str) -> String {
+    let letters: Vec<&str> = feedback_code
+        .chars()
+        .take(5)
+        .map(|ch| match ch {
+            '2' => "G",
+            '1' => "Y",
+            _ => "-",
+        })
+        .collect();
+    letters.join(" ")
+}
+
 fn format_feedback_squares(feedback_code: &str) -> String {
     // Use fixed-width ANSI background-colored cells to avoid emoji width issues.
     // Each cell is two spaces with background color; separated by one normal space.
@@ -0,0 +1,241 @@
+//! Live progress screen for long benchmarks
+//!
+//! The games run on a worker thread (and from there on the rayon pool) while
+//! this thread redraws a [`BenchmarkProgress`] a few times a second. `q`, Esc
+//! or Ctrl-C stops drawing new targets; games already started still finish and
+//! count towards the returned totals.
+
+use anyhow::Result;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Gauge, List, ListItem, Paragraph},
+};
+use std::io::{self, Stdout};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use crate::{
+    application::{
+        BenchmarkGoal, BenchmarkProgress, BenchmarkReporter, BenchmarkRunner, BenchmarkSummary,
+        SimulatedGame,
+    },
+    core::types::Word,
+    presentation::{
+        colors::ColorSupport,
+        glyphs::GlyphSupport,
+        tui::{
+            components::Colors,
+            theme::{apply_colors, apply_glyphs},
+        },
+    },
+};
+
+/// Redraw interval of the progress screen
+const REDRAW: Duration = Duration::from_millis(100);
+
+/// Restores the terminal however the screen is left
+struct TerminalGuard(Terminal<CrosstermBackend<Stdout>>);
+
+impl TerminalGuard {
+    fn enter() -> Result<Self> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        Ok(Self(Terminal::new(CrosstermBackend::new(stdout))?))
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(self.0.backend_mut(), LeaveAlternateScreen);
+    }
+}
+
+/// Run `targets` concurrently behind a full-screen progress display
+///
+/// The caller checks that stdout is a terminal; the totals are returned once
+/// the run ends or is stopped, after the screen is closed.
+pub fn run_benchmark_tui<I>(
+    runner: &BenchmarkRunner<'_>,
+    targets: I,
+    goal: BenchmarkGoal,
+) -> Result<BenchmarkSummary>
+where
+    I: IntoIterator<Item = Word>,
+    I::IntoIter: Send,
+{
+    let progress = Mutex::new(BenchmarkProgress::new(goal));
+    let stopped = AtomicBool::new(false);
+    let glyphs = GlyphSupport::detect();
+    let colors = ColorSupport::detect();
+    let mut terminal = TerminalGuard::enter()?;
+
+    let summary = std::thread::scope(|scope| -> Result<_> {
+        let targets = targets
+            .into_iter()
+            .take_while(|_| !stopped.load(Ordering::Relaxed));
+        let worker = scope.spawn(|| {
+            let mut report = |game: &SimulatedGame, summary: &BenchmarkSummary| {
+                if let Ok(mut progress) = progress.lock() {
+                    progress.game_played(game, summary);
+                }
+            };
+            runner.run_concurrent(targets, &mut report)
+        });
+
+        loop {
+            let finished = worker.is_finished();
+            // Draw from a copy so the games aren't held up by the terminal
+            if let Ok(snapshot) = progress.lock().map(|progress| progress.clone()) {
+                let stopping = stopped.load(Ordering::Relaxed);
+                terminal.0.draw(|frame| {
+                    render_benchmark(frame, &snapshot, stopping);
+                    apply_colors(colors, frame.buffer_mut());
+                    apply_glyphs(glyphs, frame.buffer_mut());
+                })?;
+            }
+            if finished {
+                break;
+            }
+            if !event::poll(REDRAW)? {
+                continue;
+            }
+            if let Event::Key(key) = event::read()? {
+                let ctrl_c =
+                    key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+                let quit = matches!(key.code, KeyCode::Char('q') | KeyCode::Esc);
+                if key.kind == KeyEventKind::Press && (quit || ctrl_c) {
+                    stopped.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+        Ok(worker.join().expect("benchmark thread panicked")?)
+    })?;
+    Ok(summary)
+}
+
+/// `m:ss`, or `h:mm:ss` past an hour
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match secs {
+        0..=3599 => format!("{}:{:02}", secs / 60, secs % 60),
+        _ => format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60),
+    }
+}
+
+fn panel(title: &str) -> Block<'_> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Colors::ACCENT))
+        .title(title)
+        .title_style(Style::default().fg(Colors::ACCENT))
+}
+
+fn stat_line(label: &str, value: String) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{:<18}", label), Style::default().fg(Colors::MUTED)),
+        Span::styled(
+            value,
+            Style::default()
+                .fg(Colors::FOREGROUND)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ])
+}
+
+/// Progress gauge, running totals and the worst games so far
+fn render_benchmark(frame: &mut Frame, progress: &BenchmarkProgress, stopping: bool) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(8),
+            Constraint::Min(4),
+            Constraint::Length(1),
+        ])
+        .split(frame.size());
+    let summary = &progress.summary;
+
+    let label = match progress.goal {
+        BenchmarkGoal::Games(games) => format!("{} / {} games", summary.games, games),
+        BenchmarkGoal::Duration(budget) => format!(
+            "{} / {}",
+            format_duration(summary.elapsed),
+            format_duration(budget)
+        ),
+    };
+    let gauge = Gauge::default()
+        .block(panel(" Benchmark "))
+        .gauge_style(Style::default().fg(Colors::SUCCESS))
+        .ratio(progress.fraction())
+        .label(label);
+    frame.render_widget(gauge, chunks[0]);
+
+    let eta = match progress.eta() {
+        Some(eta) => format_duration(eta),
+        None => "—".to_string(),
+    };
+    let stats = vec![
+        stat_line("Games", summary.games.to_string()),
+        stat_line(
+            "Solved",
+            format!("{:.1}% ({})", summary.win_rate() * 100.0, summary.wins),
+        ),
+        stat_line(
+            "Average guesses",
+            format!("{:.3}", summary.average_guesses()),
+        ),
+        stat_line(
+            "Games per second",
+            format!("{:.1}", summary.games_per_second()),
+        ),
+        stat_line("Elapsed", format_duration(summary.elapsed)),
+        stat_line("Time left", eta),
+    ];
+    frame.render_widget(Paragraph::new(stats).block(panel(" Totals ")), chunks[1]);
+
+    let worst: Vec<ListItem> = progress
+        .worst
+        .iter()
+        .map(|game| {
+            let (result, color) = if game.solved {
+                (format!("{} guesses", game.guess_count()), Colors::WARNING)
+            } else {
+                ("not solved".to_string(), Colors::ERROR)
+            };
+            let path: Vec<&str> = game.guesses.iter().map(|g| g.word.as_str()).collect();
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<7}", game.target.as_str().to_uppercase()),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!("{:<12}", result), Style::default().fg(color)),
+                Span::styled(path.join(" → "), Style::default().fg(Colors::MUTED)),
+            ]))
+        })
+        .collect();
+    frame.render_widget(
+        List::new(worst).block(panel(" Worst words so far ")),
+        chunks[2],
+    );
+
+    let hint = if stopping {
+        "Stopping after the games in progress..."
+    } else {
+        "q/Esc: stop early"
+    };
+    let hint = Paragraph::new(hint).style(Style::default().fg(Colors::MUTED));
+    frame.render_widget(hint, chunks[3]);
+}
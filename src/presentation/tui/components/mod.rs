@@ -1,3 +1,5 @@
+pub mod screens;
 pub mod widgets;
 
+pub use screens::*;
 pub use widgets::*;
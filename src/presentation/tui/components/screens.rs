@@ -0,0 +1,263 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use super::widgets::{Colors, render_title};
+use crate::core::morphology::MorphologyTags;
+use crate::presentation::tui::{
+    screen::{MenuItem, Screen, SettingField},
+    state::TuiState,
+};
+
+/// Width of an explorer column: the word, a space, up to three tag marks and a gap
+const EXPLORER_COLUMN: u16 = 10;
+
+fn screen_block(title: &str) -> Block<'_> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Colors::ACCENT))
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(Colors::ACCENT)
+                .add_modifier(Modifier::BOLD),
+        )
+}
+
+fn key_hint(frame: &mut Frame, area: Rect, hint: &str) {
+    let hint = Paragraph::new(hint).style(Style::default().fg(Colors::MUTED));
+    frame.render_widget(hint, area);
+}
+
+/// Split the frame into title, body and a one-line key hint
+fn screen_chunks(frame: &mut Frame) -> (Rect, Rect, Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(5),
+            Constraint::Length(1),
+        ])
+        .split(frame.size());
+    (chunks[0], chunks[1], chunks[2])
+}
+
+/// Render the start menu as a navigable list
+pub fn render_start_menu(frame: &mut Frame, state: &TuiState) {
+    let (title, body, hint) = screen_chunks(frame);
+    render_title(frame, title);
+
+    let items: Vec<ListItem> = MenuItem::ALL
+        .iter()
+        .map(|item| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<20}", item.label()),
+                    Style::default()
+                        .fg(Colors::FOREGROUND)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(item.description(), Style::default().fg(Colors::MUTED)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(screen_block(Screen::Menu.title()))
+        .highlight_style(
+            Style::default()
+                .fg(Colors::ACCENT)
+                .add_modifier(Modifier::REVERSED),
+        )
+        .highlight_symbol("▶ ");
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(state.menu.selected_index()));
+    frame.render_stateful_widget(list, body, &mut list_state);
+
+    let keys = match &state.reveal_prompt {
+        Some(prompt) => prompt.question(),
+        None => " ↑/↓ Select  •  Enter Open  •  q Quit",
+    };
+    key_hint(frame, hint, keys);
+}
+
+/// Render a screen made of pre-rendered text lines (statistics)
+pub fn render_text_screen(frame: &mut Frame, state: &TuiState) {
+    let (title, body, hint) = screen_chunks(frame);
+    render_title(frame, title);
+
+    let lines: Vec<Line> = state
+        .screen_lines
+        .iter()
+        .map(|line| Line::from(line.as_str()))
+        .collect();
+    let text = Paragraph::new(lines)
+        .style(Style::default().fg(Colors::FOREGROUND))
+        .wrap(Wrap { trim: false })
+        .block(screen_block(state.screen.title()));
+    frame.render_widget(text, body);
+
+    key_hint(frame, hint, " Esc Back to menu");
+}
+
+/// Render the editable settings list above the read-only details in `screen_lines`
+pub fn render_settings(frame: &mut Frame, state: &TuiState) {
+    let (title, body, hint) = screen_chunks(frame);
+    render_title(frame, title);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(SettingField::ALL.len() as u16 + 2),
+            Constraint::Min(3),
+        ])
+        .split(body);
+
+    let items: Vec<ListItem> = SettingField::ALL
+        .iter()
+        .map(|field| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<16}", field.label()),
+                    Style::default().fg(Colors::FOREGROUND),
+                ),
+                Span::styled(
+                    format!("◀ {} ▶", field.value(&state.settings)),
+                    Style::default()
+                        .fg(Colors::INPUT)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(screen_block(Screen::Settings.title()))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("▶ ");
+    let mut list_state = ListState::default();
+    list_state.select(Some(state.settings_menu.selected_index()));
+    frame.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let lines: Vec<Line> = state
+        .screen_lines
+        .iter()
+        .map(|line| Line::from(line.as_str()))
+        .collect();
+    let details = Paragraph::new(lines)
+        .style(Style::default().fg(Colors::MUTED))
+        .wrap(Wrap { trim: false })
+        .block(screen_block(" Details "));
+    frame.render_widget(details, chunks[1]);
+
+    key_hint(
+        frame,
+        hint,
+        " ↑/↓ Select  •  ←/→/Enter Change (saved immediately)  •  Esc Back to menu",
+    );
+}
+
+/// Render the word explorer: a search field and the matching answers
+pub fn render_explorer(frame: &mut Frame, state: &TuiState) {
+    let (title, body, hint) = screen_chunks(frame);
+    render_title(frame, title);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(3)])
+        .split(body);
+
+    let query = Paragraph::new(format!("{}▌", state.explorer_query.to_uppercase()))
+        .style(
+            Style::default()
+                .fg(Colors::INPUT)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(screen_block(" Starts with "));
+    frame.render_widget(query, chunks[0]);
+
+    // Fill the width with as many columns as fit: the word, then the marks of its tags.
+    // Tagged words are dimmed, as they rarely turn out to be the answer.
+    let per_row = ((chunks[1].width.saturating_sub(2)) / EXPLORER_COLUMN).max(1) as usize;
+    let lines: Vec<Line> = state
+        .explorer_matches
+        .chunks(per_row)
+        .map(|row| {
+            Line::from(
+                row.iter()
+                    .map(|(word, tags)| {
+                        let color = if tags.is_empty() {
+                            Colors::FOREGROUND
+                        } else {
+                            Colors::MUTED
+                        };
+                        let text = format!("{} {:<4}", word.to_uppercase(), tags.symbols());
+                        Span::styled(text, Style::default().fg(color))
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+    let legend: Vec<String> = MorphologyTags::ALL
+        .iter()
+        .map(|tag| format!("{} {}", tag.symbol(), tag.name()))
+        .collect();
+    let footer = format!(
+        " {} matches  •  {} ",
+        state.explorer_matches.len(),
+        legend.join("  ")
+    );
+    let results = Paragraph::new(lines)
+        .style(Style::default().fg(Colors::FOREGROUND))
+        .block(screen_block(Screen::Explorer.title()).title_bottom(footer));
+    frame.render_widget(results, chunks[1]);
+
+    key_hint(frame, hint, " a-z Search  •  Backspace Delete  •  Esc Back to menu");
+}
+
+/// Render the archive picker: the puzzle number being typed above the player's
+/// results in `screen_lines`
+pub fn render_archive(frame: &mut Frame, state: &TuiState) {
+    let (title, body, hint) = screen_chunks(frame);
+    render_title(frame, title);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(3)])
+        .split(body);
+
+    // Say why the number typed can't be played, e.g. past the end of the archive
+    let mut block = screen_block(" Puzzle number ");
+    if let Some(status) = &state.status_message {
+        block = block.title_bottom(Span::styled(
+            format!(" {} ", status.text),
+            Style::default().fg(Colors::ERROR),
+        ));
+    }
+    let query = Paragraph::new(format!("#{}▌", state.archive_query))
+        .style(
+            Style::default()
+                .fg(Colors::INPUT)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(block);
+    frame.render_widget(query, chunks[0]);
+
+    let lines: Vec<Line> = state
+        .screen_lines
+        .iter()
+        .map(|line| Line::from(line.as_str()))
+        .collect();
+    let results = Paragraph::new(lines)
+        .style(Style::default().fg(Colors::FOREGROUND))
+        .wrap(Wrap { trim: false })
+        .block(screen_block(Screen::Archive.title()));
+    frame.render_widget(results, chunks[1]);
+
+    key_hint(frame, hint, " 0-9 Number  •  Enter Play  •  Backspace Delete  •  Esc Back to menu");
+}
@@ -1,11 +1,26 @@
 use ratatui::{
     Frame,
-    layout::{Constraint, Layout, Rect},
+    layout::{Constraint, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Clear, Gauge, List, ListItem, Paragraph, Wrap},
+    widgets::{
+        Block, BorderType, Borders, Clear, Gauge, List, ListItem, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Wrap,
+    },
 };
 
+use std::time::Duration;
+
+use crate::core::metrics::OperationTiming;
+use crate::core::types::{Feedback, PrefixScore, SuggestionQuality};
+use crate::domain::CandidateDiff;
+use crate::infrastructure::format_turn_time;
+use crate::presentation::tui::animation::TilePhase;
+use crate::presentation::tui::focus::Panel;
+use crate::presentation::tui::hints::contextual_hint;
+use crate::presentation::tui::keyboard::{
+    KEY_STRIDE, KEY_WIDTH, KEYBOARD_ROWS, KeyboardKey, OnScreenKeyboard, letter_feedback,
+};
 use crate::presentation::tui::mode::InteractionMode;
 use crate::presentation::tui::state::{LogLevel, MessageType, TuiState};
 
@@ -29,6 +44,19 @@ impl Colors {
     pub const INPUT: Color = Color::Cyan;
 }
 
+/// Border of a focusable content panel; the focused one is doubled and highlighted
+fn panel_block(state: &TuiState, panel: Panel, color: Color) -> Block<'static> {
+    let (border_type, color) = if state.focus.is_focused(panel) {
+        (BorderType::Double, Colors::ACCENT)
+    } else {
+        (BorderType::Rounded, color)
+    };
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(border_type)
+        .border_style(Style::default().fg(color))
+}
+
 /// Render the main title bar
 pub fn render_title(frame: &mut Frame, area: Rect) {
     let title = Paragraph::new("🎯 Modern Wordle Solver · Press 'h' for help")
@@ -54,44 +82,135 @@ pub fn render_title(frame: &mut Frame, area: Rect) {
 
 /// Render the input area
 pub fn render_input(frame: &mut Frame, area: Rect, state: &TuiState) {
-    let input_text = if state.input.len() < 5 {
-        format!(
-            "{}{}",
-            state.input.to_uppercase(),
-            "█".repeat(5 - state.input.len())
-        )
-    } else {
-        state.input.to_uppercase()
-    };
-
     let style = if state.is_input_valid() {
         Style::default().fg(Colors::SUCCESS)
     } else {
         Style::default().fg(Colors::INPUT)
     };
+    // Letters locked in hard mode are dimmed, and shown ahead of the cursor too
+    let typed: Vec<char> = state.input.to_uppercase().chars().collect();
+    let tiles: Vec<Span> = (0..5)
+        .map(|pos| match (typed.get(pos), state.locked_letters[pos]) {
+            (_, Some(locked)) => Span::styled(
+                locked.to_ascii_uppercase().to_string(),
+                Style::default().fg(Colors::MUTED),
+            ),
+            (Some(letter), None) => Span::raw(letter.to_string()),
+            (None, None) => Span::raw("█"),
+        })
+        .collect();
+    let input_text = Line::from(tiles);
+
+    // A refused character turns the box red for a moment instead of landing in the guess
+    let (title, border) = match state.rejected_char() {
+        Some(c) => (format!(" '{}' rejected: letters only ", c), Colors::ERROR),
+        None if state.has_locked_letters() => (
+            " Enter Your Guess (greens locked) ".to_string(),
+            Colors::ACCENT,
+        ),
+        None => (" Enter Your Guess ".to_string(), Colors::ACCENT),
+    };
 
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(border))
+        .title(title)
+        .title_style(Style::default().fg(border));
+    if let Some(score) = state.typed_prefix_score() {
+        block = block.title_bottom(Span::styled(
+            prefix_hint(score, state.hide_suggestion),
+            Style::default().fg(Colors::MUTED),
+        ));
+    }
     let input = Paragraph::new(input_text)
         .style(style.add_modifier(Modifier::BOLD))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Colors::ACCENT))
-                .title(" Enter Your Guess ")
-                .title_style(Style::default().fg(Colors::ACCENT)),
-        );
+        .block(block);
 
     frame.render_widget(input, area);
 }
 
-/// Render the current suggestion
+/// Line under the input box about the letters typed so far; the best completion stays
+/// hidden while suggestions are
+fn prefix_hint(score: &PrefixScore, hide_suggestion: bool) -> String {
+    let prefix = score.prefix.to_uppercase();
+    let count = match score.consistent {
+        0 => return format!(" No remaining answer starts with {} ", prefix),
+        1 => "1 answer starts".to_string(),
+        count => format!("{} answers start", count),
+    };
+    match score.best_completion.as_ref().filter(|_| !hide_suggestion) {
+        Some((word, bits)) => format!(
+            " {} with {} · best {} ({:.1} bits) ",
+            count,
+            prefix,
+            word.as_str().to_uppercase(),
+            bits
+        ),
+        None => format!(" {} with {} ", count, prefix),
+    }
+}
+
+/// Render the current suggestion, or one column per risk level once a suggestion set is known
 pub fn render_suggestion(frame: &mut Frame, area: Rect, state: &TuiState) {
-    let suggestion_text = match &state.current_suggestion {
-        Some(word) => format!("💡 Suggested: {}", word.to_uppercase()),
-        None => "💡 Getting suggestion...".to_string(),
+    // Down to the last guesses every pick is an answer, so one banner replaces the set
+    let urgent = state.urgent && !state.hide_suggestion && !state.is_solved && !state.game_over;
+    let set = state
+        .suggestion_set
+        .as_ref()
+        .filter(|_| !state.hide_suggestion && !urgent);
+    if let Some(set) = set {
+        let columns = Layout::horizontal([Constraint::Ratio(1, 3); 3]).split(area);
+        for ((kind, suggestion), column) in set.iter().zip(columns.iter()) {
+            let quality = &suggestion.quality;
+            let mut spans = heat_spans(suggestion.word.as_str(), state);
+            spans.push(Span::styled(
+                format!(
+                    " {:.2}b {:.0}%",
+                    quality.entropy,
+                    quality.answer_probability * 100.0
+                ),
+                Style::default().fg(Colors::FOREGROUND),
+            ));
+            let line = Line::from(spans);
+            let pick = Paragraph::new(line).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Colors::WARNING))
+                    .title(format!(" {} ", kind.label()))
+                    .title_style(Style::default().fg(Colors::WARNING)),
+            );
+            frame.render_widget(pick, *column);
+        }
+        return;
+    }
+
+    let suggestion_line = match &state.current_suggestion {
+        _ if state.hide_suggestion => Line::from("💡 Hidden while you play against the solver"),
+        Some(word) => {
+            let mut spans = vec![Span::raw("💡 Suggested: ")];
+            spans.extend(heat_spans(word, state));
+            if let Some((_, heat)) = state.suggestion_heat.as_ref().filter(|(w, _)| w == word) {
+                let bits: Vec<String> = heat.iter().map(|b| format!("{:.1}", b)).collect();
+                spans.push(Span::styled(
+                    format!("  letter bits {}", bits.join(" ")),
+                    Style::default()
+                        .fg(Colors::MUTED)
+                        .remove_modifier(Modifier::BOLD),
+                ));
+            }
+            Line::from(spans)
+        }
+        None => Line::from("💡 Getting suggestion..."),
     };
 
-    let suggestion = Paragraph::new(suggestion_text)
+    let (title, color) = if urgent {
+        (" URGENT: guess an answer candidate ", Colors::ERROR)
+    } else {
+        (" AI Suggestion ", Colors::WARNING)
+    };
+    let suggestion = Paragraph::new(suggestion_line)
         .style(
             Style::default()
                 .fg(Colors::WARNING)
@@ -101,22 +220,58 @@ pub fn render_suggestion(frame: &mut Frame, area: Rect, state: &TuiState) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Colors::WARNING))
-                .title(" AI Suggestion ")
-                .title_style(Style::default().fg(Colors::WARNING)),
+                .border_style(Style::default().fg(color))
+                .title(title)
+                .title_style(
+                    Style::default()
+                        .fg(color)
+                        .add_modifier(Modifier::BOLD),
+                ),
         );
 
     frame.render_widget(suggestion, area);
 }
 
-/// Render the guess history
+/// Letters of `word`, colored by how much information each brings when it is the heat-mapped
+/// suggestion: red for the strongest letters, yellow for middling, grey for the rest
+fn heat_spans(word: &str, state: &TuiState) -> Vec<Span<'static>> {
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let Some((_, heat)) = state.suggestion_heat.as_ref().filter(|(w, _)| w == word) else {
+        return vec![Span::styled(word.to_uppercase(), bold.fg(Colors::WARNING))];
+    };
+    let hottest = heat.iter().copied().fold(0.0, f64::max);
+    word.chars()
+        .zip(heat)
+        .map(|(letter, &bits)| {
+            let share = if hottest > 0.0 { bits / hottest } else { 0.0 };
+            let color = if share >= 0.66 {
+                Colors::ERROR
+            } else if share >= 0.33 {
+                Colors::WARNING
+            } else {
+                Colors::MUTED
+            };
+            Span::styled(letter.to_ascii_uppercase().to_string(), bold.fg(color))
+        })
+        .collect()
+}
+
+/// Render the guess history, scrolled to `state.history_scroll`, with a scrollbar on overflow
 pub fn render_history(frame: &mut Frame, area: Rect, state: &TuiState) {
-    let history_items: Vec<ListItem> = state
-        .guess_history
+    let rows = area.height.saturating_sub(2) as usize;
+    let window = state.history_window(rows);
+    let total = state.guess_history.len();
+    let overflow = window.len() < total;
+    let mut history_items: Vec<ListItem> = state.guess_history[window.clone()]
         .iter()
-        .enumerate()
-        .map(|(i, entry)| {
-            let feedback_line = colorize_feedback(&entry.feedback, &entry.word);
+        .zip(window.clone())
+        .map(|(entry, i)| {
+            let feedback_line = match &state.reveal {
+                Some(reveal) if reveal.row() == i => {
+                    animate_feedback(&entry.feedback, &entry.word, |tile| reveal.phase(tile))
+                }
+                _ => colorize_feedback(&entry.feedback, &entry.word),
+            };
             let mut full_line = vec![Span::styled(
                 format!("{}. ", i + 1),
                 Style::default().fg(Colors::MUTED),
@@ -131,21 +286,63 @@ pub fn render_history(frame: &mut Frame, area: Rect, state: &TuiState) {
         })
         .collect();
 
+    // The classic grid below the played rows, while scrolled to the latest guess
+    let (typing, empty) = state.blank_board_rows();
+    if window.end == total {
+        let typing_row = typing.then(|| input_tiles(&state.input));
+        let empty_rows = std::iter::repeat_with(|| input_tiles("")).take(empty);
+        let numbered = typing_row.into_iter().chain(empty_rows).zip(total + 1..);
+        for (tiles, number) in numbered.take(rows.saturating_sub(history_items.len())) {
+            let mut line = vec![Span::styled(
+                format!("{}. ", number),
+                Style::default().fg(Colors::MUTED),
+            )];
+            line.extend(tiles.spans);
+            history_items.push(ListItem::new(Line::from(line)));
+        }
+    }
+
+    let title = if overflow {
+        format!(
+            " Guess History ({}-{} of {}) ",
+            window.start + 1,
+            window.end,
+            total
+        )
+    } else {
+        format!(" Guess History ({}) ", total)
+    };
     let history = List::new(history_items).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Colors::INFO))
-            .title(format!(" Guess History ({}) ", state.guess_history.len()))
+        panel_block(state, Panel::History, Colors::INFO)
+            .title(title)
             .title_style(Style::default().fg(Colors::INFO)),
     );
 
     frame.render_widget(history, area);
+
+    if overflow {
+        // Positions run from 0 (first guess at the top) to total - visible
+        let mut scrollbar_state = ScrollbarState::new(total - window.len() + 1)
+            .viewport_content_length(window.len())
+            .position(window.start);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .style(Style::default().fg(Colors::INFO));
+        frame.render_stateful_widget(
+            scrollbar,
+            area.inner(&Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut scrollbar_state,
+        );
+    }
 }
 
 /// Render statistics panel
 pub fn render_stats(frame: &mut Frame, area: Rect, state: &TuiState) {
-    let stats_text = vec![
+    let mut stats_text = vec![
         Line::from(vec![
             Span::styled("Remaining Words: ", Style::default().fg(Colors::FOREGROUND)),
             Span::styled(
@@ -192,11 +389,66 @@ pub fn render_stats(frame: &mut Frame, area: Rect, state: &TuiState) {
         ]),
     ];
 
+    if let Some(pack) = &state.pack {
+        stats_text.insert(
+            0,
+            Line::from(vec![
+                Span::styled("Pack: ", Style::default().fg(Colors::FOREGROUND)),
+                Span::styled(
+                    pack.label(),
+                    Style::default()
+                        .fg(Colors::ACCENT)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+        );
+    }
+
+    if let Some(last) = state.stats.entropy.last() {
+        stats_text.push(Line::from(vec![
+            Span::styled("Bits Gained: ", Style::default().fg(Colors::FOREGROUND)),
+            Span::styled(
+                format!("{:.2}", state.stats.bits_gained()),
+                Style::default()
+                    .fg(Colors::INFO)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        let mut last_turn = format!("{:.2} expected", last.played_entropy);
+        if let Some((_, bits)) = &last.suggested {
+            last_turn.push_str(&format!(" (best {:.2})", bits));
+        }
+        stats_text.push(Line::from(vec![
+            Span::styled("Last Guess: ", Style::default().fg(Colors::FOREGROUND)),
+            Span::styled(last_turn, Style::default().fg(Colors::MUTED)),
+        ]));
+    }
+
+    let think = state.stats.average_think_time();
+    let solver = state.stats.average_solver_time();
+    if think.is_some() || solver.is_some() {
+        let show = |time: Option<Duration>| time.map_or("-".to_string(), format_turn_time);
+        stats_text.push(Line::from(vec![
+            Span::styled("Avg. Turn: ", Style::default().fg(Colors::FOREGROUND)),
+            Span::styled(
+                format!("you {} / solver {}", show(think), show(solver)),
+                Style::default().fg(Colors::MUTED),
+            ),
+        ]));
+    }
+
+    if !state.manual_constraints.is_empty() {
+        stats_text.push(Line::from(vec![
+            Span::styled("Pinned/banned: ", Style::default().fg(Colors::FOREGROUND)),
+            Span::styled(
+                manual_constraint_list(state),
+                Style::default().fg(Colors::ACCENT),
+            ),
+        ]));
+    }
+
     let stats = Paragraph::new(stats_text).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Colors::SUCCESS))
+        panel_block(state, Panel::Stats, Colors::SUCCESS)
             .title(" Statistics ")
             .title_style(Style::default().fg(Colors::SUCCESS)),
     );
@@ -204,33 +456,303 @@ pub fn render_stats(frame: &mut Frame, area: Rect, state: &TuiState) {
     frame.render_widget(stats, area);
 }
 
-/// Render remaining words sample
+/// Render remaining words sample (the whole page when zoomed)
 pub fn render_remaining_words(frame: &mut Frame, area: Rect, state: &TuiState) {
+    if state.spoilers().hides_candidates() {
+        let hidden = Paragraph::new(format!(
+            "Hidden in no-spoiler mode ({} words possible) - v in operation mode shows them",
+            state.remaining_words
+        ))
+        .style(Style::default().fg(Colors::MUTED))
+        .wrap(Wrap { trim: true })
+        .block(
+            panel_block(state, Panel::Candidates, Colors::MUTED)
+                .title(" Candidates & Possible Words ")
+                .title_style(Style::default().fg(Colors::MUTED)),
+        );
+        frame.render_widget(hidden, area);
+        return;
+    }
+    if state.show_clusters {
+        render_word_families(frame, area, state);
+        return;
+    }
+    if state.show_word_graph {
+        render_word_graph(frame, area, state);
+        return;
+    }
+    let zoomed = state.focus.zoomed() == Some(Panel::Candidates);
+    let shown = if zoomed { usize::MAX } else { 10 };
     let words_text = if state.remaining_words_sample.is_empty() {
         "No words available".to_string()
     } else {
         state
             .remaining_words_sample
             .iter()
-            .take(10)
+            .take(shown)
             .map(|w| w.to_uppercase())
             .collect::<Vec<_>>()
             .join(", ")
     };
 
-    let remaining = Paragraph::new(words_text)
+    let mut lines = tray_lines(state);
+    lines.extend(ranked_candidate_lines(state));
+    if !lines.is_empty() {
+        lines.push(Line::from(""));
+    }
+    if let Some(diff) = &state.candidate_diff {
+        lines.extend(candidate_diff_lines(diff));
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(words_text));
+
+    let mut block = panel_block(state, Panel::Candidates, Colors::MUTED)
+        .title(" Candidates & Possible Words ")
+        .title_style(Style::default().fg(Colors::MUTED));
+    if zoomed && !state.remaining_words_sample.is_empty() {
+        let first = state.remaining_words_offset + 1;
+        let last = state.remaining_words_offset + state.remaining_words_sample.len();
+        block = block.title_bottom(format!(
+            " words {}-{} of {}  •  PgUp/PgDn ",
+            first, last, state.remaining_words
+        ));
+    }
+    let remaining = Paragraph::new(lines)
+        .style(Style::default().fg(Colors::MUTED))
+        .wrap(Wrap { trim: true })
+        .block(block);
+
+    frame.render_widget(remaining, area);
+}
+
+/// Top candidates as a table: strategy score next to the chance of being the answer
+fn ranked_candidate_lines(state: &TuiState) -> Vec<Line<'static>> {
+    if state.ranked_candidates.is_empty() {
+        return Vec::new();
+    }
+    let header = Style::default()
+        .fg(Colors::ACCENT)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = vec![Line::from(Span::styled(
+        format!("{:<7}{:>7}{:>11}", "Top", "Score", "P(answer)"),
+        header,
+    ))];
+    let selecting = state.focus.is_focused(Panel::Candidates);
+    let selected = state.selected_candidate().map(|c| &c.word);
+    lines.extend(state.ranked_candidates.iter().map(|candidate| {
+        let chance = answer_chance(candidate.answer_probability);
+        let mut word_style = Style::default().fg(Colors::FOREGROUND);
+        // Already played this game: dimmed, as it can't tell anything new
+        if state.has_guessed(candidate.word.as_str()) {
+            word_style = word_style.fg(Colors::MUTED);
+        }
+        if state.tray.contains(&candidate.word) {
+            word_style = word_style.fg(Colors::ACCENT);
+        }
+        if selecting && selected == Some(&candidate.word) {
+            word_style = word_style.add_modifier(Modifier::REVERSED);
+        }
+        Line::from(vec![
+            Span::styled(
+                format!("{:<6}", candidate.word.as_str().to_uppercase()),
+                word_style,
+            ),
+            Span::raw(format!(" {:>7.2}{:>11}", candidate.score, chance)),
+        ])
+    }));
+    lines
+}
+
+fn answer_chance(probability: f64) -> String {
+    if probability > 0.0 {
+        format!("{:.1}%", probability * 100.0)
+    } else {
+        "-".to_string()
+    }
+}
+
+/// Pinned words side by side, then what each pair of them would do played in a row
+fn tray_lines(state: &TuiState) -> Vec<Line<'static>> {
+    if state.tray.is_empty() {
+        return Vec::new();
+    }
+    let header = Style::default()
+        .fg(Colors::ACCENT)
+        .add_modifier(Modifier::BOLD);
+    let metrics = |quality: &SuggestionQuality| {
+        format!(
+            "{:>6.2}{:>7}{:>7}{:>9}",
+            quality.entropy,
+            quality.worst_case_remaining,
+            quality.bucket_count,
+            answer_chance(quality.answer_probability)
+        )
+    };
+    let mut lines = vec![Line::from(Span::styled(
+        format!("{:<13}{:>6}{:>7}{:>7}{:>9}", "Pinned", "Bits", "Worst", "Groups", "P(win)"),
+        header,
+    ))];
+    let pinned = state.tray.pinned();
+    for pin in pinned {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<13}", pin.word.as_str().to_uppercase()),
+                Style::default().fg(Colors::ACCENT),
+            ),
+            Span::raw(pin.quality.as_ref().map(metrics).unwrap_or_default()),
+        ]));
+    }
+    for (first, second, quality) in state.tray.pairs() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    "{:<13}",
+                    format!(
+                        "{}+{}",
+                        pinned[*first].word.as_str().to_uppercase(),
+                        pinned[*second].word.as_str().to_uppercase()
+                    )
+                ),
+                Style::default().fg(Colors::FOREGROUND),
+            ),
+            Span::raw(metrics(quality)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines
+}
+
+/// What the latest guess did: the count it ruled out, then notable words from before it,
+/// crossed out when eliminated
+fn candidate_diff_lines(diff: &CandidateDiff) -> Vec<Line<'static>> {
+    let header = Line::from(Span::styled(
+        format!(
+            "{}: {} → {} ({} ruled out)",
+            diff.guess.word.as_str().to_uppercase(),
+            diff.before,
+            diff.after,
+            diff.eliminated()
+        ),
+        Style::default()
+            .fg(Colors::ACCENT)
+            .add_modifier(Modifier::BOLD),
+    ));
+    let mut words = Vec::new();
+    for (word, survived) in &diff.notable {
+        let style = if *survived {
+            Style::default().fg(Colors::SUCCESS)
+        } else {
+            Style::default()
+                .fg(Colors::ERROR)
+                .add_modifier(Modifier::CROSSED_OUT)
+        };
+        words.push(Span::styled(word.as_str().to_uppercase(), style));
+        words.push(Span::raw(" "));
+    }
+    words.pop();
+    vec![header, Line::from(words)]
+}
+
+/// Render the remaining words grouped into families, one line per family
+fn render_word_families(frame: &mut Frame, area: Rect, state: &TuiState) {
+    let lines: Vec<Line> = if state.clusters.is_empty() {
+        vec![Line::from("No word families among the remaining words")]
+    } else {
+        state
+            .clusters
+            .iter()
+            .map(|cluster| {
+                let words: Vec<String> = cluster
+                    .words
+                    .iter()
+                    .map(|w| w.as_str().to_uppercase())
+                    .collect();
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ({}) ", cluster.pattern, cluster.len()),
+                        Style::default()
+                            .fg(Colors::ACCENT)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(words.join(", ")),
+                ])
+            })
+            .collect()
+    };
+
+    let families = Paragraph::new(lines)
         .style(Style::default().fg(Colors::MUTED))
         .wrap(Wrap { trim: true })
         .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Colors::MUTED))
-                .title(" Possible Words (Sample) ")
+            panel_block(state, Panel::Candidates, Colors::MUTED)
+                .title(format!(" Word Families ({}) ", state.clusters.len()))
                 .title_style(Style::default().fg(Colors::MUTED)),
         );
+    frame.render_widget(families, area);
+}
 
-    frame.render_widget(remaining, area);
+/// Render the one-letter-change groups: size, the words holding each together, the
+/// guess that splits it and its words
+fn render_word_graph(frame: &mut Frame, area: Rect, state: &TuiState) {
+    let accent = Style::default()
+        .fg(Colors::ACCENT)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = Vec::new();
+    let title = match &state.word_graph {
+        None => {
+            lines.push(Line::from("Make a guess to see how the possible words link up"));
+            " Word Graph ".to_string()
+        }
+        Some(report) if report.groups.is_empty() => {
+            lines.push(Line::from("No two possible words differ by a single letter"));
+            " Word Graph ".to_string()
+        }
+        Some(report) => {
+            for group in &report.groups {
+                let mut header = vec![Span::styled(
+                    format!("{} words, {} links", group.len(), group.edges),
+                    accent,
+                )];
+                if let Some(breaker) = &group.breaker {
+                    header.push(Span::raw(format!(
+                        "  split by {} (worst {})",
+                        breaker.word.as_str().to_uppercase(),
+                        breaker.quality.worst_case_remaining
+                    )));
+                }
+                lines.push(Line::from(header));
+                if !group.articulation.is_empty() {
+                    let cuts: Vec<String> = group
+                        .articulation
+                        .iter()
+                        .map(|w| w.as_str().to_uppercase())
+                        .collect();
+                    lines.push(Line::from(Span::styled(
+                        format!("  held by {}", cuts.join(", ")),
+                        Style::default().fg(Colors::WARNING),
+                    )));
+                }
+                let words: Vec<String> =
+                    group.words.iter().map(|w| w.as_str().to_uppercase()).collect();
+                lines.push(Line::from(format!("  {}", words.join(", "))));
+            }
+            format!(
+                " Word Graph ({} groups, {:.0}% linked) ",
+                report.groups.len(),
+                report.entanglement() * 100.0
+            )
+        }
+    };
+
+    let graph = Paragraph::new(lines)
+        .style(Style::default().fg(Colors::MUTED))
+        .wrap(Wrap { trim: true })
+        .block(
+            panel_block(state, Panel::Candidates, Colors::MUTED)
+                .title(title)
+                .title_style(Style::default().fg(Colors::MUTED)),
+        );
+    frame.render_widget(graph, area);
 }
 
 /// Render help popup
@@ -313,6 +835,109 @@ pub fn render_help(frame: &mut Frame, area: Rect, state: &TuiState) {
     frame.render_widget(help, area);
 }
 
+/// Render the dialog for pinning and banning letters the game hasn't revealed
+pub fn render_constraint_dialog(frame: &mut Frame, area: Rect, state: &TuiState) {
+    let typed = state.constraint_dialog.as_deref().unwrap_or_default();
+    let in_force = if state.manual_constraints.is_empty() {
+        "none".to_string()
+    } else {
+        manual_constraint_list(state)
+    };
+    let muted = Style::default().fg(Colors::MUTED);
+    let text = vec![
+        Line::from("a3 pins A as the third letter; -x bans X. Several at once: a3 -x -z"),
+        Line::from(Span::styled("clear removes them all", muted)),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(Colors::ACCENT)),
+            Span::styled(
+                format!("{}█", typed),
+                Style::default()
+                    .fg(Colors::INPUT)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("In force: ", Style::default().fg(Colors::FOREGROUND)),
+            Span::styled(in_force, Style::default().fg(Colors::ACCENT)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("Enter: apply  Esc: cancel", muted)),
+    ];
+
+    let dialog = Paragraph::new(text).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Colors::ACCENT))
+            .title(" Pin or ban letters ")
+            .title_style(
+                Style::default()
+                    .fg(Colors::ACCENT)
+                    .add_modifier(Modifier::BOLD),
+            ),
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(dialog, area);
+}
+
+/// Render the on-screen keyboard, letters colored by their best feedback so far
+pub fn render_keyboard(frame: &mut Frame, area: Rect, state: &TuiState) {
+    let letters = letter_feedback(&state.guess_history);
+    let gap = " ".repeat(usize::from(KEY_STRIDE - KEY_WIDTH));
+    let lines: Vec<Line> = (0..KEYBOARD_ROWS)
+        .map(|row| {
+            let indent = " ".repeat(usize::from(OnScreenKeyboard::row_indent(row)));
+            let mut spans = vec![Span::raw(indent)];
+            for (index, key) in OnScreenKeyboard::row_keys(row).into_iter().enumerate() {
+                let feedback = match key {
+                    KeyboardKey::Letter(c) => letters[usize::from(c as u8 - b'a')],
+                    _ => None,
+                };
+                let tile = |color| Style::default().fg(Color::Black).bg(color);
+                let mut style = match feedback {
+                    Some(Feedback::Correct) => tile(Colors::CORRECT),
+                    Some(Feedback::Present) => tile(Colors::PRESENT),
+                    Some(Feedback::Absent) => Style::default().fg(Color::DarkGray),
+                    None => Style::default().fg(Colors::FOREGROUND),
+                };
+                if state.keyboard.is_selected(row, index) {
+                    style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+                }
+                if index > 0 {
+                    spans.push(Span::raw(gap.clone()));
+                }
+                spans.push(Span::styled(key.label(), style));
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    let keyboard = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Colors::ACCENT))
+            .title(" Keyboard "),
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(keyboard, area);
+    state.keyboard_area.set(Some(area));
+}
+
+/// Pinned and banned letters as typed, e.g. "A3 -X"
+fn manual_constraint_list(state: &TuiState) -> String {
+    state
+        .manual_constraints
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Render status message
 pub fn render_status(frame: &mut Frame, area: Rect, state: &TuiState) {
     if let Some(status) = &state.status_message {
@@ -361,10 +986,7 @@ pub fn render_logs(frame: &mut Frame, area: Rect, state: &TuiState) {
         .collect();
 
     let logs = List::new(log_items).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Colors::MUTED))
+        panel_block(state, Panel::Logs, Colors::MUTED)
             .title(" Logs ")
             .title_style(Style::default().fg(Colors::MUTED)),
     );
@@ -400,10 +1022,18 @@ pub fn render_progress(frame: &mut Frame, area: Rect, state: &TuiState) {
 
 /// Helper function to colorize feedback text
 fn colorize_feedback(feedback: &str, word: &str) -> Line<'static> {
-    // Render letters as rounded "tiles" with colored backgrounds
+    animate_feedback(feedback, word, |_| TilePhase::Revealed)
+}
+
+/// Render letters as tiles, each drawn in the phase `phase(tile)` of its reveal
+fn animate_feedback(
+    feedback: &str,
+    word: &str,
+    phase: impl Fn(usize) -> TilePhase,
+) -> Line<'static> {
     let mut spans = Vec::new();
 
-    for (c, f) in word.chars().zip(feedback.chars()) {
+    for (i, (c, f)) in word.chars().zip(feedback.chars()).enumerate() {
         let bg = match f {
             '2' => Colors::CORRECT,
             '1' => Colors::PRESENT,
@@ -411,13 +1041,24 @@ fn colorize_feedback(feedback: &str, word: &str) -> Line<'static> {
             _ => Colors::MUTED,
         };
 
-        spans.push(Span::styled(
-            format!(" {} ", c.to_uppercase()),
-            Style::default()
-                .fg(Color::Black)
-                .bg(bg)
-                .add_modifier(Modifier::BOLD),
-        ));
+        let tile = match phase(i) {
+            TilePhase::Hidden => Span::styled(
+                format!(" {} ", c.to_uppercase()),
+                Style::default()
+                    .fg(Colors::INPUT)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            // Edge-on: a thin bar in the color about to show
+            TilePhase::Flipping => Span::styled("───", Style::default().fg(bg)),
+            TilePhase::Revealed => Span::styled(
+                format!(" {} ", c.to_uppercase()),
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(bg)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        };
+        spans.push(tile);
         spans.push(Span::raw(" "));
     }
 
@@ -428,6 +1069,26 @@ fn colorize_feedback(feedback: &str, word: &str) -> Line<'static> {
     Line::from(spans)
 }
 
+/// Unscored tiles for a row of the classic board: typed letters, then empty squares
+fn input_tiles(input: &str) -> Line<'static> {
+    let mut letters = input.chars().map(|c| c.to_ascii_uppercase());
+    let mut spans = Vec::new();
+    for _ in 0..5 {
+        spans.push(match letters.next() {
+            Some(c) => Span::styled(
+                format!("[{}]", c),
+                Style::default()
+                    .fg(Colors::INPUT)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            None => Span::styled("[ ]", Style::default().fg(Colors::MUTED)),
+        });
+        spans.push(Span::raw(" "));
+    }
+    spans.pop();
+    Line::from(spans)
+}
+
 /// Render feedback input area (when in feedback mode)
 pub fn render_feedback_input(
     frame: &mut Frame,
@@ -435,8 +1096,34 @@ pub fn render_feedback_input(
     current_guess: &str,
     feedback_input: &str,
     cursor_pos: usize,
+    awaiting_confirmation: bool,
+    rejected: Option<char>,
 ) {
-    let title = format!(" Feedback for '{}' ", current_guess.to_uppercase());
+    if awaiting_confirmation {
+        // Preview the pattern on the guessed letters before it is committed
+        let preview = Paragraph::new(colorize_feedback(feedback_input, current_guess)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Colors::ACCENT))
+                .title(" Confirm feedback: Enter/y ")
+                .title_style(
+                    Style::default()
+                        .fg(Colors::ACCENT)
+                        .add_modifier(Modifier::BOLD),
+                ),
+        );
+        frame.render_widget(preview, area);
+        return;
+    }
+
+    let (title, border) = match rejected {
+        Some(c) => (format!(" '{}' rejected: use 0, 1 or 2 ", c), Colors::ERROR),
+        None => (
+            format!(" Feedback for '{}' ", current_guess.to_uppercase()),
+            Colors::WARNING,
+        ),
+    };
 
     // Build a line of 5 tiles representing feedback digits with a blinking cursor tile
     let mut spans: Vec<Span> = Vec::with_capacity(9);
@@ -475,13 +1162,9 @@ pub fn render_feedback_input(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Colors::WARNING))
+                .border_style(Style::default().fg(border))
                 .title(title)
-                .title_style(
-                    Style::default()
-                        .fg(Colors::WARNING)
-                        .add_modifier(Modifier::BOLD),
-                ),
+                .title_style(Style::default().fg(border).add_modifier(Modifier::BOLD)),
         );
 
     frame.render_widget(feedback_widget, area);
@@ -531,7 +1214,8 @@ pub fn render_feedback_help(frame: &mut Frame, area: Rect) {
         Line::from(""),
         Line::from("⌨️ Controls:"),
         Line::from("  • 0, 1, 2: Enter feedback"),
-        Line::from("  • Enter: Submit feedback"),
+        Line::from("  • Enter: Preview feedback"),
+        Line::from("  • Enter or y: Confirm preview"),
         Line::from("  • Backspace: Delete character"),
         Line::from("  • Esc: Cancel feedback input"),
         Line::from(""),
@@ -596,7 +1280,7 @@ pub fn render_mode_indicator(frame: &mut Frame, area: Rect, state: &TuiState) {
     frame.render_widget(mode_indicator, area);
 }
 
-/// Render a compact footer command bar with common shortcuts
+/// Render the footer: the most relevant hint for the game state, or the common shortcuts
 pub fn render_footer(frame: &mut Frame, area: Rect, state: &TuiState) {
     let mode = state.interaction_mode();
     let key = |k: &str| {
@@ -610,6 +1294,69 @@ pub fn render_footer(frame: &mut Frame, area: Rect, state: &TuiState) {
     let sep = Span::styled("  •  ", Style::default().fg(Colors::MUTED));
 
     let mut spans: Vec<Span> = Vec::new();
+    if let Some(hint) = contextual_hint(state) {
+        spans.push(key(hint.key));
+        spans.push(Span::styled(
+            format!(" {}", hint.text),
+            Style::default().fg(Colors::WARNING),
+        ));
+        spans.push(sep);
+        spans.push(key("h"));
+        spans.push(Span::styled(" Help", Style::default().fg(Colors::MUTED)));
+    } else {
+        push_shortcuts(&mut spans, key, sep);
+    }
+
+    // Mode hint at the end
+    spans.push(Span::styled(
+        format!("   [{} mode]", mode.name()),
+        Style::default()
+            .fg(Colors::INFO)
+            .add_modifier(Modifier::ITALIC),
+    ));
+    // What the last solver step cost, to notice heavier strategies or lists
+    if let Some(timing) = &state.last_operation {
+        spans.push(Span::styled(
+            format!("   {}", format_operation(timing)),
+            Style::default().fg(Colors::MUTED),
+        ));
+    }
+
+    let footer = Paragraph::new(Line::from(spans))
+        .style(Style::default().fg(Colors::FOREGROUND))
+        .block(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(Colors::MUTED)),
+        );
+
+    frame.render_widget(footer, area);
+}
+
+/// A solver operation as "suggest 142ms / 12,402 cands"
+fn format_operation(timing: &OperationTiming) -> String {
+    let digits = timing.candidates.to_string();
+    let mut candidates = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            candidates.push(',');
+        }
+        candidates.push(digit);
+    }
+    format!(
+        "{} {} / {} cands",
+        timing.operation,
+        format_turn_time(timing.elapsed),
+        candidates
+    )
+}
+
+/// The footer's default list of common shortcuts
+fn push_shortcuts(
+    spans: &mut Vec<Span<'static>>,
+    key: impl Fn(&str) -> Span<'static>,
+    sep: Span<'static>,
+) {
     spans.push(key("Esc/Tab"));
     spans.push(Span::styled(
         " Switch Mode",
@@ -633,22 +1380,4 @@ pub fn render_footer(frame: &mut Frame, area: Rect, state: &TuiState) {
     spans.push(sep);
     spans.push(key("q"));
     spans.push(Span::styled(" Quit", Style::default().fg(Colors::MUTED)));
-
-    // Mode hint at the end
-    spans.push(Span::styled(
-        format!("   [{} mode]", mode.name()),
-        Style::default()
-            .fg(Colors::INFO)
-            .add_modifier(Modifier::ITALIC),
-    ));
-
-    let footer = Paragraph::new(Line::from(spans))
-        .style(Style::default().fg(Colors::FOREGROUND))
-        .block(
-            Block::default()
-                .borders(Borders::TOP)
-                .border_style(Style::default().fg(Colors::MUTED)),
-        );
-
-    frame.render_widget(footer, area);
 }
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use std::time::Duration;
 
 use super::mode::InteractionMode;
@@ -13,6 +13,10 @@ pub enum TuiEvent {
     Tick,
     /// Resize event
     Resize,
+    /// Bracketed paste of text
+    Paste(String),
+    /// Left mouse button pressed (or a tap) at a screen cell
+    Click { column: u16, row: u16 },
     /// Application quit event
     Quit,
 }
@@ -54,6 +58,52 @@ pub enum KeyAction {
     SwitchToOperationMode,
     /// Toggle between input and operation modes
     ToggleMode,
+    /// Complete the typed letters to a dictionary word, or move on to the next one
+    Complete,
+    /// Pasted text, e.g. several "word feedback" lines
+    Paste(String),
+    /// Highlight the previous list entry
+    SelectPrevious,
+    /// Highlight the next list entry
+    SelectNext,
+    /// Return to the start menu
+    ShowMenu,
+    /// Focus the previous game panel
+    FocusPrevious,
+    /// Focus the next game panel
+    FocusNext,
+    /// Zoom the focused panel to full screen, or back
+    ToggleZoom,
+    /// Take back the latest guess
+    Undo,
+    /// Show the possible words as families ("_ATCH") or as a plain list
+    ToggleClusters,
+    /// Show how the possible words link up by one-letter changes
+    ToggleWordGraph,
+    /// Switch hard mode on for the current game and future ones
+    EnableHardMode,
+    /// Open the dialog for pinning or banning letters the game hasn't revealed
+    OpenConstraintDialog,
+    /// Close a dialog without applying it
+    CloseDialog,
+    /// Pin the highlighted top candidate to the comparison tray, or unpin it
+    TogglePin,
+    /// Empty the comparison tray
+    ClearTray,
+    /// Show or hide the possible words in no-spoiler mode
+    ToggleCandidates,
+    /// Show or hide the on-screen keyboard
+    ToggleKeyboard,
+    /// A click or tap at a screen cell
+    Click { column: u16, row: u16 },
+    /// Scroll the guess history (or the candidate highlight) towards the first guess
+    ScrollUp,
+    /// Scroll the guess history (or the candidate highlight) towards the latest guess
+    ScrollDown,
+    /// Scroll the guess history up by a page
+    PageUp,
+    /// Scroll the guess history down by a page
+    PageDown,
     /// No action
     None,
 }
@@ -86,10 +136,35 @@ impl EventHandler {
                 code: KeyCode::Esc, ..
             } => KeyAction::ToggleMode,
 
+            // Tab completes a guess being typed; otherwise it switches modes like Esc
+            KeyEvent {
+                code: KeyCode::Tab, ..
+            } if is_typing && current_mode == InteractionMode::Input => KeyAction::Complete,
+
             KeyEvent {
                 code: KeyCode::Tab, ..
             } => KeyAction::ToggleMode,
 
+            // History scrolling (always available)
+            KeyEvent {
+                code: KeyCode::Up, ..
+            } => KeyAction::ScrollUp,
+
+            KeyEvent {
+                code: KeyCode::Down,
+                ..
+            } => KeyAction::ScrollDown,
+
+            KeyEvent {
+                code: KeyCode::PageUp,
+                ..
+            } => KeyAction::PageUp,
+
+            KeyEvent {
+                code: KeyCode::PageDown,
+                ..
+            } => KeyAction::PageDown,
+
             // Quit commands (always prioritized)
             KeyEvent {
                 code: KeyCode::Char('q'),
@@ -203,6 +278,177 @@ impl EventHandler {
                 ..
             } => KeyAction::Quit,
 
+            // Undo
+            KeyEvent {
+                code: KeyCode::Char('u'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => KeyAction::Undo,
+
+            // Start menu
+            KeyEvent {
+                code: KeyCode::Char('m'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => KeyAction::ShowMenu,
+
+            // Panel focus and zoom
+            KeyEvent {
+                code: KeyCode::Left,
+                ..
+            } => KeyAction::FocusPrevious,
+
+            KeyEvent {
+                code: KeyCode::Right,
+                ..
+            } => KeyAction::FocusNext,
+
+            KeyEvent {
+                code: KeyCode::Char('z'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => KeyAction::ToggleZoom,
+
+            // Word families
+            KeyEvent {
+                code: KeyCode::Char('g'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => KeyAction::ToggleClusters,
+
+            // One-letter-change graph
+            KeyEvent {
+                code: KeyCode::Char('w'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => KeyAction::ToggleWordGraph,
+
+            // Pin or ban letters
+            KeyEvent {
+                code: KeyCode::Char('p'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => KeyAction::OpenConstraintDialog,
+
+            // Possible words in no-spoiler mode
+            KeyEvent {
+                code: KeyCode::Char('v'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => KeyAction::ToggleCandidates,
+
+            // On-screen keyboard
+            KeyEvent {
+                code: KeyCode::Char('k'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => KeyAction::ToggleKeyboard,
+
+            // Shifted, so it isn't pressed by accident
+            KeyEvent {
+                code: KeyCode::Char('H'),
+                ..
+            } => KeyAction::EnableHardMode,
+
+            // Comparison tray
+            KeyEvent {
+                code: KeyCode::Char('t'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => KeyAction::TogglePin,
+
+            KeyEvent {
+                code: KeyCode::Char('T'),
+                ..
+            } => KeyAction::ClearTray,
+
+            _ => KeyAction::None,
+        }
+    }
+
+    /// Process key events on list-style screens (menu, statistics, explorer, settings)
+    ///
+    /// Esc means "back"; letters are passed through for search fields.
+    pub fn process_navigation_key(&self, key_event: KeyEvent) -> KeyAction {
+        match key_event {
+            KeyEvent {
+                code: KeyCode::Char('q' | 'c'),
+                modifiers: KeyModifiers::CONTROL,
+                ..
+            } => KeyAction::Quit,
+
+            KeyEvent {
+                code: KeyCode::Esc, ..
+            } => KeyAction::ShowMenu,
+
+            KeyEvent {
+                code: KeyCode::Up, ..
+            } => KeyAction::SelectPrevious,
+
+            KeyEvent {
+                code: KeyCode::Down,
+                ..
+            } => KeyAction::SelectNext,
+
+            KeyEvent {
+                code: KeyCode::Left,
+                ..
+            } => KeyAction::MoveCursorLeft,
+
+            KeyEvent {
+                code: KeyCode::Right,
+                ..
+            } => KeyAction::MoveCursorRight,
+
+            KeyEvent {
+                code: KeyCode::Enter,
+                ..
+            } => KeyAction::Submit,
+
+            KeyEvent {
+                code: KeyCode::Backspace,
+                ..
+            } => KeyAction::DeleteChar,
+
+            KeyEvent {
+                code: KeyCode::Char(c),
+                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+                ..
+            } if c.is_ascii_alphanumeric() => KeyAction::AddChar(c),
+
+            _ => KeyAction::None,
+        }
+    }
+
+    /// Process key events in a text dialog: every printable character is typed
+    pub fn process_dialog_key(&self, key_event: KeyEvent) -> KeyAction {
+        match key_event {
+            KeyEvent {
+                code: KeyCode::Char('q' | 'c'),
+                modifiers: KeyModifiers::CONTROL,
+                ..
+            } => KeyAction::Quit,
+
+            KeyEvent {
+                code: KeyCode::Esc, ..
+            } => KeyAction::CloseDialog,
+
+            KeyEvent {
+                code: KeyCode::Enter,
+                ..
+            } => KeyAction::Submit,
+
+            KeyEvent {
+                code: KeyCode::Backspace,
+                ..
+            } => KeyAction::DeleteChar,
+
+            KeyEvent {
+                code: KeyCode::Char(c),
+                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+                ..
+            } => KeyAction::AddChar(c),
+
             _ => KeyAction::None,
         }
     }
@@ -237,6 +483,13 @@ impl EventLoop {
             match read()? {
                 crossterm::event::Event::Key(key_event) => Ok(TuiEvent::Key(key_event)),
                 crossterm::event::Event::Resize(_, _) => Ok(TuiEvent::Resize),
+                crossterm::event::Event::Paste(text) => Ok(TuiEvent::Paste(text)),
+                crossterm::event::Event::Mouse(MouseEvent {
+                    kind: MouseEventKind::Down(MouseButton::Left),
+                    column,
+                    row,
+                    ..
+                }) => Ok(TuiEvent::Click { column, row }),
                 _ => Ok(TuiEvent::Tick),
             }
         } else {
@@ -244,6 +497,24 @@ impl EventLoop {
         }
     }
 
+    /// Process an event on a list-style screen
+    pub fn process_navigation_event(&self, event: TuiEvent) -> KeyAction {
+        match event {
+            TuiEvent::Key(key_event) => self.handler.process_navigation_key(key_event),
+            TuiEvent::Quit => KeyAction::Quit,
+            _ => KeyAction::None,
+        }
+    }
+
+    /// Process an event while a text dialog is open
+    pub fn process_dialog_event(&self, event: TuiEvent) -> KeyAction {
+        match event {
+            TuiEvent::Key(key_event) => self.handler.process_dialog_key(key_event),
+            TuiEvent::Quit => KeyAction::Quit,
+            _ => KeyAction::None,
+        }
+    }
+
     /// Process an event and return the corresponding action
     ///
     /// # Arguments
@@ -261,6 +532,8 @@ impl EventLoop {
                 self.handler
                     .process_key_event(key_event, current_mode, is_typing)
             }
+            TuiEvent::Paste(text) => KeyAction::Paste(text),
+            TuiEvent::Click { column, row } => KeyAction::Click { column, row },
             TuiEvent::Quit => KeyAction::Quit,
             _ => KeyAction::None,
         }
@@ -382,6 +655,60 @@ mod tests {
             handler.process_key_event(key_event, mode, false),
             KeyAction::Quit
         );
+
+        // Test undo
+        let key_event = KeyEvent::new(KeyCode::Char('u'), KeyModifiers::NONE);
+        assert_eq!(
+            handler.process_key_event(key_event, mode, false),
+            KeyAction::Undo
+        );
+
+        // Test panel focus and zoom
+        let key_event = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+        assert_eq!(
+            handler.process_key_event(key_event, mode, false),
+            KeyAction::FocusNext
+        );
+        let key_event = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE);
+        assert_eq!(
+            handler.process_key_event(key_event, mode, false),
+            KeyAction::ToggleZoom
+        );
+        let key_event = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE);
+        assert_eq!(
+            handler.process_key_event(key_event, mode, false),
+            KeyAction::ToggleClusters
+        );
+        let key_event = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::NONE);
+        assert_eq!(
+            handler.process_key_event(key_event, mode, false),
+            KeyAction::ToggleWordGraph
+        );
+        let key_event = KeyEvent::new(KeyCode::Char('H'), KeyModifiers::SHIFT);
+        assert_eq!(
+            handler.process_key_event(key_event, mode, false),
+            KeyAction::EnableHardMode
+        );
+        let key_event = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE);
+        assert_eq!(
+            handler.process_key_event(key_event, mode, false),
+            KeyAction::OpenConstraintDialog
+        );
+        let key_event = KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE);
+        assert_eq!(
+            handler.process_key_event(key_event, mode, false),
+            KeyAction::TogglePin
+        );
+        let key_event = KeyEvent::new(KeyCode::Char('T'), KeyModifiers::SHIFT);
+        assert_eq!(
+            handler.process_key_event(key_event, mode, false),
+            KeyAction::ClearTray
+        );
+        let key_event = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
+        assert_eq!(
+            handler.process_key_event(key_event, mode, false),
+            KeyAction::ToggleKeyboard
+        );
     }
 
     #[test]
@@ -409,6 +736,34 @@ mod tests {
             handler.process_key_event(key_event, InteractionMode::Operation, false),
             KeyAction::ToggleMode
         );
+
+        // ...unless a guess is being typed, which it completes
+        assert_eq!(
+            handler.process_key_event(key_event, InteractionMode::Input, true),
+            KeyAction::Complete
+        );
+        assert_eq!(
+            handler.process_key_event(key_event, InteractionMode::Operation, true),
+            KeyAction::ToggleMode
+        );
+    }
+
+    #[test]
+    fn test_history_scroll_keys() {
+        let handler = EventHandler::default();
+
+        for mode in [InteractionMode::Input, InteractionMode::Operation] {
+            let key_event = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+            assert_eq!(
+                handler.process_key_event(key_event, mode, false),
+                KeyAction::ScrollUp
+            );
+            let key_event = KeyEvent::new(KeyCode::PageDown, KeyModifiers::NONE);
+            assert_eq!(
+                handler.process_key_event(key_event, mode, false),
+                KeyAction::PageDown
+            );
+        }
     }
 
     #[test]
@@ -455,4 +810,31 @@ mod tests {
             KeyAction::ToggleHelp
         );
     }
+
+    #[test]
+    fn test_navigation_keys() {
+        let handler = EventHandler::default();
+        let key = |code| handler.process_navigation_key(KeyEvent::new(code, KeyModifiers::NONE));
+
+        assert_eq!(key(KeyCode::Up), KeyAction::SelectPrevious);
+        assert_eq!(key(KeyCode::Down), KeyAction::SelectNext);
+        assert_eq!(key(KeyCode::Right), KeyAction::MoveCursorRight);
+        assert_eq!(key(KeyCode::Enter), KeyAction::Submit);
+        assert_eq!(key(KeyCode::Esc), KeyAction::ShowMenu);
+        assert_eq!(key(KeyCode::Char('q')), KeyAction::AddChar('q'));
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(handler.process_navigation_key(ctrl_c), KeyAction::Quit);
+    }
+
+    #[test]
+    fn test_dialog_keys_type_punctuation() {
+        let handler = EventHandler::default();
+        let key = |code| handler.process_dialog_key(KeyEvent::new(code, KeyModifiers::NONE));
+
+        assert_eq!(key(KeyCode::Char('-')), KeyAction::AddChar('-'));
+        assert_eq!(key(KeyCode::Char('q')), KeyAction::AddChar('q'));
+        assert_eq!(key(KeyCode::Char(' ')), KeyAction::AddChar(' '));
+        assert_eq!(key(KeyCode::Esc), KeyAction::CloseDialog);
+        assert_eq!(key(KeyCode::Enter), KeyAction::Submit);
+    }
 }
@@ -2,6 +2,10 @@ use anyhow::Result;
 use std::collections::VecDeque;
 
 use super::state::{MessageType, TuiState};
+use crate::core::types::Feedback;
+
+/// Set to `0` to commit feedback on the first Enter, without a confirmation step
+pub const CONFIRM_FEEDBACK_ENV: &str = "WORDLE_CONFIRM_FEEDBACK";
 
 /// Manages the feedback input workflow
 pub struct FeedbackInputManager {
@@ -15,6 +19,10 @@ pub struct FeedbackInputManager {
     is_feedback_mode: bool,
     /// Queue of pending guesses
     pending_guesses: VecDeque<String>,
+    /// Whether a complete pattern needs a second Enter before it is committed
+    confirm_feedback: bool,
+    /// Whether the complete pattern is being previewed, waiting for confirmation
+    awaiting_confirmation: bool,
 }
 
 impl Default for FeedbackInputManager {
@@ -31,18 +39,37 @@ impl FeedbackInputManager {
             feedback_cursor: 0,
             is_feedback_mode: false,
             pending_guesses: VecDeque::new(),
+            confirm_feedback: confirmation_from_env(),
+            awaiting_confirmation: false,
         }
     }
 
+    /// Enable or disable the confirmation step before feedback is committed
+    pub fn with_confirmation(mut self, confirm: bool) -> Self {
+        self.confirm_feedback = confirm;
+        self
+    }
+
+    /// Whether complete patterns need confirming before they are committed
+    pub fn confirmation_enabled(&self) -> bool {
+        self.confirm_feedback
+    }
+
+    /// Whether the entered pattern is shown as a preview awaiting confirmation
+    pub fn is_awaiting_confirmation(&self) -> bool {
+        self.awaiting_confirmation
+    }
+
     /// Start feedback input for a guess
     pub fn start_feedback_input(&mut self, guess: String, state: &mut TuiState) {
         self.current_guess = Some(guess.clone());
         self.feedback_input.clear();
         self.feedback_cursor = 0;
         self.is_feedback_mode = true;
+        self.awaiting_confirmation = false;
 
         state.set_status(
-            format!("Enter feedback for '{}' (5 characters: 0=gray, 1=yellow, 2=green):", guess.to_uppercase()),
+            format!("Enter feedback for '{}' (0/b gray, 1/y yellow, 2/g green):", guess.to_uppercase()),
             MessageType::Info,
         );
     }
@@ -53,7 +80,10 @@ impl FeedbackInputManager {
             return false;
         }
 
-        if self.feedback_input.len() < 5 && matches!(c, '0' | '1' | '2') {
+        // Colour initials are stored as the digits they stand for
+        let code = Feedback::from_shorthand(c).map(Feedback::to_code);
+        if let Some(c) = code.filter(|_| self.feedback_input.len() < 5) {
+            self.awaiting_confirmation = false;
             self.feedback_input.insert(self.feedback_cursor, c);
             self.feedback_cursor += 1;
 
@@ -64,7 +94,7 @@ impl FeedbackInputManager {
                     "_".repeat(5 - self.feedback_input.len())
                 );
                 state.set_status(
-                    format!("'{}' feedback: {} (0=gray, 1=yellow, 2=green)",
+                    format!("'{}' feedback: {} (0/b gray, 1/y yellow, 2/g green)",
                         guess.to_uppercase(),
                         display_input
                     ),
@@ -74,7 +104,7 @@ impl FeedbackInputManager {
             true
         } else {
             state.set_status(
-                "Only use 0 (gray), 1 (yellow), or 2 (green) for feedback".to_string(),
+                "Only use 0/b (gray), 1/y (yellow), or 2/g (green) for feedback".to_string(),
                 MessageType::Warning,
             );
             false
@@ -88,6 +118,7 @@ impl FeedbackInputManager {
         }
 
         if self.feedback_cursor > 0 {
+            self.awaiting_confirmation = false;
             self.feedback_cursor -= 1;
             self.feedback_input.remove(self.feedback_cursor);
 
@@ -98,7 +129,7 @@ impl FeedbackInputManager {
                     "_".repeat(5 - self.feedback_input.len())
                 );
                 state.set_status(
-                    format!("'{}' feedback: {} (0=gray, 1=yellow, 2=green)",
+                    format!("'{}' feedback: {} (0/b gray, 1/y yellow, 2/g green)",
                         guess.to_uppercase(),
                         display_input
                     ),
@@ -112,6 +143,9 @@ impl FeedbackInputManager {
     }
 
     /// Submit feedback
+    ///
+    /// With confirmation enabled, the first call only previews the pattern and
+    /// returns `None`; the feedback is committed by the next call.
     pub fn submit_feedback(&mut self, state: &mut TuiState) -> Option<(String, String)> {
         if !self.is_feedback_mode || self.feedback_input.len() != 5 {
             state.set_status(
@@ -121,6 +155,21 @@ impl FeedbackInputManager {
             return None;
         }
 
+        if self.confirm_feedback && !self.awaiting_confirmation {
+            self.awaiting_confirmation = true;
+            if let Some(ref guess) = self.current_guess {
+                state.set_status(
+                    format!(
+                        "{} {} - Enter or 'y' to confirm, Backspace to edit",
+                        guess.to_uppercase(),
+                        feedback_emoji(&self.feedback_input)
+                    ),
+                    MessageType::Info,
+                );
+            }
+            return None;
+        }
+
         if let Some(guess) = self.current_guess.take() {
             let feedback = self.feedback_input.clone();
 
@@ -128,6 +177,7 @@ impl FeedbackInputManager {
             self.feedback_input.clear();
             self.feedback_cursor = 0;
             self.is_feedback_mode = false;
+            self.awaiting_confirmation = false;
 
             state.set_status(
                 format!("Processing: {} -> {}", guess.to_uppercase(), feedback),
@@ -146,6 +196,7 @@ impl FeedbackInputManager {
         self.feedback_input.clear();
         self.feedback_cursor = 0;
         self.is_feedback_mode = false;
+        self.awaiting_confirmation = false;
 
         state.set_status("Feedback input cancelled".to_string(), MessageType::Info);
     }
@@ -262,6 +313,7 @@ impl FeedbackInputManager {
             }
             _ => return false,
         }
+        self.awaiting_confirmation = false;
 
         if let Some(ref guess) = self.current_guess {
             state.set_status(
@@ -277,13 +329,32 @@ impl FeedbackInputManager {
     }
 }
 
+/// Read the confirmation setting; anything but an explicit "off" keeps it enabled
+fn confirmation_from_env() -> bool {
+    std::env::var(CONFIRM_FEEDBACK_ENV)
+        .map(|v| !matches!(v.trim(), "0" | "false" | "FALSE" | "off"))
+        .unwrap_or(true)
+}
+
+/// Render a feedback code as colored squares for status messages
+fn feedback_emoji(feedback: &str) -> String {
+    feedback
+        .chars()
+        .map(|c| match c {
+            '2' => '🟩',
+            '1' => '🟨',
+            _ => '⬜',
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_feedback_input() {
-        let mut manager = FeedbackInputManager::new();
+        let mut manager = FeedbackInputManager::new().with_confirmation(true);
         let mut state = TuiState::new();
 
         // Start feedback input
@@ -297,6 +368,10 @@ mod tests {
         assert!(manager.add_feedback_char('2', &mut state));
         assert_eq!(manager.get_feedback_input(), "012");
 
+        // Colour initials stand for the digits
+        assert!(manager.add_feedback_char('g', &mut state));
+        assert!(manager.delete_feedback_char(&mut state));
+
         // Invalid character should fail
         assert!(!manager.add_feedback_char('3', &mut state));
         assert_eq!(manager.get_feedback_input(), "012");
@@ -305,12 +380,42 @@ mod tests {
         assert!(manager.add_feedback_char('1', &mut state));
         assert!(manager.add_feedback_char('0', &mut state));
 
-        // Submit
+        // First Enter previews, second commits
+        assert_eq!(manager.submit_feedback(&mut state), None);
+        assert!(manager.is_awaiting_confirmation());
         let result = manager.submit_feedback(&mut state);
         assert_eq!(result, Some(("hello".to_string(), "01210".to_string())));
         assert!(!manager.is_in_feedback_mode());
     }
 
+    #[test]
+    fn test_feedback_confirmation() {
+        let mut state = TuiState::new();
+
+        // Editing after the preview requires confirming again
+        let mut manager = FeedbackInputManager::new().with_confirmation(true);
+        manager.start_feedback_input("hello".to_string(), &mut state);
+        assert!(manager.auto_complete_feedback("none", &mut state));
+        assert_eq!(manager.submit_feedback(&mut state), None);
+        assert!(manager.delete_feedback_char(&mut state));
+        assert!(!manager.is_awaiting_confirmation());
+        assert!(manager.add_feedback_char('2', &mut state));
+        assert_eq!(manager.submit_feedback(&mut state), None);
+        assert_eq!(
+            manager.submit_feedback(&mut state),
+            Some(("hello".to_string(), "00002".to_string()))
+        );
+
+        // Power users can commit on the first Enter
+        let mut manager = FeedbackInputManager::new().with_confirmation(false);
+        manager.start_feedback_input("hello".to_string(), &mut state);
+        assert!(manager.auto_complete_feedback("correct", &mut state));
+        assert_eq!(
+            manager.submit_feedback(&mut state),
+            Some(("hello".to_string(), "22222".to_string()))
+        );
+    }
+
     #[test]
     fn test_feedback_validation() {
         assert!(FeedbackInputManager::validate_feedback("01210").unwrap());
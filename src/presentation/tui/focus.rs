@@ -0,0 +1,104 @@
+//! Panel focus and zoom on the game screen
+//!
+//! In OPERATION mode ←/→ move the focus between the content panels and `z`
+//! zooms the focused panel to the whole screen.
+
+/// Content panels that can take the focus, in layout order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Panel {
+    History,
+    Stats,
+    Candidates,
+    Logs,
+}
+
+impl Panel {
+    pub const ALL: [Panel; 4] = [Panel::History, Panel::Stats, Panel::Candidates, Panel::Logs];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&p| p == self).unwrap_or(0)
+    }
+}
+
+/// Which panel has the focus and whether it is zoomed
+#[derive(Debug, Clone, Default)]
+pub struct FocusManager {
+    focused: Option<Panel>,
+    zoomed: bool,
+}
+
+impl FocusManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn focused(&self) -> Option<Panel> {
+        self.focused
+    }
+
+    pub fn is_focused(&self, panel: Panel) -> bool {
+        self.focused == Some(panel)
+    }
+
+    /// The panel shown full-screen, if any
+    pub fn zoomed(&self) -> Option<Panel> {
+        self.focused.filter(|_| self.zoomed)
+    }
+
+    /// Focus the next panel, wrapping around; a zoom follows the focus
+    pub fn focus_next(&mut self) {
+        let next = match self.focused {
+            Some(panel) => (panel.index() + 1) % Panel::ALL.len(),
+            None => 0,
+        };
+        self.focused = Some(Panel::ALL[next]);
+    }
+
+    /// Focus the previous panel, wrapping around; a zoom follows the focus
+    pub fn focus_previous(&mut self) {
+        let previous = match self.focused {
+            Some(panel) => (panel.index() + Panel::ALL.len() - 1) % Panel::ALL.len(),
+            None => Panel::ALL.len() - 1,
+        };
+        self.focused = Some(Panel::ALL[previous]);
+    }
+
+    /// Zoom the focused panel (focusing the first one if needed), or unzoom
+    pub fn toggle_zoom(&mut self) {
+        if self.focused.is_none() {
+            self.focus_next();
+        }
+        self.zoomed = !self.zoomed;
+    }
+
+    /// Return to the normal layout; false if nothing was zoomed
+    pub fn unzoom(&mut self) -> bool {
+        std::mem::take(&mut self.zoomed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focus_cycles_and_zoom_follows() {
+        let mut focus = FocusManager::new();
+        assert_eq!(focus.zoomed(), None);
+
+        focus.focus_previous();
+        assert_eq!(focus.focused(), Some(Panel::Logs));
+        focus.focus_next();
+        assert_eq!(focus.focused(), Some(Panel::History));
+
+        focus.toggle_zoom();
+        assert_eq!(focus.zoomed(), Some(Panel::History));
+        focus.focus_next();
+        assert_eq!(focus.zoomed(), Some(Panel::Stats));
+
+        assert!(focus.unzoom());
+        assert!(!focus.unzoom());
+        assert_eq!(focus.zoomed(), None);
+        assert!(focus.is_focused(Panel::Stats));
+    }
+}
@@ -0,0 +1,231 @@
+//! Context-aware hints for the footer
+//!
+//! Each rule looks at the game state and may produce a hint; the first rule
+//! that matches wins, so rules are listed from most to least urgent.
+
+use super::mode::InteractionMode;
+use super::state::TuiState;
+
+/// A suggestion shown in the footer: a key (or action) and what it does
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hint {
+    pub key: &'static str,
+    pub text: String,
+    /// Names possible words, so no-spoiler mode skips it while they're hidden
+    pub names_words: bool,
+}
+
+impl Hint {
+    fn new(key: &'static str, text: impl Into<String>) -> Self {
+        Self {
+            key,
+            text: text.into(),
+            names_words: false,
+        }
+    }
+
+    fn naming_words(mut self) -> Self {
+        self.names_words = true;
+        self
+    }
+}
+
+/// Remaining words after three guesses above which the player is told to trust the suggestion
+const MANY_REMAINING: usize = 100;
+
+type Rule = fn(&TuiState) -> Option<Hint>;
+
+const RULES: [Rule; 8] = [
+    contradiction,
+    last_guess,
+    trap,
+    disambiguate,
+    few_remaining,
+    hard_mode_detected,
+    many_remaining,
+    first_guess,
+];
+
+/// The most relevant hint for `state`, if any
+pub fn contextual_hint(state: &TuiState) -> Option<Hint> {
+    if state.is_solved || state.game_over {
+        return None;
+    }
+    let hides_words = state.spoilers().hides_candidates();
+    RULES
+        .iter()
+        .filter_map(|rule| rule(state))
+        .find(|hint| !(hides_words && hint.names_words))
+}
+
+/// Key sequence for an OPERATION mode command from the current mode
+fn operation_key(state: &TuiState, key: &'static str, via_esc: &'static str) -> &'static str {
+    match state.interaction_mode() {
+        InteractionMode::Operation => key,
+        InteractionMode::Input => via_esc,
+    }
+}
+
+/// No answer fits the entered feedback, usually a typo in the last row
+fn contradiction(state: &TuiState) -> Option<Hint> {
+    (!state.guess_history.is_empty() && state.remaining_words == 0).then(|| {
+        Hint::new(
+            operation_key(state, "u", "Esc u"),
+            "No word fits this feedback - undo the last guess",
+        )
+    })
+}
+
+/// One guess left in the budget with several answers still possible
+fn last_guess(state: &TuiState) -> Option<Hint> {
+    let used = state.guess_history.len();
+    (used > 0 && used + 1 == state.settings.max_guesses && state.remaining_words > 1).then(|| {
+        Hint::new(
+            "Last guess",
+            "Pick a possible word - only an answer can still win",
+        )
+    })
+}
+
+/// The remaining words are one family (_IGHT): guessing them in turn can run out of guesses
+fn trap(state: &TuiState) -> Option<Hint> {
+    let family = state.trap.as_ref()?;
+    let advice = match &state.disambiguator {
+        Some(found) => format!("{} splits them", found.word.as_str().to_uppercase()),
+        None => "pick a guess with several of their differing letters".to_string(),
+    };
+    let hint = Hint::new(
+        "Trap",
+        format!(
+            "{} words left in the {} family - {}",
+            family.len(),
+            family.pattern,
+            advice
+        ),
+    );
+    Some(hint.naming_words())
+}
+
+/// Near-identical words left: one guess packed with their differing letters beats trying each
+fn disambiguate(state: &TuiState) -> Option<Hint> {
+    let found = state.disambiguator.as_ref()?;
+    let letters: Vec<String> = found.covered.iter().map(|c| c.to_uppercase().to_string()).collect();
+    let hint = Hint::new(
+        "Disambiguator",
+        format!(
+            "{} checks {} of the letters separating the last {} words",
+            found.word.as_str().to_uppercase(),
+            letters.join(", "),
+            state.remaining_words
+        ),
+    );
+    Some(hint.naming_words())
+}
+
+fn few_remaining(state: &TuiState) -> Option<Hint> {
+    (2..=3).contains(&state.remaining_words).then(|| {
+        Hint::new(
+            "Go for it",
+            format!(
+                "Only {} words left - guess one of them",
+                state.remaining_words
+            ),
+        )
+    })
+}
+
+/// Guesses that keep every hint suggest the player is on hard mode but forgot to set it
+fn hard_mode_detected(state: &TuiState) -> Option<Hint> {
+    state.hard_mode_detected.then(|| {
+        Hint::new(
+            operation_key(state, "H", "Esc H"),
+            "Your guesses reuse every hint - turn on hard mode to match the suggestions",
+        )
+    })
+}
+
+/// Far behind after three guesses: the suggestion splits the words best
+fn many_remaining(state: &TuiState) -> Option<Hint> {
+    (state.guess_history.len() >= 3 && state.remaining_words > MANY_REMAINING).then(|| {
+        Hint::new(
+            "Suggestion",
+            format!(
+                "{} words still possible - the suggested guess narrows them fastest",
+                state.remaining_words
+            ),
+        )
+    })
+}
+
+fn first_guess(state: &TuiState) -> Option<Hint> {
+    (state.guess_history.is_empty() && state.input.is_empty()).then(|| {
+        Hint::new(
+            "a-z",
+            "Type your first guess, then enter its feedback as 0/1/2",
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with(guesses: usize, remaining: usize) -> TuiState {
+        let mut state = TuiState::new();
+        for _ in 0..guesses {
+            state.add_guess("crane".to_string(), "01000".to_string(), remaining);
+        }
+        state.remaining_words = remaining;
+        state
+    }
+
+    #[test]
+    fn test_rules_by_priority() {
+        assert_eq!(contextual_hint(&state_with(0, 0)).unwrap().key, "a-z");
+
+        let hint = contextual_hint(&state_with(2, 0)).unwrap();
+        assert_eq!(hint.key, "Esc u");
+        let mut operation = state_with(2, 0);
+        operation.switch_to_operation_mode();
+        assert_eq!(contextual_hint(&operation).unwrap().key, "u");
+
+        assert_eq!(contextual_hint(&state_with(3, 250)).unwrap().key, "Suggestion");
+        assert_eq!(contextual_hint(&state_with(5, 250)).unwrap().key, "Last guess");
+        assert_eq!(contextual_hint(&state_with(1, 2)).unwrap().key, "Go for it");
+        assert_eq!(contextual_hint(&state_with(2, 40)), None);
+
+        let mut detected = state_with(2, 40);
+        detected.hard_mode_detected = true;
+        assert_eq!(contextual_hint(&detected).unwrap().key, "Esc H");
+
+        let mut solved = state_with(3, 1);
+        solved.set_solved(true);
+        assert_eq!(contextual_hint(&solved), None);
+    }
+
+    #[test]
+    fn test_disambiguator_beats_go_for_it() {
+        use crate::core::types::Word;
+        let words = |list: &[&str]| -> Vec<Word> {
+            list.iter().map(|w| Word::from_str(w).unwrap()).collect()
+        };
+        let possible = words(&["shale", "shame", "share"]);
+        let found = crate::domain::find_disambiguator(&possible, &words(&["milky"]));
+
+        let mut state = state_with(2, 3);
+        state.disambiguator = found;
+        let hint = contextual_hint(&state).unwrap();
+        assert_eq!(hint.key, "Disambiguator");
+        assert!(hint.text.starts_with("MILKY checks L, M"));
+
+        state.trap = crate::domain::common_family(&possible);
+        let hint = contextual_hint(&state).unwrap();
+        assert_eq!(hint.key, "Trap");
+        assert_eq!(hint.text, "3 words left in the SHA_E family - MILKY splits them");
+
+        state.settings.no_spoilers = true;
+        assert_eq!(contextual_hint(&state).unwrap().key, "Go for it");
+        state.candidates_revealed = true;
+        assert_eq!(contextual_hint(&state).unwrap().key, "Trap");
+    }
+}
@@ -0,0 +1,256 @@
+//! On-screen keyboard for entering guesses without typing
+//!
+//! `k` in OPERATION mode opens a QWERTY keyboard docked at the bottom of the game
+//! screen. While it is open in INPUT mode the arrow keys move a highlight over the
+//! keys and Enter presses the highlighted one; a mouse click (or a tap on a touch
+//! terminal) presses a key directly. Letters are colored with the best feedback
+//! they got so far, like the keyboard of the web game.
+
+use ratatui::layout::Rect;
+
+use super::events::KeyAction;
+use super::state::GuessHistoryEntry;
+use crate::core::types::Feedback;
+
+/// Letter rows, top to bottom
+const LETTER_ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Rows of keys
+pub const KEYBOARD_ROWS: usize = LETTER_ROWS.len();
+
+/// Columns each key takes, the gap after it included
+pub const KEY_STRIDE: u16 = 4;
+
+/// Columns a key's label takes
+pub const KEY_WIDTH: u16 = 3;
+
+/// Size of the keyboard with its border
+pub const KEYBOARD_WIDTH: u16 = 10 * KEY_STRIDE - 1 + 2;
+pub const KEYBOARD_HEIGHT: u16 = LETTER_ROWS.len() as u16 + 2;
+
+/// A key of the on-screen keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyboardKey {
+    Letter(char),
+    Enter,
+    Backspace,
+}
+
+impl KeyboardKey {
+    /// The label drawn on the key, [`KEY_WIDTH`] columns wide
+    pub fn label(self) -> String {
+        match self {
+            Self::Letter(c) => format!(" {} ", c.to_ascii_uppercase()),
+            Self::Enter => "ENT".to_string(),
+            Self::Backspace => "DEL".to_string(),
+        }
+    }
+
+    /// What pressing the key does, as if it were typed
+    pub fn action(self) -> KeyAction {
+        match self {
+            Self::Letter(c) => KeyAction::AddChar(c),
+            Self::Enter => KeyAction::Submit,
+            Self::Backspace => KeyAction::DeleteChar,
+        }
+    }
+}
+
+/// Which way to move the highlight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyboardMove {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Whether the keyboard is shown and which key is highlighted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OnScreenKeyboard {
+    open: bool,
+    row: usize,
+    key: usize,
+}
+
+impl OnScreenKeyboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Show the keyboard, or hide it; returns whether it is now shown
+    pub fn toggle(&mut self) -> bool {
+        self.open = !self.open;
+        self.open
+    }
+
+    /// Keys of `row` from left to right: the bottom row has Enter and Backspace
+    /// at its ends
+    pub fn row_keys(row: usize) -> Vec<KeyboardKey> {
+        let letters = LETTER_ROWS[row].chars().map(KeyboardKey::Letter);
+        if row + 1 == LETTER_ROWS.len() {
+            std::iter::once(KeyboardKey::Enter)
+                .chain(letters)
+                .chain(std::iter::once(KeyboardKey::Backspace))
+                .collect()
+        } else {
+            letters.collect()
+        }
+    }
+
+    /// Columns `row` is indented by inside the border, to stagger the rows
+    pub fn row_indent(row: usize) -> u16 {
+        if row == 1 { KEY_STRIDE / 2 } else { 0 }
+    }
+
+    /// The highlighted key
+    pub fn selected(&self) -> KeyboardKey {
+        Self::row_keys(self.row)[self.key]
+    }
+
+    /// Whether `key` is the one in `row` at `index` that is highlighted
+    pub fn is_selected(&self, row: usize, index: usize) -> bool {
+        (self.row, self.key) == (row, index)
+    }
+
+    /// Move the highlight, staying on the keyboard; moving between rows keeps
+    /// the nearest key
+    pub fn move_selection(&mut self, direction: KeyboardMove) {
+        let rows = LETTER_ROWS.len();
+        match direction {
+            KeyboardMove::Left => self.key = self.key.saturating_sub(1),
+            KeyboardMove::Right => {
+                self.key = (self.key + 1).min(Self::row_keys(self.row).len() - 1)
+            }
+            KeyboardMove::Up => self.row = self.row.saturating_sub(1),
+            KeyboardMove::Down => self.row = (self.row + 1).min(rows - 1),
+        }
+        self.key = self.key.min(Self::row_keys(self.row).len() - 1);
+    }
+
+    /// The key drawn at `column`, `row` of the screen when the keyboard is drawn
+    /// in `area` (border included); None for the gaps between keys
+    pub fn key_at(area: Rect, column: u16, row: u16) -> Option<(usize, usize)> {
+        // Rows and keys start inside the border
+        let line = usize::from(row.checked_sub(area.y + 1)?);
+        if line >= LETTER_ROWS.len() {
+            return None;
+        }
+        let offset = column.checked_sub(area.x + 1 + Self::row_indent(line))?;
+        let index = usize::from(offset / KEY_STRIDE);
+        (offset % KEY_STRIDE < KEY_WIDTH && index < Self::row_keys(line).len())
+            .then_some((line, index))
+    }
+
+    /// Highlight the key in `row` at `index` and press it
+    pub fn press(&mut self, row: usize, index: usize) -> KeyAction {
+        self.row = row;
+        self.key = index;
+        self.selected().action()
+    }
+
+    /// What `action` does while the keyboard is open: the arrows move the
+    /// highlight and Enter presses the highlighted key; None leaves it alone
+    pub fn intercept(&mut self, action: &KeyAction) -> Option<KeyAction> {
+        if !self.open {
+            return None;
+        }
+        let direction = match action {
+            KeyAction::ScrollUp => KeyboardMove::Up,
+            KeyAction::ScrollDown => KeyboardMove::Down,
+            KeyAction::MoveCursorLeft => KeyboardMove::Left,
+            KeyAction::MoveCursorRight => KeyboardMove::Right,
+            KeyAction::Submit => return Some(self.selected().action()),
+            _ => return None,
+        };
+        self.move_selection(direction);
+        Some(KeyAction::None)
+    }
+}
+
+/// The best feedback each letter got in `history`, indexed from 'a'
+pub fn letter_feedback(history: &[GuessHistoryEntry]) -> [Option<Feedback>; 26] {
+    let mut best: [Option<Feedback>; 26] = [None; 26];
+    for entry in history {
+        for (letter, code) in entry.word.chars().zip(entry.feedback.chars()) {
+            let letter = letter.to_ascii_lowercase();
+            let Some(feedback) = Feedback::from_shorthand(code) else {
+                continue;
+            };
+            if !letter.is_ascii_lowercase() {
+                continue;
+            }
+            let index = usize::from(letter as u8 - b'a');
+            let slot = &mut best[index];
+            if slot.is_none_or(|seen| feedback.priority_score() > seen.priority_score()) {
+                *slot = Some(feedback);
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrows_move_the_highlight_and_enter_presses_it() {
+        let mut keyboard = OnScreenKeyboard::new();
+        assert_eq!(keyboard.intercept(&KeyAction::Submit), None);
+
+        keyboard.toggle();
+        assert_eq!(keyboard.selected(), KeyboardKey::Letter('q'));
+        keyboard.intercept(&KeyAction::MoveCursorRight);
+        assert_eq!(
+            keyboard.intercept(&KeyAction::Submit),
+            Some(KeyAction::AddChar('w'))
+        );
+        // P is past the end of the A-L row, so down lands on L
+        for _ in 0..9 {
+            keyboard.move_selection(KeyboardMove::Right);
+        }
+        keyboard.move_selection(KeyboardMove::Down);
+        assert_eq!(keyboard.selected(), KeyboardKey::Letter('l'));
+        keyboard.move_selection(KeyboardMove::Down);
+        keyboard.move_selection(KeyboardMove::Right);
+        assert_eq!(keyboard.selected(), KeyboardKey::Backspace);
+        assert_eq!(keyboard.intercept(&KeyAction::Undo), None);
+    }
+
+    #[test]
+    fn test_clicks_hit_keys_but_not_gaps() {
+        let area = Rect::new(10, 20, KEYBOARD_WIDTH, KEYBOARD_HEIGHT);
+        // Q starts right inside the border; W one stride later
+        assert_eq!(OnScreenKeyboard::key_at(area, 11, 21), Some((0, 0)));
+        assert_eq!(OnScreenKeyboard::key_at(area, 15, 21), Some((0, 1)));
+        assert_eq!(OnScreenKeyboard::key_at(area, 14, 21), None);
+        // The second row is indented
+        assert_eq!(OnScreenKeyboard::key_at(area, 11, 22), None);
+        assert_eq!(OnScreenKeyboard::key_at(area, 13, 22), Some((1, 0)));
+        assert_eq!(OnScreenKeyboard::key_at(area, 11, 20), None);
+
+        let mut keyboard = OnScreenKeyboard::new();
+        assert_eq!(keyboard.press(2, 0), KeyAction::Submit);
+        assert_eq!(keyboard.selected(), KeyboardKey::Enter);
+    }
+
+    #[test]
+    fn test_letters_keep_their_best_feedback() {
+        let entry = |word: &str, feedback: &str| GuessHistoryEntry {
+            word: word.to_string(),
+            feedback: feedback.to_string(),
+            remaining_count: 0,
+        };
+        let letters = letter_feedback(&[entry("crane", "01002"), entry("rebut", "20000")]);
+        let at = |c: char| letters[usize::from(c as u8 - b'a')];
+        assert_eq!(at('r'), Some(Feedback::Correct));
+        assert_eq!(at('c'), Some(Feedback::Absent));
+        assert_eq!(at('e'), Some(Feedback::Correct));
+        assert_eq!(at('z'), None);
+    }
+}
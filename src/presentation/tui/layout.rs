@@ -1,15 +1,22 @@
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::Paragraph,
 };
 
 use crate::presentation::tui::{
     components::{
-        centered_rect, render_feedback_help, render_feedback_input, render_footer, render_help,
-        render_history, render_input, render_logs, render_mode_indicator, render_progress,
-        render_remaining_words, render_stats, render_status, render_suggestion, render_title,
+        Colors, centered_rect, render_archive, render_constraint_dialog, render_explorer,
+        render_feedback_help, render_feedback_input, render_footer, render_help, render_history,
+        render_input, render_keyboard, render_logs, render_mode_indicator, render_progress,
+        render_remaining_words, render_settings, render_start_menu, render_stats, render_status,
+        render_suggestion, render_text_screen, render_title,
     },
     feedback::FeedbackInputManager,
+    focus::Panel,
+    keyboard::{KEYBOARD_HEIGHT, KEYBOARD_WIDTH},
+    screen::Screen,
     state::TuiState,
 };
 
@@ -54,6 +61,8 @@ impl LayoutManager {
                     current_guess,
                     feedback_manager.get_feedback_input(),
                     feedback_manager.get_feedback_cursor(),
+                    feedback_manager.is_awaiting_confirmation(),
+                    state.rejected_char(),
                 );
             }
         } else {
@@ -112,6 +121,29 @@ impl LayoutManager {
         }
     }
 
+    /// Render one panel over the whole content area, for detailed reading
+    pub fn render_zoomed_layout(frame: &mut Frame, state: &TuiState, panel: Panel) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title
+                Constraint::Min(4),    // Zoomed panel
+                Constraint::Length(1), // Key hint
+            ])
+            .split(frame.size());
+
+        render_title(frame, chunks[0]);
+        match panel {
+            Panel::History => render_history(frame, chunks[1], state),
+            Panel::Stats => render_stats(frame, chunks[1], state),
+            Panel::Candidates => render_remaining_words(frame, chunks[1], state),
+            Panel::Logs => render_logs(frame, chunks[1], state),
+        }
+        let hint = Paragraph::new(" ←/→ Next panel  •  z/Esc Back to the full layout")
+            .style(Style::default().fg(Colors::MUTED));
+        frame.render_widget(hint, chunks[2]);
+    }
+
     /// Render a compact layout for smaller terminals
     pub fn render_compact_layout(
         frame: &mut Frame,
@@ -152,6 +184,8 @@ impl LayoutManager {
                     current_guess,
                     feedback_manager.get_feedback_input(),
                     feedback_manager.get_feedback_cursor(),
+                    feedback_manager.is_awaiting_confirmation(),
+                    state.rejected_char(),
                 );
             }
         } else {
@@ -225,6 +259,8 @@ impl LayoutManager {
                     current_guess,
                     feedback_manager.get_feedback_input(),
                     feedback_manager.get_feedback_cursor(),
+                    feedback_manager.is_awaiting_confirmation(),
+                    state.rejected_char(),
                 );
             }
         } else {
@@ -234,7 +270,7 @@ impl LayoutManager {
         // Just show the most important content
         if feedback_manager.is_in_feedback_mode() {
             render_feedback_help(frame, main_chunks[2]);
-        } else if !state.guess_history.is_empty() {
+        } else if !state.guess_history.is_empty() || state.settings.blank_board {
             render_history(frame, main_chunks[2], state);
         } else {
             render_stats(frame, main_chunks[2], state);
@@ -322,6 +358,34 @@ impl LayoutPreset {
         frame: &mut Frame,
         state: &TuiState,
         feedback_manager: &FeedbackInputManager,
+    ) {
+        // Screens other than the game use one layout at every size
+        match state.screen {
+            Screen::Menu => return render_start_menu(frame, state),
+            Screen::Explorer => return render_explorer(frame, state),
+            Screen::Statistics => return render_text_screen(frame, state),
+            Screen::Settings => return render_settings(frame, state),
+            Screen::Archive => return render_archive(frame, state),
+            Screen::Game => {}
+        }
+        if let Some(panel) = state.focus.zoomed() {
+            LayoutManager::render_zoomed_layout(frame, state, panel);
+        } else {
+            self.render_game(frame, state, feedback_manager);
+            if state.keyboard.is_open() {
+                render_keyboard(frame, keyboard_rect(frame.size()), state);
+            }
+        }
+        if state.constraint_dialog.is_some() {
+            render_constraint_dialog(frame, centered_rect(60, 40, frame.size()), state);
+        }
+    }
+
+    fn render_game(
+        &self,
+        frame: &mut Frame,
+        state: &TuiState,
+        feedback_manager: &FeedbackInputManager,
     ) {
         match self {
             LayoutPreset::Desktop => {
@@ -340,6 +404,19 @@ impl LayoutPreset {
     }
 }
 
+/// Where the on-screen keyboard docks: centered at the bottom, above the
+/// one-row footer
+fn keyboard_rect(area: Rect) -> Rect {
+    let width = KEYBOARD_WIDTH.min(area.width);
+    let height = KEYBOARD_HEIGHT.min(area.height.saturating_sub(1));
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + area.height.saturating_sub(1 + height),
+        width,
+        height,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
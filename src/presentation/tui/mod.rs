@@ -3,23 +3,40 @@
 //! This module provides a modern, responsive terminal interface for the Wordle solver
 //! using ratatui and crossterm for cross-platform compatibility.
 
+pub mod animation;
 pub mod app;
+pub mod benchmark;
 pub mod components;
 pub mod events;
 pub mod feedback;
+pub mod focus;
+pub mod hints;
+pub mod keyboard;
 pub mod layout;
 pub mod mode;
+pub mod screen;
 pub mod state;
+pub mod theme;
+pub mod tray;
 
 #[cfg(test)]
 mod state_tests;
 
 // Re-export main components
-pub use app::{TuiApp, run_tui};
+pub use animation::{RevealAnimation, TilePhase};
+pub use app::{TuiApp, missing_terminal, run_tui, run_tui_archive, run_tui_as, run_tui_pack};
+pub use benchmark::run_benchmark_tui;
 pub use events::{EventHandler, EventLoop, KeyAction, TuiEvent};
 pub use feedback::FeedbackInputManager;
+pub use focus::{FocusManager, Panel};
+pub use hints::{Hint, contextual_hint};
+pub use keyboard::{KeyboardKey, KeyboardMove, OnScreenKeyboard};
 pub use layout::{LayoutManager, LayoutPreset};
-pub use mode::InteractionMode;
+pub use mode::{InputTarget, InteractionMode};
+pub use screen::{GameMode, MenuItem, Screen, SettingField, SettingsMenu, StartMenu};
 pub use state::{
-    GameStats, GuessHistoryEntry, LogLevel, LogMessage, MessageType, StatusMessage, TuiState,
+    GameStats, GuessHistoryEntry, LogLevel, LogMessage, MessageType, RevealPrompt, StatusMessage,
+    TuiState,
 };
+pub use theme::{Theme, apply_colors, apply_glyphs, apply_theme};
+pub use tray::{ComparisonTray, PinChange, PinnedWord, TRAY_CAPACITY};
@@ -3,21 +3,18 @@
 //! This module handles the switching between input mode and operation mode,
 //! providing a clear separation of concerns for user interactions.
 
+use crate::core::types::Feedback;
+
 /// The current interaction mode of the TUI
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum InteractionMode {
     /// Input mode: User can type words and provide feedback
+    #[default]
     Input,
     /// Operation mode: User can perform commands like reset, help, quit
     Operation,
 }
 
-impl Default for InteractionMode {
-    fn default() -> Self {
-        Self::Input
-    }
-}
-
 impl InteractionMode {
     /// Toggle between input and operation modes
     pub fn toggle(self) -> Self {
@@ -57,12 +54,14 @@ impl InteractionMode {
     pub fn available_commands(self) -> Vec<(&'static str, &'static str)> {
         match self {
             Self::Input => vec![
-                ("a-z", "Type characters"),
-                ("0-2", "Provide feedback (0=absent, 1=present, 2=correct)"),
+                ("a-z", "Type the guess"),
+                ("0-2", "Feedback for a submitted guess (0=absent, 1=present, 2=correct)"),
                 ("Enter", "Submit guess/feedback"),
                 ("Backspace", "Delete character"),
                 ("Delete", "Clear input"),
                 ("Left/Right", "Move cursor"),
+                ("Up/Down/PgUp/PgDn", "Scroll guess history"),
+                ("Tab", "Complete the typed letters (again: next word)"),
                 ("Esc/Tab", "Switch to operation mode"),
             ],
             Self::Operation => vec![
@@ -70,10 +69,82 @@ impl InteractionMode {
                 ("f", "Get first guess"),
                 ("s", "Show statistics"),
                 ("r", "Reset game"),
+                ("u", "Undo last guess"),
                 ("c", "Clear input"),
+                ("m", "Back to start menu"),
+                ("Left/Right", "Focus panel"),
+                ("Up/Down/PgUp/PgDn", "Scroll guess history (pick a candidate when focused)"),
+                ("z", "Zoom focused panel (Esc to leave)"),
+                ("PgUp/PgDn", "Page through possible words when they are zoomed"),
+                ("g", "Group possible words into families"),
+                ("w", "Show one-letter links between possible words"),
+                ("p", "Pin or ban letters from outside the game"),
+                ("t/T", "Pin/unpin the picked candidate for comparison, clear the tray"),
+                ("v", "Show/hide possible words in no-spoiler mode"),
+                ("k", "Show/hide the on-screen keyboard (arrows + Enter or click to type)"),
+                ("H", "Turn on hard mode"),
                 ("q", "Quit application"),
                 ("Esc/Tab", "Switch to input mode"),
             ],
         }
     }
 }
+
+/// What typed characters go into while in input mode
+///
+/// A guess takes letters only; once it is submitted the feedback row takes the digits 0-2
+/// and their colour initials b, y and g only. Anything else is rejected on the spot
+/// instead of failing validation on submit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputTarget {
+    #[default]
+    Guess,
+    Feedback,
+}
+
+impl InputTarget {
+    /// Whether `c` may be typed into this target
+    pub fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Guess => c.is_ascii_alphabetic(),
+            Self::Feedback => Feedback::from_shorthand(c).is_some(),
+        }
+    }
+
+    /// Status line explaining why `c` was rejected
+    pub fn rejection_message(self, c: char) -> String {
+        match self {
+            Self::Guess if c.is_ascii_digit() => {
+                "Digits are for feedback - type the guess with letters a-z".to_string()
+            }
+            Self::Guess => format!("'{}' is not a letter - guesses use a-z only", c),
+            Self::Feedback if c.is_ascii_digit() => {
+                "Only use 0 (gray), 1 (yellow), or 2 (green) for feedback".to_string()
+            }
+            Self::Feedback => {
+                "Letters are for guesses - enter feedback as 0, 1, or 2 (or b, y, g)".to_string()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_target_routes_letters_and_digits() {
+        assert!(InputTarget::Guess.accepts('a'));
+        assert!(InputTarget::Guess.accepts('Z'));
+        assert!(!InputTarget::Guess.accepts('1'));
+        assert!(!InputTarget::Guess.accepts('-'));
+
+        assert!(InputTarget::Feedback.accepts('2'));
+        assert!(!InputTarget::Feedback.accepts('3'));
+        assert!(!InputTarget::Feedback.accepts('a'));
+        assert!(InputTarget::Feedback.accepts('g') && InputTarget::Feedback.accepts('Y'));
+
+        assert!(InputTarget::Guess.rejection_message('1').contains("feedback"));
+        assert!(InputTarget::Feedback.rejection_message('a').contains("0, 1, or 2"));
+    }
+}
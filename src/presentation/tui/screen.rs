@@ -0,0 +1,366 @@
+//! Screens and start-menu navigation for the TUI
+//!
+//! The app opens on the start menu; each entry routes to a [`Screen`] and,
+//! for the game entries, a [`GameMode`].
+
+use crate::core::container::StrategyType;
+use crate::infrastructure::{Theme, UserSettings, WordSourcePreset};
+
+/// Top-level view currently shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Screen {
+    /// Start menu
+    Menu,
+    /// Solver/game view
+    #[default]
+    Game,
+    /// Player statistics
+    Statistics,
+    /// Browse the answer list
+    Explorer,
+    /// Editable settings
+    Settings,
+    /// Pick a past puzzle by number
+    Archive,
+}
+
+impl Screen {
+    /// Title shown in the screen's border
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Menu => " Start ",
+            Self::Game => " Game ",
+            Self::Statistics => " Statistics ",
+            Self::Explorer => " Word Explorer ",
+            Self::Settings => " Settings ",
+            Self::Archive => " Archive ",
+        }
+    }
+}
+
+/// How the game screen gets its feedback
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameMode {
+    /// You enter the feedback from another Wordle; the solver suggests guesses
+    #[default]
+    Assisted,
+    /// A hidden target scores your guesses; suggestions are hidden
+    VersusSolver,
+    /// The solver plays a hidden target on its own
+    AutoSolve,
+    /// Like versus, against the answer of a past puzzle; a lost game doesn't
+    /// reveal it
+    Archive { puzzle: u32 },
+    /// Like versus, against puzzle `index` (from 0) of the loaded puzzle pack
+    Pack { index: usize },
+}
+
+impl GameMode {
+    /// Whether finished games in this mode go to the player's statistics
+    pub fn records_statistics(self) -> bool {
+        !matches!(self, Self::AutoSolve)
+    }
+
+    /// Whether the player's guesses are scored against a hidden target
+    pub fn scores_guesses(self) -> bool {
+        matches!(self, Self::VersusSolver | Self::Archive { .. } | Self::Pack { .. })
+    }
+
+    /// The archive puzzle being played, if any
+    pub fn archive_puzzle(self) -> Option<u32> {
+        match self {
+            Self::Archive { puzzle } => Some(puzzle),
+            _ => None,
+        }
+    }
+
+    /// Position of the pack puzzle being played, if any
+    pub fn pack_index(self) -> Option<usize> {
+        match self {
+            Self::Pack { index } => Some(index),
+            _ => None,
+        }
+    }
+}
+
+/// Entries of the start menu, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuItem {
+    NewAssistedGame,
+    PlayVsSolver,
+    ArchivePuzzle,
+    AutoSolveDemo,
+    Statistics,
+    WordExplorer,
+    Settings,
+    Quit,
+}
+
+impl MenuItem {
+    pub const ALL: [MenuItem; 8] = [
+        MenuItem::NewAssistedGame,
+        MenuItem::PlayVsSolver,
+        MenuItem::ArchivePuzzle,
+        MenuItem::AutoSolveDemo,
+        MenuItem::Statistics,
+        MenuItem::WordExplorer,
+        MenuItem::Settings,
+        MenuItem::Quit,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::NewAssistedGame => "New assisted game",
+            Self::PlayVsSolver => "Play vs solver",
+            Self::ArchivePuzzle => "Archive puzzle",
+            Self::AutoSolveDemo => "Auto-solve demo",
+            Self::Statistics => "Statistics",
+            Self::WordExplorer => "Word explorer",
+            Self::Settings => "Settings",
+            Self::Quit => "Quit",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::NewAssistedGame => "Enter feedback from today's puzzle and get suggestions",
+            Self::PlayVsSolver => "Guess a hidden word, then see how the solver would do",
+            Self::ArchivePuzzle => "Replay a past puzzle by its number",
+            Self::AutoSolveDemo => "Watch the solver crack a random word",
+            Self::Statistics => "Games played, streaks and guess distribution",
+            Self::WordExplorer => "Search the answer list",
+            Self::Settings => "Strategy, hard mode, theme, word lists, guess budget, animations",
+            Self::Quit => "Leave the application",
+        }
+    }
+}
+
+/// Selection state of the start menu
+#[derive(Debug, Clone, Default)]
+pub struct StartMenu {
+    selected: usize,
+}
+
+impl StartMenu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index of the highlighted entry in [`MenuItem::ALL`]
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected(&self) -> MenuItem {
+        MenuItem::ALL[self.selected]
+    }
+
+    /// Move the highlight down, wrapping to the top
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % MenuItem::ALL.len();
+    }
+
+    /// Move the highlight up, wrapping to the bottom
+    pub fn select_previous(&mut self) {
+        self.selected = (self.selected + MenuItem::ALL.len() - 1) % MenuItem::ALL.len();
+    }
+}
+
+/// Editable rows of the settings screen, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingField {
+    Strategy,
+    HardMode,
+    Theme,
+    WordSource,
+    GuessBudget,
+    RepeatedGuesses,
+    Animations,
+    AvoidUsedWords,
+    BlankBoard,
+    SessionSummary,
+    NoSpoilers,
+}
+
+impl SettingField {
+    pub const ALL: [SettingField; 11] = [
+        SettingField::Strategy,
+        SettingField::HardMode,
+        SettingField::Theme,
+        SettingField::WordSource,
+        SettingField::GuessBudget,
+        SettingField::RepeatedGuesses,
+        SettingField::Animations,
+        SettingField::AvoidUsedWords,
+        SettingField::BlankBoard,
+        SettingField::SessionSummary,
+        SettingField::NoSpoilers,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Strategy => "Strategy",
+            Self::HardMode => "Hard mode",
+            Self::Theme => "Theme",
+            Self::WordSource => "Word lists",
+            Self::GuessBudget => "Guess budget",
+            Self::RepeatedGuesses => "Repeated guesses",
+            Self::Animations => "Animations",
+            Self::AvoidUsedWords => "Avoid used words",
+            Self::BlankBoard => "Classic board",
+            Self::SessionSummary => "Summary on exit",
+            Self::NoSpoilers => "No spoilers",
+        }
+    }
+
+    /// Current value of this field in `settings`, for display
+    pub fn value(self, settings: &UserSettings) -> String {
+        let on_off = |on: bool| if on { "on" } else { "off" }.to_string();
+        match self {
+            Self::Strategy => settings.strategy.name().to_string(),
+            Self::HardMode => on_off(settings.hard_mode),
+            Self::Theme => settings.theme.name().to_string(),
+            Self::WordSource => settings.word_source.name().to_string(),
+            Self::GuessBudget => settings.max_guesses.to_string(),
+            Self::RepeatedGuesses => if settings.reject_repeated_guesses {
+                "refused"
+            } else {
+                "allowed"
+            }
+            .to_string(),
+            Self::Animations => on_off(settings.animations),
+            Self::AvoidUsedWords => on_off(settings.avoid_used_words),
+            Self::BlankBoard => on_off(settings.blank_board),
+            Self::SessionSummary => on_off(settings.session_summary),
+            Self::NoSpoilers => on_off(settings.no_spoilers),
+        }
+    }
+
+    /// Whether changing this field needs the solver and game engine rebuilt
+    pub fn rebuilds_service(self) -> bool {
+        !matches!(
+            self,
+            Self::Theme
+                | Self::Animations
+                | Self::AvoidUsedWords
+                | Self::BlankBoard
+                | Self::SessionSummary
+                | Self::NoSpoilers
+        )
+    }
+
+    /// Step this field to its next (or previous) value, wrapping around
+    pub fn cycle(self, settings: &mut UserSettings, forward: bool) {
+        match self {
+            Self::Strategy => {
+                settings.strategy = cycle_value(&StrategyType::ALL, settings.strategy, forward);
+            }
+            Self::HardMode => settings.hard_mode = !settings.hard_mode,
+            Self::RepeatedGuesses => {
+                settings.reject_repeated_guesses = !settings.reject_repeated_guesses;
+            }
+            Self::Animations => settings.animations = !settings.animations,
+            Self::AvoidUsedWords => settings.avoid_used_words = !settings.avoid_used_words,
+            Self::BlankBoard => settings.blank_board = !settings.blank_board,
+            Self::SessionSummary => settings.session_summary = !settings.session_summary,
+            Self::NoSpoilers => settings.no_spoilers = !settings.no_spoilers,
+            Self::Theme => settings.theme = cycle_value(&Theme::ALL, settings.theme, forward),
+            Self::WordSource => {
+                settings.word_source =
+                    cycle_value(&WordSourcePreset::ALL, settings.word_source, forward);
+            }
+            Self::GuessBudget => {
+                let budgets: Vec<usize> = UserSettings::GUESS_BUDGETS.collect();
+                settings.max_guesses = cycle_value(&budgets, settings.max_guesses, forward);
+            }
+        }
+    }
+}
+
+/// The value after (or before) `current` in `values`; unknown values restart at the first
+fn cycle_value<T: Copy + PartialEq>(values: &[T], current: T, forward: bool) -> T {
+    match values.iter().position(|&v| v == current) {
+        Some(i) if forward => values[(i + 1) % values.len()],
+        Some(i) => values[(i + values.len() - 1) % values.len()],
+        None => values[0],
+    }
+}
+
+/// Selection state of the settings screen
+#[derive(Debug, Clone, Default)]
+pub struct SettingsMenu {
+    selected: usize,
+}
+
+impl SettingsMenu {
+    /// Index of the highlighted row in [`SettingField::ALL`]
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected(&self) -> SettingField {
+        SettingField::ALL[self.selected]
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % SettingField::ALL.len();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = (self.selected + SettingField::ALL.len() - 1) % SettingField::ALL.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_menu_navigation_wraps() {
+        let mut menu = StartMenu::new();
+        assert_eq!(menu.selected(), MenuItem::NewAssistedGame);
+
+        menu.select_previous();
+        assert_eq!(menu.selected(), MenuItem::Quit);
+        menu.select_next();
+        menu.select_next();
+        assert_eq!(menu.selected(), MenuItem::PlayVsSolver);
+    }
+
+    #[test]
+    fn test_auto_solve_is_not_recorded() {
+        assert!(GameMode::Assisted.records_statistics());
+        assert!(GameMode::VersusSolver.records_statistics());
+        assert!(!GameMode::AutoSolve.records_statistics());
+        let archive = GameMode::Archive { puzzle: 512 };
+        assert!(archive.records_statistics());
+        assert!(archive.scores_guesses());
+        assert_eq!(archive.archive_puzzle(), Some(512));
+        assert!(!GameMode::Assisted.scores_guesses());
+        assert!(GameMode::Pack { index: 2 }.scores_guesses());
+        assert_eq!(GameMode::Pack { index: 2 }.pack_index(), Some(2));
+    }
+
+    #[test]
+    fn test_setting_values_cycle() {
+        let mut settings = UserSettings::default();
+
+        SettingField::Strategy.cycle(&mut settings, false);
+        assert_eq!(settings.strategy, StrategyType::Hybrid);
+        SettingField::Strategy.cycle(&mut settings, true);
+        assert_eq!(settings.strategy, StrategyType::Entropy);
+
+        settings.max_guesses = *UserSettings::GUESS_BUDGETS.end();
+        SettingField::GuessBudget.cycle(&mut settings, true);
+        assert_eq!(settings.max_guesses, *UserSettings::GUESS_BUDGETS.start());
+
+        SettingField::HardMode.cycle(&mut settings, true);
+        assert_eq!(SettingField::HardMode.value(&settings), "on");
+
+        SettingField::BlankBoard.cycle(&mut settings, true);
+        assert_eq!(SettingField::BlankBoard.value(&settings), "on");
+        assert!(!SettingField::BlankBoard.rebuilds_service());
+        assert!(!SettingField::SessionSummary.rebuilds_service());
+        assert!(!SettingField::NoSpoilers.rebuilds_service());
+    }
+}
@@ -1,6 +1,30 @@
+use std::cell::Cell;
 use std::collections::VecDeque;
-
-use super::mode::InteractionMode;
+use std::ops::Range;
+use std::sync::Arc;
+
+use ratatui::layout::Rect;
+
+use super::animation::RevealAnimation;
+use super::focus::FocusManager;
+use super::keyboard::OnScreenKeyboard;
+use super::mode::{InputTarget, InteractionMode};
+use super::screen::{Screen, SettingsMenu, StartMenu};
+use super::tray::ComparisonTray;
+use crate::application::PackProgress;
+use crate::core::clock::{Clock, system_clock};
+use crate::core::metrics::OperationTiming;
+use crate::core::morphology::MorphologyTags;
+use crate::core::types::{
+    Disambiguator, ManualConstraint, PrefixScore, RankedCandidate, SuggestionSet, TurnEntropy,
+    WordCluster,
+};
+use crate::domain::{CandidateDiff, WordGraphReport};
+use crate::infrastructure::{TurnTime, UserSettings, average_duration};
+use crate::presentation::spoilers::{RevealPolicy, SpoilerGuard};
+
+/// How long a rejected character keeps the input box red
+const REJECTION_FLASH: std::time::Duration = std::time::Duration::from_millis(600);
 
 /// TUI application state
 #[derive(Debug, Clone)]
@@ -11,12 +35,24 @@ pub struct TuiState {
     pub guess_history: Vec<GuessHistoryEntry>,
     /// Current best guess suggestion
     pub current_suggestion: Option<String>,
+    /// Suggestions for each risk level, shown next to each other
+    pub suggestion_set: Option<SuggestionSet>,
+    /// A suggested word with the bits of information each of its letters brings
+    pub suggestion_heat: Option<(String, [f64; 5])>,
+    /// Top candidates with their score and answer chance, listed in the candidates panel
+    pub ranked_candidates: Vec<RankedCandidate>,
+    /// Guess that separates near-identical remaining words, advertised in the footer
+    pub disambiguator: Option<Disambiguator>,
     /// Number of remaining possible words
     pub remaining_words: usize,
-    /// Sample of remaining words for display
+    /// Sample of remaining words for display: one page of them
     pub remaining_words_sample: Vec<String>,
+    /// Position of the sample's first word among the remaining words
+    pub remaining_words_offset: usize,
     /// Whether the puzzle is solved
     pub is_solved: bool,
+    /// Whether the game ended without solving (out of guesses)
+    pub game_over: bool,
     /// Current cursor position in input
     pub cursor_position: usize,
     /// Whether in help mode
@@ -29,6 +65,131 @@ pub struct TuiState {
     pub stats: GameStats,
     /// Current interaction mode
     pub interaction_mode: InteractionMode,
+    /// Score of the letters typed so far, shown under the input box once typing pauses
+    pub prefix_score: Option<PrefixScore>,
+    /// Words the last Tab completed the typed letters to, cycled by further Tabs
+    pub completion: Option<Completion>,
+    /// Last character refused by the input box and when, for a brief red flash
+    pub rejected_input: Option<(char, std::time::Instant)>,
+    /// Source of the time for the turn timer, flashes and log entries
+    pub clock: Arc<dyn Clock>,
+    /// When the current game started
+    pub started_at: std::time::Instant,
+    /// When the player started on the current guess
+    pub turn_started: std::time::Instant,
+    /// Time the solver took on the suggestion for the current turn
+    pub pending_solver_time: Option<std::time::Duration>,
+    /// On-screen keyboard for entering guesses with arrows or clicks
+    pub keyboard: OnScreenKeyboard,
+    /// Where the keyboard was drawn at the last draw, to tell which key a click hit
+    pub keyboard_area: Cell<Option<Rect>>,
+    /// Latest solver operation from the metrics registry, shown in the footer
+    pub last_operation: Option<OperationTiming>,
+    /// Screen currently shown
+    pub screen: Screen,
+    /// Start menu selection
+    pub menu: StartMenu,
+    /// Hide solver suggestions (when playing against the solver)
+    pub hide_suggestion: bool,
+    /// Search text on the word explorer screen
+    pub explorer_query: String,
+    /// Words matching the explorer search, with their word-form tags
+    pub explorer_matches: Vec<(String, MorphologyTags)>,
+    /// Puzzle number typed on the archive screen
+    pub archive_query: String,
+    /// Pre-rendered lines for the statistics, settings and archive screens
+    pub screen_lines: Vec<String>,
+    /// Saved preferences, edited on the settings screen
+    pub settings: UserSettings,
+    /// Settings screen selection
+    pub settings_menu: SettingsMenu,
+    /// Focused and zoomed game panels
+    pub focus: FocusManager,
+    /// Rows the history panel is scrolled back from the latest guess (0 follows new guesses)
+    pub history_scroll: usize,
+    /// Entries the history panel had room for at the last draw, to stop scrolling at the top
+    pub history_rows: Cell<usize>,
+    /// Tile reveal of the latest guess, while it is playing
+    pub reveal: Option<RevealAnimation>,
+    /// Show the possible words panel as word families
+    pub show_clusters: bool,
+    /// Families among the remaining words, largest first
+    pub clusters: Vec<WordCluster>,
+    /// Show the possible words panel as one-letter-change groups
+    pub show_word_graph: bool,
+    /// Those groups, computed only while shown
+    pub word_graph: Option<WordGraphReport>,
+    /// Notable words the latest guess ruled out or kept
+    pub candidate_diff: Option<CandidateDiff>,
+    /// Family the remaining words collapsed into, warned about in the footer
+    pub trap: Option<WordCluster>,
+    /// Hard mode is off but every guess so far reused the revealed hints
+    pub hard_mode_detected: bool,
+    /// Few guesses left, so the suggestions are limited to possible answers
+    pub urgent: bool,
+    /// Letters confirmed green, pre-filled and locked in the input row in hard mode
+    pub locked_letters: [Option<char>; 5],
+    /// Text typed into the pin/ban dialog, while it's open
+    pub constraint_dialog: Option<String>,
+    /// Letters the player pinned or banned, as applied by the solver
+    pub manual_constraints: Vec<ManualConstraint>,
+    /// Top candidate highlighted while the candidates panel has the focus
+    pub candidate_cursor: usize,
+    /// Words pinned for side-by-side comparison, kept across turns
+    pub tray: ComparisonTray,
+    /// Progress through the puzzle pack being played
+    pub pack: Option<PackProgress>,
+    /// Hidden target the player hasn't found yet, masked in no-spoiler mode
+    pub spoiler_answer: Option<String>,
+    /// The player asked to see the possible words despite no-spoiler mode
+    pub candidates_revealed: bool,
+    /// Whether hidden answers are shown in full or masked
+    pub reveal_policy: RevealPolicy,
+    /// Waiting for `y` before showing an answer in full
+    pub reveal_prompt: Option<RevealPrompt>,
+}
+
+/// What answering `y` at the reveal prompt shows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevealPrompt {
+    /// The answer of the game just lost
+    Answer(String),
+    /// The auto-solve demo, which plays the answer out on the board
+    AutoSolve,
+}
+
+impl RevealPrompt {
+    /// The question, for screens without a status line
+    pub fn question(&self) -> &'static str {
+        match self {
+            Self::Answer(_) => " y Reveal the answer  •  any other key Continue",
+            Self::AutoSolve => " The demo shows its answer  •  y Watch  •  any other key Cancel",
+        }
+    }
+}
+
+/// Dictionary words the typed letters complete to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    /// Letters typed before the first Tab
+    pub prefix: String,
+    /// Matching words, remaining answers first
+    pub words: Vec<String>,
+    /// The word in the input box
+    pub index: usize,
+}
+
+impl Completion {
+    /// The word in the input box
+    pub fn current(&self) -> &str {
+        &self.words[self.index]
+    }
+
+    /// Move on to the next word, back to the first after the last
+    pub fn advance(&mut self) -> &str {
+        self.index = (self.index + 1) % self.words.len();
+        self.current()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -71,7 +232,27 @@ pub enum LogLevel {
 pub struct GameStats {
     pub total_guesses: usize,
     pub average_remaining_words: f64,
-    pub entropy_values: Vec<f64>,
+    /// Information the solver measured for each turn, oldest first
+    pub entropy: Vec<TurnEntropy>,
+    /// Think and solver time for each guess, oldest first
+    pub turn_times: Vec<TurnTime>,
+}
+
+impl GameStats {
+    /// Bits the feedback has given over all turns so far
+    pub fn bits_gained(&self) -> f64 {
+        self.entropy.iter().map(|turn| turn.bits_gained).sum()
+    }
+
+    /// Average time the player took per guess this game
+    pub fn average_think_time(&self) -> Option<std::time::Duration> {
+        average_duration(self.turn_times.iter().filter_map(|t| t.think))
+    }
+
+    /// Average time the solver took per suggestion this game
+    pub fn average_solver_time(&self) -> Option<std::time::Duration> {
+        average_duration(self.turn_times.iter().filter_map(|t| t.solver))
+    }
 }
 
 impl Default for TuiState {
@@ -80,15 +261,62 @@ impl Default for TuiState {
             input: String::new(),
             guess_history: Vec::new(),
             current_suggestion: None,
+            suggestion_set: None,
+            suggestion_heat: None,
+            ranked_candidates: Vec::new(),
+            disambiguator: None,
             remaining_words: 0,
             remaining_words_sample: Vec::new(),
+            remaining_words_offset: 0,
             is_solved: false,
+            game_over: false,
             cursor_position: 0,
             show_help: false,
             status_message: None,
             log_messages: VecDeque::with_capacity(100),
             stats: GameStats::default(),
             interaction_mode: InteractionMode::default(),
+            prefix_score: None,
+            completion: None,
+            rejected_input: None,
+            clock: system_clock(),
+            started_at: std::time::Instant::now(),
+            turn_started: std::time::Instant::now(),
+            pending_solver_time: None,
+            keyboard: OnScreenKeyboard::new(),
+            keyboard_area: Cell::new(None),
+            last_operation: None,
+            screen: Screen::default(),
+            menu: StartMenu::new(),
+            hide_suggestion: false,
+            explorer_query: String::new(),
+            explorer_matches: Vec::new(),
+            archive_query: String::new(),
+            screen_lines: Vec::new(),
+            settings: UserSettings::default(),
+            settings_menu: SettingsMenu::default(),
+            focus: FocusManager::new(),
+            history_scroll: 0,
+            history_rows: Cell::new(0),
+            reveal: None,
+            show_clusters: false,
+            clusters: Vec::new(),
+            show_word_graph: false,
+            word_graph: None,
+            candidate_diff: None,
+            trap: None,
+            hard_mode_detected: false,
+            urgent: false,
+            locked_letters: [None; 5],
+            constraint_dialog: None,
+            manual_constraints: Vec::new(),
+            candidate_cursor: 0,
+            tray: ComparisonTray::new(),
+            pack: None,
+            spoiler_answer: None,
+            candidates_revealed: false,
+            reveal_policy: RevealPolicy::from_env(),
+            reveal_prompt: None,
         }
     }
 }
@@ -98,7 +326,8 @@ impl Default for GameStats {
         Self {
             total_guesses: 0,
             average_remaining_words: 0.0,
-            entropy_values: Vec::new(),
+            entropy: Vec::new(),
+            turn_times: Vec::new(),
         }
     }
 }
@@ -111,6 +340,21 @@ impl TuiState {
         state
     }
 
+    /// Tell the time with `clock`, restarting the game and turn timers on it
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.started_at = clock.instant();
+        self.turn_started = clock.instant();
+        self.clock = clock;
+        self
+    }
+
+    /// Whether `word` was guessed earlier in this game, in any case
+    pub fn has_guessed(&self, word: &str) -> bool {
+        self.guess_history
+            .iter()
+            .any(|entry| entry.word.eq_ignore_ascii_case(word))
+    }
+
     /// Add a new guess to the history
     pub fn add_guess(&mut self, word: String, feedback: String, remaining_count: usize) {
         self.guess_history.push(GuessHistoryEntry {
@@ -119,7 +363,76 @@ impl TuiState {
             remaining_count,
         });
         self.stats.total_guesses += 1;
+        self.stats.turn_times.push(TurnTime {
+            think: Some(self.clock.elapsed(self.turn_started)),
+            solver: self.pending_solver_time.take().filter(|_| !self.hide_suggestion),
+        });
+        self.turn_started = self.clock.instant();
+        self.update_average_remaining_words();
+        self.history_scroll = 0;
+        if self.settings.animations {
+            self.reveal = Some(RevealAnimation::new(self.guess_history.len() - 1));
+        }
+    }
+
+    /// Step the tile reveal; returns false once nothing is animating
+    pub fn advance_animations(&mut self) -> bool {
+        if let Some(reveal) = &mut self.reveal {
+            reveal.advance();
+            if reveal.is_finished() {
+                self.reveal = None;
+            }
+        }
+        self.reveal.is_some()
+    }
+
+    /// Drop the latest guess from the history, e.g. after an undo
+    pub fn remove_last_guess(&mut self) -> Option<GuessHistoryEntry> {
+        let entry = self.guess_history.pop()?;
+        self.stats.total_guesses = self.stats.total_guesses.saturating_sub(1);
+        self.stats.turn_times.pop();
+        self.turn_started = self.clock.instant();
         self.update_average_remaining_words();
+        self.history_scroll = 0;
+        self.reveal = None;
+        self.is_solved = false;
+        self.game_over = false;
+        Some(entry)
+    }
+
+    /// Scroll the history panel back towards the first guess
+    pub fn scroll_history_up(&mut self, rows: usize) {
+        let max_scroll = self
+            .guess_history
+            .len()
+            .saturating_sub(self.history_rows.get().max(1));
+        self.history_scroll = (self.history_scroll + rows).min(max_scroll);
+    }
+
+    /// Scroll the history panel forward towards the latest guess
+    pub fn scroll_history_down(&mut self, rows: usize) {
+        self.history_scroll = self.history_scroll.saturating_sub(rows);
+    }
+
+    /// Guesses visible in a history panel with room for `rows` entries
+    ///
+    /// Also records `rows` so scrolling up stops once the first guess is shown.
+    pub fn history_window(&self, rows: usize) -> Range<usize> {
+        self.history_rows.set(rows);
+        let total = self.guess_history.len();
+        let back = self.history_scroll.min(total.saturating_sub(rows));
+        let end = total - back;
+        end.saturating_sub(rows)..end
+    }
+
+    /// Unplayed rows of the classic board: whether the row being typed is shown, and how
+    /// many empty rows follow it; none when the board is off or the game has ended
+    pub fn blank_board_rows(&self) -> (bool, usize) {
+        let left = self.settings.max_guesses.saturating_sub(self.guess_history.len());
+        if !self.settings.blank_board || self.is_solved || self.game_over || left == 0 {
+            return (false, 0);
+        }
+        (true, left - 1)
     }
 
     /// Set the current suggestion
@@ -131,25 +444,98 @@ impl TuiState {
     pub fn update_remaining_words(&mut self, count: usize, sample: Vec<String>) {
         self.remaining_words = count;
         self.remaining_words_sample = sample;
+        self.remaining_words_offset = 0;
         self.update_average_remaining_words();
     }
 
+    /// Show the remaining words from `offset` on, keeping the count
+    pub fn set_remaining_words_page(&mut self, offset: usize, words: Vec<String>) {
+        self.remaining_words_offset = offset;
+        self.remaining_words_sample = words;
+    }
+
     /// Set solved status
     pub fn set_solved(&mut self, solved: bool) {
         self.is_solved = solved;
     }
 
-    /// Add input character at cursor position
-    pub fn add_char(&mut self, c: char) {
-        if self.input.len() < 5 && c.is_ascii_alphabetic() {
+    /// Add input character at cursor position; false when it is not a letter or the guess is full
+    ///
+    /// With letters locked, typing always fills the next free tile.
+    pub fn add_char(&mut self, c: char) -> bool {
+        if self.input.len() < 5 && InputTarget::Guess.accepts(c) {
+            if self.has_locked_letters() {
+                self.cursor_position = self.input.len();
+            }
             self.input
                 .insert(self.cursor_position, c.to_ascii_lowercase());
             self.cursor_position += 1;
+            self.fill_locked_letters();
+            self.rejected_input = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Lock `letters` into the input row, restarting the guess if they changed
+    pub fn set_locked_letters(&mut self, letters: [Option<char>; 5]) {
+        if self.locked_letters != letters {
+            self.locked_letters = letters;
+            self.clear_input();
+        }
+    }
+
+    pub fn has_locked_letters(&self) -> bool {
+        self.locked_letters.iter().any(Option::is_some)
+    }
+
+    /// Whether the player typed anything besides the locked letters
+    pub fn has_typed_input(&self) -> bool {
+        (0..self.input.len()).any(|pos| self.locked_letters[pos].is_none())
+    }
+
+    /// Type the locked letters that come next, so the cursor stops on a free tile
+    fn fill_locked_letters(&mut self) {
+        if !self.has_locked_letters() {
+            return;
+        }
+        while let Some(&Some(letter)) = self.locked_letters.get(self.input.len()) {
+            self.input.push(letter);
         }
+        self.cursor_position = self.input.len();
     }
 
-    /// Remove character before cursor
+    /// Refuse a typed character: explain why and flash the input box
+    pub fn reject_input(&mut self, c: char, target: InputTarget) {
+        self.set_status(target.rejection_message(c), MessageType::Warning);
+        self.rejected_input = Some((c, self.clock.instant()));
+    }
+
+    /// Character still being flashed as rejected, if any
+    pub fn rejected_char(&self) -> Option<char> {
+        self.rejected_input
+            .filter(|(_, at)| self.clock.elapsed(*at) < REJECTION_FLASH)
+            .map(|(c, _)| c)
+    }
+
+    /// Score of the letters in the input box, once it has caught up with the typing
+    pub fn typed_prefix_score(&self) -> Option<&PrefixScore> {
+        self.prefix_score
+            .as_ref()
+            .filter(|score| score.prefix.eq_ignore_ascii_case(&self.input))
+    }
+
+    /// Remove character before cursor; locked letters stay, taking the free letter before them
     pub fn delete_char(&mut self) {
+        if self.has_locked_letters() {
+            while !self.input.is_empty() && self.locked_letters[self.input.len() - 1].is_some() {
+                self.input.pop();
+            }
+            self.input.pop();
+            self.fill_locked_letters();
+            return;
+        }
         if self.cursor_position > 0 {
             self.cursor_position -= 1;
             self.input.remove(self.cursor_position);
@@ -170,10 +556,17 @@ impl TuiState {
         }
     }
 
-    /// Clear current input
+    /// Replace the input with `text`, cursor at the end
+    pub fn set_input(&mut self, text: &str) {
+        self.input = text.to_string();
+        self.cursor_position = self.input.len();
+    }
+
+    /// Clear current input, keeping the locked letters
     pub fn clear_input(&mut self) {
         self.input.clear();
         self.cursor_position = 0;
+        self.fill_locked_letters();
     }
 
     /// Get current input as uppercase
@@ -186,12 +579,36 @@ impl TuiState {
         self.show_help = !self.show_help;
     }
 
+    /// What no-spoiler mode hides right now; every status, log and candidate list
+    /// goes through it
+    pub fn spoilers(&self) -> SpoilerGuard<'_> {
+        SpoilerGuard::new(self.settings.no_spoilers)
+            .with_answer(self.spoiler_answer.as_deref())
+            .with_candidates_revealed(self.candidates_revealed)
+    }
+
+    /// The player answered `y` at the reveal prompt: answers are shown in full from
+    /// now on, and the one asked about is no longer a spoiler
+    pub fn confirm_reveal(&mut self) -> Option<RevealPrompt> {
+        let prompt = self.reveal_prompt.take()?;
+        self.reveal_policy.reveal();
+        self.spoiler_answer = None;
+        Some(prompt)
+    }
+
+    /// Show or hide the possible words in no-spoiler mode; false when the mode is off
+    pub fn toggle_candidates_revealed(&mut self) -> bool {
+        if !self.settings.no_spoilers {
+            return false;
+        }
+        self.candidates_revealed = !self.candidates_revealed;
+        true
+    }
+
     /// Set status message
     pub fn set_status(&mut self, message: String, message_type: MessageType) {
-        self.status_message = Some(StatusMessage {
-            text: message,
-            message_type,
-        });
+        let text = self.spoilers().redact(&message).into_owned();
+        self.status_message = Some(StatusMessage { text, message_type });
     }
 
     /// Clear status message
@@ -201,8 +618,9 @@ impl TuiState {
 
     /// Add log message
     pub fn add_log(&mut self, level: LogLevel, message: String) {
+        let message = self.spoilers().redact(&message).into_owned();
         self.log_messages.push_back(LogMessage {
-            timestamp: std::time::Instant::now(),
+            timestamp: self.clock.instant(),
             level,
             message,
         });
@@ -213,9 +631,38 @@ impl TuiState {
         }
     }
 
-    /// Add entropy value to stats
-    pub fn add_entropy_value(&mut self, entropy: f64) {
-        self.stats.entropy_values.push(entropy);
+    /// Move the highlight over the top candidates, stopping at either end
+    pub fn move_candidate_cursor(&mut self, up: bool) {
+        let last = self.ranked_candidates.len().saturating_sub(1);
+        self.candidate_cursor = if up {
+            self.candidate_cursor.saturating_sub(1)
+        } else {
+            (self.candidate_cursor + 1).min(last)
+        };
+    }
+
+    /// The highlighted top candidate
+    pub fn selected_candidate(&self) -> Option<&RankedCandidate> {
+        self.ranked_candidates
+            .get(self.candidate_cursor.min(self.ranked_candidates.len().saturating_sub(1)))
+    }
+
+    /// Note how long the solver took on the suggestion the player now sees
+    pub fn record_solver_time(&mut self, elapsed: std::time::Duration) {
+        self.pending_solver_time = Some(elapsed);
+    }
+
+    /// Overwrite the timing of the latest guess, e.g. for one the solver played or
+    /// that was pasted, where the time since the last turn isn't think time
+    pub fn set_last_turn_time(&mut self, time: TurnTime) {
+        if let Some(last) = self.stats.turn_times.last_mut() {
+            *last = time;
+        }
+    }
+
+    /// Replace the per-turn entropy with the solver's latest
+    pub fn set_entropy(&mut self, entropy: Vec<TurnEntropy>) {
+        self.stats.entropy = entropy;
     }
 
     /// Update average remaining words
@@ -301,4 +748,10 @@ impl TuiState {
     pub fn interaction_mode(&self) -> InteractionMode {
         self.interaction_mode
     }
+
+    /// Switch to another screen
+    pub fn show_screen(&mut self, screen: Screen) {
+        self.screen = screen;
+        self.clear_status();
+    }
 }
@@ -1,6 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use crate::presentation::tui::{TuiState, InteractionMode};
+    use crate::presentation::tui::{InputTarget, TuiState, InteractionMode, LogLevel, MessageType};
+    use crate::presentation::tui::RevealPrompt;
+    use crate::presentation::spoilers::RevealPolicy;
+    use crate::core::clock::ManualClock;
+    use crate::core::types::{RankedCandidate, Word};
+    use std::sync::Arc;
+    use std::time::Duration;
 
     #[test]
     fn test_help_visibility_in_input_mode() {
@@ -66,4 +72,167 @@ mod tests {
         state.show_help = false;
         assert!(state.should_show_help());
     }
+
+    #[test]
+    fn test_history_scrolling() {
+        let mut state = TuiState::new();
+        for i in 0..10 {
+            state.add_guess(format!("word{}", i), "00000".to_string(), 100 - i);
+        }
+
+        // Follows the latest guesses until scrolled
+        assert_eq!(state.history_window(4), 6..10);
+        state.scroll_history_up(3);
+        assert_eq!(state.history_window(4), 3..7);
+
+        // Can't scroll past the first guess
+        state.scroll_history_up(50);
+        assert_eq!(state.history_window(4), 0..4);
+        state.scroll_history_down(1);
+        assert_eq!(state.history_window(4), 1..5);
+        assert_eq!(state.history_window(20), 0..10);
+
+        // A new guess scrolls back to the latest
+        state.add_guess("crane".to_string(), "22222".to_string(), 1);
+        assert_eq!(state.history_window(4), 7..11);
+    }
+
+    #[test]
+    fn test_guess_input_rejects_digits() {
+        let mut state = TuiState::new();
+        assert!(state.add_char('C'));
+        assert!(!state.add_char('1'));
+        assert_eq!(state.input, "c");
+        assert_eq!(state.rejected_char(), None);
+
+        state.reject_input('1', InputTarget::Guess);
+        assert_eq!(state.rejected_char(), Some('1'));
+        assert!(state.status_message.as_ref().unwrap().text.contains("feedback"));
+
+        // The next accepted letter clears the flash
+        assert!(state.add_char('r'));
+        assert_eq!(state.rejected_char(), None);
+        assert_eq!(state.input, "cr");
+    }
+
+    #[test]
+    fn test_turn_times_follow_guesses() {
+        let mut state = TuiState::new();
+        state.record_solver_time(Duration::from_millis(40));
+        state.add_guess("crane".to_string(), "01000".to_string(), 50);
+        let first = state.stats.turn_times[0];
+        assert!(first.think.is_some());
+        assert_eq!(first.solver, Some(Duration::from_millis(40)));
+
+        // Hidden suggestions don't count as solver time
+        state.hide_suggestion = true;
+        state.record_solver_time(Duration::from_millis(60));
+        state.add_guess("sloth".to_string(), "00000".to_string(), 10);
+        assert_eq!(state.stats.turn_times[1].solver, None);
+        assert_eq!(state.stats.average_solver_time(), Some(Duration::from_millis(40)));
+
+        state.remove_last_guess();
+        assert_eq!(state.stats.turn_times.len(), 1);
+    }
+
+    #[test]
+    fn test_long_turns_are_timed_by_the_clock() {
+        let clock = Arc::new(ManualClock::at(0));
+        let mut state = TuiState::new().with_clock(clock.clone());
+        clock.advance(Duration::from_secs(600));
+        state.add_guess("crane".to_string(), "01000".to_string(), 50);
+        let think = state.stats.turn_times[0].think;
+        assert_eq!(think, Some(Duration::from_secs(600)));
+
+        state.reject_input('1', InputTarget::Guess);
+        assert_eq!(state.rejected_char(), Some('1'));
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(state.rejected_char(), None);
+    }
+
+    #[test]
+    fn test_candidate_cursor_stays_on_the_list() {
+        let mut state = TuiState::new();
+        assert!(state.selected_candidate().is_none());
+        state.ranked_candidates = ["crane", "slate", "trace"]
+            .iter()
+            .map(|w| RankedCandidate {
+                word: Word::from_str(w).unwrap(),
+                score: 5.0,
+                answer_probability: 0.0,
+            })
+            .collect();
+
+        state.move_candidate_cursor(true);
+        assert_eq!(state.selected_candidate().unwrap().word.as_str(), "crane");
+        for _ in 0..5 {
+            state.move_candidate_cursor(false);
+        }
+        assert_eq!(state.selected_candidate().unwrap().word.as_str(), "trace");
+
+        // A shorter list after the next turn keeps the highlight on it
+        state.ranked_candidates.truncate(1);
+        assert_eq!(state.selected_candidate().unwrap().word.as_str(), "crane");
+    }
+
+    #[test]
+    fn test_locked_letters_fill_their_tiles() {
+        let mut state = TuiState::new();
+        // C and N confirmed green in the first and fourth place
+        state.set_locked_letters([Some('c'), None, None, Some('n'), None]);
+        assert_eq!(state.input, "c");
+        assert!(!state.has_typed_input());
+
+        assert!(state.add_char('r'));
+        assert!(state.add_char('a'));
+        assert_eq!(state.input, "cran");
+        assert!(state.add_char('E'));
+        assert_eq!(state.input, "crane");
+        assert!(!state.add_char('s'));
+
+        // Backspace skips the locked N and takes the free letter before it
+        state.delete_char();
+        assert_eq!(state.input, "cran");
+        state.delete_char();
+        assert_eq!(state.input, "cr");
+        state.delete_char();
+        state.delete_char();
+        assert_eq!(state.input, "c");
+
+        state.set_locked_letters([None; 5]);
+        assert_eq!(state.input, "");
+    }
+
+    #[test]
+    fn test_no_spoilers_mask_the_answer_in_messages() {
+        let mut state = TuiState::new();
+        state.spoiler_answer = Some("crane".to_string());
+        state.set_status("The word was CRANE".to_string(), MessageType::Warning);
+        let status = state.status_message.take().unwrap();
+        assert_eq!(status.text, "The word was CRANE");
+
+        state.settings.no_spoilers = true;
+        state.add_log(LogLevel::Info, "Target: crane".to_string());
+        assert_eq!(state.log_messages.back().unwrap().message, "Target: ?????");
+        assert!(state.spoilers().hides_candidates());
+        assert!(state.toggle_candidates_revealed());
+        assert!(!state.spoilers().hides_candidates());
+    }
+
+    #[test]
+    fn test_confirming_a_reveal_unmasks_the_answer() {
+        let mut state = TuiState::new();
+        state.reveal_policy = RevealPolicy::default();
+        state.spoiler_answer = Some("crane".to_string());
+        assert_eq!(state.reveal_policy.answer("crane"), "C___E");
+        assert_eq!(state.confirm_reveal(), None);
+
+        state.reveal_prompt = Some(RevealPrompt::Answer("crane".to_string()));
+        assert_eq!(
+            state.confirm_reveal(),
+            Some(RevealPrompt::Answer("crane".to_string()))
+        );
+        assert_eq!(state.reveal_policy.answer("crane"), "CRANE");
+        assert!(state.reveal_prompt.is_none() && state.spoiler_answer.is_none());
+    }
 }
@@ -0,0 +1,212 @@
+//! Color themes applied to each rendered frame
+//!
+//! Widgets draw with the dark palette in [`Colors`](super::components::Colors);
+//! other themes remap those colors on the finished buffer, so no widget needs
+//! to know which theme is active. Colors the terminal can't show are rounded to
+//! the sixteen ANSI ones or dropped for attributes, and glyphs it can't draw are
+//! swapped for ASCII, the same way.
+
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+
+pub use crate::infrastructure::Theme;
+use crate::presentation::colors::ColorSupport;
+use crate::presentation::glyphs::{GlyphSupport, ascii_fallback};
+
+/// The sixteen ANSI colors with the RGB values xterm gives them
+const BASIC_PALETTE: [(Color, [u8; 3]); 16] = [
+    (Color::Black, [0, 0, 0]),
+    (Color::Red, [205, 0, 0]),
+    (Color::Green, [0, 205, 0]),
+    (Color::Yellow, [205, 205, 0]),
+    (Color::Blue, [0, 0, 238]),
+    (Color::Magenta, [205, 0, 205]),
+    (Color::Cyan, [0, 205, 205]),
+    (Color::Gray, [229, 229, 229]),
+    (Color::DarkGray, [127, 127, 127]),
+    (Color::LightRed, [255, 0, 0]),
+    (Color::LightGreen, [0, 255, 0]),
+    (Color::LightYellow, [255, 255, 0]),
+    (Color::LightBlue, [92, 92, 255]),
+    (Color::LightMagenta, [255, 0, 255]),
+    (Color::LightCyan, [0, 255, 255]),
+    (Color::White, [255, 255, 255]),
+];
+
+/// Recolor a rendered frame for `theme`
+pub fn apply_theme(theme: Theme, buffer: &mut Buffer) {
+    if theme == Theme::Dark {
+        return;
+    }
+    for cell in &mut buffer.content {
+        cell.fg = map_foreground(theme, cell.fg);
+        cell.bg = map_background(theme, cell.bg);
+    }
+}
+
+/// Bring a rendered (and themed) frame within the colors the terminal can show
+///
+/// Without color, a tile's background becomes an attribute: reverse bold for
+/// green, reverse underline for yellow and dim for gray, so feedback still reads.
+pub fn apply_colors(colors: ColorSupport, buffer: &mut Buffer) {
+    match colors {
+        ColorSupport::Full => {}
+        ColorSupport::Basic => {
+            for cell in &mut buffer.content {
+                cell.fg = basic_color(cell.fg);
+                cell.bg = basic_color(cell.bg);
+            }
+        }
+        ColorSupport::Monochrome => {
+            for cell in &mut buffer.content {
+                let emphasis = match basic_color(cell.bg) {
+                    Color::Green | Color::LightGreen => Modifier::REVERSED | Modifier::BOLD,
+                    Color::Yellow | Color::LightYellow => Modifier::REVERSED | Modifier::UNDERLINED,
+                    Color::Gray | Color::DarkGray => Modifier::DIM,
+                    _ => Modifier::empty(),
+                };
+                cell.modifier.insert(emphasis);
+                cell.fg = Color::Reset;
+                cell.bg = Color::Reset;
+            }
+        }
+    }
+}
+
+/// Replace the glyphs of a rendered frame the terminal can't draw
+pub fn apply_glyphs(glyphs: GlyphSupport, buffer: &mut Buffer) {
+    if !glyphs.is_ascii() {
+        return;
+    }
+    for cell in &mut buffer.content {
+        if let Some(ascii) = ascii_fallback(cell.symbol()) {
+            cell.set_symbol(ascii);
+        }
+    }
+}
+
+fn map_foreground(theme: Theme, color: Color) -> Color {
+    match (theme, color) {
+        (Theme::Light, Color::Reset | Color::White) => Color::Black,
+        (Theme::Light, Color::Black) => Color::White,
+        (Theme::Light, Color::Gray) => Color::DarkGray,
+        (Theme::Light, Color::Cyan) => Color::Blue,
+        (Theme::Light, Color::Yellow) => Color::Rgb(160, 120, 0),
+        (Theme::HighContrast, Color::Reset | Color::Gray) => Color::White,
+        (Theme::HighContrast, Color::DarkGray) => Color::Gray,
+        (Theme::HighContrast, _) => brighten(color),
+        _ => color,
+    }
+}
+
+fn map_background(theme: Theme, color: Color) -> Color {
+    match (theme, color) {
+        (Theme::Light, Color::Reset | Color::Black) => Color::White,
+        (Theme::Light, Color::White) => Color::Black,
+        (Theme::HighContrast, Color::Reset) => Color::Black,
+        (Theme::HighContrast, _) => brighten(color),
+        _ => color,
+    }
+}
+
+/// The nearest of the sixteen ANSI colors to an RGB or 256-palette one
+fn basic_color(color: Color) -> Color {
+    let rgb = match color {
+        Color::Rgb(r, g, b) => [r, g, b],
+        Color::Indexed(index @ 0..=15) => return BASIC_PALETTE[usize::from(index)].0,
+        Color::Indexed(index @ 16..=231) => {
+            // The 6x6x6 cube: levels 0, 95, 135, 175, 215, 255
+            let level = |step: u8| if step == 0 { 0 } else { 55 + step * 40 };
+            let index = index - 16;
+            [level(index / 36), level(index / 6 % 6), level(index % 6)]
+        }
+        Color::Indexed(index) => {
+            let gray = 8 + (index - 232) * 10;
+            [gray; 3]
+        }
+        other => return other,
+    };
+    let distance = |[r, g, b]: [u8; 3]| {
+        let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2);
+        d(r, rgb[0]) + d(g, rgb[1]) + d(b, rgb[2])
+    };
+    BASIC_PALETTE
+        .iter()
+        .min_by_key(|(_, palette)| distance(*palette))
+        .map_or(color, |(basic, _)| *basic)
+}
+
+fn brighten(color: Color) -> Color {
+    match color {
+        Color::Red => Color::LightRed,
+        Color::Green => Color::LightGreen,
+        Color::Yellow => Color::LightYellow,
+        Color::Blue => Color::LightBlue,
+        Color::Magenta => Color::LightMagenta,
+        Color::Cyan => Color::LightCyan,
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+
+    #[test]
+    fn test_light_theme_inverts_text() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buffer.get_mut(0, 0).set_fg(Color::White);
+        buffer.get_mut(1, 0).set_fg(Color::Green);
+
+        let mut dark = buffer.clone();
+        apply_theme(Theme::Dark, &mut dark);
+        assert_eq!(dark, buffer);
+
+        apply_theme(Theme::Light, &mut buffer);
+        assert_eq!(buffer.get(0, 0).fg, Color::Black);
+        assert_eq!(buffer.get(0, 0).bg, Color::White);
+        assert_eq!(buffer.get(1, 0).fg, Color::Green);
+    }
+
+    #[test]
+    fn test_limited_colors_round_or_become_attributes() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buffer.get_mut(0, 0).set_fg(Color::Rgb(160, 120, 0));
+        buffer.get_mut(1, 0).set_bg(Color::Green);
+        buffer.get_mut(2, 0).set_bg(Color::Indexed(226));
+
+        let mut full = buffer.clone();
+        apply_colors(ColorSupport::Full, &mut full);
+        assert_eq!(full, buffer);
+
+        let mut basic = buffer.clone();
+        apply_colors(ColorSupport::Basic, &mut basic);
+        assert_eq!(basic.get(0, 0).fg, Color::Yellow);
+        assert_eq!(basic.get(1, 0).bg, Color::Green);
+        assert_eq!(basic.get(2, 0).bg, Color::LightYellow);
+
+        apply_colors(ColorSupport::Monochrome, &mut buffer);
+        assert!(buffer.content.iter().all(|cell| cell.fg == Color::Reset));
+        assert!(buffer.content.iter().all(|cell| cell.bg == Color::Reset));
+        assert!(buffer.get(0, 0).modifier.is_empty());
+        assert!(buffer.get(1, 0).modifier.contains(Modifier::BOLD));
+        assert!(buffer.get(2, 0).modifier.contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn test_ascii_glyphs_replace_borders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buffer.set_string(0, 0, "╭─a→", ratatui::style::Style::default());
+
+        let mut unicode = buffer.clone();
+        apply_glyphs(GlyphSupport::Unicode, &mut unicode);
+        assert_eq!(unicode, buffer);
+
+        apply_glyphs(GlyphSupport::Ascii, &mut buffer);
+        let symbols: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+        assert_eq!(symbols, "+-a>");
+    }
+}
@@ -0,0 +1,131 @@
+//! Comparison tray on the game screen
+//!
+//! In OPERATION mode, with the candidates panel focused, ↑/↓ pick a top
+//! candidate and `t` pins it to (or unpins it from) the tray. Pinned words stay
+//! across turns with their metrics recomputed for the words still possible, so
+//! two-guess combinations can be planned by hand.
+
+use crate::core::types::{SuggestionQuality, Word};
+
+/// Most words the tray holds
+pub const TRAY_CAPACITY: usize = 3;
+
+/// A pinned word with its metrics against the current possible words
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinnedWord {
+    pub word: Word,
+    pub quality: Option<SuggestionQuality>,
+}
+
+/// What pressing the pin key did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinChange {
+    Pinned,
+    Unpinned,
+    /// The tray already holds [`TRAY_CAPACITY`] words
+    Full,
+}
+
+/// Up to [`TRAY_CAPACITY`] pinned words, in the order they were pinned
+#[derive(Debug, Clone, Default)]
+pub struct ComparisonTray {
+    pinned: Vec<PinnedWord>,
+    /// Metrics of playing each pair of pinned words one after the other
+    pairs: Vec<(usize, usize, SuggestionQuality)>,
+}
+
+impl ComparisonTray {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn pinned(&self) -> &[PinnedWord] {
+        &self.pinned
+    }
+
+    /// Indices into [`pinned`](Self::pinned) of each pair, with its combined metrics
+    pub fn pairs(&self) -> &[(usize, usize, SuggestionQuality)] {
+        &self.pairs
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pinned.is_empty()
+    }
+
+    pub fn contains(&self, word: &Word) -> bool {
+        self.pinned.iter().any(|p| &p.word == word)
+    }
+
+    /// Pin `word`, or unpin it if it is already in the tray
+    ///
+    /// Metrics are filled in by the next [`refresh`](Self::refresh).
+    pub fn toggle(&mut self, word: Word) -> PinChange {
+        if let Some(index) = self.pinned.iter().position(|p| p.word == word) {
+            self.pinned.remove(index);
+            self.pairs.clear();
+            return PinChange::Unpinned;
+        }
+        if self.pinned.len() >= TRAY_CAPACITY {
+            return PinChange::Full;
+        }
+        self.pinned.push(PinnedWord {
+            word,
+            quality: None,
+        });
+        self.pairs.clear();
+        PinChange::Pinned
+    }
+
+    pub fn clear(&mut self) {
+        self.pinned.clear();
+        self.pairs.clear();
+    }
+
+    /// Recompute every metric against `possible_words`
+    pub fn refresh(&mut self, possible_words: &[Word]) {
+        for pinned in &mut self.pinned {
+            pinned.quality = Some(SuggestionQuality::evaluate(&pinned.word, possible_words));
+        }
+        self.pairs.clear();
+        for first in 0..self.pinned.len() {
+            for second in first + 1..self.pinned.len() {
+                let quality = SuggestionQuality::evaluate_pair(
+                    &self.pinned[first].word,
+                    &self.pinned[second].word,
+                    possible_words,
+                );
+                self.pairs.push((first, second, quality));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(w: &str) -> Word {
+        Word::from_str(w).unwrap()
+    }
+
+    #[test]
+    fn test_tray_pins_up_to_capacity_and_pairs_them() {
+        let mut tray = ComparisonTray::new();
+        for w in ["crane", "sloth", "pudgy"] {
+            assert_eq!(tray.toggle(word(w)), PinChange::Pinned);
+        }
+        assert_eq!(tray.toggle(word("mimic")), PinChange::Full);
+
+        let possible: Vec<Word> = ["crane", "crate", "trace", "slate"].map(word).to_vec();
+        tray.refresh(&possible);
+        assert!(tray.pinned().iter().all(|p| p.quality.is_some()));
+        assert_eq!(tray.pairs().len(), 3);
+        assert_eq!((tray.pairs()[2].0, tray.pairs()[2].1), (1, 2));
+
+        assert_eq!(tray.toggle(word("sloth")), PinChange::Unpinned);
+        assert!(!tray.contains(&word("sloth")));
+        assert!(tray.pairs().is_empty());
+        tray.refresh(&possible);
+        assert_eq!(tray.pairs().len(), 1);
+    }
+}
@@ -0,0 +1,215 @@
+//! Stable solver API
+//!
+//! Types in this module follow semver: they only change in breaking ways with a
+//! major version bump, and they don't expose the internal layer traits
+//! (`core`, `domain`, `infrastructure`, `application`), which may be refactored in
+//! any release. Library consumers should prefer this module.
+//!
+//! Escape hatches into the internal layers are available behind the `unstable`
+//! feature and carry no compatibility guarantees.
+
+use crate::{
+    application::WordleApplicationService,
+    core::{
+        error::{GameError, SolverError, WordleError},
+        types::{FeedbackPattern, Word},
+    },
+    Container,
+};
+use std::fmt;
+
+/// Version of the stable API, incremented on breaking changes to this module
+pub const API_VERSION: u32 = 1;
+
+/// Broad category of a stable API error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A word or feedback string was malformed
+    InvalidInput,
+    /// No words are consistent with the feedback given so far
+    NoCandidates,
+    /// Word lists could not be loaded
+    Data,
+    /// Any other failure
+    Other,
+}
+
+/// Error returned by the stable API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    kind: ErrorKind,
+    message: String,
+}
+
+impl Error {
+    fn invalid_input(message: String) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message,
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<WordleError> for Error {
+    fn from(error: WordleError) -> Self {
+        let kind = match &error {
+            WordleError::Game(GameError::InvalidWordLength { .. })
+            | WordleError::Game(GameError::WordNotFound(_))
+            | WordleError::Game(GameError::InvalidCharacters(_))
+            | WordleError::Game(GameError::HardModeViolation { .. })
+            | WordleError::Game(GameError::RepeatedGuess(_))
+            | WordleError::Solver(SolverError::InvalidFeedback(_)) => ErrorKind::InvalidInput,
+            WordleError::Solver(SolverError::NoPossibleWords)
+            | WordleError::Solver(SolverError::NoCandidates) => ErrorKind::NoCandidates,
+            WordleError::Data(_) => ErrorKind::Data,
+            _ => ErrorKind::Other,
+        };
+        Self {
+            kind,
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Result type of the stable API
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Solver state after submitting a guess
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct GuessOutcome {
+    /// Number of answers still consistent with all feedback
+    pub remaining: usize,
+    /// Whether the feedback was all green
+    pub solved: bool,
+    /// Suggested next guess, if the puzzle isn't solved
+    pub next_guess: Option<String>,
+}
+
+/// Handle to a solver session
+///
+/// Feed it the feedback from each guess and ask for the next suggestion:
+///
+/// ```no_run
+/// # async fn demo() -> wordle::stable::Result<()> {
+/// let mut solver = wordle::stable::SolverHandle::new().await?;
+/// let first = solver.best_guess()?;
+/// let outcome = solver.submit(&first, "01020")?;
+/// println!("{} words left, try {:?}", outcome.remaining, outcome.next_guess);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SolverHandle {
+    service: WordleApplicationService,
+}
+
+impl SolverHandle {
+    /// Create a solver with the default word lists and strategy
+    pub async fn new() -> Result<Self> {
+        let service = Container::new().create_application_service().await?;
+        Ok(Self { service })
+    }
+
+    /// Best word to guess next
+    pub fn best_guess(&mut self) -> Result<String> {
+        let word = if self.service.get_state().guess_history.is_empty() {
+            self.service.get_best_first_guess()?
+        } else {
+            self.service.get_best_next_guess()?
+        };
+        Ok(word.to_string())
+    }
+
+    /// Record a guess and its feedback (`0` absent, `1` present, `2` correct per letter)
+    pub fn submit(&mut self, word: &str, feedback: &str) -> Result<GuessOutcome> {
+        let word = Word::from_str(word).map_err(Error::invalid_input)?;
+        let feedback = FeedbackPattern::from_code_string(feedback).map_err(Error::invalid_input)?;
+        self.service.add_guess_result(&word, &feedback)?;
+
+        let solved = feedback.is_win();
+        let state = self.service.get_state();
+        Ok(GuessOutcome {
+            remaining: state.remaining_words_count,
+            solved,
+            next_guess: if solved {
+                None
+            } else {
+                state
+                    .current_suggestion
+                    .as_ref()
+                    .or_else(|| state.top_candidates.first().map(|(w, _)| w))
+                    .map(|w| w.to_string())
+            },
+        })
+    }
+
+    /// Number of answers still possible
+    pub fn remaining(&self) -> usize {
+        self.service.get_state().remaining_words_count
+    }
+
+    /// Guesses submitted so far as (word, feedback code) pairs
+    pub fn history(&self) -> Vec<(String, String)> {
+        self.service
+            .get_state()
+            .guess_history
+            .iter()
+            .map(|g| (g.word.to_string(), g.feedback.to_code_string()))
+            .collect()
+    }
+
+    /// Forget all submitted guesses
+    pub fn reset(&mut self) -> Result<()> {
+        Ok(self.service.reset_game()?)
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl SolverHandle {
+    /// Wrap an existing application service. Unstable: the service type may change.
+    pub fn from_service(service: WordleApplicationService) -> Self {
+        Self { service }
+    }
+
+    /// Access the underlying application service. Unstable: the service type may change.
+    pub fn service_mut(&mut self) -> &mut WordleApplicationService {
+        &mut self.service
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::error::DataError;
+
+    #[test]
+    fn test_error_kinds() {
+        let invalid: Error = WordleError::from(SolverError::InvalidFeedback("x".into())).into();
+        assert_eq!(invalid.kind(), ErrorKind::InvalidInput);
+
+        let empty: Error = WordleError::from(SolverError::NoPossibleWords).into();
+        assert_eq!(empty.kind(), ErrorKind::NoCandidates);
+
+        let data: Error = WordleError::from(DataError::InvalidFormat("bad".into())).into();
+        assert_eq!(data.kind(), ErrorKind::Data);
+        assert!(!data.message().is_empty());
+    }
+}